@@ -0,0 +1,308 @@
+//! I provide derive macros for the traits [`Triple`] and [`Quad`] of [`r2c2_statement`].
+//!
+//! [`Triple`]: https://docs.rs/r2c2_statement/latest/r2c2_statement/trait.Triple.html
+//! [`Quad`]: https://docs.rs/r2c2_statement/latest/r2c2_statement/trait.Quad.html
+//! [`r2c2_statement`]: https://docs.rs/r2c2_statement/
+//!
+//! Each position of the statement is mapped to a field of the struct,
+//! either explicitly with an attribute (`#[r2c2(subject)]`, `#[r2c2(predicate)]`,
+//! `#[r2c2(object)]` or `#[r2c2(graph_name)]`),
+//! or implicitly by the name of the field (`subject` or `s`, `predicate` or `p`,
+//! `object` or `o`, `graph_name` or `g`).
+//!
+//! The associated types of the trait are references to the types of the corresponding fields,
+//! which must therefore implement the corresponding term trait.
+//! For [`Quad`], the graph name field must be of type `Option<G>`,
+//! where `G` implements `GraphName`.
+//!
+//! ```
+//! # use r2c2_statement::{GraphNameProxy, Iri, ObjectProxy, SimpleTriple, SubjectProxy};
+//! use r2c2_derive::{Quad, Triple};
+//!
+//! #[derive(Triple)]
+//! struct MyTriple {
+//!     s: SubjectProxy<'static>,
+//!     p: Iri<'static>,
+//!     o: ObjectProxy<'static, Box<SimpleTriple<'static>>>,
+//! }
+//!
+//! #[derive(Quad)]
+//! struct MyQuad {
+//!     #[r2c2(subject)]
+//!     who: SubjectProxy<'static>,
+//!     #[r2c2(predicate)]
+//!     what: Iri<'static>,
+//!     #[r2c2(object)]
+//!     value: ObjectProxy<'static, Box<SimpleTriple<'static>>>,
+//!     #[r2c2(graph_name)]
+//!     source: Option<GraphNameProxy<'static>>,
+//! }
+//! ```
+#![deny(missing_docs)]
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{ToTokens, quote, quote_spanned};
+use syn::{
+    Data, DeriveInput, Error, Fields, GenericArgument, Member, PathArguments, Type,
+    parse_macro_input, spanned::Spanned,
+};
+
+/// Derive the trait `Triple` for a struct (see the [crate-level documentation](crate)).
+#[proc_macro_derive(Triple, attributes(r2c2))]
+pub fn derive_triple(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(
+        &input,
+        &[Position::Subject, Position::Predicate, Position::Object],
+    )
+    .unwrap_or_else(Error::into_compile_error)
+    .into()
+}
+
+/// Derive the trait `Quad` for a struct (see the [crate-level documentation](crate)).
+#[proc_macro_derive(Quad, attributes(r2c2))]
+pub fn derive_quad(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(
+        &input,
+        &[
+            Position::Subject,
+            Position::Predicate,
+            Position::Object,
+            Position::GraphName,
+        ],
+    )
+    .unwrap_or_else(Error::into_compile_error)
+    .into()
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Position {
+    Subject,
+    Predicate,
+    Object,
+    GraphName,
+}
+
+impl Position {
+    fn from_attribute(name: &str) -> Option<Self> {
+        match name {
+            "subject" => Some(Position::Subject),
+            "predicate" => Some(Position::Predicate),
+            "object" => Some(Position::Object),
+            "graph_name" => Some(Position::GraphName),
+            _ => None,
+        }
+    }
+
+    fn from_field_name(name: &str) -> Option<Self> {
+        match name {
+            "subject" | "s" => Some(Position::Subject),
+            "predicate" | "p" => Some(Position::Predicate),
+            "object" | "o" => Some(Position::Object),
+            "graph_name" | "g" => Some(Position::GraphName),
+            _ => None,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Position::Subject => "subject",
+            Position::Predicate => "predicate",
+            Position::Object => "object",
+            Position::GraphName => "graph_name",
+        }
+    }
+}
+
+/// A field of the struct, mapped to a position.
+struct Mapped<'a> {
+    member: Member,
+    ty: &'a Type,
+}
+
+fn expand(input: &DeriveInput, positions: &[Position]) -> syn::Result<TokenStream2> {
+    let is_quad = positions.contains(&Position::GraphName);
+    let trait_name = if is_quad { "Quad" } else { "Triple" };
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new(
+            Span::call_site(),
+            format!("`{trait_name}` can only be derived for structs"),
+        ));
+    };
+
+    // collect explicit and implicit mappings
+    let mut explicit: Vec<(Position, Mapped)> = vec![];
+    let mut implicit: Vec<(Position, Mapped)> = vec![];
+    for (i, field) in data.fields.iter().enumerate() {
+        let member = match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(i.into()),
+        };
+        let mut field_position = None;
+        for attr in field.attrs.iter().filter(|a| a.path().is_ident("r2c2")) {
+            attr.parse_nested_meta(|meta| {
+                let name = meta.path.to_token_stream().to_string();
+                let Some(pos) = Position::from_attribute(&name).filter(|p| positions.contains(p))
+                else {
+                    return Err(meta.error(format!(
+                        "unknown position `{name}` for `{trait_name}`, expected one of: {}",
+                        list(positions)
+                    )));
+                };
+                if field_position.is_some() {
+                    return Err(meta.error("a field can only have one position"));
+                }
+                field_position = Some(pos);
+                Ok(())
+            })?;
+        }
+        if let Some(pos) = field_position {
+            if let Some((_, other)) = explicit.iter().find(|(p, _)| *p == pos) {
+                return Err(Error::new(
+                    member.span(),
+                    format!(
+                        "duplicate `{}` position (already assigned to field `{}`)",
+                        pos.name(),
+                        other.member.to_token_stream()
+                    ),
+                ));
+            }
+            explicit.push((
+                pos,
+                Mapped {
+                    member,
+                    ty: &field.ty,
+                },
+            ));
+        } else if let Some(pos) = field
+            .ident
+            .as_ref()
+            .and_then(|id| Position::from_field_name(&id.to_string()))
+            .filter(|p| positions.contains(p))
+        {
+            implicit.push((
+                pos,
+                Mapped {
+                    member,
+                    ty: &field.ty,
+                },
+            ));
+        }
+    }
+
+    // resolve each position
+    let mut mapped = vec![];
+    for pos in positions {
+        let m = match explicit.iter().position(|(p, _)| p == pos) {
+            Some(i) => explicit.swap_remove(i).1,
+            None => {
+                let mut candidates = implicit.iter().filter(|(p, _)| p == pos);
+                match (candidates.next(), candidates.next()) {
+                    (Some((_, m)), None) => Mapped {
+                        member: m.member.clone(),
+                        ty: m.ty,
+                    },
+                    (Some(_), Some((_, m))) => {
+                        return Err(Error::new(
+                            m.member.span(),
+                            format!(
+                                "ambiguous `{}` position, use #[r2c2({})] to disambiguate",
+                                pos.name(),
+                                pos.name()
+                            ),
+                        ));
+                    }
+                    (None, _) => {
+                        let err_span = match &data.fields {
+                            Fields::Unit => input.ident.span(),
+                            fields => fields.span(),
+                        };
+                        return Err(Error::new(
+                            err_span,
+                            format!(
+                                "missing `{}` position: add a field named `{}` or mark a field with #[r2c2({})]",
+                                pos.name(),
+                                pos.name(),
+                                pos.name()
+                            ),
+                        ));
+                    }
+                }
+            }
+        };
+        mapped.push(m);
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let lt = quote! { '__r2c2 };
+    let assoc = |assoc: &str, ty: &Type| {
+        let assoc = syn::Ident::new(assoc, Span::call_site());
+        let ty = quote_spanned! { ty.span() => &#lt #ty };
+        quote! {
+            type #assoc<#lt> = #ty where Self: #lt;
+        }
+    };
+    let s = &mapped[0].member;
+    let p = &mapped[1].member;
+    let o = &mapped[2].member;
+    let mut items = vec![
+        assoc("Subject", mapped[0].ty),
+        assoc("Predicate", mapped[1].ty),
+        assoc("Object", mapped[2].ty),
+    ];
+    items.push(quote! {
+        fn subject(&self) -> Self::Subject<'_> { &self.#s }
+        fn predicate(&self) -> Self::Predicate<'_> { &self.#p }
+        fn object(&self) -> Self::Object<'_> { &self.#o }
+    });
+    if is_quad {
+        let g = &mapped[3].member;
+        let Some(gty) = option_inner(mapped[3].ty) else {
+            return Err(Error::new(
+                mapped[3].ty.span(),
+                "the `graph_name` field must be of type `Option<_>`",
+            ));
+        };
+        items.push(assoc("GraphName", gty));
+        items.push(quote! {
+            fn graph_name(&self) -> ::core::option::Option<Self::GraphName<'_>> {
+                self.#g.as_ref()
+            }
+        });
+    }
+    let trait_path = syn::Ident::new(trait_name, Span::call_site());
+    Ok(quote! {
+        impl #impl_generics ::r2c2_statement::#trait_path for #name #ty_generics #where_clause {
+            #(#items)*
+        }
+    })
+}
+
+/// If `ty` is of the form `Option<T>`, return `T`.
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let last = path.path.segments.last()?;
+    if last.ident != "Option" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &last.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        GenericArgument::Type(ty) if args.args.len() == 1 => Some(ty),
+        _ => None,
+    }
+}
+
+fn list(positions: &[Position]) -> String {
+    positions
+        .iter()
+        .map(|p| format!("`{}`", p.name()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -0,0 +1,110 @@
+use r2c2_derive::{Quad, Triple};
+use r2c2_statement::{
+    GraphNameProxy, Iri, ObjectProxy, Quad as _, SimpleQuad, SimpleTriple, Subject, SubjectProxy,
+    Triple as _, quad_eq, triple_eq,
+};
+
+#[derive(Triple)]
+struct ByName {
+    subject: SubjectProxy<'static>,
+    predicate: Iri<'static>,
+    object: ObjectProxy<'static, Box<SimpleTriple<'static>>>,
+}
+
+#[derive(Triple)]
+struct ByShortName<S: Subject> {
+    s: S,
+    p: Iri<'static>,
+    o: ObjectProxy<'static, Box<SimpleTriple<'static>>>,
+    #[allow(dead_code)]
+    comment: String,
+}
+
+#[derive(Triple)]
+struct ByAttribute(
+    #[r2c2(object)] ObjectProxy<'static, Box<SimpleTriple<'static>>>,
+    #[r2c2(subject)] SubjectProxy<'static>,
+    #[r2c2(predicate)] Iri<'static>,
+);
+
+#[derive(Quad)]
+struct MyQuad {
+    #[r2c2(subject)]
+    who: SubjectProxy<'static>,
+    #[r2c2(predicate)]
+    what: Iri<'static>,
+    // the attribute takes precedence over the name of the other field
+    #[r2c2(object)]
+    value: ObjectProxy<'static, Box<SimpleTriple<'static>>>,
+    #[allow(dead_code)]
+    o: String,
+    g: Option<GraphNameProxy<'static>>,
+}
+
+#[test]
+fn triple_by_name() {
+    let t = ByName {
+        subject: SubjectProxy::BlankNode("b1".into()),
+        predicate: iri("p"),
+        object: ObjectProxy::Iri(iri("o")),
+    };
+    assert!(triple_eq(&t, &expected()));
+}
+
+#[test]
+fn triple_by_short_name() {
+    let t = ByShortName {
+        s: SubjectProxy::BlankNode("b1".into()),
+        p: iri("p"),
+        o: ObjectProxy::Iri(iri("o")),
+        comment: "a comment".into(),
+    };
+    assert!(triple_eq(&t, &expected()));
+}
+
+#[test]
+fn triple_by_attribute() {
+    let t = ByAttribute(
+        ObjectProxy::Iri(iri("o")),
+        SubjectProxy::BlankNode("b1".into()),
+        iri("p"),
+    );
+    assert!(triple_eq(&t, &expected()));
+    assert_eq!(t.predicate(), &iri("p"));
+}
+
+#[test]
+fn quad() {
+    let q = MyQuad {
+        who: SubjectProxy::BlankNode("b1".into()),
+        what: iri("p"),
+        value: ObjectProxy::Iri(iri("o")),
+        o: "not the object".into(),
+        g: None,
+    };
+    let SimpleTriple {
+        subject,
+        predicate,
+        object,
+    } = expected();
+    let expected = SimpleQuad {
+        subject,
+        predicate,
+        object,
+        graph_name: None,
+    };
+    assert!(quad_eq(&q, &expected));
+    assert!(q.graph_name().is_none());
+}
+
+fn iri(suffix: &str) -> Iri<'static> {
+    Iri::new_unchecked(format!("https://example.org/ns/{suffix}"))
+}
+
+fn expected() -> SimpleTriple<'static> {
+    SimpleTriple {
+        subject: SubjectProxy::BlankNode("b1".into()),
+        predicate: iri("p"),
+        object: ObjectProxy::Iri(iri("o")),
+    }
+}
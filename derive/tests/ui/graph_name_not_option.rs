@@ -0,0 +1,12 @@
+use r2c2_derive::Quad;
+use r2c2_statement::{GraphNameProxy, Iri, ObjectProxy, SimpleTriple, SubjectProxy};
+
+#[derive(Quad)]
+struct MyQuad {
+    s: SubjectProxy<'static>,
+    p: Iri<'static>,
+    o: ObjectProxy<'static, Box<SimpleTriple<'static>>>,
+    g: GraphNameProxy<'static>,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use r2c2_derive::Triple;
+use r2c2_statement::{Iri, SubjectProxy};
+
+#[derive(Triple)]
+struct MyTriple {
+    s: SubjectProxy<'static>,
+    p: Iri<'static>,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use r2c2_derive::Triple;
+
+#[derive(Triple)]
+enum MyTriple {
+    A,
+}
+
+fn main() {}
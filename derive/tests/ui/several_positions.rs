@@ -0,0 +1,12 @@
+use r2c2_derive::Triple;
+use r2c2_statement::{Iri, ObjectProxy, SimpleTriple, SubjectProxy};
+
+#[derive(Triple)]
+struct MyTriple {
+    #[r2c2(subject, object)]
+    s: SubjectProxy<'static>,
+    p: Iri<'static>,
+    o: ObjectProxy<'static, Box<SimpleTriple<'static>>>,
+}
+
+fn main() {}
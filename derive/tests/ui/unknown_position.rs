@@ -0,0 +1,13 @@
+use r2c2_derive::Triple;
+use r2c2_statement::{GraphNameProxy, Iri, ObjectProxy, SimpleTriple, SubjectProxy};
+
+#[derive(Triple)]
+struct MyTriple {
+    s: SubjectProxy<'static>,
+    p: Iri<'static>,
+    o: ObjectProxy<'static, Box<SimpleTriple<'static>>>,
+    #[r2c2(graph_name)]
+    g: Option<GraphNameProxy<'static>>,
+}
+
+fn main() {}
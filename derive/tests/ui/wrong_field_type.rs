@@ -0,0 +1,11 @@
+use r2c2_derive::Triple;
+use r2c2_statement::{Iri, ObjectProxy, SimpleTriple};
+
+#[derive(Triple)]
+struct MyTriple {
+    s: String,
+    p: Iri<'static>,
+    o: ObjectProxy<'static, Box<SimpleTriple<'static>>>,
+}
+
+fn main() {}
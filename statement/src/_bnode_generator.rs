@@ -0,0 +1,154 @@
+use alloc::{format, string::String};
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// A utility type for minting fresh blank node labels.
+///
+/// Labels are made of the generator's prefix followed by a counter (e.g. `b0`, `b1`, …).
+/// The counter is atomic, so a generator can be shared between threads
+/// (it is [`Send`] and [`Sync`]), and every call to [`BnodeGenerator::fresh`] returns a new label.
+///
+/// Labels are only guaranteed to be unique among the labels produced by the same generator,
+/// and by the generators created from it with [`BnodeGenerator::scoped`].
+/// Independent generators should use distinct prefixes
+/// (none of which being a prefix of the other followed by digits).
+///
+/// ```
+/// # use r2c2_statement::*;
+/// let generator = BnodeGenerator::new("b");
+/// assert_eq!(generator.fresh(), "b0");
+/// assert_eq!(generator.fresh(), "b1");
+/// let scope = generator.scoped();
+/// assert_eq!(scope.fresh(), "b2_0");
+/// assert_eq!(generator.fresh(), "b3");
+/// ```
+#[derive(Debug)]
+pub struct BnodeGenerator {
+    prefix: String,
+    counter: AtomicUsize,
+}
+
+impl BnodeGenerator {
+    /// Create a new generator, producing labels starting with `prefix`.
+    ///
+    /// In order for the produced labels to be [valid](crate::is_valid_bnode_label),
+    /// `prefix` should itself be a valid blank node label.
+    pub fn new(prefix: impl Into<String>) -> Self {
+        BnodeGenerator {
+            prefix: prefix.into(),
+            counter: AtomicUsize::new(0),
+        }
+    }
+
+    /// Return a fresh label.
+    pub fn fresh(&self) -> String {
+        format!("{}{}", self.prefix, self.next())
+    }
+
+    /// Return a fresh label for which `taken` returns false.
+    ///
+    /// This is useful when the generated labels must not clash with pre-existing ones,
+    /// e.g. when adding blank nodes to existing data.
+    pub fn fresh_where_not(&self, mut taken: impl FnMut(&str) -> bool) -> String {
+        loop {
+            let label = self.fresh();
+            if !taken(&label) {
+                return label;
+            }
+        }
+    }
+
+    /// Return a fresh label that is not in `taken`.
+    ///
+    /// See also [`BnodeGenerator::fresh_where_not`].
+    #[cfg(feature = "std")]
+    pub fn fresh_not_in<S: core::hash::BuildHasher>(
+        &self,
+        taken: &std::collections::HashSet<String, S>,
+    ) -> String {
+        self.fresh_where_not(|label| taken.contains(label))
+    }
+
+    /// Create a new generator for a distinct scope,
+    /// whose labels will never clash with the labels of `self`, nor of any other scope created from `self`.
+    ///
+    /// This is done by reserving a number from `self`'s counter, and using it in the prefix of the new generator.
+    /// This can be used, for example, to split the generation of labels between several sources
+    /// before merging them.
+    pub fn scoped(&self) -> Self {
+        BnodeGenerator::new(format!("{}{}_", self.prefix, self.next()))
+    }
+
+    fn next(&self) -> usize {
+        self.counter.fetch_add(1, Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn sequence() {
+        let generator = BnodeGenerator::new("x");
+        let labels: Vec<_> = (0..3).map(|_| generator.fresh()).collect();
+        assert_eq!(labels, ["x0", "x1", "x2"]);
+        assert!(labels.iter().all(|l| crate::is_valid_bnode_label(l)));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn not_in() {
+        let generator = BnodeGenerator::new("b");
+        let taken: HashSet<_> = ["b0", "b1", "b3"].into_iter().map(String::from).collect();
+        assert_eq!(generator.fresh_not_in(&taken), "b2");
+        assert_eq!(generator.fresh_not_in(&taken), "b4");
+        assert_eq!(generator.fresh_where_not(|l| l.ends_with('5')), "b6");
+    }
+
+    #[test]
+    fn threads() {
+        const THREADS: usize = 8;
+        const LABELS: usize = 1000;
+        let generator = BnodeGenerator::new("b");
+        let labels: Vec<String> = std::thread::scope(|s| {
+            let handles: Vec<_> = (0..THREADS)
+                .map(|i| {
+                    let generator = &generator;
+                    s.spawn(move || {
+                        // half of the threads also use their own scope
+                        let scope = (i % 2 == 0).then(|| generator.scoped());
+                        (0..LABELS)
+                            .map(|j| match &scope {
+                                Some(scope) if j % 2 == 0 => scope.fresh(),
+                                _ => generator.fresh(),
+                            })
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .flat_map(|h| h.join().unwrap())
+                .collect()
+        });
+        assert_eq!(labels.len(), THREADS * LABELS);
+        let distinct: HashSet<_> = labels.iter().collect();
+        assert_eq!(distinct.len(), labels.len());
+    }
+
+    #[test]
+    fn nested_scopes() {
+        let generator = BnodeGenerator::new("b");
+        let s1 = generator.scoped();
+        let s11 = s1.scoped();
+        let s2 = generator.scoped();
+        let mut labels = HashSet::new();
+        for _ in 0..20 {
+            for g in [&generator, &s1, &s11, &s2] {
+                assert!(labels.insert(g.fresh()));
+            }
+        }
+    }
+}
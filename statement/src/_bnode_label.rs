@@ -0,0 +1,332 @@
+use alloc::{borrow::Cow, format, string::String};
+use core::{fmt::Write, hash::Hasher};
+
+/// Wrapper around a [`Cow<str>`] signaling that it is a valid blank node label,
+/// i.e. that `_:` followed by it matches the [`BLANK_NODE_LABEL`] production of Turtle and SPARQL
+/// (which is slightly stricter than the one of N-Triples, as it does not allow `:`).
+///
+/// ## Contract
+/// * Consumers of [`BnodeId`]s can safely assume that the underlying text is a valid blank node label.
+/// * Producers of [`BnodeId`]s are responsible for ensuring that constraint.
+///
+/// Note that blank node identifiers, as conveyed by this crate's proxies, are *not* constrained;
+/// [`BnodeId::sanitized`] can be used to turn any of them into a valid label.
+///
+/// [`BLANK_NODE_LABEL`]: https://www.w3.org/TR/rdf12-turtle/#grammar-production-BLANK_NODE_LABEL
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct BnodeId<'a>(Cow<'a, str>);
+
+impl<'a> BnodeId<'a> {
+    /// Return a new [`BnodeId`], assuming the argument is a valid blank node label.
+    ///
+    /// ## Precondition
+    /// It is the responsibility of the caller to ensure that `txt` is a valid blank node label.
+    pub fn new_unchecked(txt: impl Into<Cow<'a, str>>) -> Self {
+        BnodeId(txt.into())
+    }
+
+    /// Return a valid [`BnodeId`] derived from `bnid`, which can be any blank node identifier.
+    ///
+    /// `bnid` is returned unchanged if it is a non-empty string of [`PN_CHARS`],
+    /// excluding `_` and `:`, and starting with a letter or a digit.
+    /// Otherwise, every character that does not fit this description is replaced by
+    /// its hexadecimal code point surrounded with `_` (e.g. `_20_` for a space),
+    /// and the empty identifier is sanitized into `__`.
+    ///
+    /// This transformation is deterministic, and it is also injective,
+    /// as `_` is itself escaped:
+    /// distinct identifiers are always sanitized into distinct labels.
+    ///
+    /// ```
+    /// # use r2c2_statement::*;
+    /// assert_eq!(BnodeId::sanitized("b1").as_str(), "b1");
+    /// assert_eq!(BnodeId::sanitized("a b").as_str(), "a_20_b");
+    /// assert_eq!(BnodeId::sanitized("a_20_b").as_str(), "a_5f_20_5f_b");
+    /// ```
+    ///
+    /// [`PN_CHARS`]: https://www.w3.org/TR/rdf12-turtle/#grammar-production-PN_CHARS
+    pub fn sanitized(bnid: &'a str) -> Self {
+        let keep = |i: usize, c: char| {
+            c != '_'
+                && c != ':'
+                && if i == 0 {
+                    is_pn_chars_base(c) || c.is_ascii_digit()
+                } else {
+                    is_pn_chars(c)
+                }
+        };
+        if bnid.is_empty() {
+            return BnodeId(Cow::Borrowed("__"));
+        }
+        if bnid.chars().enumerate().all(|(i, c)| keep(i, c)) {
+            return BnodeId(Cow::Borrowed(bnid));
+        }
+        let mut label = String::with_capacity(bnid.len() + 8);
+        for (i, c) in bnid.chars().enumerate() {
+            if keep(i, c) {
+                label.push(c);
+            } else {
+                write!(label, "_{:x}_", c as u32).unwrap();
+            }
+        }
+        BnodeId(Cow::Owned(label))
+    }
+
+    /// Return the inner [`Cow<str>`](Cow).
+    pub fn unwrap(self) -> Cow<'a, str> {
+        self.0
+    }
+
+    /// The text of this [`BnodeId`] (without the `_:` prefix).
+    pub fn as_str(&self) -> &str {
+        self.0.as_ref()
+    }
+
+    /// Borrow this [`BnodeId`] as another [`BnodeId`].
+    pub fn borrowed(&self) -> BnodeId<'_> {
+        BnodeId::new_unchecked(self.0.as_ref())
+    }
+}
+
+impl AsRef<str> for BnodeId<'_> {
+    fn as_ref(&self) -> &str {
+        self.0.as_ref()
+    }
+}
+
+/// Display this blank node label as `_:label`.
+impl core::fmt::Display for BnodeId<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "_:{}", self.0)
+    }
+}
+
+/// Whether `s` is a valid blank node label,
+/// i.e. whether `_:` followed by `s` matches the [`BLANK_NODE_LABEL`] production of N-Triples.
+///
+/// Note that blank node identifiers, as conveyed by this crate's proxies, are not constrained.
+/// This function is intended for implementations and serializers
+/// that need to check whether a given identifier can be used as is.
+///
+/// [`BLANK_NODE_LABEL`]: https://www.w3.org/TR/n-triples/#grammar-production-BLANK_NODE_LABEL
+pub fn is_valid_bnode_label(s: &str) -> bool {
+    let mut chars = s.chars();
+    let Some(first) = chars.next() else {
+        return false;
+    };
+    if !(is_pn_chars_u(first) || first.is_ascii_digit()) {
+        return false;
+    }
+    let mut last = first;
+    for c in chars {
+        if !(is_pn_chars(c) || c == '.') {
+            return false;
+        }
+        last = c;
+    }
+    last != '.'
+}
+
+/// Return `s` unchanged if it is a [valid blank node label](is_valid_bnode_label),
+/// otherwise return a valid label derived from `s`.
+///
+/// The derived label is the hexadecimal representation of a hash of `s`.
+/// This hash is stable (it does not depend on the platform or on the execution),
+/// so a given invalid label is always sanitized into the same valid label.
+/// Distinct invalid labels are very unlikely (but not guaranteed) to be sanitized into the same label.
+pub fn sanitize_bnode_label(s: &str) -> Cow<'_, str> {
+    if is_valid_bnode_label(s) {
+        Cow::Borrowed(s)
+    } else {
+        Cow::Owned(format!("{:x}", fnv1a(s.as_bytes())))
+    }
+}
+
+/// [PN_CHARS_BASE](https://www.w3.org/TR/n-triples/#grammar-production-PN_CHARS_BASE)
+fn is_pn_chars_base(c: char) -> bool {
+    matches!(c,
+        'A'..='Z'
+        | 'a'..='z'
+        | '\u{00C0}'..='\u{00D6}'
+        | '\u{00D8}'..='\u{00F6}'
+        | '\u{00F8}'..='\u{02FF}'
+        | '\u{0370}'..='\u{037D}'
+        | '\u{037F}'..='\u{1FFF}'
+        | '\u{200C}'..='\u{200D}'
+        | '\u{2070}'..='\u{218F}'
+        | '\u{2C00}'..='\u{2FEF}'
+        | '\u{3001}'..='\u{D7FF}'
+        | '\u{F900}'..='\u{FDCF}'
+        | '\u{FDF0}'..='\u{FFFD}'
+        | '\u{10000}'..='\u{EFFFF}'
+    )
+}
+
+/// [PN_CHARS_U](https://www.w3.org/TR/n-triples/#grammar-production-PN_CHARS_U)
+fn is_pn_chars_u(c: char) -> bool {
+    is_pn_chars_base(c) || c == '_' || c == ':'
+}
+
+/// [PN_CHARS](https://www.w3.org/TR/n-triples/#grammar-production-PN_CHARS)
+fn is_pn_chars(c: char) -> bool {
+    is_pn_chars_u(c)
+        || matches!(c,
+            '-'
+            | '0'..='9'
+            | '\u{00B7}'
+            | '\u{0300}'..='\u{036F}'
+            | '\u{203F}'..='\u{2040}'
+        )
+}
+
+/// 64-bit [FNV-1a](https://en.wikipedia.org/wiki/Fowler%E2%80%93Noll%E2%80%93Vo_hash_function) hash,
+/// used because, unlike [`core::hash::DefaultHasher`], it is guaranteed to be stable.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    let mut h = Fnv1aHasher::default();
+    h.write(bytes);
+    h.finish()
+}
+
+/// A [`Hasher`] computing the 64-bit [FNV-1a](https://en.wikipedia.org/wiki/Fowler%E2%80%93Noll%E2%80%93Vo_hash_function) hash,
+/// usable in `no_std` contexts.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Fnv1aHasher(u64);
+
+impl Default for Fnv1aHasher {
+    fn default() -> Self {
+        Fnv1aHasher(0xcbf29ce484222325)
+    }
+}
+
+impl Hasher for Fnv1aHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for b in bytes {
+            self.0 ^= *b as u64;
+            self.0 = self.0.wrapping_mul(0x100000001b3);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn valid() {
+        for label in [
+            "b", "b1", "1", "_", ":", "_b", "a.b", "a-b", "a:b", "é", "a\u{B7}b", "a..b",
+        ] {
+            assert!(is_valid_bnode_label(label), "{label}");
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        for label in [
+            "",
+            "-a",
+            ".a",
+            "a.",
+            "a b",
+            "a/b",
+            "a#b",
+            "\u{B7}",
+            "a\u{2041}",
+        ] {
+            assert!(!is_valid_bnode_label(label), "{label}");
+        }
+    }
+
+    #[test]
+    fn sanitize_valid() {
+        let label = "b1";
+        assert!(matches!(sanitize_bnode_label(label), Cow::Borrowed(l) if l == label));
+    }
+
+    #[test]
+    fn sanitize_invalid() {
+        for label in ["", "a b", "a.", "a#b"] {
+            let sanitized = sanitize_bnode_label(label);
+            assert!(is_valid_bnode_label(&sanitized), "{label} -> {sanitized}");
+            assert_eq!(sanitized, sanitize_bnode_label(label));
+        }
+        assert_ne!(sanitize_bnode_label("a b"), sanitize_bnode_label("a#b"));
+    }
+
+    /// Decode the output of [`BnodeId::sanitized`], proving that it is injective.
+    fn unsanitize(label: &str) -> String {
+        if label == "__" {
+            return String::new();
+        }
+        let mut parts = label.split('_');
+        let mut decoded = String::from(parts.next().unwrap());
+        while let Some(hex) = parts.next() {
+            decoded.push(char::from_u32(u32::from_str_radix(hex, 16).unwrap()).unwrap());
+            decoded.push_str(parts.next().unwrap());
+        }
+        decoded
+    }
+
+    #[test]
+    fn bnode_id_sanitized() {
+        let inputs = [
+            "",
+            "_",
+            "__",
+            "___",
+            "_5f_",
+            "a_5f_b",
+            "a_b",
+            "a b",
+            "a_20_b",
+            "a20b",
+            "a:b",
+            "a_3a_b",
+            ":",
+            "-",
+            "-a",
+            "a-",
+            ".",
+            "a.",
+            "a.b",
+            "a..b",
+            "1",
+            "é",
+            "a\u{B7}",
+            "\u{B7}a",
+            "a\u{2041}",
+            "\u{300}",
+            "a/b",
+            "a#b",
+            "a\nb",
+            "\u{1F600}",
+        ];
+        let mut seen = alloc::collections::BTreeSet::new();
+        for input in inputs {
+            let label = BnodeId::sanitized(input);
+            let valid = is_valid_bnode_label(label.as_str()) && !label.as_str().contains(':');
+            assert!(valid, "{input:?} -> {label}");
+            assert_eq!(BnodeId::sanitized(input), label);
+            assert_eq!(unsanitize(label.as_str()), input);
+            assert!(seen.insert(label.unwrap().into_owned()), "{input:?}");
+        }
+        assert!(matches!(
+            BnodeId::sanitized("b1").unwrap(),
+            Cow::Borrowed("b1")
+        ));
+        assert!(matches!(
+            BnodeId::sanitized("é-1\u{B7}").unwrap(),
+            Cow::Borrowed(_)
+        ));
+    }
+
+    #[test]
+    fn bnode_id_display() {
+        assert_eq!(BnodeId::new_unchecked("b1").to_string(), "_:b1");
+        assert_eq!(BnodeId::sanitized("a b").to_string(), "_:a_20_b");
+    }
+}
@@ -0,0 +1,192 @@
+use alloc::{
+    borrow::Cow,
+    boxed::Box,
+    collections::BTreeMap,
+    format,
+    string::{String, ToString},
+};
+
+use crate::{
+    _simple_triple::owned_object_proxy_with, GraphNameProxy, ObjectProxy, Quad, SimpleQuad,
+    SimpleTriple, SubjectProxy, Triple,
+};
+
+/// A utility type for consistently renaming blank nodes,
+/// e.g. before merging RDF data coming from different sources.
+///
+/// Each distinct label passed to [`BlankNodeRenamer::rename`] is mapped to a fresh label,
+/// made of the renamer's prefix followed by a counter.
+/// The mapping is remembered, so that the same input label always yields the same output label.
+///
+/// Output labels are only guaranteed to be unique among the labels produced by the same renamer.
+/// When merging several sources, either use the same renamer for all of them
+/// (and [`reset`](BlankNodeRenamer::reset) it between sources),
+/// or use renamers with distinct prefixes.
+///
+/// ```
+/// # use r2c2_statement::*;
+/// let mut renamer = BlankNodeRenamer::new("x");
+/// assert_eq!(renamer.rename("a"), "x0");
+/// assert_eq!(renamer.rename("b"), "x1");
+/// assert_eq!(renamer.rename("a"), "x0");
+/// ```
+///
+/// As labels are assigned in the order in which they are first encountered,
+/// a renamer can also be used to get reproducible output when serializing,
+/// regardless of the (arbitrary) labels handed out by the backend.
+/// Blank nodes nested in triple terms are renamed consistently with the other ones:
+///
+/// ```
+/// # use r2c2_statement::*;
+/// const EX: &str = "https://example.org/ns/";
+/// let graph = [
+///     triple!(_:n42, EX:p, _:n7),
+///     triple!(_:n7, EX:p, <<( _:n42, EX:q, _:n1 )>>),
+/// ];
+/// let mut renamer = BlankNodeRenamer::new("b");
+/// let labeled: Vec<_> = graph.iter().map(|t| renamer.rename_triple(t)).collect();
+/// assert_eq!(
+///     write_ntriples(&labeled),
+///     r#"_:b0 <https://example.org/ns/p> _:b1 .
+/// _:b1 <https://example.org/ns/p> <<( _:b0 <https://example.org/ns/q> _:b2 )>> .
+/// "#
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct BlankNodeRenamer {
+    prefix: String,
+    counter: usize,
+    mapping: BTreeMap<String, String>,
+}
+
+impl BlankNodeRenamer {
+    /// Create a new renamer, producing labels starting with `prefix`.
+    ///
+    /// In order for the produced labels to be [valid](crate::is_valid_bnode_label),
+    /// `prefix` should itself be a valid blank node label.
+    pub fn new(prefix: impl Into<String>) -> Self {
+        BlankNodeRenamer {
+            prefix: prefix.into(),
+            counter: 0,
+            mapping: BTreeMap::new(),
+        }
+    }
+
+    /// Return the label associated to `label`, creating a fresh one if necessary.
+    pub fn rename<'a>(&mut self, label: &str) -> Cow<'a, str> {
+        if let Some(renamed) = self.mapping.get(label) {
+            return Cow::Owned(renamed.clone());
+        }
+        let renamed = format!("{}{}", self.prefix, self.counter);
+        self.counter += 1;
+        self.mapping.insert(label.to_string(), renamed.clone());
+        Cow::Owned(renamed)
+    }
+
+    /// Forget the mapping built so far, without resetting the counter.
+    ///
+    /// After this, any label will be renamed to a label that was never produced before,
+    /// even if it had already been renamed.
+    pub fn reset(&mut self) {
+        self.mapping.clear();
+    }
+
+    /// Rename the blank node in `subject`, if any.
+    pub fn rename_subject<'a>(&mut self, subject: SubjectProxy<'a>) -> SubjectProxy<'a> {
+        match subject {
+            SubjectProxy::BlankNode(bnid) => SubjectProxy::BlankNode(self.rename(&bnid)),
+            iri => iri,
+        }
+    }
+
+    /// Rename the blank node in `graph_name`, if any.
+    pub fn rename_graph_name<'a>(&mut self, graph_name: GraphNameProxy<'a>) -> GraphNameProxy<'a> {
+        match graph_name {
+            GraphNameProxy::BlankNode(bnid) => GraphNameProxy::BlankNode(self.rename(&bnid)),
+            iri => iri,
+        }
+    }
+
+    /// Rename the blank nodes in `object`, if any, including blank nodes nested in triple terms.
+    ///
+    /// Triple terms are copied into [`SimpleTriple`]s.
+    pub fn rename_object<'a, T: Triple>(
+        &mut self,
+        object: ObjectProxy<'a, T>,
+    ) -> ObjectProxy<'a, Box<SimpleTriple<'a>>> {
+        match object {
+            ObjectProxy::Iri(iri) => ObjectProxy::Iri(iri),
+            ObjectProxy::BlankNode(bnid) => ObjectProxy::BlankNode(self.rename(&bnid)),
+            ObjectProxy::Literal(literal) => ObjectProxy::Literal(literal),
+            triple @ ObjectProxy::Triple(_) => {
+                owned_object_proxy_with(triple, &mut |bnid| self.rename(&bnid))
+            }
+        }
+    }
+
+    /// Copy `triple` into a [`SimpleTriple`], renaming all its blank nodes.
+    pub fn rename_triple<T: Triple>(&mut self, triple: T) -> SimpleTriple<'static> {
+        SimpleTriple::from_triple_with(triple, &mut |bnid| self.rename(&bnid))
+    }
+
+    /// Copy `quad` into a [`SimpleQuad`], renaming all its blank nodes.
+    pub fn rename_quad<Q: Quad>(&mut self, quad: Q) -> SimpleQuad<'static> {
+        SimpleQuad::from_quad_with(quad, &mut |bnid| self.rename(&bnid))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Iri, SimpleTriple, quad, triple};
+
+    use super::*;
+
+    #[test]
+    fn rename() {
+        let mut renamer = BlankNodeRenamer::new("b");
+        let a1 = renamer.rename("a");
+        let b1 = renamer.rename("b");
+        assert_ne!(a1, b1);
+        assert_eq!(renamer.rename("a"), a1);
+        assert_eq!(renamer.rename("b"), b1);
+        renamer.reset();
+        let a2 = renamer.rename("a");
+        assert_ne!(a2, a1);
+        assert_ne!(a2, b1);
+    }
+
+    #[test]
+    fn rename_subject() {
+        let mut renamer = BlankNodeRenamer::new("b");
+        let iri = SubjectProxy::Iri(Iri::new_unchecked("https://example.org/ns/s"));
+        assert_eq!(renamer.rename_subject(iri.clone()), iri);
+        let a = renamer.rename_subject(SubjectProxy::BlankNode("a".into()));
+        assert_eq!(a, SubjectProxy::BlankNode("b0".into()));
+    }
+
+    #[test]
+    fn rename_nested() {
+        let mut renamer = BlankNodeRenamer::new("b");
+        let t = triple!(_:x, EX:p, <<( _:y, EX:p, _:x )>>);
+        let expected = triple!(_:b0, EX:p, <<( _:b1, EX:p, _:b0 )>>);
+        assert_eq!(renamer.rename_triple(&t), expected);
+    }
+
+    #[test]
+    fn rename_quad() {
+        let mut renamer = BlankNodeRenamer::new("b");
+        let q = quad!(_:x, EX:p, _:y, _:x);
+        let renamed = renamer.rename_quad(&q);
+        assert_eq!(renamed.subject, SubjectProxy::BlankNode("b0".into()));
+        assert_eq!(
+            renamed.object,
+            ObjectProxy::<SimpleTriple>::BlankNode("b1".into())
+        );
+        assert_eq!(
+            renamed.graph_name,
+            Some(GraphNameProxy::BlankNode("b0".into()))
+        );
+    }
+
+    const EX: &str = "https://example.org/ns/";
+}
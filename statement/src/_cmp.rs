@@ -0,0 +1,331 @@
+use core::cmp::Ordering;
+
+use crate::{
+    GraphName, Literal, NeverTriple, Object, ObjectProxy, Predicate, Quad, Subject, TermProxy,
+    Triple,
+};
+
+/// Compare two [terms](https://www.w3.org/TR/rdf12-concepts/#dfn-rdf-term),
+/// possibly from different implementations, according to a canonical total order.
+///
+/// Terms are ordered by kind first: IRIs < blank nodes < literals < triple terms.
+/// Then:
+/// * IRIs and blank nodes are ordered lexicographically on their strings;
+/// * literals are ordered by lexical form, then datatype IRI,
+///   then lower-cased language tag (absent first), then base direction (absent first, then `ltr`, then `rtl`);
+/// * triple terms are ordered recursively with [`triple_cmp`].
+///
+/// This order is consistent with [`triple_eq`](crate::triple_eq)
+/// (and with the equality of [`Literal`], which ignores the case of language tags).
+/// It is also the order used for every position by [`triple_cmp`] and [`quad_cmp`]
+/// (subjects, predicates and graph names being compared as terms).
+pub fn term_cmp<A: Triple, B: Triple>(a: &ObjectProxy<A>, b: &ObjectProxy<B>) -> Ordering {
+    match (a, b) {
+        (ObjectProxy::Iri(ia), ObjectProxy::Iri(ib)) => ia.as_str().cmp(ib.as_str()),
+        (ObjectProxy::BlankNode(ba), ObjectProxy::BlankNode(bb)) => ba.cmp(bb),
+        (ObjectProxy::Literal(la), ObjectProxy::Literal(lb)) => literal_cmp(la, lb),
+        (ObjectProxy::Triple(ta), ObjectProxy::Triple(tb)) => triple_cmp(ta, tb),
+        _ => object_rank(a).cmp(&object_rank(b)),
+    }
+}
+
+/// Compare two triples, possibly from different implementations, according to a canonical total order.
+///
+/// Triples are ordered by subject, then predicate, then object,
+/// each term being ordered as per [`term_cmp`].
+pub fn triple_cmp<A: Triple, B: Triple>(a: &A, b: &B) -> Ordering {
+    subject_cmp(&a.subject(), &b.subject())
+        .then_with(|| predicate_cmp(&a.predicate(), &b.predicate()))
+        .then_with(|| term_cmp(&a.object().as_object_proxy(), &b.object().as_object_proxy()))
+}
+
+/// Compare two quads, possibly from different implementations, according to a canonical total order.
+///
+/// Quads are ordered by subject, then predicate, then object, then graph name,
+/// each term being ordered as per [`term_cmp`],
+/// and quads in the [default graph](https://www.w3.org/TR/rdf12-concepts/#dfn-default-graph)
+/// coming before quads in any named graph.
+///
+/// ```
+/// # use r2c2_statement::*;
+/// let quad = |s: &'static str, g: Option<&'static str>| SimpleQuad {
+///     subject: SubjectProxy::BlankNode(s.into()),
+///     predicate: Iri::new_unchecked("https://example.org/ns/p"),
+///     object: ObjectProxy::Iri(Iri::new_unchecked("https://example.org/ns/o")),
+///     graph_name: g.map(|g| GraphNameProxy::Iri(Iri::new_unchecked(g))),
+/// };
+/// let mut quads = vec![
+///     quad("b", None),
+///     quad("a", Some("https://example.org/g")),
+///     quad("a", None),
+/// ];
+/// quads.sort_by(quad_cmp);
+/// assert_eq!(
+///     quads,
+///     vec![
+///         quad("a", None),
+///         quad("a", Some("https://example.org/g")),
+///         quad("b", None),
+///     ]
+/// );
+/// ```
+pub fn quad_cmp<A: Quad, B: Quad>(a: &A, b: &B) -> Ordering {
+    subject_cmp(&a.subject(), &b.subject())
+        .then_with(|| predicate_cmp(&a.predicate(), &b.predicate()))
+        .then_with(|| term_cmp(&a.object().as_object_proxy(), &b.object().as_object_proxy()))
+        .then_with(|| match (a.graph_name(), b.graph_name()) {
+            (None, None) => Ordering::Equal,
+            (None, Some(_)) => Ordering::Less,
+            (Some(_), None) => Ordering::Greater,
+            (Some(ga), Some(gb)) => term_cmp(
+                &TermProxy::<NeverTriple>::from(ga.as_graph_name_proxy()),
+                &TermProxy::<NeverTriple>::from(gb.as_graph_name_proxy()),
+            ),
+        })
+}
+
+// utility functions
+
+// NB: subjects, predicates and graph names are compared as terms,
+// so that statement ordering can never diverge from term_cmp.
+
+fn subject_cmp<A: Subject, B: Subject>(a: &A, b: &B) -> Ordering {
+    term_cmp(
+        &TermProxy::<NeverTriple>::from(a.as_subject_proxy()),
+        &TermProxy::<NeverTriple>::from(b.as_subject_proxy()),
+    )
+}
+
+fn predicate_cmp<A: Predicate, B: Predicate>(a: &A, b: &B) -> Ordering {
+    term_cmp(
+        &TermProxy::<NeverTriple>::from(a.as_iri()),
+        &TermProxy::<NeverTriple>::from(b.as_iri()),
+    )
+}
+
+pub(crate) fn literal_cmp(a: &Literal, b: &Literal) -> Ordering {
+    a.lexical_form()
+        .cmp(&b.lexical_form())
+        .then_with(|| a.datatype_iri().as_str().cmp(b.datatype_iri().as_str()))
+        .then_with(|| a.language_tag().cmp(&b.language_tag()))
+        .then_with(|| {
+            let rank = |l: &Literal| l.base_direction().map(|d| d as u8);
+            rank(a).cmp(&rank(b))
+        })
+}
+
+fn object_rank<T: Triple>(object: &ObjectProxy<T>) -> u8 {
+    match object {
+        ObjectProxy::Iri(_) => 0,
+        ObjectProxy::BlankNode(_) => 1,
+        ObjectProxy::Literal(_) => 2,
+        ObjectProxy::Triple(_) => 3,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        BaseDir, GraphNameProxy, Iri, LangTag, SimpleQuad, SimpleTriple, SubjectProxy, proxy_eq,
+        quad_eq, triple_eq,
+    };
+
+    #[test]
+    fn kinds() {
+        let terms = [
+            ObjectProxy::Iri(Iri::new_unchecked("https://example.org/z")),
+            ObjectProxy::BlankNode("a".into()),
+            ObjectProxy::Literal(Literal::Typed(
+                "a".into(),
+                Iri::new_unchecked("https://example.org/a"),
+            )),
+            ObjectProxy::Triple(Box::new(triple(&mut Generator(1)))),
+        ];
+        for (i, ti) in terms.iter().enumerate() {
+            for (j, tj) in terms.iter().enumerate() {
+                assert_eq!(term_cmp(ti, tj), i.cmp(&j));
+            }
+        }
+    }
+
+    #[test]
+    fn lang_tag_case() {
+        let lit = |tag| {
+            ObjectProxy::<SimpleTriple>::Literal(Literal::LanguageString(
+                "chat".into(),
+                LangTag::new_unchecked(tag),
+                None,
+            ))
+        };
+        assert_eq!(term_cmp(&lit("en-GB"), &lit("en-gb")), Ordering::Equal);
+        assert_eq!(term_cmp(&lit("EN"), &lit("en-gb")), Ordering::Less);
+    }
+
+    #[test]
+    fn total_order_terms() {
+        let mut g = Generator(1234);
+        let terms: Vec<_> = (0..80).map(|_| object(&mut g, 2)).collect();
+        for a in &terms {
+            for b in &terms {
+                let ab = term_cmp(a, b);
+                assert_eq!(ab, term_cmp(b, a).reverse());
+                assert_eq!(ab == Ordering::Equal, proxy_eq(a, b));
+                for c in &terms {
+                    if ab != Ordering::Greater && term_cmp(b, c) != Ordering::Greater {
+                        assert_ne!(term_cmp(a, c), Ordering::Greater);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn statements_follow_terms() {
+        let mut g = Generator(99);
+        let quads: Vec<_> = (0..40).map(|_| quad(&mut g)).collect();
+        for a in &quads {
+            for b in &quads {
+                let expected = term_cmp(
+                    &TermProxy::<NeverTriple>::from(a.subject.clone()),
+                    &TermProxy::<NeverTriple>::from(b.subject.clone()),
+                )
+                .then_with(|| {
+                    term_cmp(
+                        &TermProxy::<NeverTriple>::from(a.predicate.clone()),
+                        &TermProxy::<NeverTriple>::from(b.predicate.clone()),
+                    )
+                })
+                .then_with(|| term_cmp(&a.object, &b.object));
+                if expected != Ordering::Equal {
+                    assert_eq!(quad_cmp(a, b), expected);
+                }
+                let triple = |q: &SimpleQuad<'static>| SimpleTriple {
+                    subject: q.subject.clone(),
+                    predicate: q.predicate.clone(),
+                    object: q.object.clone(),
+                };
+                assert_eq!(triple_cmp(&triple(a), &triple(b)), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn total_order_triples() {
+        let mut g = Generator(42);
+        let triples: Vec<_> = (0..60).map(|_| triple(&mut g)).collect();
+        for a in &triples {
+            for b in &triples {
+                let ab = triple_cmp(a, b);
+                assert_eq!(ab, triple_cmp(b, a).reverse());
+                assert_eq!(ab == Ordering::Equal, triple_eq(a, b));
+                // compare with another implementation of Triple
+                assert_eq!(ab, triple_cmp(&a, &SimpleTriple::from_triple(b)));
+                for c in &triples {
+                    if ab != Ordering::Greater && triple_cmp(b, c) != Ordering::Greater {
+                        assert_ne!(triple_cmp(a, c), Ordering::Greater);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn total_order_quads() {
+        let mut g = Generator(7);
+        let quads: Vec<_> = (0..60).map(|_| quad(&mut g)).collect();
+        for a in &quads {
+            for b in &quads {
+                let ab = quad_cmp(a, b);
+                assert_eq!(ab, quad_cmp(b, a).reverse());
+                assert_eq!(ab == Ordering::Equal, quad_eq(a, b));
+                for c in &quads {
+                    if ab != Ordering::Greater && quad_cmp(b, c) != Ordering::Greater {
+                        assert_ne!(quad_cmp(a, c), Ordering::Greater);
+                    }
+                }
+            }
+        }
+    }
+
+    /// A minimal deterministic pseudo-random generator (xorshift),
+    /// producing statements out of small pools of terms, so that collisions happen.
+    struct Generator(u64);
+
+    impl Generator {
+        fn next(&mut self, n: u64) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0 % n
+        }
+
+        fn pick(&mut self, pool: &[&'static str]) -> &'static str {
+            pool[self.next(pool.len() as u64) as usize]
+        }
+    }
+
+    const IRIS: &[&str] = &["https://example.org/a", "https://example.org/b"];
+    const BNODES: &[&str] = &["a", "b"];
+    const TAGS: &[&str] = &["en", "EN", "en-GB", "fr"];
+
+    fn subject(g: &mut Generator) -> SubjectProxy<'static> {
+        match g.next(2) {
+            0 => SubjectProxy::Iri(Iri::new_unchecked(g.pick(IRIS))),
+            _ => SubjectProxy::BlankNode(g.pick(BNODES).into()),
+        }
+    }
+
+    fn object(g: &mut Generator, depth: u8) -> ObjectProxy<'static, Box<SimpleTriple<'static>>> {
+        match g.next(if depth > 0 { 6 } else { 5 }) {
+            0 => ObjectProxy::Iri(Iri::new_unchecked(g.pick(IRIS))),
+            1 => ObjectProxy::BlankNode(g.pick(BNODES).into()),
+            2 => ObjectProxy::Literal(Literal::Typed(
+                g.pick(BNODES).into(),
+                Iri::new_unchecked(g.pick(IRIS)),
+            )),
+            3 => ObjectProxy::Literal(Literal::LanguageString(
+                g.pick(BNODES).into(),
+                LangTag::new_unchecked(g.pick(TAGS)),
+                None,
+            )),
+            4 => ObjectProxy::Literal(Literal::LanguageString(
+                g.pick(BNODES).into(),
+                LangTag::new_unchecked(g.pick(TAGS)),
+                Some([BaseDir::Ltr, BaseDir::Rtl][g.next(2) as usize]),
+            )),
+            _ => ObjectProxy::Triple(Box::new(triple_with_depth(g, depth - 1))),
+        }
+    }
+
+    fn triple_with_depth(g: &mut Generator, depth: u8) -> SimpleTriple<'static> {
+        SimpleTriple {
+            subject: subject(g),
+            predicate: Iri::new_unchecked(g.pick(IRIS)),
+            object: object(g, depth),
+        }
+    }
+
+    fn triple(g: &mut Generator) -> SimpleTriple<'static> {
+        triple_with_depth(g, 2)
+    }
+
+    fn quad(g: &mut Generator) -> SimpleQuad<'static> {
+        let SimpleTriple {
+            subject,
+            predicate,
+            object,
+        } = triple(g);
+        let graph_name = match g.next(3) {
+            0 => None,
+            1 => Some(GraphNameProxy::Iri(Iri::new_unchecked(g.pick(IRIS)))),
+            _ => Some(GraphNameProxy::BlankNode(g.pick(BNODES).into())),
+        };
+        SimpleQuad {
+            subject,
+            predicate,
+            object,
+            graph_name,
+        }
+    }
+}
@@ -0,0 +1,248 @@
+use alloc::vec::Vec;
+
+use crate::{GraphNameProxy, Quad, quad_eq};
+
+/// A trait for [RDF datasets].
+///
+/// An RDF dataset is a set of quads,
+/// so the order in which [`Dataset::quads`] yields them is not significant,
+/// and any duplicate should be ignored by consumers.
+///
+/// [RDF datasets]: https://www.w3.org/TR/rdf12-concepts/#dfn-rdf-dataset
+pub trait Dataset {
+    /// The type of [quads] in this dataset, as returned by [`Dataset::quads`]
+    ///
+    /// [quads]: https://www.w3.org/TR/rdf12-concepts/#dfn-quad
+    type Quad<'x>: Quad
+    where
+        Self: 'x;
+
+    /// Iterate over the [quads] of this dataset.
+    ///
+    /// [quads]: https://www.w3.org/TR/rdf12-concepts/#dfn-quad
+    fn quads(&self) -> impl Iterator<Item = Self::Quad<'_>> + '_;
+
+    /// Whether this dataset contains a [quad] equal to `q` (as per [`quad_eq`]).
+    ///
+    /// The default implementation scans [`Dataset::quads`];
+    /// implementations with an index should override it.
+    ///
+    /// [quad]: https://www.w3.org/TR/rdf12-concepts/#dfn-quad
+    fn contains_quad<Q: Quad>(&self, q: &Q) -> bool {
+        self.quads().any(|u| quad_eq(&u, q))
+    }
+
+    /// The number of [quads] in this dataset.
+    ///
+    /// As for [`Graph::len`](crate::Graph::len), the default implementation counts the quads
+    /// yielded by [`Dataset::quads`], and other implementations may return an estimate.
+    ///
+    /// [quads]: https://www.w3.org/TR/rdf12-concepts/#dfn-quad
+    fn len(&self) -> usize {
+        self.quads().count()
+    }
+
+    /// Whether this dataset contains no [quad].
+    ///
+    /// Unlike [`Dataset::len`], this must always be exact.
+    ///
+    /// [quad]: https://www.w3.org/TR/rdf12-concepts/#dfn-quad
+    fn is_empty(&self) -> bool {
+        self.quads().next().is_none()
+    }
+
+    /// Iterate over the [graph names] of this dataset, without duplicates
+    /// (the [default graph] is not included).
+    ///
+    /// The default implementation scans [`Dataset::quads`],
+    /// keeping track of the graph names it has already yielded;
+    /// implementations with an index should override it.
+    ///
+    /// ```
+    /// # use r2c2_statement::*;
+    /// const EX: &str = "https://example.org/ns/";
+    /// let d = vec![
+    ///     quad!(EX:s, EX:p, EX:o),
+    ///     quad!(EX:s, EX:p, EX:o, EX:g1),
+    ///     quad!(EX:s, EX:q, EX:o, EX:g1),
+    ///     quad!(EX:s, EX:p, EX:o, _:g2),
+    /// ];
+    /// assert_eq!(d.graph_names().count(), 2);
+    /// ```
+    ///
+    /// [graph names]: https://www.w3.org/TR/rdf12-concepts/#dfn-graph-name
+    /// [default graph]: https://www.w3.org/TR/rdf12-concepts/#dfn-default-graph
+    fn graph_names(&self) -> impl Iterator<Item = GraphNameProxy<'static>> + '_ {
+        let mut seen = Vec::new();
+        self.quads().filter_map(move |q| {
+            let graph_name = q.graph_name_proxy()?;
+            if seen.contains(&graph_name) {
+                return None;
+            }
+            let graph_name = graph_name.into_owned();
+            seen.push(graph_name.clone());
+            Some(graph_name)
+        })
+    }
+
+    /// Iterate over the [quads] of this dataset in the graph named `graph_name`,
+    /// or in the [default graph] if `graph_name` is `None`.
+    ///
+    /// ```
+    /// # use r2c2_statement::*;
+    /// const EX: &str = "https://example.org/ns/";
+    /// let d = vec![
+    ///     quad!(EX:s, EX:p, EX:o),
+    ///     quad!(EX:s, EX:p, EX:o, EX:g),
+    ///     quad!(EX:s, EX:q, EX:o, EX:g),
+    /// ];
+    /// let g = GraphNameProxy::Iri(Iri::new_unchecked(format!("{EX}g")));
+    /// assert_eq!(d.quads_in_graph(None).count(), 1);
+    /// assert_eq!(d.quads_in_graph(Some(&g)).count(), 2);
+    /// ```
+    ///
+    /// [quads]: https://www.w3.org/TR/rdf12-concepts/#dfn-quad
+    /// [default graph]: https://www.w3.org/TR/rdf12-concepts/#dfn-default-graph
+    fn quads_in_graph(
+        &self,
+        graph_name: Option<&GraphNameProxy<'_>>,
+    ) -> impl Iterator<Item = Self::Quad<'_>> {
+        self.quads()
+            .filter(move |q| q.graph_name_proxy().as_ref() == graph_name)
+    }
+}
+
+impl<Q: Quad> Dataset for [Q] {
+    type Quad<'x>
+        = &'x Q
+    where
+        Self: 'x;
+
+    fn quads(&self) -> impl Iterator<Item = Self::Quad<'_>> + '_ {
+        self.iter()
+    }
+
+    fn len(&self) -> usize {
+        <[Q]>::len(self)
+    }
+
+    fn is_empty(&self) -> bool {
+        <[Q]>::is_empty(self)
+    }
+}
+
+impl<Q: Quad> Dataset for Vec<Q> {
+    type Quad<'x>
+        = &'x Q
+    where
+        Self: 'x;
+
+    fn quads(&self) -> impl Iterator<Item = Self::Quad<'_>> + '_ {
+        self.iter()
+    }
+
+    fn len(&self) -> usize {
+        <Vec<Q>>::len(self)
+    }
+
+    fn is_empty(&self) -> bool {
+        <Vec<Q>>::is_empty(self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, S: std::hash::BuildHasher> Dataset
+    for std::collections::HashSet<crate::SimpleQuad<'a>, S>
+{
+    type Quad<'x>
+        = &'x crate::SimpleQuad<'a>
+    where
+        Self: 'x;
+
+    fn quads(&self) -> impl Iterator<Item = Self::Quad<'_>> + '_ {
+        self.iter()
+    }
+
+    fn contains_quad<Q: Quad>(&self, q: &Q) -> bool {
+        std::collections::HashSet::contains(self, &crate::SimpleQuad::from_quad(q))
+    }
+
+    fn len(&self) -> usize {
+        std::collections::HashSet::len(self)
+    }
+
+    fn is_empty(&self) -> bool {
+        std::collections::HashSet::is_empty(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Iri, SimpleQuad, quad};
+
+    const EX: &str = "https://example.org/ns/";
+
+    fn check<D: Dataset + ?Sized>(d: &D) {
+        let g1 = GraphNameProxy::Iri(Iri::new_unchecked("https://example.org/ns/g1"));
+        let g2 = GraphNameProxy::BlankNode("g2".into());
+        let g3 = GraphNameProxy::BlankNode("g3".into());
+        assert_eq!(d.len(), 5);
+        assert!(!d.is_empty());
+        assert!(d.contains_quad(&quad!(EX:s, EX:p, "a"@EN)));
+        assert!(d.contains_quad(&quad!(EX:s, EX:p, EX:o, EX:g1)));
+        assert!(!d.contains_quad(&quad!(EX:s, EX:p, EX:o)));
+        assert!(!d.contains_quad(&quad!(EX:s, EX:p, "a"@en, EX:g1)));
+        assert!(!d.contains_quad(&quad!(EX:s, EX:p, EX:o, _:g3)));
+
+        let mut names: Vec<_> = d.graph_names().collect();
+        names.sort_by_key(|gn| matches!(gn, GraphNameProxy::BlankNode(_)));
+        assert_eq!(names, [g1.clone(), g2.clone()]);
+
+        // default graph versus named graphs
+        let mut default: Vec<_> = d.quads_in_graph(None).map(SimpleQuad::from_quad).collect();
+        default.sort_by_key(|q| q.predicate.as_str().to_string());
+        assert_eq!(
+            default,
+            [
+                SimpleQuad::from_quad(quad!(EX:s, EX:p, "a"@en)),
+                SimpleQuad::from_quad(quad!(EX:s, EX:q, EX:o)),
+            ]
+        );
+        assert_eq!(d.quads_in_graph(Some(&g1)).count(), 2);
+        let in_g2: Vec<_> = d
+            .quads_in_graph(Some(&g2))
+            .map(SimpleQuad::from_quad)
+            .collect();
+        assert_eq!(
+            in_g2,
+            [SimpleQuad::from_quad(quad!(EX:s, EX:p, EX:o, _:g2))]
+        );
+        assert_eq!(d.quads_in_graph(Some(&g3)).count(), 0);
+    }
+
+    #[test]
+    fn datasets() {
+        let v = vec![
+            SimpleQuad::from_quad(quad!(EX:s, EX:p, "a"@en)),
+            SimpleQuad::from_quad(quad!(EX:s, EX:q, EX:o)),
+            SimpleQuad::from_quad(quad!(EX:s, EX:p, EX:o, EX:g1)),
+            SimpleQuad::from_quad(quad!(EX:s, EX:q, EX:o, EX:g1)),
+            SimpleQuad::from_quad(quad!(EX:s, EX:p, EX:o, _:g2)),
+        ];
+        check(&v);
+        check(&v[..]);
+        #[cfg(feature = "std")]
+        {
+            check(&v.iter().cloned().collect::<std::collections::HashSet<_>>());
+            assert!(Dataset::is_empty(
+                &std::collections::HashSet::<SimpleQuad>::new()
+            ));
+        }
+
+        let empty: Vec<SimpleQuad> = vec![];
+        assert_eq!(Dataset::len(&empty), 0);
+        assert!(Dataset::is_empty(&empty));
+        assert_eq!(empty.graph_names().count(), 0);
+    }
+}
@@ -0,0 +1,194 @@
+use alloc::{borrow::Cow, boxed::Box};
+
+use crate::{
+    _simple_triple::{owned_iri, owned_literal},
+    GraphName, GraphNameProxy, Iri, Object, ObjectProxy, Predicate, Quad, SimpleTriple, Subject,
+    SubjectProxy, Triple,
+};
+
+/// A [dyn-compatible] companion trait of [`Triple`].
+///
+/// [`Triple`] is not dyn-compatible (because of its generic associated types),
+/// but any [`Triple`] implements [`DynTriple`], so that triples from different implementations
+/// can be stored together, e.g. in a `Vec<Box<dyn DynTriple>>`.
+/// Conversely, `dyn DynTriple` implements [`Triple`].
+///
+/// The price to pay is that the methods of this trait return *owned* proxies
+/// (the terms of the triple are copied), and that triple terms are copied into [`SimpleTriple`]s.
+///
+/// [dyn-compatible]: https://doc.rust-lang.org/reference/items/traits.html#dyn-compatibility
+pub trait DynTriple {
+    /// The [subject](https://www.w3.org/TR/rdf12-concepts/#dfn-subject) of this triple.
+    fn dyn_subject(&self) -> SubjectProxy<'_>;
+
+    /// The [predicate](https://www.w3.org/TR/rdf12-concepts/#dfn-predicate) of this triple.
+    fn dyn_predicate(&self) -> Iri<'_>;
+
+    /// The [object](https://www.w3.org/TR/rdf12-concepts/#dfn-object) of this triple.
+    fn dyn_object(&self) -> ObjectProxy<'_, Box<dyn DynTriple + '_>>;
+}
+
+impl<T: Triple> DynTriple for T {
+    fn dyn_subject(&self) -> SubjectProxy<'_> {
+        self.subject().as_subject_proxy().into_owned()
+    }
+
+    fn dyn_predicate(&self) -> Iri<'_> {
+        owned_iri(self.predicate().as_iri())
+    }
+
+    fn dyn_object(&self) -> ObjectProxy<'_, Box<dyn DynTriple + '_>> {
+        match self.object().as_object_proxy() {
+            ObjectProxy::Iri(iri) => ObjectProxy::Iri(owned_iri(iri)),
+            ObjectProxy::BlankNode(bnid) => ObjectProxy::BlankNode(Cow::Owned(bnid.into_owned())),
+            ObjectProxy::Literal(literal) => ObjectProxy::Literal(owned_literal(literal)),
+            ObjectProxy::Triple(triple) => {
+                ObjectProxy::Triple(Box::new(SimpleTriple::from_triple(triple)))
+            }
+        }
+    }
+}
+
+impl Triple for dyn DynTriple + '_ {
+    type Subject<'x>
+        = SubjectProxy<'x>
+    where
+        Self: 'x;
+
+    type Predicate<'x>
+        = Iri<'x>
+    where
+        Self: 'x;
+
+    type Object<'x>
+        = ObjectProxy<'x, Box<dyn DynTriple + 'x>>
+    where
+        Self: 'x;
+
+    fn subject(&self) -> Self::Subject<'_> {
+        self.dyn_subject()
+    }
+
+    fn predicate(&self) -> Self::Predicate<'_> {
+        self.dyn_predicate()
+    }
+
+    fn object(&self) -> Self::Object<'_> {
+        self.dyn_object()
+    }
+}
+
+/// A [dyn-compatible] companion trait of [`Quad`].
+///
+/// [`Quad`] is not dyn-compatible (because of its generic associated types),
+/// but any [`Quad`] implements [`DynQuad`], so that quads from different implementations
+/// can be stored together, e.g. in a `Vec<Box<dyn DynQuad>>`.
+/// Conversely, `dyn DynQuad` implements [`Quad`].
+///
+/// See [`DynTriple`] for the limitations of this trait.
+///
+/// [dyn-compatible]: https://doc.rust-lang.org/reference/items/traits.html#dyn-compatibility
+pub trait DynQuad {
+    /// The [subject](https://www.w3.org/TR/rdf12-concepts/#dfn-subject) of this quad.
+    fn dyn_subject(&self) -> SubjectProxy<'_>;
+
+    /// The [predicate](https://www.w3.org/TR/rdf12-concepts/#dfn-predicate) of this quad.
+    fn dyn_predicate(&self) -> Iri<'_>;
+
+    /// The [object](https://www.w3.org/TR/rdf12-concepts/#dfn-object) of this quad.
+    fn dyn_object(&self) -> ObjectProxy<'_, Box<dyn DynTriple + '_>>;
+
+    /// The [graph name](https://www.w3.org/TR/rdf12-concepts/#dfn-graph-name) of this quad,
+    /// or `None` if it belongs to the [default graph](https://www.w3.org/TR/rdf12-concepts/#dfn-default-graph).
+    fn dyn_graph_name(&self) -> Option<GraphNameProxy<'_>>;
+}
+
+impl<Q: Quad> DynQuad for Q {
+    fn dyn_subject(&self) -> SubjectProxy<'_> {
+        self.subject().as_subject_proxy().into_owned()
+    }
+
+    fn dyn_predicate(&self) -> Iri<'_> {
+        owned_iri(self.predicate().as_iri())
+    }
+
+    fn dyn_object(&self) -> ObjectProxy<'_, Box<dyn DynTriple + '_>> {
+        match self.object().as_object_proxy() {
+            ObjectProxy::Iri(iri) => ObjectProxy::Iri(owned_iri(iri)),
+            ObjectProxy::BlankNode(bnid) => ObjectProxy::BlankNode(Cow::Owned(bnid.into_owned())),
+            ObjectProxy::Literal(literal) => ObjectProxy::Literal(owned_literal(literal)),
+            ObjectProxy::Triple(triple) => {
+                ObjectProxy::Triple(Box::new(SimpleTriple::from_triple(triple)))
+            }
+        }
+    }
+
+    fn dyn_graph_name(&self) -> Option<GraphNameProxy<'_>> {
+        self.graph_name()
+            .map(|gn| gn.as_graph_name_proxy().into_owned())
+    }
+}
+
+impl Quad for dyn DynQuad + '_ {
+    type Subject<'x>
+        = SubjectProxy<'x>
+    where
+        Self: 'x;
+
+    type Predicate<'x>
+        = Iri<'x>
+    where
+        Self: 'x;
+
+    type Object<'x>
+        = ObjectProxy<'x, Box<dyn DynTriple + 'x>>
+    where
+        Self: 'x;
+
+    type GraphName<'x>
+        = GraphNameProxy<'x>
+    where
+        Self: 'x;
+
+    fn subject(&self) -> Self::Subject<'_> {
+        self.dyn_subject()
+    }
+
+    fn predicate(&self) -> Self::Predicate<'_> {
+        self.dyn_predicate()
+    }
+
+    fn object(&self) -> Self::Object<'_> {
+        self.dyn_object()
+    }
+
+    fn graph_name(&self) -> Option<Self::GraphName<'_>> {
+        self.dyn_graph_name()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{quad, quad_eq, triple, triple_eq};
+
+    const EX: &str = "https://example.org/ns/";
+
+    #[test]
+    fn dyn_triple() {
+        let t1 = triple!(_:b1, EX:p, <<( EX:s, EX:p, _:b2 )>>);
+        let t2: Box<dyn DynTriple> = Box::new(t1.clone());
+        assert_eq!(t2.dyn_subject(), t1.subject);
+        assert_eq!(t2.dyn_predicate(), t1.predicate);
+        assert!(triple_eq(&t1, &t2));
+        assert!(triple_eq(&t2, &t1));
+    }
+
+    #[test]
+    fn dyn_quad() {
+        let q1 = quad!(_:b1, EX:p, _:b2, _:g);
+        let q2: &dyn DynQuad = &q1;
+        assert_eq!(q2.dyn_graph_name(), q1.graph_name);
+        assert!(quad_eq(&q1, &q2));
+    }
+}
@@ -0,0 +1,154 @@
+use crate::{GraphName, Object, ObjectProxy, Predicate, Quad, Subject, Triple};
+
+/// Whether two triples, possibly from different implementations, are equal.
+///
+/// Two triples are equal if their subjects, predicates and objects are respectively equal
+/// (see [`subject_eq`], [`predicate_eq`] and [`object_eq`]).
+/// Language tags are compared case-insensitively (as per [`LangTag`](crate::LangTag)'s equality),
+/// and triple terms are compared recursively.
+pub fn triple_eq<A: Triple, B: Triple>(a: &A, b: &B) -> bool {
+    subject_eq(&a.subject(), &b.subject())
+        && predicate_eq(&a.predicate(), &b.predicate())
+        && object_eq(&a.object(), &b.object())
+}
+
+/// Whether two quads, possibly from different implementations, are equal.
+///
+/// Two quads are equal if their subjects, predicates, objects and graph names are respectively equal
+/// (see [`triple_eq`] and [`graph_name_eq`]).
+pub fn quad_eq<A: Quad, B: Quad>(a: &A, b: &B) -> bool {
+    subject_eq(&a.subject(), &b.subject())
+        && predicate_eq(&a.predicate(), &b.predicate())
+        && object_eq(&a.object(), &b.object())
+        && match (a.graph_name(), b.graph_name()) {
+            (None, None) => true,
+            (Some(ga), Some(gb)) => graph_name_eq(&ga, &gb),
+            _ => false,
+        }
+}
+
+/// Whether two subjects, possibly from different implementations, are equal,
+/// as conveyed by their proxies.
+pub fn subject_eq<A: Subject + ?Sized, B: Subject + ?Sized>(a: &A, b: &B) -> bool {
+    a.as_subject_proxy() == b.as_subject_proxy()
+}
+
+/// Whether two predicates, possibly from different implementations, are equal,
+/// as conveyed by their IRIs.
+pub fn predicate_eq<A: Predicate + ?Sized, B: Predicate + ?Sized>(a: &A, b: &B) -> bool {
+    a.as_iri() == b.as_iri()
+}
+
+/// Whether two objects, possibly from different implementations, are equal,
+/// as conveyed by their proxies (see [`proxy_eq`]).
+///
+/// Literals are compared as per [`Literal`](crate::Literal)'s equality
+/// (in particular, language tags are compared case-insensitively),
+/// and triple terms are compared with [`triple_eq`].
+pub fn object_eq<A: Object + ?Sized, B: Object + ?Sized>(a: &A, b: &B) -> bool {
+    proxy_eq(&a.as_object_proxy(), &b.as_object_proxy())
+}
+
+/// Whether two graph names, possibly from different implementations, are equal,
+/// as conveyed by their proxies.
+pub fn graph_name_eq<A: GraphName + ?Sized, B: GraphName + ?Sized>(a: &A, b: &B) -> bool {
+    a.as_graph_name_proxy() == b.as_graph_name_proxy()
+}
+
+/// Whether two [`ObjectProxy`]s, possibly with different types of triple terms, are equal.
+///
+/// This is the equality used by [`triple_eq`] for objects, with the same rules
+/// (triple terms are compared recursively).
+/// See [`term_cmp`](crate::term_cmp) for the corresponding ordering.
+///
+/// ```
+/// # use r2c2_statement::*;
+/// let iri = Iri::new_unchecked("https://example.org/ns/o");
+/// let a = ObjectProxy::<NeverTriple>::Iri(iri.clone());
+/// let b = ObjectProxy::<SimpleTriple>::Iri(iri);
+/// assert!(proxy_eq(&a, &b));
+/// ```
+pub fn proxy_eq<A: Triple, B: Triple>(a: &ObjectProxy<A>, b: &ObjectProxy<B>) -> bool {
+    match (a, b) {
+        (ObjectProxy::Iri(ia), ObjectProxy::Iri(ib)) => ia == ib,
+        (ObjectProxy::BlankNode(ba), ObjectProxy::BlankNode(bb)) => ba == bb,
+        (ObjectProxy::Literal(la), ObjectProxy::Literal(lb)) => la == lb,
+        (ObjectProxy::Triple(ta), ObjectProxy::Triple(tb)) => triple_eq(ta, tb),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn eq() {
+        let t1 = triple("b1", "en-GB");
+        assert!(triple_eq(&t1, &t1));
+        assert!(triple_eq(&t1, &triple("b1", "en-gb")));
+        assert!(!triple_eq(&t1, &triple("b2", "en-GB")));
+        assert!(!triple_eq(&t1, &triple("b1", "en")));
+    }
+
+    #[test]
+    fn eq_proxy() {
+        let t1 = triple("b1", "en-GB");
+        let t2 = triple("b1", "en-gb");
+        let o1 = t1.object.as_object_proxy();
+        let o2: ObjectProxy<SimpleTriple> = t2.object.map_triple(|t| *t);
+        assert!(proxy_eq(&o1, &o2));
+        assert!(!proxy_eq(&o1, &triple("b1", "fr").object.as_object_proxy()));
+
+        let ObjectProxy::Triple(inner) = o1 else {
+            unreachable!()
+        };
+        let lit1 = inner.object.as_object_proxy();
+        let lit2 = ObjectProxy::<NeverTriple>::Literal(Literal::LanguageString(
+            "chat".into(),
+            LangTag::new_unchecked("EN-gb"),
+            None,
+        ));
+        assert!(proxy_eq(&lit1, &lit2));
+        assert!(!proxy_eq(&lit2, &o2));
+        assert!(!proxy_eq(
+            &lit2,
+            &ObjectProxy::<NeverTriple>::BlankNode("chat".into())
+        ));
+    }
+
+    #[test]
+    fn eq_quad() {
+        let t = triple("b1", "en-GB");
+        let q1 = SimpleQuad {
+            subject: t.subject.clone(),
+            predicate: t.predicate.clone(),
+            object: t.object.clone(),
+            graph_name: None,
+        };
+        let q2 = SimpleQuad {
+            graph_name: Some(GraphNameProxy::BlankNode("g".into())),
+            ..q1.clone()
+        };
+        assert!(quad_eq(&q1, &q1));
+        assert!(quad_eq(&q2, &q2));
+        assert!(!quad_eq(&q1, &q2));
+        assert!(!quad_eq(&q2, &q1));
+    }
+
+    fn triple(bnid: &'static str, tag: &'static str) -> SimpleTriple<'static> {
+        SimpleTriple {
+            subject: SubjectProxy::BlankNode(bnid.into()),
+            predicate: Iri::new_unchecked("https://example.org/ns/p"),
+            object: ObjectProxy::Triple(Box::new(SimpleTriple {
+                subject: SubjectProxy::Iri(Iri::new_unchecked("https://example.org/ns/s")),
+                predicate: Iri::new_unchecked("https://example.org/ns/p"),
+                object: ObjectProxy::Literal(Literal::LanguageString(
+                    "chat".into(),
+                    LangTag::new_unchecked(tag),
+                    None,
+                )),
+            })),
+        }
+    }
+}
@@ -0,0 +1,265 @@
+use alloc::{borrow::Cow, boxed::Box};
+use core::cmp::Ordering;
+
+use crate::{
+    _simple_triple::{owned_literal, owned_object_proxy_with},
+    GraphNameProxy, Iri, Literal, NeverTriple, NotASubject, Object, ObjectProxy, SimpleTriple,
+    SubjectProxy, Triple,
+};
+
+/// An owned representation of any [RDF term], independent of the implementation it comes from.
+///
+/// Unlike proxies, it has no lifetime nor type parameter,
+/// so it can be stored in collections mixing terms from different implementations
+/// (e.g. in a cache, or in a user interface).
+///
+/// It can be built with [`From`] from any proxy ([`SubjectProxy`], [`Iri`], [`Literal`],
+/// [`ObjectProxy`] or [`GraphNameProxy`]), and converted back into them with [`TryFrom`]
+/// (or [`From`] for [`ObjectProxy`], since any term can be an object).
+///
+/// Its [`PartialEq`], [`Hash`] and [`Ord`] implementations are consistent with
+/// [`proxy_eq`](crate::proxy_eq), [`term_hash`](crate::term_hash) and [`term_cmp`](crate::term_cmp), respectively,
+/// and [`Display`](core::fmt::Display) uses the N-Triples syntax, as for [`ObjectProxy`].
+///
+/// ```
+/// # use r2c2_statement::*;
+/// let t = triple!(_:a, <"https://example.org/ns/p">, "chat"@en);
+/// let mut terms = vec![
+///     ErasedTerm::from(t.object.clone()),
+///     ErasedTerm::from(t.predicate.clone()),
+///     ErasedTerm::from(t.subject.clone()),
+/// ];
+/// terms.sort();
+/// assert_eq!(terms[0].to_string(), "<https://example.org/ns/p>");
+/// assert_eq!(SubjectProxy::try_from(terms[1].clone()).unwrap(), t.subject);
+/// assert!(Iri::try_from(terms[2].clone()).is_err());
+/// ```
+///
+/// [RDF term]: https://www.w3.org/TR/rdf12-concepts/#dfn-rdf-term
+#[derive(Clone, Debug)]
+pub enum ErasedTerm {
+    /// An [IRI](https://www.w3.org/TR/rdf12-concepts/#section-IRIs)
+    Iri(Iri<'static>),
+    /// A [blank node](https://www.w3.org/TR/rdf12-concepts/#dfn-blank-node),
+    /// identified by its internal [blank node identifier](https://www.w3.org/TR/rdf12-concepts/#dfn-blank-node-identifier)
+    BlankNode(Cow<'static, str>),
+    /// A [literal](https://www.w3.org/TR/rdf12-concepts/#dfn-literal)
+    Literal(Literal<'static>),
+    /// A [triple term](https://www.w3.org/TR/rdf12-concepts/#dfn-triple-term)
+    Triple(Box<SimpleTriple<'static>>),
+}
+
+impl Object for ErasedTerm {
+    type Triple<'x>
+        = &'x SimpleTriple<'static>
+    where
+        Self: 'x;
+
+    fn as_object_proxy(&self) -> ObjectProxy<'_, <Self as Object>::Triple<'_>> {
+        match self {
+            ErasedTerm::Iri(iri) => ObjectProxy::Iri(iri.borrowed()),
+            ErasedTerm::BlankNode(bnid) => ObjectProxy::BlankNode(Cow::from(bnid.as_ref())),
+            ErasedTerm::Literal(literal) => ObjectProxy::Literal(literal.borrowed()),
+            ErasedTerm::Triple(triple) => ObjectProxy::Triple(triple),
+        }
+    }
+}
+
+impl<T: Triple> From<ObjectProxy<'_, T>> for ErasedTerm {
+    fn from(value: ObjectProxy<'_, T>) -> Self {
+        match owned_object_proxy_with(value, &mut |bnid| Cow::Owned(bnid.into_owned())) {
+            ObjectProxy::Iri(iri) => ErasedTerm::Iri(iri),
+            ObjectProxy::BlankNode(bnid) => ErasedTerm::BlankNode(bnid),
+            ObjectProxy::Literal(literal) => ErasedTerm::Literal(literal),
+            ObjectProxy::Triple(triple) => ErasedTerm::Triple(triple),
+        }
+    }
+}
+
+impl From<SubjectProxy<'_>> for ErasedTerm {
+    fn from(value: SubjectProxy<'_>) -> Self {
+        ObjectProxy::<NeverTriple>::from(value).into()
+    }
+}
+
+impl From<Iri<'_>> for ErasedTerm {
+    fn from(value: Iri<'_>) -> Self {
+        ObjectProxy::<NeverTriple>::from(value).into()
+    }
+}
+
+impl From<Literal<'_>> for ErasedTerm {
+    fn from(value: Literal<'_>) -> Self {
+        ErasedTerm::Literal(owned_literal(value))
+    }
+}
+
+impl From<GraphNameProxy<'_>> for ErasedTerm {
+    fn from(value: GraphNameProxy<'_>) -> Self {
+        ObjectProxy::<NeverTriple>::from(value).into()
+    }
+}
+
+impl From<ErasedTerm> for ObjectProxy<'static, Box<SimpleTriple<'static>>> {
+    fn from(value: ErasedTerm) -> Self {
+        match value {
+            ErasedTerm::Iri(iri) => ObjectProxy::Iri(iri),
+            ErasedTerm::BlankNode(bnid) => ObjectProxy::BlankNode(bnid),
+            ErasedTerm::Literal(literal) => ObjectProxy::Literal(literal),
+            ErasedTerm::Triple(triple) => ObjectProxy::Triple(triple),
+        }
+    }
+}
+
+impl TryFrom<ErasedTerm> for SubjectProxy<'static> {
+    type Error = NotASubject<'static, Box<SimpleTriple<'static>>>;
+
+    fn try_from(value: ErasedTerm) -> Result<Self, Self::Error> {
+        ObjectProxy::from(value).try_into()
+    }
+}
+
+impl TryFrom<ErasedTerm> for Iri<'static> {
+    type Error = &'static str;
+
+    fn try_from(value: ErasedTerm) -> Result<Self, Self::Error> {
+        ObjectProxy::from(value).try_into()
+    }
+}
+
+impl TryFrom<ErasedTerm> for Literal<'static> {
+    type Error = &'static str;
+
+    fn try_from(value: ErasedTerm) -> Result<Self, Self::Error> {
+        match value {
+            ErasedTerm::Literal(literal) => Ok(literal),
+            _ => Err("Expected a literal"),
+        }
+    }
+}
+
+impl TryFrom<ErasedTerm> for GraphNameProxy<'static> {
+    type Error = &'static str;
+
+    fn try_from(value: ErasedTerm) -> Result<Self, Self::Error> {
+        ObjectProxy::from(value).try_into()
+    }
+}
+
+impl core::fmt::Display for ErasedTerm {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.as_object_proxy().fmt(f)
+    }
+}
+
+impl PartialEq for ErasedTerm {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_object_proxy() == other.as_object_proxy()
+    }
+}
+
+impl Eq for ErasedTerm {}
+
+impl core::hash::Hash for ErasedTerm {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        crate::term_hash(&self.as_object_proxy(), state)
+    }
+}
+
+impl PartialOrd for ErasedTerm {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ErasedTerm {
+    fn cmp(&self, other: &Self) -> Ordering {
+        crate::term_cmp(&self.as_object_proxy(), &other.as_object_proxy())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::{BTreeSet, HashSet};
+
+    use super::*;
+    use crate::{LangTag, triple};
+
+    const EX: &str = "https://example.org/ns/";
+
+    #[test]
+    fn round_trip() {
+        let t = triple!(_:a, EX:p, <<( EX:s, EX:q, "chat"@en )>>);
+        let ObjectProxy::Triple(inner) = &t.object else {
+            unreachable!()
+        };
+
+        let s = ErasedTerm::from(t.subject.clone());
+        assert_eq!(SubjectProxy::try_from(s.clone()), Ok(t.subject.clone()));
+        assert_eq!(
+            GraphNameProxy::try_from(s.clone()),
+            Ok(t.subject.clone().into())
+        );
+        assert!(Iri::try_from(s).is_err());
+
+        let p = ErasedTerm::from(t.predicate.clone());
+        assert_eq!(Iri::try_from(p.clone()), Ok(t.predicate.clone()));
+        assert!(Literal::try_from(p).is_err());
+
+        let o = ErasedTerm::from(t.object.clone());
+        assert!(matches!(o, ErasedTerm::Triple(_)));
+        assert_eq!(ObjectProxy::<Box<SimpleTriple>>::from(o.clone()), t.object);
+        assert!(SubjectProxy::try_from(o.clone()).is_err());
+        assert!(GraphNameProxy::try_from(o).is_err());
+
+        let lit = ErasedTerm::from(inner.object.clone());
+        let expected = Literal::LanguageString("chat".into(), LangTag::new_unchecked("en"), None);
+        assert_eq!(Literal::try_from(lit.clone()), Ok(expected.clone()));
+        assert_eq!(ErasedTerm::from(expected), lit);
+        assert!(SubjectProxy::try_from(lit).is_err());
+    }
+
+    #[test]
+    fn traits() {
+        let t = triple!(_:a, EX:p, <<( _:a, EX:p, "chat"@en-GB )>>);
+        let ObjectProxy::Triple(inner) = &t.object else {
+            unreachable!()
+        };
+        let terms = [
+            ErasedTerm::from(t.object.clone()),
+            ErasedTerm::from(inner.object.clone()),
+            ErasedTerm::from(t.subject.clone()),
+            ErasedTerm::from(t.predicate.clone()),
+            ErasedTerm::from(inner.predicate.clone()),
+        ];
+        let set: HashSet<_> = terms.iter().cloned().collect();
+        assert_eq!(set.len(), 4);
+        assert!(set.contains(&ErasedTerm::Literal(Literal::LanguageString(
+            "chat".into(),
+            LangTag::new_unchecked("EN-gb"),
+            None
+        ))));
+        let set: BTreeSet<_> = terms.iter().cloned().collect();
+        assert_eq!(
+            set.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            [
+                "<https://example.org/ns/p>",
+                "_:a",
+                r#""chat"@en-GB"#,
+                r#"<<( _:a <https://example.org/ns/p> "chat"@en-GB )>>"#,
+            ]
+        );
+        for a in &terms {
+            for b in &terms {
+                assert_eq!(
+                    a == b,
+                    crate::proxy_eq(&a.as_object_proxy(), &b.as_object_proxy())
+                );
+                assert_eq!(
+                    a.cmp(b),
+                    crate::term_cmp(&a.as_object_proxy(), &b.as_object_proxy())
+                );
+            }
+        }
+    }
+}
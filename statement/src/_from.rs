@@ -0,0 +1,48 @@
+use crate::{Quad, Triple};
+
+/// A type that can be built from any [`Triple`].
+///
+/// This is the counterpart of [`Triple`] for implementations that want to *create* triples,
+/// allowing generic code to convert triples from one implementation to another
+/// (see [`convert`]).
+///
+/// The conversion may fail, e.g. if the implementation does not support some RDF 1.2 features
+/// (such as triple terms or base direction).
+pub trait FromTriple: Sized {
+    /// The error raised when a triple can not be converted.
+    type Error;
+
+    /// Build a value of this type by copying the terms of `triple`.
+    fn from_triple<T: Triple>(triple: &T) -> Result<Self, Self::Error>;
+}
+
+/// A type that can be built from any [`Quad`].
+///
+/// See [`FromTriple`] and [`convert_quad`].
+pub trait FromQuad: Sized {
+    /// The error raised when a quad can not be converted.
+    type Error;
+
+    /// Build a value of this type by copying the terms of `quad`.
+    fn from_quad<Q: Quad>(quad: &Q) -> Result<Self, Self::Error>;
+}
+
+/// Convert a [`Triple`] from any implementation into a triple of type `D`.
+///
+/// ```
+/// # use r2c2_statement::*;
+/// const EX: &str = "https://example.org/ns/";
+/// let t = triple!(_:a, EX:p, "chat"@en);
+/// let copy: SimpleTriple = convert(&t).unwrap();
+/// assert_eq!(copy, t);
+/// ```
+pub fn convert<S: Triple, D: FromTriple>(triple: &S) -> Result<D, D::Error> {
+    D::from_triple(triple)
+}
+
+/// Convert a [`Quad`] from any implementation into a quad of type `D`.
+///
+/// See [`convert`].
+pub fn convert_quad<S: Quad, D: FromQuad>(quad: &S) -> Result<D, D::Error> {
+    D::from_quad(quad)
+}
@@ -0,0 +1,156 @@
+use alloc::{boxed::Box, rc::Rc, sync::Arc};
+
+use crate::{GraphName, GraphNameProxy, Iri, SharedIri, Subject, SubjectProxy};
+
+/// A trait for terms of *generalized* RDF implementations,
+/// that may be used in the [subject] position of an [RDF triple],
+/// but are not necessarily valid RDF 1.2 subjects.
+///
+/// For example, implementations of [RDF-star] allow triple terms in the subject position,
+/// and, more generally, [generalized RDF] allows any term in any position.
+/// Tools that accept such data can use [`GeneralizedSubject::try_as_subject_proxy`]
+/// to handle those cases gracefully.
+///
+/// [subject]: https://www.w3.org/TR/rdf12-concepts/#dfn-subject
+/// [RDF triple]: https://www.w3.org/TR/rdf12-concepts/#dfn-rdf-triple
+/// [RDF-star]: https://www.w3.org/2021/12/rdf-star.html
+/// [generalized RDF]: https://www.w3.org/TR/rdf12-concepts/#section-generalized-rdf
+pub trait GeneralizedSubject {
+    /// Return a [`SubjectProxy`] representing this subject,
+    /// or an error if it is not a valid RDF 1.2 subject.
+    fn try_as_subject_proxy(&self) -> Result<SubjectProxy<'_>, GeneralizedError>;
+}
+
+/// A trait for terms of *generalized* RDF implementations,
+/// that may be used as the [graph name] of a [quad],
+/// but are not necessarily valid RDF 1.2 graph names.
+///
+/// See [`GeneralizedSubject`].
+///
+/// [graph name]: https://www.w3.org/TR/rdf12-concepts/#dfn-graph-name
+/// [quad]: https://www.w3.org/TR/rdf12-concepts/#dfn-quad
+pub trait GeneralizedGraphName {
+    /// Return a [`GraphNameProxy`] representing this graph name,
+    /// or an error if it is not a valid RDF 1.2 graph name.
+    fn try_as_graph_name_proxy(&self) -> Result<GraphNameProxy<'_>, GeneralizedError>;
+}
+
+/// The error returned when a term of a generalized RDF implementation
+/// is not allowed in a given position by RDF 1.2.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum GeneralizedError {
+    /// A [triple term](https://www.w3.org/TR/rdf12-concepts/#dfn-triple-term) in subject position
+    TripleTermInSubject,
+    /// A [literal](https://www.w3.org/TR/rdf12-concepts/#dfn-literal) in subject position
+    LiteralInSubject,
+    /// A [triple term](https://www.w3.org/TR/rdf12-concepts/#dfn-triple-term) in graph name position
+    TripleTermInGraphName,
+    /// A [literal](https://www.w3.org/TR/rdf12-concepts/#dfn-literal) in graph name position
+    LiteralInGraphName,
+    /// A term that is not an [RDF term](https://www.w3.org/TR/rdf12-concepts/#dfn-rdf-term) at all
+    /// (e.g. a variable)
+    NotAnRdfTerm,
+}
+
+impl core::fmt::Display for GeneralizedError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            GeneralizedError::TripleTermInSubject => write!(f, "triple term in subject position"),
+            GeneralizedError::LiteralInSubject => write!(f, "literal in subject position"),
+            GeneralizedError::TripleTermInGraphName => {
+                write!(f, "triple term in graph name position")
+            }
+            GeneralizedError::LiteralInGraphName => write!(f, "literal in graph name position"),
+            GeneralizedError::NotAnRdfTerm => write!(f, "not an RDF term"),
+        }
+    }
+}
+
+impl core::error::Error for GeneralizedError {}
+
+// Strict terms trivially implement the generalized traits
+
+/// [`SubjectProxy`]s are always valid RDF 1.2 subjects.
+impl GeneralizedSubject for SubjectProxy<'_> {
+    fn try_as_subject_proxy(&self) -> Result<SubjectProxy<'_>, GeneralizedError> {
+        Ok(self.as_subject_proxy())
+    }
+}
+
+/// [`GraphNameProxy`]s are always valid RDF 1.2 graph names.
+impl GeneralizedGraphName for GraphNameProxy<'_> {
+    fn try_as_graph_name_proxy(&self) -> Result<GraphNameProxy<'_>, GeneralizedError> {
+        Ok(self.as_graph_name_proxy())
+    }
+}
+
+macro_rules! impl_generalized_for_strict {
+    ($($ty:ty),*) => {$(
+        impl GeneralizedSubject for $ty {
+            fn try_as_subject_proxy(&self) -> Result<SubjectProxy<'_>, GeneralizedError> {
+                Ok(self.as_subject_proxy())
+            }
+        }
+
+        impl GeneralizedGraphName for $ty {
+            fn try_as_graph_name_proxy(&self) -> Result<GraphNameProxy<'_>, GeneralizedError> {
+                Ok(self.as_graph_name_proxy())
+            }
+        }
+    )*};
+}
+impl_generalized_for_strict!(Iri<'_>, SharedIri);
+
+/// Any reference to a generalized term is itself a generalized term.
+impl<T: GeneralizedSubject + ?Sized> GeneralizedSubject for &'_ T {
+    fn try_as_subject_proxy(&self) -> Result<SubjectProxy<'_>, GeneralizedError> {
+        (*self).try_as_subject_proxy()
+    }
+}
+
+/// Any reference to a generalized term is itself a generalized term.
+impl<T: GeneralizedGraphName + ?Sized> GeneralizedGraphName for &'_ T {
+    fn try_as_graph_name_proxy(&self) -> Result<GraphNameProxy<'_>, GeneralizedError> {
+        (*self).try_as_graph_name_proxy()
+    }
+}
+
+/// Any [`Box`], [`Rc`] or [`Arc`] of a generalized term is itself a generalized term.
+macro_rules! impl_generalized_for_pointer {
+    ($($ptr:ident),*) => {$(
+        impl<T: GeneralizedSubject + ?Sized> GeneralizedSubject for $ptr<T> {
+            fn try_as_subject_proxy(&self) -> Result<SubjectProxy<'_>, GeneralizedError> {
+                self.as_ref().try_as_subject_proxy()
+            }
+        }
+
+        impl<T: GeneralizedGraphName + ?Sized> GeneralizedGraphName for $ptr<T> {
+            fn try_as_graph_name_proxy(&self) -> Result<GraphNameProxy<'_>, GeneralizedError> {
+                self.as_ref().try_as_graph_name_proxy()
+            }
+        }
+    )*};
+}
+impl_generalized_for_pointer!(Box, Rc, Arc);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn strict() {
+        let iri = Iri::new_unchecked("https://example.org/ns/s");
+        assert_eq!(
+            iri.try_as_subject_proxy(),
+            Ok(SubjectProxy::Iri(iri.clone()))
+        );
+        assert_eq!(
+            Box::new(&iri).try_as_graph_name_proxy(),
+            Ok(GraphNameProxy::Iri(iri.clone()))
+        );
+        let bnode = SubjectProxy::BlankNode("b".into());
+        assert_eq!(bnode.try_as_subject_proxy(), Ok(bnode.clone()));
+        let bnode = GraphNameProxy::BlankNode("g".into());
+        assert_eq!(Arc::new(bnode.clone()).try_as_graph_name_proxy(), Ok(bnode));
+    }
+}
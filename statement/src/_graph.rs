@@ -0,0 +1,259 @@
+use alloc::vec::Vec;
+
+use crate::{Predicate, Subject, Triple, TriplePattern, predicate_eq, subject_eq, triple_eq};
+
+/// A trait for [RDF graphs].
+///
+/// An RDF graph is a set of triples,
+/// so the order in which [`Graph::triples`] yields them is not significant,
+/// and any duplicate should be ignored by consumers.
+///
+/// [RDF graphs]: https://www.w3.org/TR/rdf12-concepts/#dfn-rdf-graph
+pub trait Graph {
+    /// The type of [triples] in this graph, as returned by [`Graph::triples`]
+    ///
+    /// [triples]: https://www.w3.org/TR/rdf12-concepts/#dfn-rdf-triple
+    type Triple<'x>: Triple
+    where
+        Self: 'x;
+
+    /// Iterate over the [triples] of this graph.
+    ///
+    /// [triples]: https://www.w3.org/TR/rdf12-concepts/#dfn-rdf-triple
+    fn triples(&self) -> impl Iterator<Item = Self::Triple<'_>> + '_;
+
+    /// Whether this graph contains a [triple] equal to `t` (as per [`triple_eq`]).
+    ///
+    /// The default implementation scans [`Graph::triples`];
+    /// implementations with an index should override it.
+    ///
+    /// [triple]: https://www.w3.org/TR/rdf12-concepts/#dfn-rdf-triple
+    fn contains<T: Triple>(&self, t: &T) -> bool {
+        self.triples().any(|u| triple_eq(&u, t))
+    }
+
+    /// The number of [triples] in this graph.
+    ///
+    /// The default implementation counts the triples yielded by [`Graph::triples`]
+    /// (including duplicates, if any).
+    /// Implementations for which counting is expensive (e.g. remote or lazily computed graphs)
+    /// may return an estimate instead, provided they document it;
+    /// consumers needing an exact count should therefore use `self.triples().count()`.
+    ///
+    /// [triples]: https://www.w3.org/TR/rdf12-concepts/#dfn-rdf-triple
+    fn len(&self) -> usize {
+        self.triples().count()
+    }
+
+    /// Whether this graph contains no [triple].
+    ///
+    /// Unlike [`Graph::len`], this must always be exact.
+    ///
+    /// [triple]: https://www.w3.org/TR/rdf12-concepts/#dfn-rdf-triple
+    fn is_empty(&self) -> bool {
+        self.triples().next().is_none()
+    }
+
+    /// Iterate over the [triples] of this graph whose [predicate] is `p`
+    /// (as per [`predicate_eq`]).
+    ///
+    /// ```
+    /// # use r2c2_statement::*;
+    /// const EX: &str = "https://example.org/ns/";
+    /// let g = vec![
+    ///     triple!(EX:alice, EX:name, "Alice"),
+    ///     triple!(EX:alice, EX:knows, EX:bob),
+    ///     triple!(EX:bob, EX:name, "Bob"),
+    /// ];
+    /// let name = Iri::new_unchecked("https://example.org/ns/name");
+    /// assert_eq!(g.with_predicate(&name).count(), 2);
+    /// ```
+    ///
+    /// [triples]: https://www.w3.org/TR/rdf12-concepts/#dfn-rdf-triple
+    /// [predicate]: https://www.w3.org/TR/rdf12-concepts/#dfn-predicate
+    fn with_predicate<P: Predicate + ?Sized>(
+        &self,
+        p: &P,
+    ) -> impl Iterator<Item = Self::Triple<'_>> {
+        self.triples()
+            .filter(move |t| predicate_eq(&t.predicate(), p))
+    }
+
+    /// Iterate over the [triples] of this graph whose [subject] is `s`
+    /// (as per [`subject_eq`]).
+    ///
+    /// [triples]: https://www.w3.org/TR/rdf12-concepts/#dfn-rdf-triple
+    /// [subject]: https://www.w3.org/TR/rdf12-concepts/#dfn-subject
+    fn with_subject<S: Subject + ?Sized>(&self, s: &S) -> impl Iterator<Item = Self::Triple<'_>> {
+        self.triples().filter(move |t| subject_eq(&t.subject(), s))
+    }
+
+    /// Iterate over the [triples] of this graph matching `pattern`
+    /// (see [`TriplePattern::matches`]).
+    ///
+    /// ```
+    /// # use r2c2_statement::*;
+    /// const EX: &str = "https://example.org/ns/";
+    /// let g = vec![
+    ///     triple!(EX:alice, EX:knows, EX:bob),
+    ///     triple!(EX:bob, EX:knows, EX:alice),
+    ///     triple!(EX:bob, EX:name, "Bob"),
+    /// ];
+    /// let pattern = TriplePattern::any()
+    ///     .with_predicate(Iri::new_unchecked(format!("{EX}knows")))
+    ///     .with_object(ObjectProxy::Iri(Iri::new_unchecked(format!("{EX}alice"))));
+    /// assert_eq!(g.matching(&pattern).collect::<Vec<_>>(), [&g[1]]);
+    /// ```
+    ///
+    /// [triples]: https://www.w3.org/TR/rdf12-concepts/#dfn-rdf-triple
+    fn matching(&self, pattern: &TriplePattern<'_>) -> impl Iterator<Item = Self::Triple<'_>> {
+        self.triples().filter(move |t| pattern.matches(t))
+    }
+}
+
+impl<T: Triple> Graph for [T] {
+    type Triple<'x>
+        = &'x T
+    where
+        Self: 'x;
+
+    fn triples(&self) -> impl Iterator<Item = Self::Triple<'_>> + '_ {
+        self.iter()
+    }
+
+    fn len(&self) -> usize {
+        <[T]>::len(self)
+    }
+
+    fn is_empty(&self) -> bool {
+        <[T]>::is_empty(self)
+    }
+}
+
+impl<T: Triple> Graph for Vec<T> {
+    type Triple<'x>
+        = &'x T
+    where
+        Self: 'x;
+
+    fn triples(&self) -> impl Iterator<Item = Self::Triple<'_>> + '_ {
+        self.iter()
+    }
+
+    fn len(&self) -> usize {
+        <Vec<T>>::len(self)
+    }
+
+    fn is_empty(&self) -> bool {
+        <Vec<T>>::is_empty(self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, S: std::hash::BuildHasher> Graph
+    for std::collections::HashSet<crate::SimpleTriple<'a>, S>
+{
+    type Triple<'x>
+        = &'x crate::SimpleTriple<'a>
+    where
+        Self: 'x;
+
+    fn triples(&self) -> impl Iterator<Item = Self::Triple<'_>> + '_ {
+        self.iter()
+    }
+
+    fn contains<T: Triple>(&self, t: &T) -> bool {
+        std::collections::HashSet::contains(self, &crate::SimpleTriple::from_triple(t))
+    }
+
+    fn len(&self) -> usize {
+        std::collections::HashSet::len(self)
+    }
+
+    fn is_empty(&self) -> bool {
+        std::collections::HashSet::is_empty(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Iri, SimpleTriple, SubjectProxy, triple};
+
+    const EX: &str = "https://example.org/ns/";
+
+    #[test]
+    fn combinators() {
+        let g = vec![
+            triple!(EX:alice, EX:name, "Alice"),
+            triple!(EX:alice, EX:knows, _:b),
+            triple!(_:b, EX:name, "Bob"),
+            triple!(_:b, EX:knows, EX:alice),
+        ];
+        let name = Iri::new_unchecked("https://example.org/ns/name");
+        let bob = SubjectProxy::BlankNode("b".into());
+        assert_eq!(g.with_predicate(&name).collect::<Vec<_>>(), [&g[0], &g[2]]);
+        assert_eq!(g.with_subject(&bob).collect::<Vec<_>>(), [&g[2], &g[3]]);
+        // combinators can be chained with other iterator adapters
+        assert_eq!(
+            g.with_subject(&bob)
+                .filter(|t| predicate_eq(&t.predicate(), &name))
+                .count(),
+            1
+        );
+        // subjects from another implementation
+        let alice = Iri::new_unchecked("https://example.org/ns/alice");
+        assert_eq!(g[1..].with_subject(&alice).collect::<Vec<_>>(), [&g[1]]);
+        let nobody = Iri::new_unchecked("https://example.org/ns/nobody");
+        assert_eq!(g.with_subject(&nobody).count(), 0);
+        // patterns
+        let pattern = TriplePattern::any().with_subject(bob.clone());
+        assert_eq!(g.matching(&pattern).collect::<Vec<_>>(), [&g[2], &g[3]]);
+        assert_eq!(g.matching(&TriplePattern::any()).count(), g.len());
+    }
+
+    #[test]
+    fn membership() {
+        let v = vec![
+            triple!(EX:alice, EX:name, "Alice"@en),
+            triple!(EX:alice, EX:knows, _:b),
+            triple!(_:b, EX:knows, <<( EX:alice, EX:name, "Alice"@en )>>),
+        ];
+        let present = [
+            triple!(EX:alice, EX:name, "Alice"@EN),
+            triple!(EX:alice, EX:knows, _:b),
+            triple!(_:b, EX:knows, <<( EX:alice, EX:name, "Alice"@en )>>),
+        ];
+        let absent = [
+            triple!(EX:alice, EX:name, "Alice"),
+            triple!(EX:alice, EX:knows, _:c),
+            triple!(_:b, EX:knows, <<( EX:alice, EX:name, "Bob"@en )>>),
+        ];
+        fn check<G: Graph + ?Sized>(g: &G, present: &[SimpleTriple], absent: &[SimpleTriple]) {
+            assert_eq!(g.len(), 3);
+            assert!(!g.is_empty());
+            for t in present {
+                assert!(g.contains(t), "{t:?}");
+                assert!(g.contains(&&t), "{t:?}");
+            }
+            for t in absent {
+                assert!(!g.contains(t), "{t:?}");
+            }
+        }
+        check(&v, &present, &absent);
+        check(&v[..], &present, &absent);
+        #[cfg(feature = "std")]
+        {
+            let s: std::collections::HashSet<_> = v.iter().cloned().collect();
+            check(&s, &present, &absent);
+            assert!(Graph::is_empty(
+                &std::collections::HashSet::<SimpleTriple>::new()
+            ));
+        }
+
+        let empty: Vec<SimpleTriple> = vec![];
+        assert_eq!(Graph::len(&empty), 0);
+        assert!(Graph::is_empty(&empty));
+        assert!(!Graph::contains(&empty, &v[0]));
+    }
+}
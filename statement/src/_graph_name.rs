@@ -1,6 +1,6 @@
-use std::borrow::Cow;
+use alloc::{borrow::Cow, boxed::Box, rc::Rc, sync::Arc};
 
-use crate::Iri;
+use crate::{_simple_triple::owned_iri, BnodeId, Iri, TermKind};
 
 /// A trait for [RDF terms] allowed as a [graph name] in an [RDF dataset].
 ///
@@ -18,7 +18,7 @@ pub trait GraphName {
     /// # Implementers
     /// A default implementation is provided for this method, based on [`GraphName::as_graph_name_proxy`].
     /// It may be useful to override it, especially for types where the inner values of [`GraphNameProxy`]
-    /// are allocated as owned [`Cow<str>`](std::borrow::Cow) rather than borrowed.
+    /// are allocated as owned [`Cow<str>`](alloc::borrow::Cow) rather than borrowed.
     fn graph_name_kind(&self) -> GraphNameKind {
         match self.as_graph_name_proxy() {
             GraphNameProxy::Iri(_) => GraphNameKind::Iri,
@@ -26,6 +26,14 @@ pub trait GraphName {
         }
     }
 
+    /// Return the [kind](TermKind) of this graph name, independently of its position.
+    ///
+    /// # Implementers
+    /// A default implementation is provided for this method, based on [`GraphName::graph_name_kind`].
+    fn term_kind(&self) -> TermKind {
+        self.graph_name_kind().into()
+    }
+
     /// Whether this graph_name is [ground](https://https://www.w3.org/TR/rdf12-concepts/#dfn-ground).
     fn ground(&self) -> bool {
         match self.graph_name_kind() {
@@ -51,6 +59,129 @@ pub enum GraphNameProxy<'a> {
     BlankNode(Cow<'a, str>),
 }
 
+impl GraphNameProxy<'_> {
+    /// Convert this [`GraphNameProxy`] into one that owns its data,
+    /// so that it can be stored independently of the value it was obtained from.
+    pub fn into_owned(self) -> GraphNameProxy<'static> {
+        match self {
+            GraphNameProxy::Iri(iri) => GraphNameProxy::Iri(owned_iri(iri)),
+            GraphNameProxy::BlankNode(bnid) => {
+                GraphNameProxy::BlankNode(Cow::Owned(bnid.into_owned()))
+            }
+        }
+    }
+
+    /// If this is a blank node, return its identifier as a valid [`BnodeId`]
+    /// (see [`BnodeId::sanitized`]), otherwise None.
+    pub fn as_bnode_id(&self) -> Option<BnodeId<'_>> {
+        match self {
+            GraphNameProxy::BlankNode(bnid) => Some(BnodeId::sanitized(bnid)),
+            GraphNameProxy::Iri(_) => None,
+        }
+    }
+}
+
+/// Display this graph name in [N-Triples] syntax, i.e. `<iri>` or `_:label`,
+/// as written by [`write_ntriple`](crate::write_ntriple).
+///
+/// Blank node labels are written as is, without any escaping,
+/// as they are considered opaque.
+/// The output is therefore only valid N-Triples if the label matches the [`BLANK_NODE_LABEL`] production;
+/// for other labels, this is a best-effort representation.
+///
+/// [N-Triples]: https://www.w3.org/TR/rdf12-n-triples/
+/// [`BLANK_NODE_LABEL`]: https://www.w3.org/TR/rdf12-n-triples/#grammar-production-BLANK_NODE_LABEL
+impl core::fmt::Display for GraphNameProxy<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            GraphNameProxy::Iri(iri) => write!(f, "{iri}"),
+            GraphNameProxy::BlankNode(bnid) => write!(f, "_:{bnid}"),
+        }
+    }
+}
+
+/// Either the [graph name] of a [named graph], or the [default graph].
+///
+/// [`GraphName`] deliberately excludes the default graph, which is represented by `None`
+/// in [`Quad::graph_name`](crate::Quad::graph_name) and [`Quad::graph_name_proxy`](crate::Quad::graph_name_proxy).
+/// This type is a more explicit equivalent of `Option<GraphNameProxy>`,
+/// which it can be converted from and into,
+/// convenient to interface with implementations having a dedicated variant for the default graph.
+///
+/// ```
+/// # use r2c2_statement::*;
+/// let q = quad!(_:s, <"https://example.org/ns/p">, _:o, <"https://example.org/ns/g">);
+/// let g = GraphNameOrDefault::from(q.graph_name_proxy());
+/// assert_eq!(g.to_string(), "<https://example.org/ns/g>");
+/// let q = quad!(_:s, <"https://example.org/ns/p">, _:o);
+/// let g = GraphNameOrDefault::from(q.graph_name_proxy());
+/// assert_eq!(g, GraphNameOrDefault::Default);
+/// assert_eq!(g.to_string(), "DEFAULT");
+/// ```
+///
+/// [graph name]: https://www.w3.org/TR/rdf12-concepts/#dfn-graph-name
+/// [named graph]: https://www.w3.org/TR/rdf12-concepts/#dfn-named-graph
+/// [default graph]: https://www.w3.org/TR/rdf12-concepts/#dfn-default-graph
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub enum GraphNameOrDefault<'a> {
+    /// The [default graph](https://www.w3.org/TR/rdf12-concepts/#dfn-default-graph)
+    #[default]
+    Default,
+    /// The [graph name](https://www.w3.org/TR/rdf12-concepts/#dfn-graph-name) of a named graph
+    Named(GraphNameProxy<'a>),
+}
+
+impl GraphNameOrDefault<'_> {
+    /// Whether this is the [default graph](https://www.w3.org/TR/rdf12-concepts/#dfn-default-graph).
+    pub fn is_default(&self) -> bool {
+        matches!(self, GraphNameOrDefault::Default)
+    }
+
+    /// Convert this [`GraphNameOrDefault`] into one that owns its data,
+    /// so that it can be stored independently of the value it was obtained from.
+    pub fn into_owned(self) -> GraphNameOrDefault<'static> {
+        match self {
+            GraphNameOrDefault::Default => GraphNameOrDefault::Default,
+            GraphNameOrDefault::Named(gn) => GraphNameOrDefault::Named(gn.into_owned()),
+        }
+    }
+}
+
+impl<'a> From<GraphNameProxy<'a>> for GraphNameOrDefault<'a> {
+    fn from(value: GraphNameProxy<'a>) -> Self {
+        GraphNameOrDefault::Named(value)
+    }
+}
+
+impl<'a> From<Option<GraphNameProxy<'a>>> for GraphNameOrDefault<'a> {
+    fn from(value: Option<GraphNameProxy<'a>>) -> Self {
+        match value {
+            Some(gn) => GraphNameOrDefault::Named(gn),
+            None => GraphNameOrDefault::Default,
+        }
+    }
+}
+
+impl<'a> From<GraphNameOrDefault<'a>> for Option<GraphNameProxy<'a>> {
+    fn from(value: GraphNameOrDefault<'a>) -> Self {
+        match value {
+            GraphNameOrDefault::Default => None,
+            GraphNameOrDefault::Named(gn) => Some(gn),
+        }
+    }
+}
+
+/// Display the default graph as `DEFAULT` (as in SPARQL),
+/// and graph names as [`GraphNameProxy`] does.
+impl core::fmt::Display for GraphNameOrDefault<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            GraphNameOrDefault::Default => write!(f, "DEFAULT"),
+            GraphNameOrDefault::Named(gn) => gn.fmt(f),
+        }
+    }
+}
+
 /// An enum representing the different kinds of [RDF terms] that can be [graph name].
 /// The return type of [`GraphName::graph_name_kind`].
 ///
@@ -75,11 +206,53 @@ impl<T: GraphName> GraphName for &'_ T {
         (*self).graph_name_kind()
     }
 
+    fn term_kind(&self) -> TermKind {
+        (*self).term_kind()
+    }
+
     fn ground(&self) -> bool {
         (*self).ground()
     }
 }
 
+/// Any [`Box`], [`Rc`] or [`Arc`] of a [`GraphName`] also trivially implements [`GraphName`].
+macro_rules! impl_graph_name_for_pointer {
+    ($($ptr:ident),*) => {$(
+        impl<T: GraphName + ?Sized> GraphName for $ptr<T> {
+            fn as_graph_name_proxy(&self) -> GraphNameProxy<'_> {
+                self.as_ref().as_graph_name_proxy()
+            }
+
+            fn graph_name_kind(&self) -> GraphNameKind {
+                self.as_ref().graph_name_kind()
+            }
+
+            fn term_kind(&self) -> TermKind {
+                self.as_ref().term_kind()
+            }
+
+            fn ground(&self) -> bool {
+                self.as_ref().ground()
+            }
+        }
+    )*};
+}
+impl_graph_name_for_pointer!(Box, Rc, Arc);
+
+/// [`Iri`] implements the trait [`GraphName`], always returning the [`GraphNameProxy::Iri`] variant
+/// (borrowing the IRI rather than cloning it).
+///
+/// This is mostly useful for testing or prototyping, as [`Iri`] also implements [`Predicate`](crate::Predicate).
+impl GraphName for Iri<'_> {
+    fn as_graph_name_proxy(&self) -> GraphNameProxy<'_> {
+        GraphNameProxy::Iri(self.borrowed())
+    }
+
+    fn graph_name_kind(&self) -> GraphNameKind {
+        GraphNameKind::Iri
+    }
+}
+
 /// [`GraphNameProxy`] implements the trait [`GraphName`].
 /// This has not particular interest for [`GraphNameProxy`]s obtained from another [`GraphName`]-implementing type,
 /// via the [`GraphName::as_graph_name_proxy`] method.
@@ -94,3 +267,35 @@ impl GraphName for GraphNameProxy<'_> {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn display() {
+        let iri = GraphNameProxy::Iri(Iri::new_unchecked("https://example.org/ns/s"));
+        assert_eq!(iri.to_string(), "<https://example.org/ns/s>");
+        let bnode = GraphNameProxy::BlankNode("b1".into());
+        assert_eq!(bnode.to_string(), "_:b1");
+        // labels that are not valid in N-Triples are written as is
+        let bnode = GraphNameProxy::BlankNode("a b>.".into());
+        assert_eq!(bnode.to_string(), "_:a b>.");
+    }
+
+    #[test]
+    fn graph_name_or_default() {
+        let iri = GraphNameProxy::Iri(Iri::new_unchecked("https://example.org/ns/g"));
+        let named = GraphNameOrDefault::from(Some(iri.clone()));
+        assert_eq!(named, GraphNameOrDefault::from(iri.clone()));
+        assert!(!named.is_default());
+        assert_eq!(named.to_string(), "<https://example.org/ns/g>");
+        assert_eq!(Option::from(named.into_owned()), Some(iri));
+
+        let default = GraphNameOrDefault::from(None);
+        assert_eq!(default, GraphNameOrDefault::default());
+        assert!(default.is_default());
+        assert_eq!(default.to_string(), "DEFAULT");
+        assert_eq!(Option::<GraphNameProxy>::from(default), None);
+    }
+}
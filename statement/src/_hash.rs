@@ -0,0 +1,346 @@
+use core::hash::{Hash, Hasher};
+
+use crate::{
+    GraphName, GraphNameProxy, Literal, Object, ObjectProxy, Predicate, Quad, Subject,
+    SubjectProxy, TermProxy, Triple, quad_eq, triple_eq,
+};
+
+/// Feed a triple into the given [`Hasher`],
+/// in a way that does not depend on the implementation of [`Triple`].
+///
+/// This is consistent with [`triple_eq`]: triples that are equal according to [`triple_eq`]
+/// produce the same hash, whatever their implementation.
+///
+/// The canonical representation that is hashed is, in that order,
+/// the subject, the predicate and the object of the triple, where:
+/// * each term is prefixed by a tag identifying its kind (IRI, blank node, literal or triple term);
+/// * IRIs and blank node identifiers are hashed as is;
+/// * literals are hashed as their lexical form, datatype IRI,
+///   lower-cased language tag (if any) and base direction (if any);
+/// * triple terms are hashed recursively.
+pub fn triple_hash<T: Triple, H: Hasher>(triple: &T, state: &mut H) {
+    subject_proxy_hash(&triple.subject().as_subject_proxy(), state);
+    triple.predicate().as_iri().as_str().hash(state);
+    object_proxy_hash(&triple.object().as_object_proxy(), state);
+}
+
+/// Feed a quad into the given [`Hasher`],
+/// in a way that does not depend on the implementation of [`Quad`].
+///
+/// This is consistent with [`quad_eq`]: quads that are equal according to [`quad_eq`]
+/// produce the same hash, whatever their implementation.
+///
+/// The canonical representation that is hashed is the same as for [`triple_hash`],
+/// followed by the graph name (or a tag identifying the default graph).
+pub fn quad_hash<Q: Quad, H: Hasher>(quad: &Q, state: &mut H) {
+    subject_proxy_hash(&quad.subject().as_subject_proxy(), state);
+    quad.predicate().as_iri().as_str().hash(state);
+    object_proxy_hash(&quad.object().as_object_proxy(), state);
+    match quad.graph_name() {
+        None => DEFAULT_GRAPH_TAG.hash(state),
+        Some(gn) => graph_name_proxy_hash(&gn.as_graph_name_proxy(), state),
+    }
+}
+
+/// Feed a term into the given [`Hasher`],
+/// in a way that does not depend on the implementation it comes from.
+///
+/// This is consistent with [`proxy_eq`](crate::proxy_eq) (and therefore [`object_eq`](crate::object_eq)):
+/// terms that are equal produce the same hash, whatever their implementation.
+/// The positional wrappers [`subject_hash`], [`predicate_hash`], [`object_hash`] and [`graph_name_hash`]
+/// are consistent with it, so that the same term produces the same hash in any position.
+///
+/// The canonical representation that is hashed is a tag identifying the kind of the term, followed by:
+/// * for IRIs, the IRI as is (IRIs are *not* normalized);
+/// * for blank nodes, their identifier as is;
+/// * for literals, their lexical form, datatype IRI (so simple literals are hashed with `xsd:string`),
+///   lower-cased language tag (if any) and base direction (if any);
+/// * for triple terms, their subject, predicate and object, hashed recursively as in [`triple_hash`].
+pub fn term_hash<T: Triple, H: Hasher>(term: &TermProxy<T>, state: &mut H) {
+    object_proxy_hash(term, state)
+}
+
+/// Feed a subject into the given [`Hasher`], consistently with [`subject_eq`](crate::subject_eq) and [`term_hash`].
+pub fn subject_hash<S: Subject + ?Sized, H: Hasher>(subject: &S, state: &mut H) {
+    subject_proxy_hash(&subject.as_subject_proxy(), state)
+}
+
+/// Feed a predicate into the given [`Hasher`], consistently with [`predicate_eq`](crate::predicate_eq) and [`term_hash`].
+pub fn predicate_hash<P: Predicate + ?Sized, H: Hasher>(predicate: &P, state: &mut H) {
+    IRI_TAG.hash(state);
+    predicate.as_iri().as_str().hash(state);
+}
+
+/// Feed an object into the given [`Hasher`], consistently with [`object_eq`](crate::object_eq) and [`term_hash`].
+pub fn object_hash<O: Object + ?Sized, H: Hasher>(object: &O, state: &mut H) {
+    object_proxy_hash(&object.as_object_proxy(), state)
+}
+
+/// Feed a graph name into the given [`Hasher`], consistently with [`graph_name_eq`](crate::graph_name_eq) and [`term_hash`].
+pub fn graph_name_hash<G: GraphName + ?Sized, H: Hasher>(graph_name: &G, state: &mut H) {
+    graph_name_proxy_hash(&graph_name.as_graph_name_proxy(), state)
+}
+
+/// A wrapper around any [`Triple`], implementing [`Hash`] and [`Eq`]
+/// with [`triple_hash`] and [`triple_eq`].
+///
+/// This is useful for deduplicating triples coming from different implementations,
+/// e.g. by storing them in a [`HashSet`](std::collections::HashSet).
+#[derive(Clone, Copy, Debug)]
+pub struct HashableTriple<T>(pub T);
+
+impl<T: Triple> Hash for HashableTriple<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        triple_hash(&self.0, state)
+    }
+}
+
+impl<T: Triple, U: Triple> PartialEq<HashableTriple<U>> for HashableTriple<T> {
+    fn eq(&self, other: &HashableTriple<U>) -> bool {
+        triple_eq(&self.0, &other.0)
+    }
+}
+
+impl<T: Triple> Eq for HashableTriple<T> {}
+
+/// A wrapper around any [`Quad`], implementing [`Hash`] and [`Eq`]
+/// with [`quad_hash`] and [`quad_eq`].
+///
+/// This is useful for deduplicating quads coming from different implementations,
+/// e.g. by storing them in a [`HashSet`](std::collections::HashSet).
+#[derive(Clone, Copy, Debug)]
+pub struct HashableQuad<Q>(pub Q);
+
+impl<Q: Quad> Hash for HashableQuad<Q> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        quad_hash(&self.0, state)
+    }
+}
+
+impl<Q: Quad, R: Quad> PartialEq<HashableQuad<R>> for HashableQuad<Q> {
+    fn eq(&self, other: &HashableQuad<R>) -> bool {
+        quad_eq(&self.0, &other.0)
+    }
+}
+
+impl<Q: Quad> Eq for HashableQuad<Q> {}
+
+// utility functions and constants
+
+pub(crate) fn subject_proxy_hash<H: Hasher>(subject: &SubjectProxy, state: &mut H) {
+    match subject {
+        SubjectProxy::Iri(iri) => {
+            IRI_TAG.hash(state);
+            iri.as_str().hash(state);
+        }
+        SubjectProxy::BlankNode(bnid) => {
+            BLANK_NODE_TAG.hash(state);
+            bnid.as_ref().hash(state);
+        }
+    }
+}
+
+pub(crate) fn graph_name_proxy_hash<H: Hasher>(graph_name: &GraphNameProxy, state: &mut H) {
+    match graph_name {
+        GraphNameProxy::Iri(iri) => {
+            IRI_TAG.hash(state);
+            iri.as_str().hash(state);
+        }
+        GraphNameProxy::BlankNode(bnid) => {
+            BLANK_NODE_TAG.hash(state);
+            bnid.as_ref().hash(state);
+        }
+    }
+}
+
+pub(crate) fn object_proxy_hash<T: Triple, H: Hasher>(object: &ObjectProxy<T>, state: &mut H) {
+    match object {
+        ObjectProxy::Iri(iri) => {
+            IRI_TAG.hash(state);
+            iri.as_str().hash(state);
+        }
+        ObjectProxy::BlankNode(bnid) => {
+            BLANK_NODE_TAG.hash(state);
+            bnid.as_ref().hash(state);
+        }
+        ObjectProxy::Literal(literal) => {
+            LITERAL_TAG.hash(state);
+            literal_hash(literal, state);
+        }
+        ObjectProxy::Triple(triple) => {
+            TRIPLE_TAG.hash(state);
+            triple_hash(triple, state);
+        }
+    }
+}
+
+pub(crate) fn literal_hash<H: Hasher>(literal: &Literal, state: &mut H) {
+    literal.lexical_form().as_ref().hash(state);
+    literal.datatype_iri().as_str().hash(state);
+    literal
+        .language_tag()
+        .map(|tag| tag.to_ascii_lowercase())
+        .hash(state);
+    literal.base_direction().hash(state);
+}
+
+const IRI_TAG: u8 = 0;
+const BLANK_NODE_TAG: u8 = 1;
+const LITERAL_TAG: u8 = 2;
+const TRIPLE_TAG: u8 = 3;
+const DEFAULT_GRAPH_TAG: u8 = 4;
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use crate::*;
+
+    #[test]
+    fn hashable_triple() {
+        let mut set = HashSet::new();
+        set.insert(HashableTriple(triple("b1", "en-GB")));
+        set.insert(HashableTriple(triple("b1", "en-gb")));
+        assert_eq!(set.len(), 1);
+        set.insert(HashableTriple(triple("b2", "en-GB")));
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn hashable_quad() {
+        let t = triple("b1", "en-GB");
+        let q1 = SimpleQuad {
+            subject: t.subject.clone(),
+            predicate: t.predicate.clone(),
+            object: t.object.clone(),
+            graph_name: None,
+        };
+        let q2 = SimpleQuad {
+            graph_name: Some(GraphNameProxy::BlankNode("g".into())),
+            ..q1.clone()
+        };
+        let mut set = HashSet::new();
+        set.insert(HashableQuad(q1.clone()));
+        set.insert(HashableQuad(q2.clone()));
+        set.insert(HashableQuad(q1));
+        set.insert(HashableQuad(q2));
+        assert_eq!(set.len(), 2);
+    }
+
+    fn hash_of(f: impl FnOnce(&mut std::hash::DefaultHasher)) -> u64 {
+        use std::hash::Hasher;
+        let mut state = std::hash::DefaultHasher::new();
+        f(&mut state);
+        state.finish()
+    }
+
+    /// Terms with many equal pairs, represented differently
+    fn terms() -> Vec<ObjectProxy<'static, Box<SimpleTriple<'static>>>> {
+        let mut terms = vec![];
+        for iri in ["https://example.org/ns/a", "https://example.org/ns/A"] {
+            terms.push(ObjectProxy::Iri(Iri::new_unchecked(iri)));
+            terms.push(ObjectProxy::Iri(Iri::new_unchecked(iri.to_string())));
+        }
+        for bnid in ["a", "b"] {
+            terms.push(ObjectProxy::BlankNode(bnid.into()));
+            terms.push(ObjectProxy::BlankNode(bnid.to_string().into()));
+        }
+        for lex in ["chat", "CHAT"] {
+            terms.push(ObjectProxy::Literal(Literal::new_typed(
+                lex,
+                ns::xsd::STRING,
+            )));
+            terms.push(ObjectProxy::Literal(Literal::new_typed(
+                lex.to_string(),
+                ns::xsd::STRING,
+            )));
+            for tag in ["en", "EN", "en-GB", "en-gb"] {
+                let lit = Literal::new_lang(lex, LangTag::new_unchecked(tag));
+                terms.push(ObjectProxy::Literal(lit.clone()));
+                terms.push(ObjectProxy::Literal(lit.with_direction(BaseDir::Rtl)));
+            }
+        }
+        // triple terms (nested up to twice), each built twice
+        let flat_len = terms.len();
+        for depth in 0..2 {
+            let inner = terms.clone();
+            for (i, o) in inner
+                .into_iter()
+                .enumerate()
+                .filter(|(i, _)| i % 3 == depth)
+            {
+                let nested = SimpleTriple {
+                    subject: SubjectProxy::BlankNode(if i % 2 == 0 { "a" } else { "b" }.into()),
+                    predicate: Iri::new_unchecked("https://example.org/ns/p"),
+                    object: o,
+                };
+                terms.push(ObjectProxy::Triple(Box::new(nested.clone())));
+                terms.push(ObjectProxy::Triple(Box::new(nested)));
+            }
+        }
+        assert!(terms.len() > flat_len);
+        terms
+    }
+
+    #[test]
+    fn term_hash_consistent_with_eq() {
+        let terms = terms();
+        let hashes: Vec<_> = terms.iter().map(|t| hash_of(|h| term_hash(t, h))).collect();
+        let mut equal_pairs = 0;
+        for (i, ti) in terms.iter().enumerate() {
+            // via the positional wrappers, and with a different triple type
+            assert_eq!(hash_of(|h| object_hash(ti, h)), hashes[i]);
+            if let Some(never) = ti.clone().into_never() {
+                assert_eq!(hash_of(|h| term_hash(&never, h)), hashes[i]);
+            }
+            for (j, tj) in terms.iter().enumerate() {
+                if proxy_eq(ti, tj) {
+                    assert_eq!(hashes[i], hashes[j], "{ti} {tj}");
+                    equal_pairs += usize::from(i != j);
+                } else {
+                    // not guaranteed in general, but expected with these few distinct terms
+                    assert_ne!(hashes[i], hashes[j], "{ti} {tj}");
+                }
+            }
+        }
+        // every term is equal to at least one other term
+        assert!(equal_pairs >= terms.len());
+    }
+
+    #[test]
+    fn positional_hash_consistent() {
+        let iri = Iri::new_unchecked("https://example.org/ns/a");
+        let expected = hash_of(|h| term_hash(&ObjectProxy::<NeverTriple>::Iri(iri.clone()), h));
+        assert_eq!(hash_of(|h| subject_hash(&iri, h)), expected);
+        assert_eq!(hash_of(|h| predicate_hash(&iri, h)), expected);
+        assert_eq!(hash_of(|h| object_hash(&iri, h)), expected);
+        assert_eq!(hash_of(|h| graph_name_hash(&iri, h)), expected);
+
+        let s = SubjectProxy::BlankNode("b".into());
+        let g = GraphNameProxy::BlankNode("b".into());
+        assert_eq!(
+            hash_of(|h| subject_hash(&s, h)),
+            hash_of(|h| graph_name_hash(&g, h))
+        );
+        assert_eq!(
+            hash_of(|h| subject_hash(&s, h)),
+            hash_of(|h| object_hash(&s, h))
+        );
+    }
+
+    fn triple(bnid: &'static str, tag: &'static str) -> SimpleTriple<'static> {
+        SimpleTriple {
+            subject: SubjectProxy::BlankNode(bnid.into()),
+            predicate: Iri::new_unchecked("https://example.org/ns/p"),
+            object: ObjectProxy::Triple(Box::new(SimpleTriple {
+                subject: SubjectProxy::Iri(Iri::new_unchecked("https://example.org/ns/s")),
+                predicate: Iri::new_unchecked("https://example.org/ns/p"),
+                object: ObjectProxy::Literal(Literal::LanguageString(
+                    "chat".into(),
+                    LangTag::new_unchecked(tag),
+                    None,
+                )),
+            })),
+        }
+    }
+}
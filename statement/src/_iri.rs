@@ -1,15 +1,33 @@
-use std::borrow::Cow;
+use alloc::{borrow::Cow, string::String, sync::Arc};
+
+use crate::_text::Text;
 
 /// Wrapper around a [`Cow<str>`] signaling that it complies with [RFC3987],
 /// i.e. it is a valid IRI.
 ///
+/// The text may also be stored in an [`Arc<str>`](alloc::sync::Arc) (see [`Iri::shared`]),
+/// in which case cloning the [`Iri`] does not allocate.
+///
 /// ## Contract
 /// * Consumers of [`Iri`]s can safely assume that the underlying text is a valid IRI.
 /// * Producers of [`Iri`]s are responsible for ensuring that constraint.
 ///
 /// [RFC3987]: https://datatracker.ietf.org/doc/rfc3987/
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
-pub struct Iri<'a>(Cow<'a, str>);
+pub struct Iri<'a>(Text<'a>);
+
+impl Iri<'static> {
+    /// Return a new [`Iri`] borrowing a static string, assuming it is a valid IRI.
+    ///
+    /// Contrarily to [`Iri::new_unchecked`], this is a `const` function,
+    /// so it can be used to define constants (see [`ns`](crate::ns)).
+    ///
+    /// ## Precondition
+    /// It is the responsibility of the caller to ensure that `txt` is a valid IRI
+    pub const fn new_static(txt: &'static str) -> Self {
+        Iri(Text::Borrowed(txt))
+    }
+}
 
 impl<'a> Iri<'a> {
     /// Return a new [`Iri`], assuming the argument is a valid IRI.
@@ -17,12 +35,14 @@ impl<'a> Iri<'a> {
     /// ## Precondition
     /// It is the responsibility of the caller to ensure that `txt` is a valid IRI
     pub fn new_unchecked(txt: impl Into<Cow<'a, str>>) -> Self {
-        Iri(txt.into())
+        Iri(Text::from(txt.into()))
     }
 
     /// Return the inner [`Cow<str>`](Cow).
+    ///
+    /// If this [`Iri`] is [shared](Iri::shared), its text is copied into a new [`String`].
     pub fn unwrap(self) -> Cow<'a, str> {
-        self.0
+        self.0.into_cow()
     }
 
     /// Apply a function to the inner text, assuming the result is still a valid IRI.
@@ -31,28 +51,74 @@ impl<'a> Iri<'a> {
     /// It is the responsibility of the caller to ensure that `f`
     /// produces a valid IRI when its argument is a valid IRI.
     pub fn map_unchecked(self, mut f: impl FnMut(Cow<'a, str>) -> Cow<'a, str>) -> Self {
-        Self(f(self.0))
+        Self(Text::from(f(self.0.into_cow())))
+    }
+
+    /// The text of this [`Iri`].
+    ///
+    /// This is equivalent to [`AsRef::<str>::as_ref`],
+    /// but does not require a type annotation, as [`Iri`] also implements [`AsRef<[u8]>`](AsRef).
+    pub fn as_str(&self) -> &str {
+        self.0.as_ref()
+    }
+
+    /// The UTF-8 bytes of this [`Iri`].
+    pub fn as_bytes(&self) -> &[u8] {
+        self.0.as_bytes()
     }
 
     /// Borrow this [`Iri`] as another [`Iri`].
     pub fn borrowed(&self) -> Iri<'_> {
-        Iri::new_unchecked(self.as_ref())
+        Iri::new_unchecked(self.as_str())
+    }
+
+    /// Convert this [`Iri`] into one backed by an [`Arc<str>`](alloc::sync::Arc),
+    /// so that it can then be cloned in constant time, without allocating.
+    ///
+    /// This copies the text once, unless this [`Iri`] is already shared.
+    /// Shared [`Iri`]s compare and hash exactly like any other [`Iri`].
+    ///
+    /// ```
+    /// # use r2c2_statement::Iri;
+    /// let iri = Iri::new_unchecked("https://example.org/").shared();
+    /// let copy = iri.clone();
+    /// assert_eq!(copy.as_str().as_ptr(), iri.as_str().as_ptr());
+    /// ```
+    pub fn shared(self) -> Iri<'static> {
+        Iri(self.0.into_shared())
+    }
+
+    /// Return a new [`Iri`] backed by `txt`, assuming it is a valid IRI.
+    pub(crate) fn new_shared_unchecked(txt: Arc<str>) -> Iri<'static> {
+        Iri(Text::Shared(txt))
+    }
+
+    /// Convert this [`Iri`] into one that does not borrow anything,
+    /// keeping it [shared](Iri::shared) if it already is.
+    pub(crate) fn into_owned(self) -> Iri<'static> {
+        Iri(self.0.into_owned())
     }
 }
 
-impl std::borrow::Borrow<str> for Iri<'_> {
+impl core::borrow::Borrow<str> for Iri<'_> {
     fn borrow(&self) -> &str {
         self.0.as_ref()
     }
 }
 
-impl std::convert::AsRef<str> for Iri<'_> {
+impl core::convert::AsRef<str> for Iri<'_> {
     fn as_ref(&self) -> &str {
         self.0.as_ref()
     }
 }
 
-impl std::ops::Deref for Iri<'_> {
+impl core::convert::AsRef<[u8]> for Iri<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl core::ops::Deref for Iri<'_> {
     type Target = str;
 
     fn deref(&self) -> &Self::Target {
@@ -60,32 +126,80 @@ impl std::ops::Deref for Iri<'_> {
     }
 }
 
-impl std::cmp::PartialEq<&str> for Iri<'_> {
+impl core::cmp::PartialEq<&str> for Iri<'_> {
     fn eq(&self, other: &&str) -> bool {
         self.0.as_ref() == *other
     }
 }
 
-impl std::cmp::PartialEq<Iri<'_>> for &str {
+impl core::cmp::PartialEq<Iri<'_>> for &str {
     fn eq(&self, other: &Iri) -> bool {
         *self == other.0.as_ref()
     }
 }
 
-impl std::cmp::PartialOrd<&str> for Iri<'_> {
-    fn partial_cmp(&self, other: &&str) -> Option<std::cmp::Ordering> {
+impl core::cmp::PartialOrd<&str> for Iri<'_> {
+    fn partial_cmp(&self, other: &&str) -> Option<core::cmp::Ordering> {
         Some(self.0.as_ref().cmp(other))
     }
 }
 
-impl std::cmp::PartialOrd<Iri<'_>> for &str {
-    fn partial_cmp(&self, other: &Iri<'_>) -> Option<std::cmp::Ordering> {
+impl core::cmp::PartialOrd<Iri<'_>> for &str {
+    fn partial_cmp(&self, other: &Iri<'_>) -> Option<core::cmp::Ordering> {
         Some(self.cmp(&other.0.as_ref()))
     }
 }
 
-impl std::fmt::Display for Iri<'_> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::cmp::PartialEq<str> for Iri<'_> {
+    fn eq(&self, other: &str) -> bool {
+        self.0.as_ref() == other
+    }
+}
+
+impl core::cmp::PartialEq<Iri<'_>> for str {
+    fn eq(&self, other: &Iri) -> bool {
+        self == other.0.as_ref()
+    }
+}
+
+impl core::cmp::PartialOrd<str> for Iri<'_> {
+    fn partial_cmp(&self, other: &str) -> Option<core::cmp::Ordering> {
+        Some(self.0.as_ref().cmp(other))
+    }
+}
+
+impl core::cmp::PartialOrd<Iri<'_>> for str {
+    fn partial_cmp(&self, other: &Iri<'_>) -> Option<core::cmp::Ordering> {
+        Some(str::cmp(self, other.0.as_ref()))
+    }
+}
+
+impl core::cmp::PartialEq<String> for Iri<'_> {
+    fn eq(&self, other: &String) -> bool {
+        self.0.as_ref() == other
+    }
+}
+
+impl core::cmp::PartialEq<Iri<'_>> for String {
+    fn eq(&self, other: &Iri) -> bool {
+        self == other.0.as_ref()
+    }
+}
+
+impl core::cmp::PartialOrd<String> for Iri<'_> {
+    fn partial_cmp(&self, other: &String) -> Option<core::cmp::Ordering> {
+        Some(self.0.as_ref().cmp(other))
+    }
+}
+
+impl core::cmp::PartialOrd<Iri<'_>> for String {
+    fn partial_cmp(&self, other: &Iri<'_>) -> Option<core::cmp::Ordering> {
+        Some(str::cmp(self, other.0.as_ref()))
+    }
+}
+
+impl core::fmt::Display for Iri<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "<{}>", self.0.as_ref())
     }
 }
@@ -94,6 +208,17 @@ impl std::fmt::Display for Iri<'_> {
 mod test {
     use super::*;
 
+    #[test]
+    fn as_bytes() {
+        let txt = "http://example.org/café";
+        let x = Iri::new_unchecked(txt);
+        assert_eq!(x.as_bytes(), txt.as_bytes());
+        assert_eq!(x.as_str(), txt);
+        let mut sink: Vec<u8> = vec![];
+        std::io::Write::write_all(&mut sink, x.as_ref()).unwrap();
+        assert_eq!(sink, txt.as_bytes());
+    }
+
     #[test]
     fn as_str() {
         let ex = "http://example.org/foo/bar";
@@ -104,6 +229,20 @@ mod test {
         assert!("http:" < iri1 && iri1 < "i");
     }
 
+    #[test]
+    fn owned_and_unsized_str() {
+        let ex = String::from("http://example.org/foo/bar");
+        let iri1 = Iri::new_unchecked(ex.as_str());
+        assert_eq!(iri1, ex);
+        assert_eq!(ex, iri1);
+        assert_eq!(iri1, *ex);
+        assert_eq!(*ex, iri1);
+        let (low, high) = (String::from("http:"), String::from("i"));
+        assert!(low < iri1 && iri1 < high);
+        assert!(*"http:" < iri1 && iri1 < *"i");
+        assert_ne!(iri1, String::from("http://example.org/foo/BAR"));
+    }
+
     #[test]
     fn borrowed() {
         let ex = "http://example.org/foo/bar";
@@ -112,6 +251,34 @@ mod test {
         assert_eq!(iri1, iri2);
     }
 
+    #[test]
+    fn shared() {
+        use core::hash::BuildHasher;
+
+        let ex = "http://example.org/foo/bar";
+        let hasher = std::hash::RandomState::new();
+        let iri1 = Iri::new_unchecked(ex.to_string()).shared();
+        let iri2 = iri1.clone();
+        // cloning a shared IRI does not copy its text
+        assert_eq!(iri2.as_str().as_ptr(), iri1.as_str().as_ptr());
+        assert_eq!(
+            iri1.clone().shared().as_str().as_ptr(),
+            iri1.as_str().as_ptr()
+        );
+        let iri3 = iri1.clone().into_owned();
+        assert_eq!(iri3.as_str().as_ptr(), iri1.as_str().as_ptr());
+        for other in [Iri::new_unchecked(ex), Iri::new_unchecked(ex.to_string())] {
+            assert_eq!(iri1, other);
+            assert_eq!(iri1.cmp(&other), core::cmp::Ordering::Equal);
+            assert_eq!(hasher.hash_one(&iri1), hasher.hash_one(&other));
+            assert_eq!(format!("{iri1:?}"), format!("{other:?}"));
+        }
+        assert_eq!(iri1.to_string(), format!("<{ex}>"));
+        assert_eq!(iri1.unwrap(), ex);
+        let iri4 = iri2.map_unchecked(|txt| Cow::Owned(format!("{txt}/baz")));
+        assert_eq!(iri4, "http://example.org/foo/bar/baz");
+    }
+
     #[test]
     fn display() {
         let ex = "http://example.org/foo/bar";
@@ -0,0 +1,378 @@
+use alloc::{
+    borrow::Cow,
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::hash::{Hash, Hasher};
+
+use crate::{
+    _bnode_label::Fnv1aHasher,
+    _hash::{object_proxy_hash, subject_proxy_hash},
+    Graph, ObjectProxy, SimpleTriple, SubjectProxy, Triple, triple_cmp, triple_eq,
+};
+
+/// Whether two [RDF graphs] are [isomorphic],
+/// i.e. whether they only differ by the labels of their blank nodes.
+///
+/// Triples without blank nodes must match exactly (according to [`triple_eq`]),
+/// while triples containing blank nodes (possibly nested in triple terms)
+/// must match under some bijection between the blank nodes of both graphs.
+/// Duplicate triples are ignored, as graphs are sets.
+///
+/// The search for a bijection is pruned by iteratively refining a partition of the blank nodes,
+/// based on a hash of the triples they occur in
+/// (so that only blank nodes with the same "neighbourhood" are ever tried against each other);
+/// it only has to backtrack on highly symmetric graphs.
+///
+/// ```
+/// # use r2c2_statement::*;
+/// const EX: &str = "https://example.org/ns/";
+/// let g1 = vec![triple!(_:a, EX:knows, _:b), triple!(_:b, EX:name, "Bob")];
+/// let g2 = vec![triple!(_:y, EX:name, "Bob"), triple!(_:x, EX:knows, _:y)];
+/// let g3 = vec![triple!(_:x, EX:knows, _:y), triple!(_:x, EX:name, "Bob")];
+/// assert!(are_isomorphic(&g1, &g2));
+/// assert!(!are_isomorphic(&g1, &g3));
+/// ```
+///
+/// [RDF graphs]: https://www.w3.org/TR/rdf12-concepts/#dfn-rdf-graph
+/// [isomorphic]: https://www.w3.org/TR/rdf12-concepts/#dfn-graph-isomorphism
+pub fn are_isomorphic<A: Graph + ?Sized, B: Graph + ?Sized>(a: &A, b: &B) -> bool {
+    let a = IsoGraph::new(a.triples());
+    let b = IsoGraph::new(b.triples());
+    a.ground.len() == b.ground.len()
+        && a.ground
+            .iter()
+            .zip(&b.ground)
+            .all(|(ta, tb)| triple_eq(ta, tb))
+        && a.triples.len() == b.triples.len()
+        && a.labels.len() == b.labels.len()
+        && search(&a, &b, vec![0; a.labels.len()], vec![0; b.labels.len()])
+}
+
+/// A graph prepared for the isomorphism check.
+struct IsoGraph {
+    /// The sorted triples without blank nodes
+    ground: Vec<SimpleTriple<'static>>,
+    /// The sorted triples with blank nodes
+    triples: Vec<SimpleTriple<'static>>,
+    /// The label of each blank node, by index
+    labels: Vec<String>,
+    /// The index of each blank node, by label
+    index: BTreeMap<String, usize>,
+    /// For each blank node, the indices (in `triples`) of the triples where it occurs
+    occurrences: Vec<Vec<usize>>,
+}
+
+impl IsoGraph {
+    fn new<T: Triple>(triples: impl Iterator<Item = T>) -> Self {
+        let mut all: Vec<_> = triples.map(SimpleTriple::from_triple).collect();
+        all.sort_by(triple_cmp);
+        all.dedup_by(|t1, t2| triple_eq(t1, t2));
+        let (ground, triples): (Vec<_>, Vec<_>) = all.into_iter().partition(|t| t.ground());
+        let mut graph = IsoGraph {
+            ground,
+            triples: Vec::new(),
+            labels: Vec::new(),
+            index: BTreeMap::new(),
+            occurrences: Vec::new(),
+        };
+        for (i, t) in triples.iter().enumerate() {
+            for_each_bnode(t, &mut |label| {
+                let idx = match graph.index.get(label) {
+                    Some(idx) => *idx,
+                    None => {
+                        let idx = graph.labels.len();
+                        graph.labels.push(label.to_string());
+                        graph.index.insert(label.to_string(), idx);
+                        graph.occurrences.push(Vec::new());
+                        idx
+                    }
+                };
+                if graph.occurrences[idx].last() != Some(&i) {
+                    graph.occurrences[idx].push(i);
+                }
+            });
+        }
+        graph.triples = triples;
+        graph
+    }
+
+    /// Compute the next color of each blank node,
+    /// from its current color and the signatures of the triples where it occurs.
+    fn refine(&self, colors: &[u64]) -> Vec<u64> {
+        (0..colors.len())
+            .map(|me| {
+                let mut signatures: Vec<u64> = self.occurrences[me]
+                    .iter()
+                    .map(|i| {
+                        let mut state = Fnv1aHasher::default();
+                        self.hash_triple(&self.triples[*i], me, colors, &mut state);
+                        state.finish()
+                    })
+                    .collect();
+                signatures.sort_unstable();
+                let mut state = Fnv1aHasher::default();
+                colors[me].hash(&mut state);
+                signatures.hash(&mut state);
+                state.finish()
+            })
+            .collect()
+    }
+
+    /// Hash `triple` from the point of view of blank node `me`,
+    /// replacing blank nodes by their color.
+    fn hash_triple<H: Hasher>(
+        &self,
+        triple: &SimpleTriple,
+        me: usize,
+        colors: &[u64],
+        state: &mut H,
+    ) {
+        match &triple.subject {
+            SubjectProxy::BlankNode(bnid) => self.hash_bnode(bnid, me, colors, state),
+            subject => subject_proxy_hash(subject, state),
+        }
+        triple.predicate.as_str().hash(state);
+        match &triple.object {
+            ObjectProxy::BlankNode(bnid) => self.hash_bnode(bnid, me, colors, state),
+            ObjectProxy::Triple(inner) => {
+                TRIPLE_TAG.hash(state);
+                self.hash_triple(inner, me, colors, state);
+            }
+            object => object_proxy_hash(object, state),
+        }
+    }
+
+    fn hash_bnode<H: Hasher>(&self, bnid: &str, me: usize, colors: &[u64], state: &mut H) {
+        let idx = self.index[bnid];
+        BLANK_NODE_TAG.hash(state);
+        (idx == me).hash(state);
+        colors[idx].hash(state);
+    }
+}
+
+/// Refine the colors of both graphs in lockstep until the partition is stable,
+/// then either check the bijection induced by the colors (if all of them are distinct),
+/// or try every candidate for one of the ambiguous blank nodes.
+fn search(a: &IsoGraph, b: &IsoGraph, mut colors_a: Vec<u64>, mut colors_b: Vec<u64>) -> bool {
+    let mut distinct = count_distinct(&colors_a);
+    loop {
+        let next_a = a.refine(&colors_a);
+        let next_b = b.refine(&colors_b);
+        if sorted(&next_a) != sorted(&next_b) {
+            return false;
+        }
+        colors_a = next_a;
+        colors_b = next_b;
+        let next_distinct = count_distinct(&colors_a);
+        if next_distinct == distinct {
+            break;
+        }
+        distinct = next_distinct;
+    }
+
+    if distinct == colors_a.len() {
+        return check_bijection(a, b, &colors_a, &colors_b);
+    }
+
+    // pick a blank node in the smallest ambiguous class,
+    // and try to map it to every blank node of the same class in b
+    let mut classes = BTreeMap::<u64, usize>::new();
+    for c in &colors_a {
+        *classes.entry(*c).or_default() += 1;
+    }
+    let (color, _) = classes
+        .iter()
+        .filter(|(_, size)| **size > 1)
+        .min_by_key(|(_, size)| **size)
+        .unwrap(); // since not all colors are distinct
+    let mut mark = color.wrapping_add(1);
+    while classes.contains_key(&mark) {
+        mark = mark.wrapping_add(1);
+    }
+    let x = colors_a.iter().position(|c| c == color).unwrap();
+    colors_b
+        .iter()
+        .enumerate()
+        .filter(|(_, c)| *c == color)
+        .any(|(y, _)| {
+            let mut colors_a = colors_a.clone();
+            let mut colors_b = colors_b.clone();
+            colors_a[x] = mark;
+            colors_b[y] = mark;
+            search(a, b, colors_a, colors_b)
+        })
+}
+
+/// Check that renaming the blank nodes of `a` to the blank nodes of `b` with the same color
+/// (all colors being distinct) yields exactly the triples of `b`.
+fn check_bijection(a: &IsoGraph, b: &IsoGraph, colors_a: &[u64], colors_b: &[u64]) -> bool {
+    let by_color: BTreeMap<u64, &str> = colors_b
+        .iter()
+        .zip(&b.labels)
+        .map(|(c, label)| (*c, label.as_str()))
+        .collect();
+    let mut renamed: Vec<_> = a
+        .triples
+        .iter()
+        .map(|t| {
+            SimpleTriple::from_triple_with(t, &mut |bnid| {
+                Cow::Owned(by_color[&colors_a[a.index[bnid.as_ref()]]].to_string())
+            })
+        })
+        .collect();
+    renamed.sort_by(triple_cmp);
+    renamed
+        .iter()
+        .zip(&b.triples)
+        .all(|(ta, tb)| triple_eq(ta, tb))
+}
+
+fn for_each_bnode(triple: &SimpleTriple, f: &mut impl FnMut(&str)) {
+    if let SubjectProxy::BlankNode(bnid) = &triple.subject {
+        f(bnid);
+    }
+    match &triple.object {
+        ObjectProxy::BlankNode(bnid) => f(bnid),
+        ObjectProxy::Triple(inner) => for_each_bnode(inner, f),
+        _ => {}
+    }
+}
+
+fn count_distinct(colors: &[u64]) -> usize {
+    let mut colors = sorted(colors);
+    colors.dedup();
+    colors.len()
+}
+
+fn sorted(colors: &[u64]) -> Vec<u64> {
+    let mut colors = colors.to_vec();
+    colors.sort_unstable();
+    colors
+}
+
+const BLANK_NODE_TAG: u8 = 1;
+const TRIPLE_TAG: u8 = 3;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::triple;
+
+    const EX: &str = "https://example.org/ns/";
+
+    #[test]
+    fn ground() {
+        let g1 = vec![triple!(EX:s, EX:p, "chat"@en), triple!(EX:s, EX:p, EX:o)];
+        let g2 = [
+            triple!(EX:s, EX:p, EX:o),
+            triple!(EX:s, EX:p, "chat"@EN),
+            triple!(EX:s, EX:p, EX:o),
+        ];
+        assert!(are_isomorphic(&g1, &g2[..]));
+        let g3 = vec![triple!(EX:s, EX:p, "chat"@fr), triple!(EX:s, EX:p, EX:o)];
+        assert!(!are_isomorphic(&g1, &g3));
+        assert!(!are_isomorphic(&g1, &g1[..1]));
+        assert!(are_isomorphic(
+            &Vec::<SimpleTriple>::new(),
+            &[] as &[SimpleTriple]
+        ));
+    }
+
+    #[test]
+    fn blank_nodes() {
+        let g1 = vec![
+            triple!(_:a, EX:p, _:b),
+            triple!(_:b, EX:p, _:a),
+            triple!(_:a, EX:name, "a"),
+            triple!(_:c, EX:p, EX:o),
+        ];
+        let g2 = vec![
+            triple!(_:x2, EX:p, EX:o),
+            triple!(_:x0, EX:name, "a"),
+            triple!(_:x1, EX:p, _:x0),
+            triple!(_:x0, EX:p, _:x1),
+        ];
+        assert!(are_isomorphic(&g1, &g2));
+        let g3 = vec![
+            triple!(_:x2, EX:p, EX:o),
+            triple!(_:x1, EX:name, "a"),
+            triple!(_:x1, EX:p, _:x0),
+            triple!(_:x1, EX:p, _:x1),
+        ];
+        assert!(!are_isomorphic(&g1, &g3));
+        // a blank node can not be mapped to an IRI
+        let g4 = vec![
+            triple!(EX:a, EX:p, _:b),
+            triple!(_:b, EX:p, EX:a),
+            triple!(EX:a, EX:name, "a"),
+            triple!(_:c, EX:p, EX:o),
+        ];
+        assert!(!are_isomorphic(&g1, &g4));
+    }
+
+    #[test]
+    fn nested() {
+        let g1 = vec![
+            triple!(_:a, EX:says, <<( _:b, EX:p, <<( _:a, EX:q, _:c )>> )>>),
+            triple!(_:c, EX:p, EX:o),
+        ];
+        let g2 = vec![
+            triple!(_:z, EX:p, EX:o),
+            triple!(_:x, EX:says, <<( _:y, EX:p, <<( _:x, EX:q, _:z )>> )>>),
+        ];
+        let g3 = vec![
+            triple!(_:z, EX:p, EX:o),
+            triple!(_:x, EX:says, <<( _:y, EX:p, <<( _:y, EX:q, _:z )>> )>>),
+        ];
+        assert!(are_isomorphic(&g1, &g2));
+        assert!(!are_isomorphic(&g1, &g3));
+    }
+
+    /// A cycle of `n` blank nodes, labelled with `prefix`.
+    fn cycle(prefix: &str, n: usize, offset: usize) -> Vec<SimpleTriple<'static>> {
+        (0..n)
+            .map(|i| {
+                let mut t = triple!(_:x, EX:p, _:y);
+                t.subject = SubjectProxy::BlankNode(format!("{prefix}{}", i + offset).into());
+                t.object =
+                    ObjectProxy::BlankNode(format!("{prefix}{}", (i + 1) % n + offset).into());
+                t
+            })
+            .collect()
+    }
+
+    #[test]
+    fn symmetric() {
+        // all blank nodes are indistinguishable by refinement alone
+        let six = cycle("a", 6, 0);
+        let mut three_three = cycle("b", 3, 0);
+        three_three.extend(cycle("b", 3, 3));
+        let mut other_six = cycle("c", 6, 0);
+        other_six.reverse();
+        assert!(are_isomorphic(&six, &other_six));
+        assert!(!are_isomorphic(&six, &three_three));
+        assert!(!are_isomorphic(&three_three, &six));
+
+        let mut g1 = three_three.clone();
+        g1.push(triple!(_:b0, EX:p, _:b3));
+        let mut g2 = cycle("d", 3, 0);
+        g2.extend(cycle("d", 3, 3));
+        g2.push(triple!(_:d4, EX:p, _:d2));
+        assert!(are_isomorphic(&g1, &g2));
+        g2.pop();
+        g2.push(triple!(_:d4, EX:p, _:d5));
+        assert!(!are_isomorphic(&g1, &g2));
+    }
+
+    #[test]
+    fn other_implementation() {
+        let g1 = vec![triple!(_:a, EX:p, <<( _:b, EX:q, "1" )>>)];
+        let g2: Vec<alloc::boxed::Box<dyn crate::DynTriple>> = vec![alloc::boxed::Box::new(
+            triple!(_:x, EX:p, <<( _:y, EX:q, "1" )>>),
+        )];
+        assert!(are_isomorphic(&g1, &g2));
+    }
+}
@@ -0,0 +1,288 @@
+use alloc::{
+    borrow::Cow,
+    boxed::Box,
+    collections::{BTreeMap, BTreeSet},
+    string::String,
+    vec::Vec,
+};
+
+use crate::{ObjectProxy, SimpleTriple, SubjectProxy, Triple, ns::rdf};
+
+/// Encode `items` as an [RDF collection] (a.k.a. `rdf:List`).
+///
+/// Return the head of the list, and the triples encoding it.
+/// Every item gets its own list node, a fresh blank node labelled by `bnode_gen`,
+/// which is the subject of an `rdf:first` triple (whose object is the item)
+/// and an `rdf:rest` triple (whose object is the next node, or `rdf:nil` for the last one).
+/// The empty list is encoded as `rdf:nil` and no triple.
+///
+/// `bnode_gen` is expected to return labels that do not appear elsewhere in the data.
+/// The list can be read back with [`read_list`].
+///
+/// ```
+/// # use r2c2_statement::*;
+/// const EX: &str = "https://example.org/ns/";
+/// let mut counter = 0;
+/// let (head, triples) = make_list(
+///     ["a", "b"].map(|local| ObjectProxy::Iri(Iri::new_unchecked(format!("{EX}{local}")))),
+///     &mut || {
+///         counter += 1;
+///         format!("l{counter}")
+///     },
+/// );
+/// assert_eq!(head, SubjectProxy::BlankNode("l1".into()));
+/// assert_eq!(triples.len(), 4);
+/// ```
+///
+/// [RDF collection]: https://www.w3.org/TR/rdf12-schema/#ch_collectionvocab
+pub fn make_list<'a, I>(
+    items: I,
+    bnode_gen: &mut impl FnMut() -> String,
+) -> (SubjectProxy<'a>, Vec<SimpleTriple<'a>>)
+where
+    I: IntoIterator<Item = ObjectProxy<'a, Box<SimpleTriple<'a>>>>,
+{
+    let items: Vec<_> = items.into_iter().collect();
+    let nodes: Vec<Cow<'a, str>> = items.iter().map(|_| Cow::Owned(bnode_gen())).collect();
+    let mut triples = Vec::with_capacity(2 * items.len());
+    for (i, item) in items.into_iter().enumerate() {
+        triples.push(SimpleTriple {
+            subject: SubjectProxy::BlankNode(nodes[i].clone()),
+            predicate: rdf::FIRST,
+            object: item,
+        });
+        triples.push(SimpleTriple {
+            subject: SubjectProxy::BlankNode(nodes[i].clone()),
+            predicate: rdf::REST,
+            object: match nodes.get(i + 1) {
+                Some(next) => ObjectProxy::BlankNode(next.clone()),
+                None => ObjectProxy::Iri(rdf::NIL),
+            },
+        });
+    }
+    let head = match nodes.into_iter().next() {
+        Some(first) => SubjectProxy::BlankNode(first),
+        None => SubjectProxy::Iri(rdf::NIL),
+    };
+    (head, triples)
+}
+
+/// Read the items of the [RDF collection] starting at `head` in `triples`.
+///
+/// Only `rdf:first` and `rdf:rest` triples whose subject is a blank node are considered;
+/// other triples are ignored.
+/// The walk always terminates, even if the triples encode a cyclic collection.
+///
+/// # Errors
+/// If the collection is not well-formed (see [`ListError`]).
+///
+/// [RDF collection]: https://www.w3.org/TR/rdf12-schema/#ch_collectionvocab
+pub fn read_list<T: Triple>(
+    head: &SubjectProxy,
+    triples: &[T],
+) -> Result<Vec<ObjectProxy<'static, Box<SimpleTriple<'static>>>>, ListError> {
+    let triples: Vec<_> = triples.iter().map(SimpleTriple::from_triple).collect();
+    let mut nodes: BTreeMap<&str, Node> = BTreeMap::new();
+    for t in &triples {
+        let SubjectProxy::BlankNode(bnid) = &t.subject else {
+            continue;
+        };
+        if t.predicate == rdf::FIRST {
+            nodes.entry(bnid).or_default().first.push(&t.object);
+        } else if t.predicate == rdf::REST {
+            nodes.entry(bnid).or_default().rest.push(&t.object);
+        }
+    }
+
+    let mut items = Vec::new();
+    let mut visited = BTreeSet::new();
+    let mut current = match head {
+        SubjectProxy::Iri(iri) if *iri == rdf::NIL => return Ok(items),
+        SubjectProxy::Iri(_) => return Err(ListError::NotAListNode),
+        SubjectProxy::BlankNode(bnid) => bnid.as_ref(),
+    };
+    loop {
+        if !visited.insert(current) {
+            return Err(ListError::Cycle(current.into()));
+        }
+        let (first, rest) = match nodes.get(current) {
+            None => return Err(ListError::MissingFirst(current.into())),
+            Some(node) => match (node.first.as_slice(), node.rest.as_slice()) {
+                ([first], [rest]) => (first, rest),
+                ([], _) => return Err(ListError::MissingFirst(current.into())),
+                (_, []) => return Err(ListError::MissingRest(current.into())),
+                ([_, _, ..], _) => return Err(ListError::MultipleFirst(current.into())),
+                (_, [_, _, ..]) => return Err(ListError::MultipleRest(current.into())),
+            },
+        };
+        items.push((*first).clone());
+        current = match rest {
+            ObjectProxy::Iri(iri) if *iri == rdf::NIL => return Ok(items),
+            ObjectProxy::BlankNode(bnid) => bnid,
+            _ => return Err(ListError::NotAListNode),
+        };
+    }
+}
+
+/// The error returned by [`read_list`] when the triples do not encode a well-formed [RDF collection].
+///
+/// The variants carrying a blank node label identify the offending list node.
+///
+/// [RDF collection]: https://www.w3.org/TR/rdf12-schema/#ch_collectionvocab
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum ListError {
+    /// A list node (the head or the object of an `rdf:rest` triple) is neither `rdf:nil` nor a blank node
+    NotAListNode,
+    /// A list node has no `rdf:first` value
+    MissingFirst(String),
+    /// A list node has no `rdf:rest` value
+    MissingRest(String),
+    /// A list node has several `rdf:first` values
+    MultipleFirst(String),
+    /// A list node has several `rdf:rest` values (i.e. the list is branching)
+    MultipleRest(String),
+    /// A list node is reached twice (i.e. the list is cyclic)
+    Cycle(String),
+}
+
+impl core::fmt::Display for ListError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ListError::NotAListNode => write!(f, "list node is neither rdf:nil nor a blank node"),
+            ListError::MissingFirst(bnid) => write!(f, "list node _:{bnid} has no rdf:first"),
+            ListError::MissingRest(bnid) => write!(f, "list node _:{bnid} has no rdf:rest"),
+            ListError::MultipleFirst(bnid) => {
+                write!(f, "list node _:{bnid} has several rdf:first")
+            }
+            ListError::MultipleRest(bnid) => write!(f, "list node _:{bnid} has several rdf:rest"),
+            ListError::Cycle(bnid) => write!(f, "list node _:{bnid} is part of a cycle"),
+        }
+    }
+}
+
+impl core::error::Error for ListError {}
+
+/// The `rdf:first` and `rdf:rest` values of a candidate list node.
+#[derive(Default)]
+struct Node<'t> {
+    first: Vec<&'t ObjectProxy<'static, Box<SimpleTriple<'static>>>>,
+    rest: Vec<&'t ObjectProxy<'static, Box<SimpleTriple<'static>>>>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Iri, SimpleTriple, triple};
+
+    const EX: &str = "https://example.org/ns/";
+
+    fn counter() -> impl FnMut() -> String {
+        let mut counter = 0;
+        move || {
+            counter += 1;
+            format!("l{counter}")
+        }
+    }
+
+    #[test]
+    fn empty() {
+        let (head, triples) = make_list([], &mut counter());
+        assert_eq!(head, SubjectProxy::Iri(rdf::NIL));
+        assert!(triples.is_empty());
+        assert_eq!(read_list(&head, &triples), Ok(vec![]));
+    }
+
+    #[test]
+    fn single() {
+        let item = triple!(_:x, EX:p, "a").object;
+        let (head, triples) = make_list([item.clone()], &mut counter());
+        assert_eq!(head, SubjectProxy::BlankNode("l1".into()));
+        assert_eq!(
+            triples,
+            vec![
+                triple!(_:l1, <"http://www.w3.org/1999/02/22-rdf-syntax-ns#first">, "a"),
+                triple!(_:l1, <"http://www.w3.org/1999/02/22-rdf-syntax-ns#rest">, <"http://www.w3.org/1999/02/22-rdf-syntax-ns#nil">),
+            ]
+        );
+        assert_eq!(read_list(&head, &triples), Ok(vec![item]));
+    }
+
+    #[test]
+    fn several() {
+        let items = vec![
+            triple!(_:x, EX:p, EX:a).object,
+            triple!(_:x, EX:p, _:b).object,
+            triple!(_:x, EX:p, "c"@en).object,
+            triple!(_:x, EX:p, <<( EX:s, EX:p, EX:o )>>).object,
+        ];
+        let (head, mut triples) = make_list(items.clone(), &mut counter());
+        assert_eq!(triples.len(), 8);
+        assert_eq!(
+            triples[3].object,
+            ObjectProxy::<SimpleTriple>::BlankNode("l3".into())
+        );
+        // unrelated triples are ignored
+        triples.insert(2, triple!(_:l2, EX:p, EX:o));
+        triples.reverse();
+        assert_eq!(read_list(&head, &triples), Ok(items));
+    }
+
+    #[test]
+    fn ill_formed() {
+        let items = [
+            triple!(_:x, EX:p, "x").object,
+            triple!(_:x, EX:p, "y").object,
+        ];
+        let (head, triples) = make_list(items, &mut counter());
+        assert_eq!(
+            read_list(&SubjectProxy::Iri(Iri::new_unchecked(EX)), &triples),
+            Err(ListError::NotAListNode)
+        );
+        assert_eq!(
+            read_list(&SubjectProxy::BlankNode("l0".into()), &triples),
+            Err(ListError::MissingFirst("l0".into()))
+        );
+        assert_eq!(
+            read_list(&head, &triples[..3]),
+            Err(ListError::MissingRest("l2".into()))
+        );
+        let mut not_a_node = triples.clone();
+        not_a_node[3].object = triple!(_:x, EX:p, "z").object;
+        assert_eq!(read_list(&head, &not_a_node), Err(ListError::NotAListNode));
+        let mut several_first = triples.clone();
+        several_first
+            .push(triple!(_:l2, <"http://www.w3.org/1999/02/22-rdf-syntax-ns#first">, "z"));
+        let err = read_list(&head, &several_first).unwrap_err();
+        assert_eq!(err, ListError::MultipleFirst("l2".into()));
+        assert_eq!(err.to_string(), "list node _:l2 has several rdf:first");
+    }
+
+    #[test]
+    fn cyclic() {
+        let items = [EX, EX, EX].map(|iri| ObjectProxy::Iri(Iri::new_unchecked(iri)));
+        let (head, mut triples) = make_list(items, &mut counter());
+        // l3 rdf:rest l2
+        triples[5].object = ObjectProxy::BlankNode("l2".into());
+        assert_eq!(
+            read_list(&head, &triples),
+            Err(ListError::Cycle("l2".into()))
+        );
+        // l1 rdf:rest l1
+        triples[1].object = ObjectProxy::BlankNode("l1".into());
+        assert_eq!(
+            read_list(&head, &triples),
+            Err(ListError::Cycle("l1".into()))
+        );
+    }
+
+    #[test]
+    fn branching() {
+        let items = [EX, EX].map(|iri| ObjectProxy::Iri(Iri::new_unchecked(iri)));
+        let (head, mut triples) = make_list(items, &mut counter());
+        triples.push(triple!(_:l1, <"http://www.w3.org/1999/02/22-rdf-syntax-ns#rest">, _:other));
+        assert_eq!(
+            read_list(&head, &triples),
+            Err(ListError::MultipleRest("l1".into()))
+        );
+    }
+}
@@ -1,14 +1,18 @@
+mod _canonical;
 mod _language_tag;
-use std::borrow::Cow;
+use alloc::borrow::Cow;
 
 pub use _language_tag::*;
 
-use crate::Iri;
+use crate::{
+    Iri,
+    ns::{rdf, xsd},
+};
 
 /// The different possible value for literals' [base direction].
 ///
 /// [base direction]: https://www.w3.org/TR/rdf12-concepts/#dfn-base-direction
-#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum BaseDir {
     #[default]
     /// The [base direction] `ltr` (left to right)
@@ -23,8 +27,21 @@ pub enum BaseDir {
 
 /// A utility type representing an RDF [literal].
 ///
+/// Literals are ordered by [datatype IRI](Literal::datatype_iri) first
+/// (so language strings are ordered under `rdf:langString` or `rdf:dirLangString`),
+/// then by language tag (case-insensitively, absent first),
+/// then by base direction (absent first, then `ltr`, then `rtl`),
+/// then by lexical form.
+/// This order is consistent with the equality of [`Literal`].
+///
+/// Two literals are equal if they have the same lexical form, datatype IRI,
+/// language tag (compared case-insensitively) and base direction,
+/// as per [literal term equality].
+/// Their hash is consistent with this equality.
+///
 /// [literal]: https://www.w3.org/TR/rdf12-concepts/#dfn-literal
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+/// [literal term equality]: https://www.w3.org/TR/rdf12-concepts/#dfn-literal-term-equality
+#[derive(Clone, Debug)]
 pub enum Literal<'a> {
     /// A literal with a specified datatype.
     Typed(Cow<'a, str>, Iri<'a>),
@@ -34,9 +51,71 @@ pub enum Literal<'a> {
     LanguageString(Cow<'a, str>, LangTag<'a>, Option<BaseDir>),
 }
 
+impl<'a> Literal<'a> {
+    /// Build a [`Literal::Typed`] from its lexical form and datatype IRI.
+    ///
+    /// `rdf:langString` and `rdf:dirLangString` are not valid datatypes for such literals;
+    /// use [`Literal::new_lang`] or [`Literal::new_dir_lang`] instead.
+    /// See also `LiteralValidation::typed` in the `r2c2_statement_validation` crate,
+    /// which accepts an unchecked datatype IRI.
+    ///
+    /// ```
+    /// # use r2c2_statement::{*, ns::xsd};
+    /// let lit = Literal::new_typed("42", xsd::INTEGER);
+    /// assert_eq!(lit.datatype_iri(), xsd::INTEGER);
+    /// ```
+    pub fn new_typed(lex: impl Into<Cow<'a, str>>, datatype: Iri<'a>) -> Self {
+        debug_assert!(datatype != rdf::LANG_STRING && datatype != rdf::DIR_LANG_STRING);
+        Literal::Typed(lex.into(), datatype)
+    }
+
+    /// Build a [language tagged string](https://www.w3.org/TR/rdf12-concepts/#dfn-language-tagged-string).
+    ///
+    /// ```
+    /// # use r2c2_statement::*;
+    /// let lit = Literal::new_lang("chat", LangTag::new_unchecked("fr"));
+    /// assert_eq!(lit.language_tag().unwrap(), LangTag::new_unchecked("fr"));
+    /// assert_eq!(lit.base_direction(), None);
+    /// ```
+    pub fn new_lang(lex: impl Into<Cow<'a, str>>, tag: LangTag<'a>) -> Self {
+        Literal::LanguageString(lex.into(), tag, None)
+    }
+
+    /// Build a [directional language tagged string](https://www.w3.org/TR/rdf12-concepts/#dfn-dir-lang-string).
+    ///
+    /// ```
+    /// # use r2c2_statement::*;
+    /// let lit = Literal::new_dir_lang("مرحبا", LangTag::new_unchecked("ar"), BaseDir::Rtl);
+    /// assert_eq!(lit.base_direction(), Some(BaseDir::Rtl));
+    /// ```
+    pub fn new_dir_lang(lex: impl Into<Cow<'a, str>>, tag: LangTag<'a>, dir: BaseDir) -> Self {
+        Literal::LanguageString(lex.into(), tag, Some(dir))
+    }
+
+    /// Give this language string the [base direction] `dir`
+    /// (replacing its previous base direction, if any).
+    ///
+    /// Literals that are not language strings are returned unchanged,
+    /// as they can not have a base direction.
+    ///
+    /// ```
+    /// # use r2c2_statement::*;
+    /// let lit = Literal::new_lang("שלום", LangTag::new_unchecked("he")).with_direction(BaseDir::Rtl);
+    /// assert_eq!(lit, Literal::new_dir_lang("שלום", LangTag::new_unchecked("he"), BaseDir::Rtl));
+    /// ```
+    ///
+    /// [base direction]: https://www.w3.org/TR/rdf12-concepts/#dfn-base-direction
+    pub fn with_direction(self, dir: BaseDir) -> Self {
+        match self {
+            Literal::LanguageString(lex, tag, _) => Literal::LanguageString(lex, tag, Some(dir)),
+            typed => typed,
+        }
+    }
+}
+
 impl Literal<'_> {
     /// Borrow this [`Literal`] as another [`Literal`].
-    pub fn borrowed(&self) -> Literal {
+    pub fn borrowed(&self) -> Literal<'_> {
         match self {
             Literal::Typed(lex, iri) => Literal::Typed(Cow::from(lex.as_ref()), iri.borrowed()),
             Literal::LanguageString(lex, lang_tag, base_dir) => {
@@ -46,7 +125,7 @@ impl Literal<'_> {
     }
 
     /// [lexical form](https://www.w3.org/TR/rdf12-concepts/#dfn-lexical-form) of this literal
-    pub fn lexical_form(&self) -> Cow<str> {
+    pub fn lexical_form(&self) -> Cow<'_, str> {
         let ref_cow = match self {
             Literal::Typed(lex, ..) => lex,
             Literal::LanguageString(lex, ..) => lex,
@@ -54,12 +133,24 @@ impl Literal<'_> {
         Cow::from(ref_cow.as_ref())
     }
 
+    /// Length, in bytes, of the [lexical form](https://www.w3.org/TR/rdf12-concepts/#dfn-lexical-form) of this literal
+    pub fn lexical_len(&self) -> usize {
+        match self {
+            Literal::Typed(lex, ..) | Literal::LanguageString(lex, ..) => lex.len(),
+        }
+    }
+
+    /// Whether the [lexical form](https://www.w3.org/TR/rdf12-concepts/#dfn-lexical-form) of this literal is empty
+    pub fn lexical_is_empty(&self) -> bool {
+        self.lexical_len() == 0
+    }
+
     /// [datatype IRI](https://www.w3.org/TR/rdf12-concepts/#dfn-datatype-iri) of this literal
     pub fn datatype_iri(&self) -> Iri<'_> {
         match self {
             Literal::Typed(_, iri) => iri.borrowed(),
-            Literal::LanguageString(_, _, None) => Iri::new_unchecked(RDF_LANG_STRING),
-            Literal::LanguageString(_, _, Some(_)) => Iri::new_unchecked(RDF_DIR_LANG_STRING),
+            Literal::LanguageString(_, _, None) => rdf::LANG_STRING,
+            Literal::LanguageString(_, _, Some(_)) => rdf::DIR_LANG_STRING,
         }
     }
 
@@ -72,6 +163,16 @@ impl Literal<'_> {
         }
     }
 
+    /// Whether this literal is a [simple literal](https://www.w3.org/TR/rdf12-concepts/#dfn-simple-literal),
+    /// i.e. a literal with datatype `xsd:string`.
+    ///
+    /// Simple literals are always represented as [`Literal::Typed`] with datatype [`xsd::STRING`](crate::ns::xsd::STRING)
+    /// (even if the underlying implementation stores them without a datatype),
+    /// so that they are compared and hashed uniformly across implementations.
+    pub fn is_simple(&self) -> bool {
+        matches!(self, Literal::Typed(_, dt) if *dt == xsd::STRING)
+    }
+
     /// [base direction](https://www.w3.org/TR/rdf12-concepts/#dfn-base-direction) of this literal, if any
     pub fn base_direction(&self) -> Option<BaseDir> {
         if let Literal::LanguageString(_, _, Some(dir)) = self {
@@ -82,5 +183,178 @@ impl Literal<'_> {
     }
 }
 
-static RDF_LANG_STRING: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#langString";
-static RDF_DIR_LANG_STRING: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#dirLangString";
+impl PartialEq for Literal<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.lexical_form() == other.lexical_form()
+            && self.datatype_iri() == other.datatype_iri()
+            && self.language_tag() == other.language_tag()
+            && self.base_direction() == other.base_direction()
+    }
+}
+
+impl Eq for Literal<'_> {}
+
+impl core::hash::Hash for Literal<'_> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.lexical_form().hash(state);
+        self.datatype_iri().hash(state);
+        // LangTag hashes case-insensitively, consistently with its equality
+        self.language_tag().hash(state);
+        self.base_direction().hash(state);
+    }
+}
+
+impl Ord for Literal<'_> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.datatype_iri()
+            .cmp(&other.datatype_iri())
+            .then_with(|| self.language_tag().cmp(&other.language_tag()))
+            .then_with(|| self.base_direction().cmp(&other.base_direction()))
+            .then_with(|| self.lexical_form().cmp(&other.lexical_form()))
+    }
+}
+
+impl PartialOrd for Literal<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn constructors() {
+        let xsd_integer = Iri::new_unchecked("http://www.w3.org/2001/XMLSchema#integer");
+        let en = LangTag::new_unchecked("en");
+        assert_eq!(
+            Literal::new_typed("42", xsd_integer.clone()),
+            Literal::Typed("42".into(), xsd_integer.clone())
+        );
+        assert_eq!(
+            Literal::new_lang("chat", en.clone()),
+            Literal::LanguageString("chat".into(), en.clone(), None)
+        );
+        assert_eq!(
+            Literal::new_dir_lang("chat", en.clone(), BaseDir::Ltr),
+            Literal::LanguageString("chat".into(), en.clone(), Some(BaseDir::Ltr))
+        );
+
+        let lit = Literal::new_lang("chat", en.clone()).with_direction(BaseDir::Ltr);
+        assert_eq!(lit.datatype_iri(), rdf::DIR_LANG_STRING);
+        assert_eq!(lit.base_direction(), Some(BaseDir::Ltr));
+        let lit = lit.with_direction(BaseDir::Rtl);
+        assert_eq!(lit.base_direction(), Some(BaseDir::Rtl));
+        let typed = Literal::new_typed("42", xsd_integer).with_direction(BaseDir::Rtl);
+        assert_eq!(typed.base_direction(), None);
+    }
+
+    #[test]
+    fn is_simple() {
+        assert!(Literal::new_typed("chat", xsd::STRING).is_simple());
+        assert!(!Literal::new_typed("42", xsd::INTEGER).is_simple());
+        assert!(!Literal::new_lang("chat", LangTag::new_unchecked("en")).is_simple());
+        assert!(
+            !Literal::new_dir_lang("chat", LangTag::new_unchecked("en"), BaseDir::Ltr).is_simple()
+        );
+    }
+
+    #[test]
+    fn lexical_len() {
+        let empty = Literal::new_typed("", xsd::STRING);
+        assert_eq!(empty.lexical_len(), 0);
+        assert!(empty.lexical_is_empty());
+        let lit = Literal::new_dir_lang("été", LangTag::new_unchecked("fr"), BaseDir::Ltr);
+        assert_eq!(lit.lexical_len(), lit.lexical_form().len());
+        assert_eq!(lit.lexical_len(), 5);
+        assert!(!lit.lexical_is_empty());
+        assert!(!Literal::new_typed("0", xsd::INTEGER).lexical_is_empty());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn new_typed_lang_string() {
+        Literal::new_typed("chat", rdf::LANG_STRING);
+    }
+
+    #[test]
+    fn ord() {
+        let typed = |lex: &'static str, dt: &'static str| {
+            Literal::Typed(lex.into(), Iri::new_unchecked(dt))
+        };
+        let lang = |lex: &'static str, tag: &'static str, dir: Option<BaseDir>| {
+            Literal::LanguageString(lex.into(), LangTag::new_unchecked(tag), dir)
+        };
+        let sorted = [
+            typed("b", "http://example.org/dt"),
+            typed("a", "http://example.org/eu"),
+            lang("a", "en", Some(BaseDir::Ltr)),
+            lang("b", "en", Some(BaseDir::Ltr)),
+            lang("a", "en", Some(BaseDir::Rtl)),
+            lang("b", "EN", None),
+            lang("a", "fr", None),
+            typed("a", "http://www.w3.org/1999/02/22-rdf-syntax-ns#langStrinh"),
+        ];
+        for (i, a) in sorted.iter().enumerate() {
+            for (j, b) in sorted.iter().enumerate() {
+                assert_eq!(a.cmp(b), i.cmp(&j), "{a:?} {b:?}");
+            }
+        }
+        assert_eq!(
+            lang("a", "en", None).cmp(&lang("a", "EN", None)),
+            core::cmp::Ordering::Equal
+        );
+        assert_eq!(lang("a", "en", None), lang("a", "EN", None));
+    }
+
+    fn hash(lit: &Literal) -> u64 {
+        use core::hash::{Hash, Hasher};
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        lit.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn eq_and_hash() {
+        const EX: &str = "https://example.org/ns/";
+        let lit = |t: crate::SimpleTriple<'static>| match t.object {
+            crate::ObjectProxy::Literal(lit) => lit,
+            _ => panic!(),
+        };
+        let equal_pairs = [
+            (
+                lit(crate::triple!(_:a, EX:p, "x"@EN)),
+                lit(crate::triple!(_:a, EX:p, "x"@en)),
+            ),
+            (
+                lit(crate::triple!(_:a, EX:p, "x"@en-GB--rtl)),
+                lit(crate::triple!(_:a, EX:p, "x"@EN-gb--rtl)),
+            ),
+            (
+                lit(crate::triple!(_:a, EX:p, "x")),
+                lit(crate::triple!(_:a, EX:p, "x"^^<"http://www.w3.org/2001/XMLSchema#string">)),
+            ),
+        ];
+        for (a, b) in &equal_pairs {
+            assert_eq!(a, b);
+            assert_eq!(hash(a), hash(b));
+        }
+        let different = [
+            lit(crate::triple!(_:a, EX:p, "x")),
+            lit(crate::triple!(_:a, EX:p, "X")),
+            lit(crate::triple!(_:a, EX:p, "x"@en)),
+            lit(crate::triple!(_:a, EX:p, "x"@en--ltr)),
+            lit(crate::triple!(_:a, EX:p, "x"@en--rtl)),
+            lit(
+                crate::triple!(_:a, EX:p, "x"^^<"http://www.w3.org/1999/02/22-rdf-syntax-ns#langString">),
+            ),
+        ];
+        for (i, a) in different.iter().enumerate() {
+            for (j, b) in different.iter().enumerate() {
+                assert_eq!(a == b, i == j, "{a:?} {b:?}");
+            }
+        }
+    }
+}
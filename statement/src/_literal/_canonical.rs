@@ -0,0 +1,443 @@
+use alloc::{
+    borrow::Cow,
+    format,
+    string::{String, ToString},
+};
+
+use crate::{Iri, Literal, ns::xsd};
+
+impl<'a> Literal<'a> {
+    /// The [canonical] form of this literal, if its datatype is supported
+    /// and its lexical form is valid for this datatype.
+    ///
+    /// Two literals with a supported datatype have the same canonical form if and only if they have the same value
+    /// (e.g. `"42"^^xsd:integer` and `"+042"^^xsd:integer`).
+    /// The supported datatypes are:
+    /// * `xsd:integer`, canonicalized without sign (unless negative) nor leading zeros;
+    /// * `xsd:decimal`, canonicalized without sign (unless negative), leading or trailing zeros,
+    ///   and without decimal point if the value is an integer (as per XSD 1.1);
+    /// * `xsd:boolean`, canonicalized as `true` or `false`;
+    /// * `xsd:double`, canonicalized in scientific notation with a single digit before the decimal point
+    ///   (e.g. `4.2E1`), or as `INF`, `-INF` or `NaN`;
+    /// * `xsd:dateTime`, canonicalized with the timezone (if any) normalized to UTC (`Z`),
+    ///   `24:00:00` replaced by `00:00:00` on the next day, and no trailing zeros in fractional seconds.
+    ///
+    /// Return `None` for any other datatype (including language strings),
+    /// or if the lexical form is not in the [lexical space] of the datatype
+    /// (leading and trailing spaces are not allowed).
+    ///
+    /// ```
+    /// # use r2c2_statement::*;
+    /// let lit = Literal::Typed("+042".into(), ns::xsd::INTEGER);
+    /// assert_eq!(lit.canonical(), Some(Literal::Typed("42".into(), ns::xsd::INTEGER)));
+    /// let lit = Literal::Typed("forty-two".into(), ns::xsd::INTEGER);
+    /// assert_eq!(lit.canonical(), None);
+    /// ```
+    ///
+    /// [canonical]: https://www.w3.org/TR/xmlschema11-2/#dt-canonical-mapping
+    /// [lexical space]: https://www.w3.org/TR/rdf12-concepts/#dfn-lexical-space
+    pub fn canonical(&self) -> Option<Literal<'static>> {
+        let Literal::Typed(lex, dt) = self else {
+            return None;
+        };
+        let (lex, dt): (String, Iri<'static>) = if *dt == xsd::INTEGER {
+            (canonical_integer(lex)?, xsd::INTEGER)
+        } else if *dt == xsd::DECIMAL {
+            (canonical_decimal(lex)?, xsd::DECIMAL)
+        } else if *dt == xsd::BOOLEAN {
+            (canonical_boolean(lex)?.to_string(), xsd::BOOLEAN)
+        } else if *dt == xsd::DOUBLE {
+            (canonical_double(lex)?, xsd::DOUBLE)
+        } else if *dt == xsd::DATE_TIME {
+            (canonical_date_time(lex)?, xsd::DATE_TIME)
+        } else {
+            return None;
+        };
+        Some(Literal::Typed(Cow::Owned(lex), dt))
+    }
+
+    /// The [canonical form](Literal::canonical) of this literal if any, or this literal unchanged otherwise.
+    pub fn canonicalized_or_self(self) -> Literal<'a> {
+        match self.canonical() {
+            Some(canonical) => canonical,
+            None => self,
+        }
+    }
+}
+
+/// Split the optional sign of `lex`, returning whether it is negative.
+fn split_sign(lex: &str) -> (bool, &str) {
+    match lex.as_bytes().first() {
+        Some(b'-') => (true, &lex[1..]),
+        Some(b'+') => (false, &lex[1..]),
+        _ => (false, lex),
+    }
+}
+
+fn all_digits(txt: &str) -> bool {
+    txt.bytes().all(|b| b.is_ascii_digit())
+}
+
+fn canonical_integer(lex: &str) -> Option<String> {
+    let (negative, digits) = split_sign(lex);
+    if digits.is_empty() || !all_digits(digits) {
+        return None;
+    }
+    Some(match digits.trim_start_matches('0') {
+        "" => "0".into(),
+        digits if negative => format!("-{digits}"),
+        digits => digits.into(),
+    })
+}
+
+fn canonical_decimal(lex: &str) -> Option<String> {
+    let (negative, unsigned) = split_sign(lex);
+    let (int, frac) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    if (int.is_empty() && frac.is_empty()) || !all_digits(int) || !all_digits(frac) {
+        return None;
+    }
+    let int = int.trim_start_matches('0');
+    let frac = frac.trim_end_matches('0');
+    let sign = if negative && !(int.is_empty() && frac.is_empty()) {
+        "-"
+    } else {
+        ""
+    };
+    let int = if int.is_empty() { "0" } else { int };
+    Some(if frac.is_empty() {
+        format!("{sign}{int}")
+    } else {
+        format!("{sign}{int}.{frac}")
+    })
+}
+
+fn canonical_boolean(lex: &str) -> Option<&'static str> {
+    match lex {
+        "true" | "1" => Some("true"),
+        "false" | "0" => Some("false"),
+        _ => None,
+    }
+}
+
+fn canonical_double(lex: &str) -> Option<String> {
+    let value: f64 = match lex {
+        "INF" | "+INF" => f64::INFINITY,
+        "-INF" => f64::NEG_INFINITY,
+        "NaN" => f64::NAN,
+        _ => {
+            // check the lexical space, which is stricter than what `f64::from_str` accepts
+            let (_, unsigned) = split_sign(lex);
+            let (mantissa, exponent) = match unsigned.find(['e', 'E']) {
+                Some(i) => (&unsigned[..i], Some(&unsigned[i + 1..])),
+                None => (unsigned, None),
+            };
+            let (int, frac) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+            if (int.is_empty() && frac.is_empty()) || !all_digits(int) || !all_digits(frac) {
+                return None;
+            }
+            if let Some(exponent) = exponent {
+                let (_, digits) = split_sign(exponent);
+                if digits.is_empty() || !all_digits(digits) {
+                    return None;
+                }
+            }
+            lex.parse().ok()?
+        }
+    };
+    Some(if value.is_nan() {
+        "NaN".into()
+    } else if value.is_infinite() {
+        if value > 0.0 { "INF" } else { "-INF" }.into()
+    } else {
+        let txt = format!("{value:E}");
+        match txt.split_once('E') {
+            Some((mantissa, exponent)) if !mantissa.contains('.') => {
+                format!("{mantissa}.0E{exponent}")
+            }
+            _ => txt,
+        }
+    })
+}
+
+fn canonical_date_time(lex: &str) -> Option<String> {
+    let (negative, rest) = match lex.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, lex),
+    };
+    let (year, rest) = rest.split_once('-')?;
+    if year.len() < 4 || (year.len() > 4 && year.starts_with('0')) || !all_digits(year) {
+        return None;
+    }
+    let year: i64 = year.parse().ok()?;
+    let year = if negative { -year } else { year };
+
+    let bytes = rest.as_bytes();
+    if bytes.len() < 14
+        || bytes[2] != b'-'
+        || bytes[5] != b'T'
+        || bytes[8] != b':'
+        || bytes[11] != b':'
+    {
+        return None;
+    }
+    let two_digits = |i: usize| -> Option<i64> {
+        let txt = rest.get(i..i + 2)?;
+        all_digits(txt).then(|| txt.parse().ok())?
+    };
+    let month = two_digits(0)?;
+    let day = two_digits(3)?;
+    let hour = two_digits(6)?;
+    let minute = two_digits(9)?;
+    let second = two_digits(12)?;
+    let rest = &rest[14..];
+
+    let (frac, tz) = match rest.strip_prefix('.') {
+        Some(rest) => {
+            let end = rest
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(rest.len());
+            if end == 0 {
+                return None;
+            }
+            (rest[..end].trim_end_matches('0'), &rest[end..])
+        }
+        None => ("", rest),
+    };
+    let offset = match tz {
+        "" => None,
+        "Z" => Some(0),
+        _ => {
+            let bytes = tz.as_bytes();
+            if bytes.len() != 6 || bytes[3] != b':' {
+                return None;
+            }
+            let sign = match bytes[0] {
+                b'+' => 1,
+                b'-' => -1,
+                _ => return None,
+            };
+            let hh: i64 = all_digits(&tz[1..3]).then(|| tz[1..3].parse().ok())??;
+            let mm: i64 = all_digits(&tz[4..6]).then(|| tz[4..6].parse().ok())??;
+            if hh > 14 || mm > 59 || (hh == 14 && mm > 0) {
+                return None;
+            }
+            Some(sign * (hh * 60 + mm))
+        }
+    };
+
+    if !(1..=12).contains(&month)
+        || day < 1
+        || day > days_in_month(year, month)
+        || minute > 59
+        || second > 59
+        || hour > 24
+        || (hour == 24 && (minute > 0 || second > 0 || !frac.is_empty()))
+    {
+        return None;
+    }
+
+    // normalize to UTC, and 24:00:00 to 00:00:00 on the next day
+    let minutes = hour * 60 + minute - offset.unwrap_or(0);
+    let (mut year, mut month, mut day) = (year, month, day);
+    match minutes.div_euclid(24 * 60) {
+        -1 => {
+            day -= 1;
+            if day == 0 {
+                month -= 1;
+                if month == 0 {
+                    month = 12;
+                    year -= 1;
+                }
+                day = days_in_month(year, month);
+            }
+        }
+        1 => {
+            day += 1;
+            if day > days_in_month(year, month) {
+                day = 1;
+                month += 1;
+                if month == 13 {
+                    month = 1;
+                    year += 1;
+                }
+            }
+        }
+        _ => {}
+    }
+    let minutes = minutes.rem_euclid(24 * 60);
+
+    let sign = if year < 0 { "-" } else { "" };
+    let mut txt = format!(
+        "{sign}{:04}-{month:02}-{day:02}T{:02}:{:02}:{second:02}",
+        year.unsigned_abs(),
+        minutes / 60,
+        minutes % 60,
+    );
+    if !frac.is_empty() {
+        txt.push('.');
+        txt.push_str(frac);
+    }
+    if offset.is_some() {
+        txt.push('Z');
+    }
+    Some(txt)
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        4 | 6 | 9 | 11 => 30,
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        _ => 31,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn canonical(lex: &str, dt: Iri<'static>) -> Option<String> {
+        Literal::Typed(lex.into(), dt)
+            .canonical()
+            .map(|lit| lit.lexical_form().into_owned())
+    }
+
+    #[test]
+    fn integer() {
+        for (lex, expected) in [
+            ("42", "42"),
+            ("+42", "42"),
+            ("0042", "42"),
+            ("-42", "-42"),
+            ("-0", "0"),
+            ("000", "0"),
+            (
+                "123456789012345678901234567890",
+                "123456789012345678901234567890",
+            ),
+        ] {
+            assert_eq!(
+                canonical(lex, xsd::INTEGER).as_deref(),
+                Some(expected),
+                "{lex}"
+            );
+        }
+        for lex in ["", "+", "4.2", " 42", "42 ", "0x2A", "--1"] {
+            assert_eq!(canonical(lex, xsd::INTEGER), None, "{lex}");
+        }
+    }
+
+    #[test]
+    fn decimal() {
+        for (lex, expected) in [
+            ("42", "42"),
+            ("42.0", "42"),
+            ("+042.500", "42.5"),
+            ("-.5", "-0.5"),
+            ("5.", "5"),
+            ("-0.0", "0"),
+            ("0.01", "0.01"),
+        ] {
+            assert_eq!(
+                canonical(lex, xsd::DECIMAL).as_deref(),
+                Some(expected),
+                "{lex}"
+            );
+        }
+        for lex in ["", ".", "-", "1.2.3", "1e3", "INF"] {
+            assert_eq!(canonical(lex, xsd::DECIMAL), None, "{lex}");
+        }
+    }
+
+    #[test]
+    fn boolean() {
+        assert_eq!(canonical("1", xsd::BOOLEAN).as_deref(), Some("true"));
+        assert_eq!(canonical("true", xsd::BOOLEAN).as_deref(), Some("true"));
+        assert_eq!(canonical("0", xsd::BOOLEAN).as_deref(), Some("false"));
+        assert_eq!(canonical("false", xsd::BOOLEAN).as_deref(), Some("false"));
+        assert_eq!(canonical("True", xsd::BOOLEAN), None);
+    }
+
+    #[test]
+    fn double() {
+        for (lex, expected) in [
+            ("42", "4.2E1"),
+            ("+4.2e1", "4.2E1"),
+            ("1", "1.0E0"),
+            ("0.001", "1.0E-3"),
+            ("-.5E+2", "-5.0E1"),
+            ("0", "0.0E0"),
+            ("-0", "-0.0E0"),
+            ("INF", "INF"),
+            ("+INF", "INF"),
+            ("-INF", "-INF"),
+            ("NaN", "NaN"),
+            ("1e400", "INF"),
+        ] {
+            assert_eq!(
+                canonical(lex, xsd::DOUBLE).as_deref(),
+                Some(expected),
+                "{lex}"
+            );
+        }
+        for lex in ["", "e1", "1e", "inf", "nan", "infinity", "1.5f", "0x1p3"] {
+            assert_eq!(canonical(lex, xsd::DOUBLE), None, "{lex}");
+        }
+    }
+
+    #[test]
+    fn date_time() {
+        for (lex, expected) in [
+            ("2024-02-29T12:00:00", "2024-02-29T12:00:00"),
+            ("2024-02-29T12:00:00.500", "2024-02-29T12:00:00.5"),
+            ("2024-02-29T12:00:00.000Z", "2024-02-29T12:00:00Z"),
+            ("2024-02-29T12:00:00+00:00", "2024-02-29T12:00:00Z"),
+            ("2024-02-29T12:00:00-00:00", "2024-02-29T12:00:00Z"),
+            ("2024-02-29T12:30:00+02:00", "2024-02-29T10:30:00Z"),
+            ("2024-02-29T23:30:00-01:00", "2024-03-01T00:30:00Z"),
+            ("2024-12-31T23:00:00-14:00", "2025-01-01T13:00:00Z"),
+            ("2024-01-01T00:00:00+00:01", "2023-12-31T23:59:00Z"),
+            ("2023-12-31T24:00:00", "2024-01-01T00:00:00"),
+            ("-0044-03-15T12:00:00", "-0044-03-15T12:00:00"),
+            ("12024-01-01T00:00:00", "12024-01-01T00:00:00"),
+        ] {
+            assert_eq!(
+                canonical(lex, xsd::DATE_TIME).as_deref(),
+                Some(expected),
+                "{lex}"
+            );
+        }
+        for lex in [
+            "",
+            "2023-02-29T12:00:00",
+            "2024-13-01T12:00:00",
+            "2024-01-01T12:60:00",
+            "2024-01-01T24:00:01",
+            "2024-01-01T12:00",
+            "2024-01-01 12:00:00",
+            "2024-01-01T12:00:00.",
+            "2024-01-01T12:00:00+15:00",
+            "2024-01-01T12:00:00+0100",
+            "024-01-01T12:00:00",
+            "02024-01-01T12:00:00",
+            "2024-1-01T12:00:00",
+        ] {
+            assert_eq!(canonical(lex, xsd::DATE_TIME), None, "{lex}");
+        }
+    }
+
+    #[test]
+    fn others() {
+        let lit = Literal::Typed("42".into(), xsd::INT);
+        assert_eq!(lit.canonical(), None);
+        assert_eq!(lit.clone().canonicalized_or_self(), lit);
+        let lit = Literal::LanguageString("1".into(), crate::LangTag::new_unchecked("en"), None);
+        assert_eq!(lit.canonical(), None);
+        assert_eq!(lit.clone().canonicalized_or_self(), lit);
+        let lit = Literal::Typed("01".into(), xsd::INTEGER);
+        assert_eq!(
+            lit.canonicalized_or_self(),
+            Literal::Typed("1".into(), xsd::INTEGER)
+        );
+    }
+}
@@ -1,4 +1,6 @@
-use std::borrow::Cow;
+use alloc::{borrow::Cow, string::String, sync::Arc};
+
+use crate::_text::Text;
 
 /// Wrapper around a [`Cow<str>`] signaling that it complies with [BCP47],
 /// i.e. it is a valid language tag.
@@ -11,9 +13,12 @@ use std::borrow::Cow;
 /// It does not require that each component is a valid code
 /// (i.e. ISO 639 for 2-3 characters language tag, or ISO 15924 for the script).
 ///
+/// The text may also be stored in an [`Arc<str>`](alloc::sync::Arc) (see [`LangTag::shared`]),
+/// in which case cloning the [`LangTag`] does not allocate.
+///
 /// [BCP47]: https://datatracker.ietf.org/doc/bcp47/
-#[derive(Clone, Debug, Eq, Ord)]
-pub struct LangTag<'a>(Cow<'a, str>);
+#[derive(Clone, Debug, Eq)]
+pub struct LangTag<'a>(Text<'a>);
 
 impl<'a> LangTag<'a> {
     /// Return a new [`LangTag`], assuming the argument is a valid language tag.
@@ -21,12 +26,14 @@ impl<'a> LangTag<'a> {
     /// ## Precondition
     /// It is the responsibility of the caller to ensure that `txt` is a valid language tag.
     pub fn new_unchecked(txt: impl Into<Cow<'a, str>>) -> Self {
-        LangTag(txt.into())
+        LangTag(Text::from(txt.into()))
     }
 
     /// Return the inner [`Cow<str>`](Cow).
+    ///
+    /// If this [`LangTag`] is [shared](LangTag::shared), its text is copied into a new [`String`].
     pub fn unwrap(self) -> Cow<'a, str> {
-        self.0
+        self.0.into_cow()
     }
 
     /// Apply a function to the inner text, assuming the result is still a valid language tag.
@@ -35,28 +42,66 @@ impl<'a> LangTag<'a> {
     /// It is the responsibility of the caller to ensure that `f`
     /// produces a valid language tag when its argument is a valid language tag.
     pub fn map_unchecked(self, mut f: impl FnMut(Cow<'a, str>) -> Cow<'a, str>) -> Self {
-        Self(f(self.0))
+        Self(Text::from(f(self.0.into_cow())))
+    }
+
+    /// The text of this [`LangTag`].
+    ///
+    /// This is equivalent to [`AsRef::<str>::as_ref`],
+    /// but does not require a type annotation, as [`LangTag`] also implements [`AsRef<[u8]>`](AsRef).
+    pub fn as_str(&self) -> &str {
+        self.0.as_ref()
+    }
+
+    /// The UTF-8 bytes of this [`LangTag`].
+    pub fn as_bytes(&self) -> &[u8] {
+        self.0.as_bytes()
     }
 
     /// Borrow this [`LangTag`] as another [`LangTag`].
     pub fn borrowed(&self) -> LangTag<'_> {
         LangTag::new_unchecked(self.0.as_ref())
     }
+
+    /// Convert this [`LangTag`] into one backed by an [`Arc<str>`](alloc::sync::Arc),
+    /// so that it can then be cloned in constant time, without allocating.
+    ///
+    /// This copies the text once, unless this [`LangTag`] is already shared.
+    pub fn shared(self) -> LangTag<'static> {
+        LangTag(self.0.into_shared())
+    }
+
+    /// Return a new [`LangTag`] backed by `txt`, assuming it is a valid language tag.
+    pub(crate) fn new_shared_unchecked(txt: Arc<str>) -> LangTag<'static> {
+        LangTag(Text::Shared(txt))
+    }
+
+    /// Convert this [`LangTag`] into one that does not borrow anything,
+    /// keeping it [shared](LangTag::shared) if it already is.
+    pub(crate) fn into_owned(self) -> LangTag<'static> {
+        LangTag(self.0.into_owned())
+    }
 }
 
-impl std::borrow::Borrow<str> for LangTag<'_> {
+impl core::borrow::Borrow<str> for LangTag<'_> {
     fn borrow(&self) -> &str {
         self.0.as_ref()
     }
 }
 
-impl std::convert::AsRef<str> for LangTag<'_> {
+impl core::convert::AsRef<str> for LangTag<'_> {
     fn as_ref(&self) -> &str {
         self.0.as_ref()
     }
 }
 
-impl std::ops::Deref for LangTag<'_> {
+impl core::convert::AsRef<[u8]> for LangTag<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl core::ops::Deref for LangTag<'_> {
     type Target = str;
 
     fn deref(&self) -> &Self::Target {
@@ -64,54 +109,106 @@ impl std::ops::Deref for LangTag<'_> {
     }
 }
 
-impl std::hash::Hash for LangTag<'_> {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+impl core::hash::Hash for LangTag<'_> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         self.0.as_ref().to_ascii_lowercase().hash(state)
     }
 }
 
-impl std::cmp::PartialEq for LangTag<'_> {
+impl core::cmp::PartialEq for LangTag<'_> {
     fn eq(&self, other: &Self) -> bool {
         self.0.as_ref().eq_ignore_ascii_case(other.0.as_ref())
     }
 }
 
-impl std::cmp::PartialEq<&str> for LangTag<'_> {
+impl core::cmp::PartialEq<&str> for LangTag<'_> {
     fn eq(&self, other: &&str) -> bool {
         self.0.as_ref().eq_ignore_ascii_case(other)
     }
 }
 
-impl std::cmp::PartialEq<LangTag<'_>> for &str {
+impl core::cmp::PartialEq<LangTag<'_>> for &str {
+    fn eq(&self, other: &LangTag) -> bool {
+        self.eq_ignore_ascii_case(other.0.as_ref())
+    }
+}
+
+impl core::cmp::PartialEq<str> for LangTag<'_> {
+    fn eq(&self, other: &str) -> bool {
+        self.0.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
+impl core::cmp::PartialEq<LangTag<'_>> for str {
+    fn eq(&self, other: &LangTag) -> bool {
+        self.eq_ignore_ascii_case(other.0.as_ref())
+    }
+}
+
+impl core::cmp::PartialOrd<str> for LangTag<'_> {
+    fn partial_cmp(&self, other: &str) -> Option<core::cmp::Ordering> {
+        Some(self.0.to_ascii_lowercase().cmp(&other.to_ascii_lowercase()))
+    }
+}
+
+impl core::cmp::PartialOrd<LangTag<'_>> for str {
+    fn partial_cmp(&self, other: &LangTag<'_>) -> Option<core::cmp::Ordering> {
+        Some(self.to_ascii_lowercase().cmp(&other.0.to_ascii_lowercase()))
+    }
+}
+
+impl core::cmp::PartialEq<String> for LangTag<'_> {
+    fn eq(&self, other: &String) -> bool {
+        self.0.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
+impl core::cmp::PartialEq<LangTag<'_>> for String {
     fn eq(&self, other: &LangTag) -> bool {
         self.eq_ignore_ascii_case(other.0.as_ref())
     }
 }
 
-impl std::cmp::PartialOrd for LangTag<'_> {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(
-            self.0
-                .to_ascii_lowercase()
-                .cmp(&other.0.to_ascii_lowercase()),
-        )
+impl core::cmp::PartialOrd<String> for LangTag<'_> {
+    fn partial_cmp(&self, other: &String) -> Option<core::cmp::Ordering> {
+        Some(self.0.to_ascii_lowercase().cmp(&other.to_ascii_lowercase()))
+    }
+}
+
+impl core::cmp::PartialOrd<LangTag<'_>> for String {
+    fn partial_cmp(&self, other: &LangTag<'_>) -> Option<core::cmp::Ordering> {
+        Some(self.to_ascii_lowercase().cmp(&other.0.to_ascii_lowercase()))
+    }
+}
+
+impl core::cmp::Ord for LangTag<'_> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.0
+            .to_ascii_lowercase()
+            .cmp(&other.0.to_ascii_lowercase())
     }
 }
 
-impl std::cmp::PartialOrd<&str> for LangTag<'_> {
-    fn partial_cmp(&self, other: &&'_ str) -> Option<std::cmp::Ordering> {
+impl core::cmp::PartialOrd for LangTag<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl core::cmp::PartialOrd<&str> for LangTag<'_> {
+    fn partial_cmp(&self, other: &&'_ str) -> Option<core::cmp::Ordering> {
         Some(self.0.to_ascii_lowercase().cmp(&other.to_ascii_lowercase()))
     }
 }
 
-impl std::cmp::PartialOrd<LangTag<'_>> for &str {
-    fn partial_cmp(&self, other: &LangTag<'_>) -> Option<std::cmp::Ordering> {
+impl core::cmp::PartialOrd<LangTag<'_>> for &str {
+    fn partial_cmp(&self, other: &LangTag<'_>) -> Option<core::cmp::Ordering> {
         Some(self.to_ascii_lowercase().cmp(&other.0.to_ascii_lowercase()))
     }
 }
 
-impl std::fmt::Display for LangTag<'_> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for LangTag<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         self.0.as_ref().fmt(f)
     }
 }
@@ -120,6 +217,17 @@ impl std::fmt::Display for LangTag<'_> {
 mod test {
     use super::*;
 
+    #[test]
+    fn as_bytes() {
+        let txt = "en-GB";
+        let x = LangTag::new_unchecked(txt);
+        assert_eq!(x.as_bytes(), txt.as_bytes());
+        assert_eq!(x.as_str(), txt);
+        let mut sink: Vec<u8> = vec![];
+        std::io::Write::write_all(&mut sink, x.as_ref()).unwrap();
+        assert_eq!(sink, txt.as_bytes());
+    }
+
     #[test]
     fn as_str() {
         let ex = "en-GB";
@@ -145,6 +253,31 @@ mod test {
         assert_eq!(tag1.to_string(), ex);
     }
 
+    #[test]
+    fn shared() {
+        use core::hash::BuildHasher;
+
+        let hasher = std::hash::RandomState::new();
+        let tag1 = LangTag::new_unchecked("en-GB").shared();
+        let tag2 = tag1.clone();
+        // cloning a shared language tag does not copy its text
+        assert_eq!(tag2.as_str().as_ptr(), tag1.as_str().as_ptr());
+        assert_eq!(
+            tag1.clone().into_owned().as_str().as_ptr(),
+            tag1.as_str().as_ptr()
+        );
+        for other in [
+            LangTag::new_unchecked("en-gb"),
+            LangTag::new_unchecked(String::from("EN-GB")),
+        ] {
+            assert_eq!(tag1, other);
+            assert_eq!(tag1.cmp(&other), core::cmp::Ordering::Equal);
+            assert_eq!(hasher.hash_one(&tag1), hasher.hash_one(&other));
+        }
+        assert_eq!(tag1.to_string(), "en-GB");
+        assert_eq!(tag2.unwrap(), "en-GB");
+    }
+
     #[test]
     fn case_insensitive() {
         let tag1 = LangTag::new_unchecked("en-GB");
@@ -154,4 +287,18 @@ mod test {
         assert!(tag1 <= tag2 && tag2 <= tag1);
         assert!("EN" < tag1 && tag1 < "EN-ZZ");
     }
+
+    #[test]
+    fn owned_and_unsized_str() {
+        let tag = LangTag::new_unchecked("en-GB");
+        let txt = String::from("EN-gb");
+        assert_eq!(tag, txt);
+        assert_eq!(txt, tag);
+        assert_eq!(tag, *txt);
+        assert_eq!(*txt, tag);
+        let (low, high) = (String::from("EN"), String::from("en-zz"));
+        assert!(low < tag && tag < high);
+        assert!(*"EN" < tag && tag < *"en-zz");
+        assert_ne!(tag, String::from("en-US"));
+    }
 }
@@ -0,0 +1,363 @@
+use alloc::{
+    borrow::Cow,
+    boxed::Box,
+    string::{String, ToString},
+};
+
+use crate::{BaseDir, Iri, LangTag, Literal, SimpleTriple};
+
+/// Build a [`SimpleTriple`](crate::SimpleTriple) from a compact syntax
+/// `triple!(subject, predicate, object)`, where each term can be:
+/// * `<"iri">` for an IRI,
+/// * `NS:local` for an IRI made of a namespace `NS` (any `&str` in scope) and a local name,
+/// * `_:label` for a blank node (subject and object only),
+/// * `"lex"`, `"lex"@tag`, `"lex"@tag--ltr` or `"lex"^^datatype` (where `datatype` is an IRI as above)
+///   for a literal (object only),
+/// * `<<( subject, predicate, object )>>` for a triple term (object only).
+///
+/// NB: this syntax differs slightly from Turtle or N-Triples,
+/// because `<http://...>` can not be parsed by Rust macros.
+///
+/// # Validation
+/// The terms are *not* validated at compile time.
+/// In debug builds only, the terms built by the macro are checked when it is evaluated,
+/// and it panics if
+/// * a blank node label is not a valid label (see [`is_valid_bnode_label`](crate::is_valid_bnode_label)),
+/// * an IRI has no scheme, or contains a space, a control character or one of `<>"{}|^`\`,
+/// * a language tag has an empty subtag, a subtag longer than 8 characters,
+///   a non-alphanumeric character, or a digit in its first subtag.
+///
+/// These are only heuristics, catching typos rather than enforcing the grammars:
+/// e.g. `<"https://example.org/%zz">` or `"chat"@a-fr` are accepted, but are not well-formed.
+/// A base direction other than `ltr` or `rtl` always panics.
+/// Fixtures that must be well-formed can be checked with `r2c2_statement_validation::validate_triple`
+/// (the validation crate depends on this one, so the macros can not use it).
+///
+/// ```
+/// # use r2c2_statement::*;
+/// const EX: &str = "https://example.org/ns/";
+/// let t = triple!(_:alice, EX:says, <<( _:bob, <"https://example.org/ns/name">, "Bob"@en-GB )>>);
+/// assert_eq!(t.subject, SubjectProxy::BlankNode("alice".into()));
+/// assert_eq!(t.predicate, Iri::new_unchecked("https://example.org/ns/says"));
+/// let ObjectProxy::Triple(inner) = t.object else { panic!() };
+/// assert_eq!(
+///     inner.object,
+///     ObjectProxy::<SimpleTriple>::Literal(Literal::LanguageString(
+///         "Bob".into(),
+///         LangTag::new_unchecked("en-GB"),
+///         None
+///     ))
+/// );
+/// ```
+#[macro_export]
+macro_rules! triple {
+    ($($t:tt)*) => {
+        $crate::__r2c2_split!(@triple [] [] $($t)*)
+    };
+}
+
+/// Build a [`SimpleQuad`](crate::SimpleQuad) from a compact syntax
+/// `quad!(subject, predicate, object)` (in the default graph)
+/// or `quad!(subject, predicate, object, graph_name)`.
+///
+/// See [`triple!`] for the syntax of terms, and how they are checked.
+///
+/// ```
+/// # use r2c2_statement::*;
+/// const EX: &str = "https://example.org/ns/";
+/// let q = quad!(EX:alice, EX:age, "42"^^<"http://www.w3.org/2001/XMLSchema#integer">, _:g);
+/// assert_eq!(q.graph_name, Some(GraphNameProxy::BlankNode("g".into())));
+/// let q = quad!(EX:alice, EX:age, "42"^^<"http://www.w3.org/2001/XMLSchema#integer">);
+/// assert_eq!(q.graph_name, None);
+/// ```
+#[macro_export]
+macro_rules! quad {
+    ($($t:tt)*) => {
+        $crate::__r2c2_split!(@quad [] [] $($t)*)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __r2c2_split {
+    // final cases
+    (@triple [[$($s:tt)*] [$($p:tt)*]] [$($o:tt)+] $(,)?) => {
+        $crate::SimpleTriple {
+            subject: $crate::__r2c2_subject!($($s)*),
+            predicate: $crate::__r2c2_iri!($($p)*),
+            object: $crate::__r2c2_object!($($o)*),
+        }
+    };
+    (@quad [[$($s:tt)*] [$($p:tt)*]] [$($o:tt)+] $(,)?) => {
+        $crate::SimpleQuad {
+            subject: $crate::__r2c2_subject!($($s)*),
+            predicate: $crate::__r2c2_iri!($($p)*),
+            object: $crate::__r2c2_object!($($o)*),
+            graph_name: None,
+        }
+    };
+    (@quad [[$($s:tt)*] [$($p:tt)*] [$($o:tt)*]] [$($g:tt)+] $(,)?) => {
+        $crate::SimpleQuad {
+            subject: $crate::__r2c2_subject!($($s)*),
+            predicate: $crate::__r2c2_iri!($($p)*),
+            object: $crate::__r2c2_object!($($o)*),
+            graph_name: Some($crate::__r2c2_graph_name!($($g)*)),
+        }
+    };
+    // splitting on commas
+    (@$k:ident [$($done:tt)*] [$($cur:tt)+] , $($rest:tt)+) => {
+        $crate::__r2c2_split!(@$k [$($done)* [$($cur)*]] [] $($rest)*)
+    };
+    (@$k:ident [$($done:tt)*] [$($cur:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::__r2c2_split!(@$k [$($done)*] [$($cur)* $next] $($rest)*)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __r2c2_iri {
+    (< $iri:literal >) => {
+        $crate::__iri($iri)
+    };
+    ($ns:ident : $local:ident) => {
+        $crate::__iri($crate::__concat_iri($ns, stringify!($local)))
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __r2c2_subject {
+    (_ : $label:tt) => {
+        $crate::SubjectProxy::BlankNode($crate::__bnode_label(stringify!($label)))
+    };
+    ($($iri:tt)*) => {
+        $crate::SubjectProxy::Iri($crate::__r2c2_iri!($($iri)*))
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __r2c2_graph_name {
+    (_ : $label:tt) => {
+        $crate::GraphNameProxy::BlankNode($crate::__bnode_label(stringify!($label)))
+    };
+    ($($iri:tt)*) => {
+        $crate::GraphNameProxy::Iri($crate::__r2c2_iri!($($iri)*))
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __r2c2_object {
+    (<< ( $($t:tt)* ) >>) => {
+        $crate::ObjectProxy::Triple($crate::__boxed_triple($crate::triple!($($t)*)))
+    };
+    (_ : $label:tt) => {
+        $crate::ObjectProxy::BlankNode($crate::__bnode_label(stringify!($label)))
+    };
+    ($lex:literal ^ ^ $($dt:tt)+) => {
+        $crate::ObjectProxy::Literal($crate::Literal::Typed(
+            $lex.into(),
+            $crate::__r2c2_iri!($($dt)+),
+        ))
+    };
+    ($lex:literal @ $($tag:tt)+) => {
+        $crate::ObjectProxy::Literal($crate::__lang_string($lex, stringify!($($tag)+)))
+    };
+    ($lex:literal) => {
+        $crate::ObjectProxy::Literal($crate::Literal::Typed(
+            $lex.into(),
+            $crate::ns::xsd::STRING,
+        ))
+    };
+    ($($iri:tt)*) => {
+        $crate::ObjectProxy::Iri($crate::__r2c2_iri!($($iri)*))
+    };
+}
+
+#[doc(hidden)]
+pub fn __bnode_label(label: &'static str) -> Cow<'static, str> {
+    debug_assert!(
+        crate::is_valid_bnode_label(label),
+        "invalid blank node label {label:?}"
+    );
+    label.into()
+}
+
+/// Build an IRI, checking in debug mode that it looks like an absolute IRI (see `looks_like_iri`).
+#[doc(hidden)]
+pub fn __iri(iri: impl Into<Cow<'static, str>>) -> Iri<'static> {
+    let iri = iri.into();
+    debug_assert!(looks_like_iri(&iri), "invalid IRI {iri:?}");
+    Iri::new_unchecked(iri)
+}
+
+/// Whether `txt` starts with a scheme and contains none of the characters forbidden in IRIs.
+///
+/// This does not check the rest of the IRI grammar (e.g. percent-encoding, or the authority).
+fn looks_like_iri(txt: &str) -> bool {
+    let Some((scheme, _)) = txt.split_once(':') else {
+        return false;
+    };
+    scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+        && scheme
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "+-.".contains(c))
+        && !txt
+            .chars()
+            .any(|c| c.is_whitespace() || c.is_control() || "<>\"{}|^`\\".contains(c))
+}
+
+/// Whether `txt` is made of alphanumeric subtags of 1 to 8 characters, the first one being alphabetic.
+///
+/// This does not check the structure of [BCP47](https://www.rfc-editor.org/rfc/bcp/bcp47.txt) tags
+/// (e.g. the length of the primary language subtag, or the position of singletons).
+fn looks_like_lang_tag(txt: &str) -> bool {
+    txt.split('-').enumerate().all(|(i, subtag)| {
+        (1..=8).contains(&subtag.len())
+            && subtag
+                .chars()
+                .all(|c| c.is_ascii_alphabetic() || (i > 0 && c.is_ascii_digit()))
+    })
+}
+
+/// Concatenate a namespace and a local name,
+/// so that the macros do not depend on `format!` being in scope (e.g. in `no_std` crates).
+#[doc(hidden)]
+pub fn __concat_iri(ns: &str, local: &str) -> String {
+    let mut iri = String::with_capacity(ns.len() + local.len());
+    iri.push_str(ns);
+    iri.push_str(local);
+    iri
+}
+
+/// Box a triple term,
+/// so that the macros do not depend on `Box` being in scope (e.g. in `no_std` crates).
+#[doc(hidden)]
+pub fn __boxed_triple(triple: SimpleTriple<'static>) -> Box<SimpleTriple<'static>> {
+    Box::new(triple)
+}
+
+/// Build a (possibly directional) language string from the stringified tokens following `@`,
+/// where spaces may have been inserted by `stringify!`.
+#[doc(hidden)]
+pub fn __lang_string(lex: &'static str, tag: &'static str) -> Literal<'static> {
+    let tag: String = tag.chars().filter(|c| !c.is_whitespace()).collect();
+    let (tag, dir) = match tag.split_once("--") {
+        None => (tag.as_str(), None),
+        Some((tag, "ltr")) => (tag, Some(BaseDir::Ltr)),
+        Some((tag, "rtl")) => (tag, Some(BaseDir::Rtl)),
+        Some((_, dir)) => panic!("invalid base direction {dir:?}"),
+    };
+    debug_assert!(looks_like_lang_tag(tag), "invalid language tag {tag:?}");
+    Literal::LanguageString(lex.into(), LangTag::new_unchecked(tag.to_string()), dir)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    const EX: &str = "https://example.org/ns/";
+
+    #[test]
+    fn triple_iris() {
+        let t = triple!(<"https://example.org/ns/s">, EX:p, EX:o);
+        assert_eq!(
+            t,
+            SimpleTriple {
+                subject: SubjectProxy::Iri(Iri::new_unchecked("https://example.org/ns/s")),
+                predicate: Iri::new_unchecked("https://example.org/ns/p"),
+                object: ObjectProxy::Iri(Iri::new_unchecked("https://example.org/ns/o")),
+            }
+        );
+    }
+
+    #[test]
+    fn triple_literals() {
+        let lit = |t: SimpleTriple<'static>| match t.object {
+            ObjectProxy::Literal(lit) => lit,
+            _ => panic!(),
+        };
+        assert_eq!(
+            lit(triple!(_:a, EX:p, "⛄")),
+            Literal::Typed("⛄".into(), ns::xsd::STRING)
+        );
+        assert_eq!(
+            lit(triple!(_:a, EX:p, "42"^^<"http://www.w3.org/2001/XMLSchema#integer">)),
+            Literal::Typed("42".into(), ns::xsd::INTEGER)
+        );
+        assert_eq!(
+            lit(triple!(_:a, EX:p, "chat"@en)),
+            Literal::LanguageString("chat".into(), LangTag::new_unchecked("en"), None)
+        );
+        assert_eq!(
+            lit(triple!(_:a, EX:p, "chat"@en-Latn-UK--rtl)),
+            Literal::LanguageString(
+                "chat".into(),
+                LangTag::new_unchecked("en-Latn-UK"),
+                Some(BaseDir::Rtl)
+            )
+        );
+    }
+
+    #[test]
+    fn triple_nested() {
+        let t = triple!(_:a, EX:p, <<( _:b, EX:p, <<( EX:s, EX:p, _:c )>> )>>,);
+        let ObjectProxy::Triple(t) = t.object else {
+            panic!()
+        };
+        assert_eq!(t.subject, SubjectProxy::BlankNode("b".into()));
+        let ObjectProxy::Triple(t) = t.object else {
+            panic!()
+        };
+        assert_eq!(t.object, ObjectProxy::<SimpleTriple>::BlankNode("c".into()));
+    }
+
+    #[test]
+    fn quad() {
+        let q = quad!(_:a, EX:p, "chat"@fr, EX:g);
+        assert_eq!(
+            q.graph_name,
+            Some(GraphNameProxy::Iri(Iri::new_unchecked(
+                "https://example.org/ns/g"
+            )))
+        );
+        let q = quad!(_:a, EX:p, <<( _:b, EX:p, "chat"@fr )>>);
+        assert_eq!(q.graph_name, None);
+    }
+
+    #[test]
+    fn debug_checks() {
+        use super::{looks_like_iri, looks_like_lang_tag};
+
+        assert!(looks_like_iri("https://example.org/ns/s"));
+        assert!(looks_like_iri("urn:isbn:0451450523"));
+        assert!(looks_like_iri("tag:été"));
+        assert!(!looks_like_iri("example.org/ns/s"));
+        assert!(!looks_like_iri("1http://example.org/"));
+        assert!(!looks_like_iri("http://example.org/a b"));
+        assert!(!looks_like_iri("http://example.org/<a>"));
+        assert!(looks_like_lang_tag("en"));
+        assert!(looks_like_lang_tag("en-Latn-UK"));
+        assert!(looks_like_lang_tag("de-CH-1996"));
+        assert!(!looks_like_lang_tag(""));
+        assert!(!looks_like_lang_tag("en-"));
+        assert!(!looks_like_lang_tag("12-en"));
+        assert!(!looks_like_lang_tag("en_GB"));
+        assert!(!looks_like_lang_tag("toolongsubtag"));
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "invalid IRI")]
+    fn invalid_iri() {
+        let _ = triple!(<"not an IRI">, EX:p, EX:o);
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "invalid language tag")]
+    fn invalid_lang_tag() {
+        let _ = triple!(_:a, EX:p, "chat"@en-toolongsubtag);
+    }
+}
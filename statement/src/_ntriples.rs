@@ -0,0 +1,163 @@
+use alloc::string::String;
+use core::fmt::{Result, Write};
+
+use crate::{BaseDir, Literal, Object, ObjectProxy, Predicate, Subject, Triple, ns::xsd};
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+/// Write a single triple as an [N-Triples] line (including the final `.` and line feed).
+///
+/// Triple terms are written with the `<<( ... )>>` syntax of [N-Triples 1.2].
+/// IRIs and language tags are written as is
+/// (they are assumed to be valid, as per the contract of [`Iri`](crate::Iri) and [`LangTag`](crate::LangTag)).
+///
+/// [N-Triples]: https://www.w3.org/TR/n-triples/
+/// [N-Triples 1.2]: https://www.w3.org/TR/rdf12-n-triples/
+pub fn write_ntriple<T: Triple, W: Write>(w: &mut W, triple: &T) -> Result {
+    write_triple_terms(w, triple)?;
+    w.write_str(" .\n")
+}
+
+/// Serialize a slice of triples as an [N-Triples] document, one triple per line.
+///
+/// See [`write_ntriple`].
+///
+/// [N-Triples]: https://www.w3.org/TR/n-triples/
+pub fn write_ntriples<T: Triple>(triples: &[T]) -> String {
+    let mut ret = String::new();
+    for triple in triples {
+        write_ntriple(&mut ret, triple).unwrap(); // writing to a String never fails
+    }
+    ret
+}
+
+/// Serialize a slice of triples as an [N-Triples] document, one triple per line,
+/// serializing the triples in parallel.
+///
+/// The output is identical to that of [`write_ntriples`].
+///
+/// [N-Triples]: https://www.w3.org/TR/n-triples/
+#[cfg(feature = "rayon")]
+pub fn write_ntriples_par<T: Triple + Sync>(triples: &[T]) -> String {
+    triples
+        .par_iter()
+        .map(|triple| {
+            let mut line = String::new();
+            write_ntriple(&mut line, triple).unwrap(); // writing to a String never fails
+            line
+        })
+        .collect()
+}
+
+// utility functions
+
+fn write_triple_terms<T: Triple, W: Write>(w: &mut W, triple: &T) -> Result {
+    write!(
+        w,
+        "{} {} ",
+        triple.subject().as_subject_proxy(),
+        triple.predicate().as_iri()
+    )?;
+    write_object(w, &triple.object().as_object_proxy())
+}
+
+/// Write `object` in N-Triples syntax (used by the [`Display`](core::fmt::Display) impl of [`ObjectProxy`]).
+pub(crate) fn write_object<T: Triple, W: Write>(w: &mut W, object: &ObjectProxy<T>) -> Result {
+    match object {
+        ObjectProxy::Iri(iri) => write!(w, "{iri}"),
+        ObjectProxy::BlankNode(bnid) => write!(w, "_:{bnid}"),
+        ObjectProxy::Literal(literal) => write_literal(w, literal),
+        ObjectProxy::Triple(triple) => {
+            w.write_str("<<( ")?;
+            write_triple_terms(w, triple)?;
+            w.write_str(" )>>")
+        }
+    }
+}
+
+fn write_literal<W: Write>(w: &mut W, literal: &Literal) -> Result {
+    w.write_char('"')?;
+    write_escaped(w, &literal.lexical_form())?;
+    w.write_char('"')?;
+    match literal {
+        Literal::Typed(_, dt) if *dt == xsd::STRING => Ok(()),
+        Literal::Typed(_, dt) => write!(w, "^^{dt}"),
+        Literal::LanguageString(_, tag, None) => write!(w, "@{tag}"),
+        Literal::LanguageString(_, tag, Some(BaseDir::Ltr)) => write!(w, "@{tag}--ltr"),
+        Literal::LanguageString(_, tag, Some(BaseDir::Rtl)) => write!(w, "@{tag}--rtl"),
+    }
+}
+
+/// Write `txt` escaped for an N-Triples string literal, as in [canonical N-Triples]:
+/// `"`, `\`, line feeds and carriage returns are escaped with a backslash,
+/// other characters in the ranges U+0000–U+001F and U+007F are written as `\uXXXX`
+/// (except for the ones allowed as is, namely backspace, tab and form feed).
+///
+/// [canonical N-Triples]: https://www.w3.org/TR/n-triples/#canonical-ntriples
+fn write_escaped<W: Write>(w: &mut W, txt: &str) -> Result {
+    for c in txt.chars() {
+        match c {
+            '"' => w.write_str("\\\"")?,
+            '\\' => w.write_str("\\\\")?,
+            '\n' => w.write_str("\\n")?,
+            '\r' => w.write_str("\\r")?,
+            '\u{0}'..='\u{7}' | '\u{B}' | '\u{E}'..='\u{1F}' | '\u{7F}' => {
+                write!(w, "\\u{:04X}", c as u32)?
+            }
+            c => w.write_char(c)?,
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::triple;
+
+    const EX: &str = "https://example.org/ns/";
+
+    #[test]
+    fn terms() {
+        let triples = [
+            triple!(EX:s, EX:p, EX:o),
+            triple!(_:b, EX:p, "a \"quoted\"\nline\\"),
+            triple!(_:b, EX:p, "42"^^<"http://www.w3.org/2001/XMLSchema#integer">),
+            triple!(_:b, EX:p, "chat"@en-GB),
+            triple!(_:b, EX:p, "chat"@en--rtl),
+        ];
+        assert_eq!(
+            write_ntriples(&triples),
+            r#"<https://example.org/ns/s> <https://example.org/ns/p> <https://example.org/ns/o> .
+_:b <https://example.org/ns/p> "a \"quoted\"\nline\\" .
+_:b <https://example.org/ns/p> "42"^^<http://www.w3.org/2001/XMLSchema#integer> .
+_:b <https://example.org/ns/p> "chat"@en-GB .
+_:b <https://example.org/ns/p> "chat"@en--rtl .
+"#
+        );
+    }
+
+    #[test]
+    fn triple_term() {
+        let t = triple!(_:a, EX:says, <<( _:b, EX:p, <<( _:c, EX:p, _:d )>> )>>);
+        let mut line = String::new();
+        write_ntriple(&mut line, &t).unwrap();
+        assert_eq!(
+            line,
+            "_:a <https://example.org/ns/says> <<( _:b <https://example.org/ns/p> <<( _:c <https://example.org/ns/p> _:d )>> )>> .\n"
+        );
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par() {
+        let triples: Vec<_> = (0..1000)
+            .map(|i| crate::SimpleTriple {
+                object: ObjectProxy::BlankNode(format!("b{i}").into()),
+                ..triple!(EX:s, EX:p, EX:o)
+            })
+            .collect();
+        assert_eq!(write_ntriples_par(&triples), write_ntriples(&triples));
+    }
+}
@@ -1,6 +1,9 @@
-use std::borrow::Cow;
+use alloc::{borrow::Cow, boxed::Box, rc::Rc, sync::Arc};
 
-use crate::{Iri, Literal, Triple};
+use crate::{
+    _ntriples::write_object, _simple_triple::owned_object_proxy_with, BaseDir, BnodeId, Iri,
+    LangTag, Literal, NeverTriple, SimpleTriple, Subject, SubjectProxy, TermKind, Triple, ns::rdf,
+};
 
 /// A trait for [RDF terms] allowed in the [object] position of an [RDF triple].
 ///
@@ -23,7 +26,7 @@ pub trait Object {
     /// # Implementers
     /// A default implementation is provided for this method, based on [`Object::as_object_proxy`].
     /// It may be useful to override it, especially for types where the inner values of [`ObjectProxy`]
-    /// are allocated as owned [`Cow<str>`](std::borrow::Cow) rather than borrowed.
+    /// are allocated as owned [`Cow<str>`](alloc::borrow::Cow) rather than borrowed.
     fn object_kind(&self) -> ObjectKind {
         match self.as_object_proxy() {
             ObjectProxy::Iri(_) => ObjectKind::Iri,
@@ -33,24 +36,117 @@ pub trait Object {
         }
     }
 
+    /// Return the [kind](TermKind) of this object, independently of its position.
+    ///
+    /// # Implementers
+    /// A default implementation is provided for this method, based on [`Object::object_kind`].
+    fn term_kind(&self) -> TermKind {
+        self.object_kind().into()
+    }
+
     /// Whether this object is [ground](https://https://www.w3.org/TR/rdf12-concepts/#dfn-ground).
+    ///
+    /// # Implementers
+    /// For triple terms, the default implementation defers to [`Triple::ground`],
+    /// which handles any depth of nesting.
     fn ground(&self) -> bool {
-        match self.object_kind() {
-            ObjectKind::Iri | ObjectKind::Literal => true,
-            ObjectKind::BlankNode => false,
-            ObjectKind::Triple => {
-                let ObjectProxy::Triple(triple) = self.as_object_proxy() else {
-                    unreachable!()
-                };
-                triple.ground()
+        match self.as_object_proxy() {
+            ObjectProxy::Iri(_) | ObjectProxy::Literal(_) => true,
+            ObjectProxy::BlankNode(_) => false,
+            ObjectProxy::Triple(triple) => triple.ground(),
+        }
+    }
+
+    /// The [lexical form] of this object, if it is a [literal], otherwise None.
+    ///
+    /// # Implementers
+    /// A default implementation is provided for this method, based on [`Object::as_object_proxy`].
+    /// It may be useful to override it, to avoid building a [`Literal`].
+    ///
+    /// [lexical form]: https://www.w3.org/TR/rdf12-concepts/#dfn-lexical-form
+    /// [literal]: https://www.w3.org/TR/rdf12-concepts/#dfn-literal
+    fn literal_lexical_form(&self) -> Option<Cow<'_, str>> {
+        match self.as_object_proxy() {
+            ObjectProxy::Literal(Literal::Typed(lex, _) | Literal::LanguageString(lex, ..)) => {
+                Some(lex)
+            }
+            _ => None,
+        }
+    }
+
+    /// The [datatype IRI] of this object, if it is a [literal], otherwise None.
+    ///
+    /// # Implementers
+    /// See [`Object::literal_lexical_form`].
+    ///
+    /// [datatype IRI]: https://www.w3.org/TR/rdf12-concepts/#dfn-datatype-iri
+    /// [literal]: https://www.w3.org/TR/rdf12-concepts/#dfn-literal
+    fn literal_datatype(&self) -> Option<Iri<'_>> {
+        match self.as_object_proxy() {
+            ObjectProxy::Literal(Literal::Typed(_, datatype)) => Some(datatype),
+            ObjectProxy::Literal(Literal::LanguageString(_, _, None)) => Some(rdf::LANG_STRING),
+            ObjectProxy::Literal(Literal::LanguageString(_, _, Some(_))) => {
+                Some(rdf::DIR_LANG_STRING)
             }
+            _ => None,
+        }
+    }
+
+    /// The [language tag] of this object, if it is a [language-tagged string], otherwise None.
+    ///
+    /// # Implementers
+    /// See [`Object::literal_lexical_form`].
+    ///
+    /// [language tag]: https://www.w3.org/TR/rdf12-concepts/#dfn-language-tag
+    /// [language-tagged string]: https://www.w3.org/TR/rdf12-concepts/#dfn-language-tagged-string
+    fn literal_language_tag(&self) -> Option<LangTag<'_>> {
+        match self.as_object_proxy() {
+            ObjectProxy::Literal(Literal::LanguageString(_, tag, _)) => Some(tag),
+            _ => None,
+        }
+    }
+
+    /// The [base direction] of this object,
+    /// if it is a [directional language-tagged string], otherwise None.
+    ///
+    /// # Implementers
+    /// See [`Object::literal_lexical_form`].
+    ///
+    /// [base direction]: https://www.w3.org/TR/rdf12-concepts/#dfn-base-direction
+    /// [directional language-tagged string]: https://www.w3.org/TR/rdf12-concepts/#dfn-dir-lang-string
+    fn literal_base_direction(&self) -> Option<BaseDir> {
+        match self.as_object_proxy() {
+            ObjectProxy::Literal(Literal::LanguageString(_, _, dir)) => dir,
+            _ => None,
+        }
+    }
+
+    /// The number of nested [triple terms] in this object:
+    /// 0 if it is not a triple term,
+    /// 1 if it is a triple term whose object is not a triple term, etc.
+    ///
+    /// # Implementers
+    /// For triple terms, the default implementation defers to [`Triple::triple_term_depth`],
+    /// which handles any depth of nesting.
+    ///
+    /// [triple terms]: https://www.w3.org/TR/rdf12-concepts/#dfn-triple-term
+    fn triple_term_depth(&self) -> usize {
+        match self.as_object_proxy() {
+            ObjectProxy::Triple(triple) => 1 + triple.triple_term_depth(),
+            _ => 0,
         }
     }
 }
 
 /// An enum conveying the inner information of a value implementing [`Object`].
 /// The return type of [`Object::as_object_proxy`].
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+///
+/// Object proxies can be compared even if their types of triple terms differ,
+/// in which case triple terms are compared with [`triple_eq`](crate::triple_eq)
+/// (see also [`proxy_eq`](crate::proxy_eq)).
+/// Accordingly, [`Hash`] hashes triple terms with [`triple_hash`](crate::triple_hash),
+/// so that it is consistent with [`Eq`], regardless of how `T` implements [`Hash`] (if at all).
+#[derive(Clone, Debug)]
 pub enum ObjectProxy<'a, T: Triple + 'a> {
     /// An [IRI](https://www.w3.org/TR/rdf12-concepts/#section-IRIs)
     Iri(Iri<'a>),
@@ -68,6 +164,105 @@ pub enum ObjectProxy<'a, T: Triple + 'a> {
     Triple(T),
 }
 
+impl<'a, T: Triple> ObjectProxy<'a, T> {
+    /// Convert this [`ObjectProxy`] into one that owns its data,
+    /// so that it can be stored independently of the value it was obtained from.
+    ///
+    /// Nested triple terms are deeply copied into [`SimpleTriple`]s.
+    ///
+    /// ```
+    /// # use r2c2_statement::*;
+    /// const EX: &str = "https://example.org/ns/";
+    /// let stored = {
+    ///     let t = triple!(_:a, EX:p, <<( _:b, EX:p, "chat"@en )>>);
+    ///     t.object().as_object_proxy().into_owned()
+    /// };
+    /// assert_eq!(stored.triple_term_depth(), 1);
+    /// ```
+    pub fn into_owned(self) -> ObjectProxy<'static, Box<SimpleTriple<'static>>> {
+        owned_object_proxy_with(self, &mut |bnid| Cow::Owned(bnid.into_owned()))
+    }
+
+    /// Convert the type of [triple term](https://www.w3.org/TR/rdf12-concepts/#dfn-triple-term) of this [`ObjectProxy`],
+    /// by applying `f` to it if this object is a triple term.
+    /// Other variants are returned unchanged.
+    ///
+    /// ```
+    /// # use r2c2_statement::*;
+    /// const EX: &str = "https://example.org/ns/";
+    /// let t = triple!(_:a, EX:p, <<( _:b, EX:p, "chat"@en )>>);
+    /// let o: ObjectProxy<SimpleTriple> = t.object.map_triple(|t| *t);
+    /// assert_eq!(o.triple_term_depth(), 1);
+    /// ```
+    pub fn map_triple<U: Triple>(self, f: impl FnOnce(T) -> U) -> ObjectProxy<'a, U> {
+        match self {
+            ObjectProxy::Iri(iri) => ObjectProxy::Iri(iri),
+            ObjectProxy::BlankNode(bnid) => ObjectProxy::BlankNode(bnid),
+            ObjectProxy::Literal(literal) => ObjectProxy::Literal(literal),
+            ObjectProxy::Triple(triple) => ObjectProxy::Triple(f(triple)),
+        }
+    }
+
+    /// Convert this [`ObjectProxy`] into one with [`NeverTriple`] as its triple type,
+    /// or return `None` if it is a [triple term](https://www.w3.org/TR/rdf12-concepts/#dfn-triple-term).
+    ///
+    /// ```
+    /// # use r2c2_statement::*;
+    /// const EX: &str = "https://example.org/ns/";
+    /// let t = triple!(_:a, EX:p, "chat"@en);
+    /// let o: Option<ObjectProxy<NeverTriple>> = t.object.into_never();
+    /// assert!(o.is_some());
+    /// ```
+    pub fn into_never(self) -> Option<ObjectProxy<'a, NeverTriple>> {
+        match self {
+            ObjectProxy::Triple(_) => None,
+            other => Some(other.map_triple(|_| unreachable!())),
+        }
+    }
+
+    /// If this is a blank node, return its identifier as a valid [`BnodeId`]
+    /// (see [`BnodeId::sanitized`]), otherwise None.
+    pub fn as_bnode_id(&self) -> Option<BnodeId<'_>> {
+        match self {
+            ObjectProxy::BlankNode(bnid) => Some(BnodeId::sanitized(bnid)),
+            _ => None,
+        }
+    }
+}
+
+/// Display this object in [N-Triples] syntax, as written by [`write_ntriple`](crate::write_ntriple):
+/// `<iri>`, `_:label`, `"lex"^^<datatype>`, `"lex"@tag`, `"lex"@tag--dir`
+/// or `<<( subject predicate object )>>` for triple terms.
+///
+/// As for [`SubjectProxy`](crate::SubjectProxy), blank node labels are written as is.
+///
+/// [N-Triples]: https://www.w3.org/TR/rdf12-n-triples/
+impl<T: Triple> core::fmt::Display for ObjectProxy<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write_object(f, self)
+    }
+}
+
+impl<T: Triple, U: Triple> PartialEq<ObjectProxy<'_, U>> for ObjectProxy<'_, T> {
+    fn eq(&self, other: &ObjectProxy<'_, U>) -> bool {
+        crate::proxy_eq(self, other)
+    }
+}
+
+impl<T: Triple> Eq for ObjectProxy<'_, T> {}
+
+impl<T: Triple> core::hash::Hash for ObjectProxy<'_, T> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        core::mem::discriminant(self).hash(state);
+        match self {
+            ObjectProxy::Iri(iri) => iri.hash(state),
+            ObjectProxy::BlankNode(bnid) => bnid.hash(state),
+            ObjectProxy::Literal(literal) => literal.hash(state),
+            ObjectProxy::Triple(triple) => crate::triple_hash(triple, state),
+        }
+    }
+}
+
 /// An enum representing the different kinds of [RDF terms] that can be [object].
 /// The return type of [`Object::object_kind`].
 ///
@@ -101,9 +296,152 @@ impl<T: Object> Object for &'_ T {
         (*self).object_kind()
     }
 
+    fn term_kind(&self) -> TermKind {
+        (*self).term_kind()
+    }
+
     fn ground(&self) -> bool {
         (*self).ground()
     }
+
+    fn literal_lexical_form(&self) -> Option<Cow<'_, str>> {
+        (*self).literal_lexical_form()
+    }
+
+    fn literal_datatype(&self) -> Option<Iri<'_>> {
+        (*self).literal_datatype()
+    }
+
+    fn literal_language_tag(&self) -> Option<LangTag<'_>> {
+        (*self).literal_language_tag()
+    }
+
+    fn literal_base_direction(&self) -> Option<BaseDir> {
+        (*self).literal_base_direction()
+    }
+
+    fn triple_term_depth(&self) -> usize {
+        (*self).triple_term_depth()
+    }
+}
+
+/// Any [`Box`], [`Rc`] or [`Arc`] of an [`Object`] also trivially implements [`Object`].
+macro_rules! impl_object_for_pointer {
+    ($($ptr:ident),*) => {$(
+        impl<T: Object + ?Sized> Object for $ptr<T> {
+            type Triple<'x>
+                = T::Triple<'x>
+            where
+                Self: 'x;
+
+            fn as_object_proxy(&self) -> ObjectProxy<'_, Self::Triple<'_>> {
+                self.as_ref().as_object_proxy()
+            }
+
+            fn object_kind(&self) -> ObjectKind {
+                self.as_ref().object_kind()
+            }
+
+            fn term_kind(&self) -> TermKind {
+                self.as_ref().term_kind()
+            }
+
+            fn ground(&self) -> bool {
+                self.as_ref().ground()
+            }
+
+            fn literal_lexical_form(&self) -> Option<Cow<'_, str>> {
+                self.as_ref().literal_lexical_form()
+            }
+
+            fn literal_datatype(&self) -> Option<Iri<'_>> {
+                self.as_ref().literal_datatype()
+            }
+
+            fn literal_language_tag(&self) -> Option<LangTag<'_>> {
+                self.as_ref().literal_language_tag()
+            }
+
+            fn literal_base_direction(&self) -> Option<BaseDir> {
+                self.as_ref().literal_base_direction()
+            }
+
+            fn triple_term_depth(&self) -> usize {
+                self.as_ref().triple_term_depth()
+            }
+        }
+    )*};
+}
+impl_object_for_pointer!(Box, Rc, Arc);
+
+/// [`Iri`] implements the trait [`Object`], always returning the [`ObjectProxy::Iri`] variant
+/// (borrowing the IRI rather than cloning it).
+///
+/// This is mostly useful for testing or prototyping, as [`Iri`] also implements [`Predicate`](crate::Predicate).
+impl Object for Iri<'_> {
+    type Triple<'x>
+        = NeverTriple
+    where
+        Self: 'x;
+
+    fn as_object_proxy(&self) -> ObjectProxy<'_, NeverTriple> {
+        ObjectProxy::Iri(self.borrowed())
+    }
+
+    fn object_kind(&self) -> ObjectKind {
+        ObjectKind::Iri
+    }
+
+    fn ground(&self) -> bool {
+        true
+    }
+}
+
+/// [`Literal`] implements the trait [`Object`], always returning the [`ObjectProxy::Literal`] variant
+/// (borrowing the literal rather than cloning it).
+///
+/// This is mostly useful for testing or prototyping.
+impl Object for Literal<'_> {
+    type Triple<'x>
+        = NeverTriple
+    where
+        Self: 'x;
+
+    fn as_object_proxy(&self) -> ObjectProxy<'_, NeverTriple> {
+        ObjectProxy::Literal(self.borrowed())
+    }
+
+    fn object_kind(&self) -> ObjectKind {
+        ObjectKind::Literal
+    }
+
+    fn ground(&self) -> bool {
+        true
+    }
+}
+
+/// [`SubjectProxy`] implements the trait [`Object`],
+/// so that the subject of a statement can be used directly as the object of another one.
+impl Object for SubjectProxy<'_> {
+    type Triple<'x>
+        = NeverTriple
+    where
+        Self: 'x;
+
+    fn as_object_proxy(&self) -> ObjectProxy<'_, NeverTriple> {
+        self.as_subject_proxy().into()
+    }
+
+    fn object_kind(&self) -> ObjectKind {
+        match self {
+            SubjectProxy::Iri(_) => ObjectKind::Iri,
+            SubjectProxy::BlankNode(_) => ObjectKind::BlankNode,
+        }
+    }
+
+    fn ground(&self) -> bool {
+        matches!(self, SubjectProxy::Iri(_))
+    }
 }
 
 /// [`ObjectProxy`] implements the trait [`Object`].
@@ -127,3 +465,354 @@ impl<T: Triple> Object for ObjectProxy<'_, T> {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use core::cell::Cell;
+
+    use super::*;
+    use crate::{SimpleTriple, Subject, SubjectProxy, triple};
+
+    const EX: &str = "https://example.org/ns/";
+
+    /// An object whose proxies are (expensively) cloned, counting how many are built.
+    struct CountingObject<'c> {
+        object: &'c ObjectProxy<'static, Box<SimpleTriple<'static>>>,
+        proxies: &'c Cell<usize>,
+    }
+
+    impl Object for CountingObject<'_> {
+        type Triple<'x>
+            = Box<SimpleTriple<'static>>
+        where
+            Self: 'x;
+
+        fn as_object_proxy(&self) -> ObjectProxy<'_, Self::Triple<'_>> {
+            self.proxies.set(self.proxies.get() + 1);
+            self.object.clone()
+        }
+    }
+
+    /// A triple counting how many times each of its accessors is called.
+    struct CountingTriple {
+        triple: SimpleTriple<'static>,
+        subjects: Cell<usize>,
+        objects: Cell<usize>,
+        object_proxies: Cell<usize>,
+    }
+
+    impl CountingTriple {
+        fn new(triple: SimpleTriple<'static>) -> Self {
+            CountingTriple {
+                triple,
+                subjects: Cell::new(0),
+                objects: Cell::new(0),
+                object_proxies: Cell::new(0),
+            }
+        }
+    }
+
+    impl Triple for CountingTriple {
+        type Subject<'x>
+            = &'x SubjectProxy<'static>
+        where
+            Self: 'x;
+
+        type Predicate<'x>
+            = &'x Iri<'static>
+        where
+            Self: 'x;
+
+        type Object<'x>
+            = CountingObject<'x>
+        where
+            Self: 'x;
+
+        fn subject(&self) -> Self::Subject<'_> {
+            self.subjects.set(self.subjects.get() + 1);
+            &self.triple.subject
+        }
+
+        fn predicate(&self) -> Self::Predicate<'_> {
+            &self.triple.predicate
+        }
+
+        fn object(&self) -> Self::Object<'_> {
+            self.objects.set(self.objects.get() + 1);
+            CountingObject {
+                object: &self.triple.object,
+                proxies: &self.object_proxies,
+            }
+        }
+    }
+
+    #[test]
+    fn single_proxy_per_call() {
+        let cases = [
+            (triple!(EX:s, EX:p, EX:o), true),
+            (triple!(EX:s, EX:p, _:o), false),
+            (triple!(EX:s, EX:p, "o"), true),
+            (triple!(EX:s, EX:p, <<( EX:s, EX:p, EX:o )>>), true),
+            (triple!(EX:s, EX:p, <<( EX:s, EX:p, _:o )>>), false),
+        ];
+        for (triple, ground) in cases {
+            let t = CountingTriple::new(triple);
+            assert_eq!(t.ground(), ground);
+            assert_eq!(t.subjects.get(), 1);
+            assert_eq!(t.objects.get(), 1);
+            assert_eq!(t.object_proxies.get(), 1);
+
+            let t = CountingTriple::new(t.triple);
+            t.object().triple_term_depth();
+            assert_eq!(t.object_proxies.get(), 1);
+        }
+
+        // a non-ground subject short-circuits
+        let t = CountingTriple::new(triple!(_:s, EX:p, EX:o));
+        assert!(!t.ground());
+        assert_eq!(t.objects.get(), 0);
+        assert_eq!(t.object_proxies.get(), 0);
+    }
+
+    #[test]
+    fn deeply_nested() {
+        let nested = |depth: usize, innermost| {
+            let mut t = triple!(EX:s, EX:p, EX:o);
+            t.object = innermost;
+            for _ in 0..depth {
+                t = SimpleTriple {
+                    subject: t.subject.clone(),
+                    predicate: t.predicate.clone(),
+                    object: ObjectProxy::Triple(Box::new(t)),
+                };
+            }
+            t
+        };
+        let bnode = || ObjectProxy::BlankNode("o".into());
+        let iri = || ObjectProxy::Iri(Iri::new_unchecked(EX));
+        // the default implementations handle any depth of nesting
+        for (innermost, expected) in [(iri(), true), (bnode(), false)] {
+            let t = CountingTriple::new(nested(1000, innermost));
+            assert_eq!(t.ground(), expected);
+            assert_eq!(t.triple_term_depth(), 1000);
+            assert_eq!(t.object().triple_term_depth(), 1000);
+            let mut subjects = 0;
+            t.for_each_nested_triple(|nt| {
+                assert!(nt.dyn_subject().is_iri());
+                subjects += 1;
+            });
+            assert_eq!(subjects, 1000);
+            // and agree with the iterative implementations of SimpleTriple
+            assert_eq!(t.triple.ground(), expected);
+            assert_eq!(t.triple.triple_term_depth(), 1000);
+        }
+        // the walk visits the triple terms from the outermost to the innermost
+        let t = CountingTriple::new(nested(3, iri()));
+        let mut depths = vec![];
+        t.for_each_nested_triple(|nt| depths.push(nt.triple_term_depth()));
+        assert_eq!(depths, [2, 1, 0]);
+    }
+
+    #[test]
+    fn into_owned() {
+        let label = String::from("b");
+        let inner = SimpleTriple {
+            subject: SubjectProxy::BlankNode(Cow::Borrowed(&label)),
+            predicate: Iri::new_unchecked(EX),
+            object: ObjectProxy::Literal(Literal::Typed(
+                Cow::Borrowed(&label),
+                Iri::new_unchecked(EX),
+            )),
+        };
+        let outer = SimpleTriple {
+            subject: SubjectProxy::BlankNode(Cow::Borrowed(&label)),
+            predicate: Iri::new_unchecked(EX),
+            object: ObjectProxy::Triple(Box::new(inner)),
+        };
+        let owned = outer.object().as_object_proxy().into_owned();
+        let subject = outer.subject().as_subject_proxy().into_owned();
+        drop(outer);
+        drop(label);
+
+        assert_eq!(subject, SubjectProxy::BlankNode("b".into()));
+        assert!(matches!(subject, SubjectProxy::BlankNode(Cow::Owned(_))));
+        let ObjectProxy::Triple(triple) = &owned else {
+            panic!("expected a triple term, got {owned:?}");
+        };
+        assert!(matches!(
+            triple.subject,
+            SubjectProxy::BlankNode(Cow::Owned(_))
+        ));
+        assert!(matches!(
+            &triple.object,
+            ObjectProxy::Literal(Literal::Typed(Cow::Owned(lex), _)) if lex == "b"
+        ));
+    }
+
+    #[test]
+    fn map_triple() {
+        let t = triple!(_:a, EX:p, <<( _:b, EX:q, EX:o )>>);
+        let mapped: ObjectProxy<SimpleTriple> = t.object.clone().map_triple(|inner| *inner);
+        assert!(
+            matches!(mapped, ObjectProxy::Triple(inner) if inner.object() == &ObjectProxy::<SimpleTriple>::Iri(Iri::new_unchecked("https://example.org/ns/o")))
+        );
+
+        let t = triple!(_:a, EX:p, "chat"@en);
+        let mut called = false;
+        let mapped = t.object.clone().map_triple(|inner| {
+            called = true;
+            *inner
+        });
+        assert!(!called);
+        assert_eq!(mapped.to_string(), r#""chat"@en"#);
+    }
+
+    #[test]
+    fn into_never() {
+        let cases = [
+            (triple!(_:a, EX:p, EX:o), true),
+            (triple!(_:a, EX:p, _:b), true),
+            (triple!(_:a, EX:p, "chat"@en), true),
+            (triple!(_:a, EX:p, <<( _:b, EX:q, EX:o )>>), false),
+        ];
+        for (t, expected) in cases {
+            let never = t.object.clone().into_never();
+            assert_eq!(never.is_some(), expected);
+            if let Some(never) = never {
+                assert_eq!(never.to_string(), t.object.to_string());
+            }
+        }
+    }
+
+    #[test]
+    fn literal_accessors() {
+        let t = triple!(_:a, EX:p, <<( _:b, EX:q, "chat"@en-GB--rtl )>>);
+        let ObjectProxy::Triple(inner) = &t.object else {
+            unreachable!()
+        };
+        let proxies = Cell::new(0);
+        let o = CountingObject {
+            object: &inner.object,
+            proxies: &proxies,
+        };
+        assert_eq!(o.literal_lexical_form().unwrap(), "chat");
+        assert_eq!(o.literal_datatype(), Some(rdf::DIR_LANG_STRING));
+        assert_eq!(o.literal_language_tag().unwrap(), "en-gb");
+        assert_eq!(o.literal_base_direction(), Some(BaseDir::Rtl));
+        assert_eq!(proxies.get(), 4);
+
+        let lit = Literal::new_typed(
+            "42",
+            Iri::new_unchecked("http://www.w3.org/2001/XMLSchema#integer"),
+        );
+        assert_eq!(lit.literal_lexical_form().unwrap(), "42");
+        assert_eq!(lit.literal_datatype(), Some(lit.datatype_iri()));
+        assert_eq!(Object::literal_language_tag(&&lit), None);
+        assert_eq!(Box::new(lit).literal_base_direction(), None);
+
+        for o in [
+            &t.object,
+            &ObjectProxy::Iri(Iri::new_unchecked("https://example.org/ns/o")),
+            &ObjectProxy::BlankNode("b".into()),
+        ] {
+            assert_eq!(o.literal_lexical_form(), None);
+            assert_eq!(o.literal_datatype(), None);
+            assert_eq!(o.literal_language_tag(), None);
+            assert_eq!(o.literal_base_direction(), None);
+        }
+    }
+
+    #[test]
+    fn iri_as_term() {
+        use crate::{GraphName, GraphNameProxy};
+
+        let iri = Iri::new_unchecked(String::from(EX));
+        let SubjectProxy::Iri(s) = iri.as_subject_proxy() else {
+            unreachable!()
+        };
+        let ObjectProxy::Iri(o) = iri.as_object_proxy() else {
+            unreachable!()
+        };
+        let Some(GraphNameProxy::Iri(g)) = Some(iri.as_graph_name_proxy()) else {
+            unreachable!()
+        };
+        for other in [s, o, g] {
+            assert!(core::ptr::eq(other.as_str(), iri.as_str()));
+        }
+        assert!(Subject::is_iri(&iri));
+        assert!(Object::ground(&iri));
+        assert!(GraphName::ground(&iri));
+        assert_eq!(Object::term_kind(&iri), TermKind::Iri);
+    }
+
+    #[test]
+    fn subject_proxy_as_object() {
+        let iri = SubjectProxy::Iri(Iri::new_unchecked(EX));
+        assert_eq!(iri.object_kind(), ObjectKind::Iri);
+        assert!(Subject::is_iri(&iri) && !Subject::is_blank_node(&iri));
+        assert!(Object::ground(&iri));
+        assert!(matches!(iri.as_object_proxy(), ObjectProxy::Iri(i) if i.as_str() == EX));
+
+        let bnode = SubjectProxy::BlankNode("b".into());
+        assert_eq!(bnode.object_kind(), ObjectKind::BlankNode);
+        assert!(Subject::is_blank_node(&bnode) && !Subject::is_iri(&bnode));
+        assert!(!Object::ground(&bnode));
+        assert_eq!(Object::term_kind(&bnode), TermKind::BlankNode);
+        assert_eq!(bnode.as_object_proxy().to_string(), "_:b");
+
+        // the subject of a triple can be used as the object of another one
+        let t = triple!(_:a, EX:p, EX:o);
+        let inverse = SimpleTriple {
+            subject: SubjectProxy::Iri(Iri::new_unchecked("https://example.org/ns/o")),
+            predicate: Iri::new_unchecked("https://example.org/ns/inverse"),
+            object: t.subject.as_object_proxy().into_owned(),
+        };
+        assert_eq!(
+            inverse.object,
+            ObjectProxy::<SimpleTriple>::BlankNode("a".into())
+        );
+    }
+
+    #[test]
+    fn display() {
+        let t = triple!(_:a, EX:p, <<( _:b, EX:q, "say \"hi\"\\\n\r\t\u{7}"@en--ltr )>>);
+        assert_eq!(
+            t.object.to_string(),
+            "<<( _:b <https://example.org/ns/q> \"say \\\"hi\\\"\\\\\\n\\r\t\\u0007\"@en--ltr )>>"
+        );
+        let t = triple!(_:a, EX:p, "42"^^<"http://www.w3.org/2001/XMLSchema#integer">);
+        assert_eq!(
+            t.object.to_string(),
+            r#""42"^^<http://www.w3.org/2001/XMLSchema#integer>"#
+        );
+        let t = triple!(_:a, EX:p, "chat"@en);
+        assert_eq!(t.object.to_string(), r#""chat"@en"#);
+        let t = triple!(_:a, EX:p, "chat");
+        assert_eq!(t.object.to_string(), r#""chat""#);
+        let t = triple!(_:a, EX:p, _:b);
+        assert_eq!(t.object.to_string(), "_:b");
+        let t = triple!(_:a, EX:p, EX:o);
+        assert_eq!(t.object.to_string(), "<https://example.org/ns/o>");
+    }
+
+    #[test]
+    fn never_triple_proxies() {
+        fn assert_traits<T: Clone + Copy + core::fmt::Debug + Eq + core::hash::Hash + Ord>() {}
+        assert_traits::<NeverTriple>();
+        assert_traits::<crate::NeverQuad>();
+        fn assert_quad<Q: crate::Quad>() {}
+        assert_quad::<crate::NeverQuad>();
+
+        let o1 = ObjectProxy::<NeverTriple>::Iri(Iri::new_unchecked("https://example.org/ns/o"));
+        let o2 = ObjectProxy::<NeverTriple>::BlankNode("b".into());
+        let set: std::collections::HashSet<_> = [o1.clone(), o2.clone(), o1.clone()].into();
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&o1) && set.contains(&o2));
+        assert_eq!(format!("{o2:?}"), r#"BlankNode("b")"#);
+        let s: std::collections::HashSet<_> = [SubjectProxy::BlankNode("b".into())]
+            .into_iter()
+            .map(ObjectProxy::<NeverTriple>::from)
+            .collect();
+        assert!(s.contains(&o2));
+    }
+}
@@ -0,0 +1,246 @@
+use alloc::boxed::Box;
+
+use crate::{
+    _eq::proxy_eq, GraphName, GraphNameProxy, Iri, Object, ObjectProxy, Predicate, Quad,
+    SimpleTriple, Subject, SubjectProxy, Triple,
+};
+
+/// A pattern matching triples, with an optional constraint on each position
+/// (`None` acting as a wildcard).
+///
+/// Terms are compared with the same equality as [`triple_eq`](crate::triple_eq),
+/// so a pattern can match triples from any implementation.
+///
+/// ```
+/// # use r2c2_statement::*;
+/// const EX: &str = "https://example.org/ns/";
+/// let pattern = TriplePattern::any().with_predicate(Iri::new_unchecked(format!("{EX}name")));
+/// assert!(pattern.matches(&triple!(_:a, EX:name, "Alice")));
+/// assert!(!pattern.matches(&triple!(_:a, EX:knows, _:b)));
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct TriplePattern<'a> {
+    /// The required [subject](https://www.w3.org/TR/rdf12-concepts/#dfn-subject), if any
+    pub subject: Option<SubjectProxy<'a>>,
+    /// The required [predicate](https://www.w3.org/TR/rdf12-concepts/#dfn-predicate), if any
+    pub predicate: Option<Iri<'a>>,
+    /// The required [object](https://www.w3.org/TR/rdf12-concepts/#dfn-object), if any
+    pub object: Option<ObjectProxy<'a, Box<SimpleTriple<'a>>>>,
+}
+
+impl<'a> TriplePattern<'a> {
+    /// A pattern matching any triple.
+    pub fn any() -> Self {
+        Self::default()
+    }
+
+    /// Constrain the subject of this pattern.
+    pub fn with_subject(self, subject: SubjectProxy<'a>) -> Self {
+        TriplePattern {
+            subject: Some(subject),
+            ..self
+        }
+    }
+
+    /// Constrain the predicate of this pattern.
+    pub fn with_predicate(self, predicate: Iri<'a>) -> Self {
+        TriplePattern {
+            predicate: Some(predicate),
+            ..self
+        }
+    }
+
+    /// Constrain the object of this pattern.
+    pub fn with_object(self, object: ObjectProxy<'a, Box<SimpleTriple<'a>>>) -> Self {
+        TriplePattern {
+            object: Some(object),
+            ..self
+        }
+    }
+
+    /// Whether `triple` satisfies all the constraints of this pattern.
+    pub fn matches<T: Triple>(&self, triple: &T) -> bool {
+        self.subject
+            .as_ref()
+            .is_none_or(|s| *s == triple.subject().as_subject_proxy())
+            && self
+                .predicate
+                .as_ref()
+                .is_none_or(|p| *p == triple.predicate().as_iri())
+            && self
+                .object
+                .as_ref()
+                .is_none_or(|o| proxy_eq(o, &triple.object().as_object_proxy()))
+    }
+}
+
+/// A pattern matching quads, with an optional constraint on each position
+/// (`None` acting as a wildcard).
+///
+/// The graph name constraint has two levels of [`Option`]:
+/// `None` matches quads in any graph,
+/// while `Some(None)` only matches quads in the [default graph].
+///
+/// ```
+/// # use r2c2_statement::*;
+/// const EX: &str = "https://example.org/ns/";
+/// let q = quad!(_:a, EX:name, "Alice", EX:g);
+/// assert!(QuadPattern::any().matches(&q));
+/// assert!(!QuadPattern::any().with_graph_name(None).matches(&q));
+/// ```
+///
+/// [default graph]: https://www.w3.org/TR/rdf12-concepts/#dfn-default-graph
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct QuadPattern<'a> {
+    /// The required [subject](https://www.w3.org/TR/rdf12-concepts/#dfn-subject), if any
+    pub subject: Option<SubjectProxy<'a>>,
+    /// The required [predicate](https://www.w3.org/TR/rdf12-concepts/#dfn-predicate), if any
+    pub predicate: Option<Iri<'a>>,
+    /// The required [object](https://www.w3.org/TR/rdf12-concepts/#dfn-object), if any
+    pub object: Option<ObjectProxy<'a, Box<SimpleTriple<'a>>>>,
+    /// The required [graph name](https://www.w3.org/TR/rdf12-concepts/#dfn-graph-name), if any,
+    /// where `Some(None)` stands for the [default graph](https://www.w3.org/TR/rdf12-concepts/#dfn-default-graph)
+    pub graph_name: Option<Option<GraphNameProxy<'a>>>,
+}
+
+impl<'a> QuadPattern<'a> {
+    /// A pattern matching any quad, in any graph.
+    pub fn any() -> Self {
+        Self::default()
+    }
+
+    /// Constrain the subject of this pattern.
+    pub fn with_subject(self, subject: SubjectProxy<'a>) -> Self {
+        QuadPattern {
+            subject: Some(subject),
+            ..self
+        }
+    }
+
+    /// Constrain the predicate of this pattern.
+    pub fn with_predicate(self, predicate: Iri<'a>) -> Self {
+        QuadPattern {
+            predicate: Some(predicate),
+            ..self
+        }
+    }
+
+    /// Constrain the object of this pattern.
+    pub fn with_object(self, object: ObjectProxy<'a, Box<SimpleTriple<'a>>>) -> Self {
+        QuadPattern {
+            object: Some(object),
+            ..self
+        }
+    }
+
+    /// Constrain the graph name of this pattern,
+    /// where `None` stands for the [default graph](https://www.w3.org/TR/rdf12-concepts/#dfn-default-graph).
+    pub fn with_graph_name(self, graph_name: Option<GraphNameProxy<'a>>) -> Self {
+        QuadPattern {
+            graph_name: Some(graph_name),
+            ..self
+        }
+    }
+
+    /// Whether `quad` satisfies all the constraints of this pattern.
+    pub fn matches<Q: Quad>(&self, quad: &Q) -> bool {
+        self.subject
+            .as_ref()
+            .is_none_or(|s| *s == quad.subject().as_subject_proxy())
+            && self
+                .predicate
+                .as_ref()
+                .is_none_or(|p| *p == quad.predicate().as_iri())
+            && self
+                .object
+                .as_ref()
+                .is_none_or(|o| proxy_eq(o, &quad.object().as_object_proxy()))
+            && self
+                .graph_name
+                .as_ref()
+                .is_none_or(|g| match (g, quad.graph_name()) {
+                    (None, None) => true,
+                    (Some(g), Some(qg)) => *g == qg.as_graph_name_proxy(),
+                    _ => false,
+                })
+    }
+}
+
+impl<'a> From<TriplePattern<'a>> for QuadPattern<'a> {
+    /// Convert a [`TriplePattern`] into a [`QuadPattern`] matching the same triples in any graph.
+    fn from(pattern: TriplePattern<'a>) -> Self {
+        QuadPattern {
+            subject: pattern.subject,
+            predicate: pattern.predicate,
+            object: pattern.object,
+            graph_name: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{quad, triple};
+
+    const EX: &str = "https://example.org/ns/";
+
+    fn ex(local: &str) -> Iri<'static> {
+        Iri::new_unchecked(format!("{EX}{local}"))
+    }
+
+    #[test]
+    fn triple_pattern() {
+        let t = triple!(_:a, EX:p, <<( _:b, EX:q, "chat"@en-GB )>>);
+        assert!(TriplePattern::any().matches(&t));
+        assert!(
+            TriplePattern::any()
+                .with_subject(SubjectProxy::BlankNode("a".into()))
+                .with_predicate(ex("p"))
+                .matches(&t)
+        );
+        assert!(
+            TriplePattern::any()
+                .with_object(triple!(_:a, EX:p, <<( _:b, EX:q, "chat"@en-gb )>>).object)
+                .matches(&t)
+        );
+        assert!(
+            !TriplePattern::any()
+                .with_subject(SubjectProxy::BlankNode("b".into()))
+                .matches(&t)
+        );
+        assert!(!TriplePattern::any().with_predicate(ex("q")).matches(&t));
+        assert!(
+            !TriplePattern::any()
+                .with_object(ObjectProxy::BlankNode("b".into()))
+                .matches(&t)
+        );
+    }
+
+    #[test]
+    fn default_graph_vs_any_graph() {
+        let in_default = quad!(_:a, EX:p, EX:o);
+        let in_named = quad!(_:a, EX:p, EX:o, EX:g);
+        let in_other = quad!(_:a, EX:p, EX:o, _:g);
+
+        let any = QuadPattern::any().with_predicate(ex("p"));
+        assert!(any.matches(&in_default));
+        assert!(any.matches(&in_named));
+        assert!(any.matches(&in_other));
+
+        let default_only = any.clone().with_graph_name(None);
+        assert!(default_only.matches(&in_default));
+        assert!(!default_only.matches(&in_named));
+        assert!(!default_only.matches(&in_other));
+
+        let named_only = any
+            .clone()
+            .with_graph_name(Some(GraphNameProxy::Iri(ex("g"))));
+        assert!(!named_only.matches(&in_default));
+        assert!(named_only.matches(&in_named));
+        assert!(!named_only.matches(&in_other));
+
+        let from_triple = QuadPattern::from(TriplePattern::any().with_predicate(ex("p")));
+        assert_eq!(from_triple, any);
+    }
+}
@@ -1,4 +1,7 @@
-use crate::Iri;
+use alloc::{boxed::Box, rc::Rc, sync::Arc};
+use core::cmp::Ordering;
+
+use crate::{Iri, predicate_eq};
 
 /// A trait for [RDF terms] allowed in the [predicate] position of an [RDF triple].
 ///
@@ -8,6 +11,25 @@ use crate::Iri;
 pub trait Predicate {
     /// Return the [`Iri`] of this predicate.
     fn as_iri(&self) -> Iri<'_>;
+
+    /// Whether this predicate and `other`, possibly from different implementations, are equal,
+    /// i.e. whether they have the same IRI (see [`predicate_eq`]).
+    ///
+    /// ```
+    /// # use r2c2_statement::*;
+    /// let p1 = Iri::new_unchecked("https://example.org/ns/p");
+    /// let p2 = std::rc::Rc::new(Iri::new_unchecked("https://example.org/ns/p".to_string()));
+    /// assert!(p1.eq_predicate(&p2));
+    /// ```
+    fn eq_predicate<P: Predicate + ?Sized>(&self, other: &P) -> bool {
+        predicate_eq(self, other)
+    }
+
+    /// Compare this predicate with `other`, possibly from different implementations,
+    /// by comparing their IRIs (consistently with [`term_cmp`](crate::term_cmp)).
+    fn cmp_predicate<P: Predicate + ?Sized>(&self, other: &P) -> Ordering {
+        self.as_iri().as_str().cmp(other.as_iri().as_str())
+    }
 }
 
 /// Any reference to a [`Predicate`] also trivially implements [`Predicate`]
@@ -16,8 +38,36 @@ impl<T: Predicate> Predicate for &'_ T {
     fn as_iri(&self) -> Iri<'_> {
         (*self).as_iri()
     }
+
+    fn eq_predicate<P: Predicate + ?Sized>(&self, other: &P) -> bool {
+        (*self).eq_predicate(other)
+    }
+
+    fn cmp_predicate<P: Predicate + ?Sized>(&self, other: &P) -> Ordering {
+        (*self).cmp_predicate(other)
+    }
 }
 
+/// Any [`Box`], [`Rc`] or [`Arc`] of a [`Predicate`] also trivially implements [`Predicate`].
+macro_rules! impl_predicate_for_pointer {
+    ($($ptr:ident),*) => {$(
+        impl<T: Predicate + ?Sized> Predicate for $ptr<T> {
+            fn as_iri(&self) -> Iri<'_> {
+                self.as_ref().as_iri()
+            }
+
+            fn eq_predicate<P: Predicate + ?Sized>(&self, other: &P) -> bool {
+                self.as_ref().eq_predicate(other)
+            }
+
+            fn cmp_predicate<P: Predicate + ?Sized>(&self, other: &P) -> Ordering {
+                self.as_ref().cmp_predicate(other)
+            }
+        }
+    )*};
+}
+impl_predicate_for_pointer!(Box, Rc, Arc);
+
 /// [`Iri`] implements the trait [`Predicate`].
 /// This has not particular interest for [`Iri`]s obtained from another [`Predicate`]-implementing type,
 /// via the [`Predicate::as_iri`] method.
@@ -29,3 +79,21 @@ impl Predicate for Iri<'_> {
         self.borrowed()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn eq_and_cmp() {
+        let p = Iri::new_unchecked("https://example.org/ns/p");
+        let q = Box::new(Iri::new_unchecked("https://example.org/ns/q"));
+        let p2 = Arc::new(Iri::new_unchecked("https://example.org/ns/p".to_string()));
+        assert!(p.eq_predicate(&p2));
+        assert!(p2.eq_predicate(&&p));
+        assert!(!p.eq_predicate(&q));
+        assert_eq!(p.cmp_predicate(&p2), Ordering::Equal);
+        assert_eq!(p.cmp_predicate(&q), Ordering::Less);
+        assert_eq!(q.cmp_predicate(&p), Ordering::Greater);
+    }
+}
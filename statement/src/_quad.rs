@@ -1,4 +1,9 @@
-use crate::{GraphName, Object, Predicate, Subject};
+use alloc::boxed::Box;
+
+use crate::{
+    GraphName, GraphNameKind, GraphNameProxy, Iri, Literal, Object, ObjectProxy, Predicate,
+    Subject, SubjectProxy,
+};
 
 /// A trait for RDF [quads].
 ///
@@ -59,6 +64,136 @@ pub trait Quad {
     /// [RDF dataset]: https://www.w3.org/TR/rdf12-concepts/#dfn-rdf-dataset
     fn graph_name(&self) -> Option<Self::GraphName<'_>>;
 
+    /// The [subject], [predicate], [object] and [graph name] of this quad, in a single call.
+    ///
+    /// This is convenient for destructuring a quad with a `let` or a `match`.
+    /// See also [`SpogProxies::spog_proxies`] for getting the proxies of all four terms at once.
+    ///
+    /// [subject]: https://www.w3.org/TR/rdf12-concepts/#dfn-subject
+    /// [predicate]: https://www.w3.org/TR/rdf12-concepts/#dfn-predicate
+    /// [object]: https://www.w3.org/TR/rdf12-concepts/#dfn-object
+    /// [graph name]: https://www.w3.org/TR/rdf12-concepts/#dfn-graph-name
+    fn spog(
+        &self,
+    ) -> (
+        Self::Subject<'_>,
+        Self::Predicate<'_>,
+        Self::Object<'_>,
+        Option<Self::GraphName<'_>>,
+    ) {
+        (
+            self.subject(),
+            self.predicate(),
+            self.object(),
+            self.graph_name(),
+        )
+    }
+
+    /// Whether this quad belongs to the [default graph] (i.e. has no [graph name]).
+    ///
+    /// [default graph]: https://www.w3.org/TR/rdf12-concepts/#dfn-default-graph
+    /// [graph name]: https://www.w3.org/TR/rdf12-concepts/#dfn-graph-name
+    fn is_default_graph(&self) -> bool {
+        self.graph_name().is_none()
+    }
+
+    /// The [kind](GraphNameKind) of the [graph name] of this quad,
+    /// or `None` if it belongs to the [default graph].
+    ///
+    /// [graph name]: https://www.w3.org/TR/rdf12-concepts/#dfn-graph-name
+    /// [default graph]: https://www.w3.org/TR/rdf12-concepts/#dfn-default-graph
+    fn graph_name_kind(&self) -> Option<GraphNameKind> {
+        self.graph_name().map(|gn| gn.graph_name_kind())
+    }
+
+    /// The [graph name] of this quad, if it is an IRI.
+    ///
+    /// [graph name]: https://www.w3.org/TR/rdf12-concepts/#dfn-graph-name
+    fn graph_name_iri(&self) -> Option<Iri<'_>> {
+        match self.graph_name_proxy()? {
+            GraphNameProxy::Iri(iri) => Some(iri),
+            GraphNameProxy::BlankNode(_) => None,
+        }
+    }
+
+    /// The [`GraphNameProxy`] of the [graph name] of this quad,
+    /// or `None` if it belongs to the [default graph].
+    ///
+    /// The proxy can not simply be obtained with
+    /// `quad.graph_name().map(|gn| gn.as_graph_name_proxy())`,
+    /// because it borrows the value returned by [`Quad::graph_name`],
+    /// which only lives as long as the closure:
+    ///
+    /// ```compile_fail
+    /// # use r2c2_statement::*;
+    /// fn graph_name_proxy<Q: Quad>(quad: &Q) -> Option<GraphNameProxy<'_>> {
+    ///     quad.graph_name().map(|gn| gn.as_graph_name_proxy())
+    /// }
+    /// ```
+    ///
+    /// This method, on the other hand, returns a proxy whose lifetime is tied to the quad:
+    ///
+    /// ```
+    /// # use r2c2_statement::*;
+    /// fn graph_name_proxy<Q: Quad>(quad: &Q) -> Option<GraphNameProxy<'_>> {
+    ///     quad.graph_name_proxy()
+    /// }
+    /// ```
+    ///
+    /// # Implementers
+    /// A default implementation is provided for this method, based on [`GraphName::as_graph_name_proxy`].
+    /// For the reason explained above, this implementation has to copy the inner text of the proxy.
+    /// It is therefore recommended to override it when the graph name can be borrowed from the quad itself,
+    /// which is always the case when [`Quad::GraphName`] is a reference type.
+    ///
+    /// [graph name]: https://www.w3.org/TR/rdf12-concepts/#dfn-graph-name
+    /// [default graph]: https://www.w3.org/TR/rdf12-concepts/#dfn-default-graph
+    fn graph_name_proxy(&self) -> Option<GraphNameProxy<'_>> {
+        Some(self.graph_name()?.as_graph_name_proxy().into_owned())
+    }
+
+    /// Whether the [predicate] of this quad is `iri`.
+    ///
+    /// [predicate]: https://www.w3.org/TR/rdf12-concepts/#dfn-predicate
+    fn has_predicate(&self, iri: &Iri) -> bool {
+        self.predicate().as_iri() == *iri
+    }
+
+    /// Whether the [subject] of this quad is `iri`.
+    ///
+    /// [subject]: https://www.w3.org/TR/rdf12-concepts/#dfn-subject
+    fn has_subject_iri(&self, iri: &Iri) -> bool {
+        self.subject().as_iri().is_some_and(|s| s == *iri)
+    }
+
+    /// Whether the [graph name] of this quad is `iri`
+    /// (which is never the case for quads in the [default graph]).
+    ///
+    /// [graph name]: https://www.w3.org/TR/rdf12-concepts/#dfn-graph-name
+    /// [default graph]: https://www.w3.org/TR/rdf12-concepts/#dfn-default-graph
+    fn has_graph_name_iri(&self, iri: &Iri) -> bool {
+        self.graph_name().is_some_and(
+            |gn| matches!(gn.as_graph_name_proxy(), GraphNameProxy::Iri(g) if g == *iri),
+        )
+    }
+
+    /// The [object] of this quad, if it is a [literal].
+    ///
+    /// # Implementers
+    /// The default implementation returns an owned copy of the literal,
+    /// because the proxy returned by [`Object::as_object_proxy`] borrows the value returned by
+    /// [`Quad::object`], which is not necessarily borrowed from the quad itself.
+    /// Implementations where this is the case should override this method to avoid the copy.
+    ///
+    /// [object]: https://www.w3.org/TR/rdf12-concepts/#dfn-object
+    /// [literal]: https://www.w3.org/TR/rdf12-concepts/#dfn-literal
+    fn object_literal(&self) -> Option<Literal<'_>> {
+        match self.object().as_object_proxy() {
+            ObjectProxy::Literal(literal) => Some(crate::_simple_triple::owned_literal(literal)),
+            _ => None,
+        }
+    }
+
     /// Whether this quad is [ground](https://https://www.w3.org/TR/rdf12-concepts/#dfn-ground).
     ///
     /// NB: RDF Concepts does not actually defined the notion of "ground quad",
@@ -68,11 +203,70 @@ pub trait Quad {
             && self.object().ground()
             && self.graph_name().map(|n| n.ground()).unwrap_or(true)
     }
+
+    /// Iterate over the [subject], [predicate], [object] and [graph name] (if any) of this quad
+    /// (see [`Terms`](crate::Terms)).
+    ///
+    /// [subject]: https://www.w3.org/TR/rdf12-concepts/#dfn-subject
+    /// [predicate]: https://www.w3.org/TR/rdf12-concepts/#dfn-predicate
+    /// [object]: https://www.w3.org/TR/rdf12-concepts/#dfn-object
+    /// [graph name]: https://www.w3.org/TR/rdf12-concepts/#dfn-graph-name
+    fn terms(
+        &self,
+    ) -> crate::Terms<Self::Subject<'_>, Self::Predicate<'_>, Self::Object<'_>, Self::GraphName<'_>>
+    {
+        let (subject, predicate, object, graph_name) = self.spog();
+        crate::Terms::new((subject, predicate, object), graph_name)
+    }
+}
+
+/// Extension trait for the tuples returned by [`Quad::spog`],
+/// providing the proxies of the four terms at once.
+///
+/// See [`SpoProxies`](crate::SpoProxies) for why this is not a method of [`Quad`].
+pub trait SpogProxies {
+    /// The type of triple terms in the object proxy.
+    type Triple<'x>: crate::Triple
+    where
+        Self: 'x;
+
+    /// Return the [`SubjectProxy`], [`Iri`], [`ObjectProxy`] and optional [`GraphNameProxy`] of the four terms.
+    fn spog_proxies(
+        &self,
+    ) -> (
+        SubjectProxy<'_>,
+        Iri<'_>,
+        ObjectProxy<'_, Self::Triple<'_>>,
+        Option<GraphNameProxy<'_>>,
+    );
+}
+
+impl<S: Subject, P: Predicate, O: Object, G: GraphName> SpogProxies for (S, P, O, Option<G>) {
+    type Triple<'x>
+        = O::Triple<'x>
+    where
+        Self: 'x;
+
+    fn spog_proxies(
+        &self,
+    ) -> (
+        SubjectProxy<'_>,
+        Iri<'_>,
+        ObjectProxy<'_, Self::Triple<'_>>,
+        Option<GraphNameProxy<'_>>,
+    ) {
+        (
+            self.0.as_subject_proxy(),
+            self.1.as_iri(),
+            self.2.as_object_proxy(),
+            self.3.as_ref().map(|g| g.as_graph_name_proxy()),
+        )
+    }
 }
 
 /// Any reference to a [`Quad`] also trivially implements [`Quad`]
 /// (as all methods of [`Quad`] apply to `&self` anyway).
-impl<T: Quad> Quad for &'_ T {
+impl<T: Quad + ?Sized> Quad for &'_ T {
     type Subject<'x>
         = T::Subject<'x>
     where
@@ -108,4 +302,207 @@ impl<T: Quad> Quad for &'_ T {
     fn graph_name(&self) -> Option<Self::GraphName<'_>> {
         (*self).graph_name()
     }
+
+    fn spog(
+        &self,
+    ) -> (
+        Self::Subject<'_>,
+        Self::Predicate<'_>,
+        Self::Object<'_>,
+        Option<Self::GraphName<'_>>,
+    ) {
+        (*self).spog()
+    }
+
+    fn is_default_graph(&self) -> bool {
+        (*self).is_default_graph()
+    }
+
+    fn graph_name_kind(&self) -> Option<GraphNameKind> {
+        (*self).graph_name_kind()
+    }
+
+    fn graph_name_iri(&self) -> Option<Iri<'_>> {
+        (*self).graph_name_iri()
+    }
+
+    fn graph_name_proxy(&self) -> Option<GraphNameProxy<'_>> {
+        (*self).graph_name_proxy()
+    }
+
+    fn has_predicate(&self, iri: &Iri) -> bool {
+        (*self).has_predicate(iri)
+    }
+
+    fn has_subject_iri(&self, iri: &Iri) -> bool {
+        (*self).has_subject_iri(iri)
+    }
+
+    fn has_graph_name_iri(&self, iri: &Iri) -> bool {
+        (*self).has_graph_name_iri(iri)
+    }
+
+    fn object_literal(&self) -> Option<Literal<'_>> {
+        (*self).object_literal()
+    }
+
+    fn ground(&self) -> bool {
+        (*self).ground()
+    }
+
+    fn terms(
+        &self,
+    ) -> crate::Terms<Self::Subject<'_>, Self::Predicate<'_>, Self::Object<'_>, Self::GraphName<'_>>
+    {
+        (*self).terms()
+    }
+}
+
+/// Any boxed [`Quad`] also trivially implements [`Quad`]
+/// (as all methods of [`Quad`] apply to `&self` anyway).
+///
+/// ```
+/// # use r2c2_statement::*;
+/// fn named<Q: Quad>(quads: &[Q]) -> usize {
+///     quads.iter().filter(|q| !q.is_default_graph()).count()
+/// }
+/// let quads = vec![
+///     Box::new(quad!(_:s, <"https://example.org/ns/p">, _:o, _:g)),
+///     Box::new(quad!(_:s, <"https://example.org/ns/p">, _:o)),
+/// ];
+/// assert_eq!(named(&quads), 1);
+/// ```
+impl<T: Quad + ?Sized> Quad for Box<T> {
+    type Subject<'x>
+        = T::Subject<'x>
+    where
+        Self: 'x;
+
+    type Predicate<'x>
+        = T::Predicate<'x>
+    where
+        Self: 'x;
+
+    type Object<'x>
+        = T::Object<'x>
+    where
+        Self: 'x;
+
+    type GraphName<'x>
+        = T::GraphName<'x>
+    where
+        Self: 'x;
+
+    fn subject(&self) -> Self::Subject<'_> {
+        self.as_ref().subject()
+    }
+
+    fn predicate(&self) -> Self::Predicate<'_> {
+        self.as_ref().predicate()
+    }
+
+    fn object(&self) -> Self::Object<'_> {
+        self.as_ref().object()
+    }
+
+    fn graph_name(&self) -> Option<Self::GraphName<'_>> {
+        self.as_ref().graph_name()
+    }
+
+    fn spog(
+        &self,
+    ) -> (
+        Self::Subject<'_>,
+        Self::Predicate<'_>,
+        Self::Object<'_>,
+        Option<Self::GraphName<'_>>,
+    ) {
+        self.as_ref().spog()
+    }
+
+    fn is_default_graph(&self) -> bool {
+        self.as_ref().is_default_graph()
+    }
+
+    fn graph_name_kind(&self) -> Option<GraphNameKind> {
+        self.as_ref().graph_name_kind()
+    }
+
+    fn graph_name_iri(&self) -> Option<Iri<'_>> {
+        self.as_ref().graph_name_iri()
+    }
+
+    fn graph_name_proxy(&self) -> Option<GraphNameProxy<'_>> {
+        self.as_ref().graph_name_proxy()
+    }
+
+    fn has_predicate(&self, iri: &Iri) -> bool {
+        self.as_ref().has_predicate(iri)
+    }
+
+    fn has_subject_iri(&self, iri: &Iri) -> bool {
+        self.as_ref().has_subject_iri(iri)
+    }
+
+    fn has_graph_name_iri(&self, iri: &Iri) -> bool {
+        self.as_ref().has_graph_name_iri(iri)
+    }
+
+    fn object_literal(&self) -> Option<Literal<'_>> {
+        self.as_ref().object_literal()
+    }
+
+    fn ground(&self) -> bool {
+        self.as_ref().ground()
+    }
+
+    fn terms(
+        &self,
+    ) -> crate::Terms<Self::Subject<'_>, Self::Predicate<'_>, Self::Object<'_>, Self::GraphName<'_>>
+    {
+        self.as_ref().terms()
+    }
+}
+
+/// A utility empty type, for APIs requiring a [`Quad`] type parameter
+/// in contexts where no quad is ever produced (see also [`NeverTriple`](crate::NeverTriple)).
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum NeverQuad {}
+
+impl Quad for NeverQuad {
+    type Subject<'x>
+        = SubjectProxy<'x>
+    where
+        Self: 'x;
+
+    type Predicate<'x>
+        = Iri<'x>
+    where
+        Self: 'x;
+
+    type Object<'x>
+        = ObjectProxy<'x, crate::NeverTriple>
+    where
+        Self: 'x;
+
+    type GraphName<'x>
+        = GraphNameProxy<'x>
+    where
+        Self: 'x;
+
+    fn subject(&self) -> Self::Subject<'_> {
+        unreachable!()
+    }
+
+    fn predicate(&self) -> Self::Predicate<'_> {
+        unreachable!()
+    }
+
+    fn object(&self) -> Self::Object<'_> {
+        unreachable!()
+    }
+
+    fn graph_name(&self) -> Option<Self::GraphName<'_>> {
+        unreachable!()
+    }
 }
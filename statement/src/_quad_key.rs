@@ -0,0 +1,174 @@
+use alloc::{borrow::Cow, collections::BTreeSet};
+use core::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+};
+
+use crate::{LangTag, Literal, ObjectProxy, Quad, SimpleQuad, quad_cmp, quad_eq, quad_hash};
+
+/// An owned, canonical copy of a [`Quad`], usable as a sort or deduplication key.
+///
+/// [`Ord`], [`Eq`] and [`Hash`] are respectively consistent with
+/// [`quad_cmp`], [`quad_eq`] and [`quad_hash`],
+/// so keys built from quads of different implementations can be compared.
+///
+/// Language tags (including in nested triple terms) are lower-cased,
+/// so that the keys of equal quads are identical, and not only equal.
+/// (Simple literals need no normalization,
+/// since they are always conveyed as literals with datatype `xsd:string` by [`ObjectProxy`].)
+///
+/// ```
+/// # use r2c2_statement::*;
+/// const EX: &str = "https://example.org/ns/";
+/// let q1 = quad!(_:a, EX:p, "chat"@EN-GB);
+/// let q2 = quad!(_:a, EX:p, "chat"@en-gb);
+/// assert_eq!(QuadKey::from_quad(&q1), QuadKey::from_quad(&q2));
+/// assert_eq!(QuadKey::from_quad(&q1).into_quad(), q2);
+/// ```
+#[derive(Clone, Debug)]
+pub struct QuadKey(SimpleQuad<'static>);
+
+impl QuadKey {
+    /// Build the [`QuadKey`] of any [`Quad`].
+    pub fn from_quad<Q: Quad>(quad: &Q) -> Self {
+        let mut quad = SimpleQuad::from_quad(quad);
+        let mut object = &mut quad.object;
+        loop {
+            match object {
+                ObjectProxy::Literal(Literal::LanguageString(_, tag, _)) => {
+                    if tag.bytes().any(|b| b.is_ascii_uppercase()) {
+                        *tag = LangTag::new_unchecked(Cow::Owned(tag.to_ascii_lowercase()));
+                    }
+                    break;
+                }
+                ObjectProxy::Triple(triple) => object = &mut triple.object,
+                _ => break,
+            }
+        }
+        QuadKey(quad)
+    }
+
+    /// Borrow the canonical quad of this [`QuadKey`].
+    pub fn as_quad(&self) -> &SimpleQuad<'static> {
+        &self.0
+    }
+
+    /// Return the canonical quad of this [`QuadKey`].
+    pub fn into_quad(self) -> SimpleQuad<'static> {
+        self.0
+    }
+}
+
+impl PartialEq for QuadKey {
+    fn eq(&self, other: &Self) -> bool {
+        quad_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for QuadKey {}
+
+impl Hash for QuadKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        quad_hash(&self.0, state)
+    }
+}
+
+impl Ord for QuadKey {
+    fn cmp(&self, other: &Self) -> Ordering {
+        quad_cmp(&self.0, &other.0)
+    }
+}
+
+impl PartialOrd for QuadKey {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Deduplicate `quads`, possibly from different implementations,
+/// according to [`quad_eq`] (see [`QuadKey`]).
+///
+/// Only the first occurrence of each quad is yielded, in the original order.
+/// Contrarily to [`Vec::dedup`], duplicates need not be consecutive.
+pub fn dedup_quads<I>(quads: I) -> DedupQuads<I::IntoIter>
+where
+    I: IntoIterator,
+    I::Item: Quad,
+{
+    DedupQuads {
+        inner: quads.into_iter(),
+        seen: BTreeSet::new(),
+    }
+}
+
+/// The iterator returned by [`dedup_quads`].
+#[derive(Clone, Debug)]
+pub struct DedupQuads<I> {
+    inner: I,
+    seen: BTreeSet<QuadKey>,
+}
+
+impl<I> Iterator for DedupQuads<I>
+where
+    I: Iterator,
+    I::Item: Quad,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner
+            .by_ref()
+            .find(|quad| self.seen.insert(QuadKey::from_quad(quad)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::quad;
+
+    const EX: &str = "https://example.org/ns/";
+
+    #[test]
+    fn normalized() {
+        let key = QuadKey::from_quad(&quad!(_:a, EX:p, <<( _:b, EX:p, "chat"@EN--rtl )>>, _:g));
+        assert_eq!(
+            key.as_quad(),
+            &quad!(_:a, EX:p, <<( _:b, EX:p, "chat"@en--rtl )>>, _:g)
+        );
+        let ObjectProxy::Triple(t) = &key.as_quad().object else {
+            unreachable!()
+        };
+        let ObjectProxy::Literal(lit) = &t.object else {
+            unreachable!()
+        };
+        assert_eq!(lit.language_tag().unwrap().as_str(), "en");
+    }
+
+    #[test]
+    fn sort_and_dedup() {
+        let quads = vec![
+            quad!(_:b, EX:p, "chat"@EN),
+            quad!(_:a, EX:p, EX:o, EX:g),
+            quad!(_:b, EX:p, "chat"@en),
+            quad!(_:a, EX:p, EX:o),
+            quad!(_:b, EX:p, "chat"@En),
+            quad!(_:a, EX:p, EX:o, EX:g),
+        ];
+        let deduped: Vec<_> = dedup_quads(&quads).collect();
+        assert_eq!(deduped, vec![&quads[0], &quads[1], &quads[3]]);
+
+        let mut keys: Vec<_> = quads.iter().map(QuadKey::from_quad).collect();
+        keys.sort();
+        keys.dedup();
+        let sorted: Vec<_> = keys.into_iter().map(QuadKey::into_quad).collect();
+        assert_eq!(
+            sorted,
+            vec![
+                quad!(_:a, EX:p, EX:o),
+                quad!(_:a, EX:p, EX:o, EX:g),
+                quad!(_:b, EX:p, "chat"@en),
+            ]
+        );
+    }
+}
@@ -0,0 +1,108 @@
+use alloc::vec::Vec;
+use core::convert::Infallible;
+
+use crate::{DynQuad, Quad, SimpleQuad};
+
+/// A push-based consumer of quads, to pair with a [`QuadSource`](crate::QuadSource).
+///
+/// This trait is implemented by
+/// * closures accepting a `&dyn DynQuad` and returning a `Result<(), E>`,
+/// * `Vec<SimpleQuad<'static>>`, which stores a copy of each quad (and never fails).
+///
+/// ```
+/// # use r2c2_statement::*;
+/// const EX: &str = "https://example.org/ns/";
+/// let mut source = [
+///     Ok::<_, &str>(quad!(_:a, EX:p, EX:o)),
+///     Ok(quad!(_:b, EX:p, EX:o, EX:g)),
+/// ]
+/// .into_iter();
+/// let mut sink = Vec::new();
+/// QuadSource::try_for_each(&mut source, |q| QuadSink::push(&mut sink, q)).unwrap();
+/// assert_eq!(sink.len(), 2);
+/// ```
+pub trait QuadSink {
+    /// The type of errors raised by this sink
+    type Error;
+
+    /// Consume one quad.
+    fn push<Q: Quad>(&mut self, q: Q) -> Result<(), Self::Error>;
+
+    /// Signal that no more quads will be pushed, allowing the sink to flush any pending data.
+    fn finish(self) -> Result<(), Self::Error>;
+}
+
+impl<F, E> QuadSink for F
+where
+    F: FnMut(&dyn DynQuad) -> Result<(), E>,
+{
+    type Error = E;
+
+    fn push<Q: Quad>(&mut self, q: Q) -> Result<(), E> {
+        self(&q)
+    }
+
+    fn finish(self) -> Result<(), E> {
+        Ok(())
+    }
+}
+
+/// As [`Vec::push`] takes precedence over [`QuadSink::push`],
+/// the latter must be called with the fully qualified syntax on vectors.
+impl QuadSink for Vec<SimpleQuad<'static>> {
+    type Error = Infallible;
+
+    fn push<Q: Quad>(&mut self, q: Q) -> Result<(), Infallible> {
+        Vec::push(self, SimpleQuad::from_quad(q));
+        Ok(())
+    }
+
+    fn finish(self) -> Result<(), Infallible> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{GraphNameProxy, QuadSource, StreamError, quad, quad_eq};
+
+    const EX: &str = "https://example.org/ns/";
+
+    #[test]
+    fn vec_sink() {
+        let quads = [quad!(_:a, EX:p, "chat"@en), quad!(_:b, EX:p, EX:o, _:g)];
+        let mut sink = Vec::new();
+        for q in &quads {
+            QuadSink::push(&mut sink, q).unwrap();
+        }
+        assert_eq!(sink.len(), 2);
+        assert!(quads.iter().zip(&sink).all(|(q1, q2)| quad_eq(q1, q2)));
+        sink.finish().unwrap();
+    }
+
+    #[test]
+    fn closure_sink() {
+        let mut graphs = Vec::new();
+        let mut sink = |q: &dyn DynQuad| match q.dyn_graph_name() {
+            Some(GraphNameProxy::BlankNode(_)) => Err("blank graph name"),
+            g => {
+                graphs.push(g.map(|g| g.into_owned()));
+                Ok(())
+            }
+        };
+        let mut source = [
+            Ok::<_, ()>(quad!(_:a, EX:p, EX:o)),
+            Ok(quad!(_:a, EX:p, EX:o, EX:g)),
+            Ok(quad!(_:a, EX:p, EX:o, _:g)),
+            Ok(quad!(_:a, EX:p, EX:o)),
+        ]
+        .into_iter();
+        let res = QuadSource::try_for_each(&mut source, |q| sink.push(q));
+        assert_eq!(res, Err(StreamError::SinkError("blank graph name")));
+        assert!(sink.finish().is_ok());
+        assert_eq!(graphs.len(), 2);
+        assert!(graphs[0].is_none());
+        assert!(graphs[1].is_some());
+    }
+}
@@ -0,0 +1,175 @@
+use crate::Quad;
+
+/// A pull-based source of quads, which may fail mid-stream (e.g. on I/O or parse errors).
+///
+/// Each quad is handed to a sink (a closure) which may itself fail;
+/// the first error, either from the source or from the sink, interrupts the stream
+/// and is returned as a [`StreamError`] telling them apart.
+///
+/// This trait is implemented by any iterator of `Result<Q, E>` where `Q` implements [`Quad`].
+/// Note that, for such iterators, [`QuadSource::try_for_each`] must be called with the fully qualified syntax,
+/// to disambiguate it from [`Iterator::try_for_each`].
+///
+/// ```
+/// # use r2c2_statement::*;
+/// const EX: &str = "https://example.org/ns/";
+/// let mut source = [
+///     Ok(quad!(_:a, EX:p, EX:o)),
+///     Err("parse error"),
+///     Ok(quad!(_:b, EX:p, EX:o)),
+/// ]
+/// .into_iter();
+/// let mut count = 0;
+/// let res = QuadSource::try_for_each(&mut source, |_| {
+///     count += 1;
+///     Ok::<_, std::convert::Infallible>(())
+/// });
+/// assert_eq!(res, Err(StreamError::SourceError("parse error")));
+/// assert_eq!(count, 1);
+/// ```
+pub trait QuadSource {
+    /// The type of quads yielded by this source
+    type Quad<'x>: Quad
+    where
+        Self: 'x;
+
+    /// The type of errors raised by this source
+    type Error;
+
+    /// Pass each quad of this source to `f`, until the source is exhausted or an error occurs.
+    ///
+    /// Returns [`StreamError::SourceError`] if the source fails,
+    /// or [`StreamError::SinkError`] if `f` fails.
+    fn try_for_each<F, E>(&mut self, f: F) -> Result<(), StreamError<Self::Error, E>>
+    where
+        F: FnMut(Self::Quad<'_>) -> Result<(), E>;
+}
+
+impl<I, Q, E> QuadSource for I
+where
+    I: Iterator<Item = Result<Q, E>>,
+    Q: Quad,
+{
+    type Quad<'x>
+        = Q
+    where
+        Self: 'x;
+
+    type Error = E;
+
+    fn try_for_each<F, E2>(&mut self, mut f: F) -> Result<(), StreamError<E, E2>>
+    where
+        F: FnMut(Q) -> Result<(), E2>,
+    {
+        for res in self {
+            let quad = res.map_err(StreamError::SourceError)?;
+            f(quad).map_err(StreamError::SinkError)?;
+        }
+        Ok(())
+    }
+}
+
+/// The error returned by [`QuadSource::try_for_each`],
+/// telling apart the errors raised by the source from those raised by the sink.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum StreamError<SourceErr, SinkErr> {
+    /// An error raised by the source
+    SourceError(SourceErr),
+    /// An error raised by the sink
+    SinkError(SinkErr),
+}
+
+impl<SourceErr, SinkErr> StreamError<SourceErr, SinkErr> {
+    /// Whether this error was raised by the source.
+    pub fn is_source_error(&self) -> bool {
+        matches!(self, StreamError::SourceError(_))
+    }
+
+    /// Whether this error was raised by the sink.
+    pub fn is_sink_error(&self) -> bool {
+        matches!(self, StreamError::SinkError(_))
+    }
+}
+
+impl<SourceErr, SinkErr> core::fmt::Display for StreamError<SourceErr, SinkErr>
+where
+    SourceErr: core::fmt::Display,
+    SinkErr: core::fmt::Display,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            StreamError::SourceError(err) => write!(f, "source error: {err}"),
+            StreamError::SinkError(err) => write!(f, "sink error: {err}"),
+        }
+    }
+}
+
+impl<SourceErr, SinkErr> core::error::Error for StreamError<SourceErr, SinkErr>
+where
+    SourceErr: core::error::Error + 'static,
+    SinkErr: core::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            StreamError::SourceError(err) => Some(err),
+            StreamError::SinkError(err) => Some(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{SimpleQuad, quad};
+
+    const EX: &str = "https://example.org/ns/";
+
+    fn source() -> impl Iterator<Item = Result<SimpleQuad<'static>, &'static str>> {
+        [
+            Ok(quad!(_:a, EX:p, EX:o)),
+            Ok(quad!(_:b, EX:p, EX:o, EX:g)),
+            Err("parse error"),
+            Ok(quad!(_:c, EX:p, EX:o)),
+        ]
+        .into_iter()
+    }
+
+    #[test]
+    fn exhausted() {
+        let mut src = source().take(2);
+        let mut graphs = 0;
+        let res = QuadSource::try_for_each(&mut src, |q| {
+            graphs += q.graph_name().is_some() as usize;
+            Ok::<_, ()>(())
+        });
+        assert_eq!(res, Ok(()));
+        assert_eq!(graphs, 1);
+    }
+
+    #[test]
+    fn source_error() {
+        let mut src = source();
+        let mut seen = 0;
+        let res = QuadSource::try_for_each(&mut src, |_| {
+            seen += 1;
+            Ok::<_, ()>(())
+        });
+        assert_eq!(res, Err(StreamError::SourceError("parse error")));
+        assert!(res.unwrap_err().is_source_error());
+        assert_eq!(seen, 2);
+        // the source can be resumed after the error
+        assert_eq!(
+            QuadSource::try_for_each(&mut src, |_| Ok::<_, ()>(())),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn sink_error() {
+        let mut src = source();
+        let res = QuadSource::try_for_each(&mut src, |_| Err("sink full"));
+        assert_eq!(res, Err(StreamError::SinkError("sink full")));
+        assert!(res.unwrap_err().is_sink_error());
+        assert_eq!(src.count(), 3);
+    }
+}
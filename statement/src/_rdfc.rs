@@ -0,0 +1,759 @@
+use alloc::{
+    borrow::Cow,
+    boxed::Box,
+    collections::{BTreeMap, BTreeSet},
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use core::{cell::Cell, fmt::Write};
+
+use sha2::{Digest, Sha256};
+
+use crate::{
+    Dataset, GraphNameProxy, Iri, ObjectProxy, QuadKey, SimpleQuad, SimpleTriple, SubjectProxy,
+};
+
+/// Canonicalize an [RDF dataset] with the [RDFC-1.0] algorithm.
+///
+/// The returned quads are the quads of `dataset`, where
+/// * blank nodes are relabelled with canonical labels (`c14n0`, `c14n1`, ...),
+/// * language tags are normalized to lower case (as by [`QuadKey`]),
+/// * duplicates are removed,
+///
+/// sorted in the code point order of their [canonical N-Quads] serialization.
+/// Hence, two datasets are isomorphic if and only if their canonical forms are equal.
+///
+/// RDFC-1.0 only covers RDF 1.1 datasets.
+/// Blank nodes nested in triple terms are handled by extending the positions of the algorithm
+/// (e.g. `os` for the subject of a triple term in the object position),
+/// so their labels are canonical too, but not covered by the specification.
+///
+/// Note that some (highly symmetric) datasets require an exponential amount of time to be canonicalized,
+/// so this function should not be applied to untrusted input without precaution
+/// (see [`canonicalize_with_limit`]).
+///
+/// ```
+/// # use r2c2_statement::*;
+/// const EX: &str = "https://example.org/ns/";
+/// let d1 = vec![quad!(_:a, EX:knows, _:b), quad!(_:b, EX:name, "Bob")];
+/// let d2 = vec![quad!(_:y, EX:name, "Bob"), quad!(_:x, EX:knows, _:y)];
+/// assert_eq!(canonicalize(&d1), canonicalize(&d2));
+/// assert_eq!(
+///     canonicalize(&d1),
+///     vec![quad!(_:c14n0, EX:knows, _:c14n1), quad!(_:c14n1, EX:name, "Bob")],
+/// );
+/// ```
+///
+/// [RDF dataset]: https://www.w3.org/TR/rdf12-concepts/#dfn-rdf-dataset
+/// [RDFC-1.0]: https://www.w3.org/TR/rdf-canon/
+/// [canonical N-Quads]: https://www.w3.org/TR/rdf-canon/#canonical-quads
+pub fn canonicalize<D: Dataset + ?Sized>(dataset: &D) -> Vec<SimpleQuad<'static>> {
+    canonicalize_with_limit(dataset, usize::MAX).unwrap() // the limit can not be reached
+}
+
+/// Canonicalize an [RDF dataset] with the [RDFC-1.0] algorithm,
+/// giving up if it requires more than `max_permutations` permutations to be examined.
+///
+/// The [Hash N-Degree Quads] algorithm examines every permutation of the blank nodes related to
+/// a blank node with a shared hash, recursively, which is exponential in the worst case
+/// (e.g. for a clique of blank nodes).
+/// `max_permutations` bounds the total number of permutations examined, across all calls;
+/// as every call examines at least one permutation, it also bounds the number of (recursive) calls.
+/// Datasets whose blank nodes all have a unique first degree hash require no permutation at all.
+///
+/// The result, when successful, is the same as [`canonicalize`].
+///
+/// ```
+/// # use r2c2_statement::*;
+/// const EX: &str = "https://example.org/ns/";
+/// let d = vec![quad!(_:a, EX:knows, _:b), quad!(_:b, EX:knows, _:a)];
+/// assert_eq!(canonicalize_with_limit(&d, 0), Err(RdfcLimitExceeded { limit: 0 }));
+/// assert_eq!(canonicalize_with_limit(&d, 10), Ok(canonicalize(&d)));
+/// ```
+///
+/// [RDF dataset]: https://www.w3.org/TR/rdf12-concepts/#dfn-rdf-dataset
+/// [RDFC-1.0]: https://www.w3.org/TR/rdf-canon/
+/// [Hash N-Degree Quads]: https://www.w3.org/TR/rdf-canon/#hash-nd-quads
+pub fn canonicalize_with_limit<D: Dataset + ?Sized>(
+    dataset: &D,
+    max_permutations: usize,
+) -> Result<Vec<SimpleQuad<'static>>, RdfcLimitExceeded> {
+    let quads: BTreeSet<_> = dataset.quads().map(|q| QuadKey::from_quad(&q)).collect();
+    let quads: Vec<_> = quads.into_iter().map(QuadKey::into_quad).collect();
+    Canonicalizer::new(quads, max_permutations).run()
+}
+
+/// The error returned by [`canonicalize_with_limit`] when the dataset requires more permutations than allowed.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct RdfcLimitExceeded {
+    /// The maximum number of permutations that was passed to [`canonicalize_with_limit`]
+    pub limit: usize,
+}
+
+impl core::fmt::Display for RdfcLimitExceeded {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "canonicalization requires more than {} permutations",
+            self.limit
+        )
+    }
+}
+
+impl core::error::Error for RdfcLimitExceeded {}
+
+/// The state of the canonicalization algorithm.
+struct Canonicalizer {
+    quads: Vec<SimpleQuad<'static>>,
+    /// The indices of the quads mentioning each blank node
+    bnode_to_quads: BTreeMap<String, Vec<usize>>,
+    /// The first degree hash of each blank node
+    first_degree: BTreeMap<String, String>,
+    canonical: IdentifierIssuer,
+    /// The number of permutations that can still be examined
+    budget: Cell<usize>,
+    limit: usize,
+}
+
+impl Canonicalizer {
+    fn new(quads: Vec<SimpleQuad<'static>>, limit: usize) -> Self {
+        let mut bnode_to_quads = BTreeMap::<String, Vec<usize>>::new();
+        for (i, q) in quads.iter().enumerate() {
+            for_each_bnode(q, &mut |bnid| {
+                let indices = bnode_to_quads.entry(bnid.to_string()).or_default();
+                if indices.last() != Some(&i) {
+                    indices.push(i);
+                }
+            });
+        }
+        let mut canonicalizer = Canonicalizer {
+            quads,
+            bnode_to_quads,
+            first_degree: BTreeMap::new(),
+            canonical: IdentifierIssuer::new("c14n"),
+            budget: Cell::new(limit),
+            limit,
+        };
+        canonicalizer.first_degree = canonicalizer
+            .bnode_to_quads
+            .keys()
+            .map(|bnid| (bnid.clone(), canonicalizer.hash_first_degree_quads(bnid)))
+            .collect();
+        canonicalizer
+    }
+
+    /// Issue canonical identifiers and return the relabelled quads, sorted
+    /// ([§ 4.4](https://www.w3.org/TR/rdf-canon/#canon-algorithm)).
+    fn run(mut self) -> Result<Vec<SimpleQuad<'static>>, RdfcLimitExceeded> {
+        let mut hash_to_bnodes = BTreeMap::<&str, Vec<&str>>::new();
+        for (bnid, hash) in &self.first_degree {
+            hash_to_bnodes.entry(hash).or_default().push(bnid);
+        }
+        for bnodes in hash_to_bnodes.values() {
+            if let [bnid] = bnodes[..] {
+                self.canonical.issue(bnid);
+            }
+        }
+        for bnodes in hash_to_bnodes.values().filter(|bnodes| bnodes.len() > 1) {
+            let mut hash_path_list = Vec::new();
+            for bnid in bnodes {
+                if self.canonical.get(bnid).is_some() {
+                    continue;
+                }
+                let mut issuer = IdentifierIssuer::new("b");
+                issuer.issue(bnid);
+                hash_path_list.push(self.hash_n_degree_quads(bnid, issuer)?);
+            }
+            hash_path_list.sort_by(|(h1, _), (h2, _)| h1.cmp(h2));
+            for (_, issuer) in hash_path_list {
+                for bnid in &issuer.issued {
+                    self.canonical.issue(bnid);
+                }
+            }
+        }
+
+        let mut canonical: Vec<_> = self
+            .quads
+            .iter()
+            .map(|q| {
+                let q = relabel(q, &mut |bnid| self.canonical.get(bnid).unwrap().to_string());
+                (nquad(&q), q)
+            })
+            .collect();
+        canonical.sort_by(|(l1, _), (l2, _)| l1.cmp(l2));
+        Ok(canonical.into_iter().map(|(_, q)| q).collect())
+    }
+
+    /// [§ 4.6](https://www.w3.org/TR/rdf-canon/#hash-1d-quads)
+    fn hash_first_degree_quads(&self, bnid: &str) -> String {
+        let mut lines: Vec<_> = self.bnode_to_quads[bnid]
+            .iter()
+            .map(|i| {
+                let q = relabel(&self.quads[*i], &mut |other| {
+                    if other == bnid { "a" } else { "z" }.to_string()
+                });
+                nquad(&q)
+            })
+            .collect();
+        lines.sort();
+        sha256(&lines.concat())
+    }
+
+    /// [§ 4.7](https://www.w3.org/TR/rdf-canon/#hash-related-blank-node)
+    fn hash_related_blank_node(
+        &self,
+        related: &str,
+        position: &str,
+        predicate: Option<&Iri>,
+        issuer: &IdentifierIssuer,
+    ) -> String {
+        let mut input = String::from(position);
+        if let Some(predicate) = predicate {
+            write!(input, "{predicate}").unwrap(); // writing to a String can not fail
+        }
+        match self.canonical.get(related).or_else(|| issuer.get(related)) {
+            Some(id) => {
+                input.push_str("_:");
+                input.push_str(id);
+            }
+            None => input.push_str(&self.first_degree[related]),
+        }
+        sha256(&input)
+    }
+
+    /// [§ 4.8](https://www.w3.org/TR/rdf-canon/#hash-nd-quads)
+    fn hash_n_degree_quads(
+        &self,
+        bnid: &str,
+        mut issuer: IdentifierIssuer,
+    ) -> Result<(String, IdentifierIssuer), RdfcLimitExceeded> {
+        let mut hash_to_related = BTreeMap::<String, Vec<&str>>::new();
+        for i in &self.bnode_to_quads[bnid] {
+            for_each_related(&self.quads[*i], &mut |position, predicate, related| {
+                if related != bnid {
+                    let hash = self.hash_related_blank_node(related, position, predicate, &issuer);
+                    hash_to_related.entry(hash).or_default().push(related);
+                }
+            });
+        }
+
+        let mut data_to_hash = String::new();
+        for (related_hash, related) in hash_to_related {
+            data_to_hash.push_str(&related_hash);
+            let mut chosen: Option<(String, IdentifierIssuer)> = None;
+            let longer_than_chosen = |path: &str, chosen: &Option<(String, IdentifierIssuer)>| {
+                chosen.as_ref().is_some_and(|(chosen_path, _)| {
+                    path.len() >= chosen_path.len() && path > chosen_path.as_str()
+                })
+            };
+            let mut permutation: Vec<usize> = (0..related.len()).collect();
+            'permutations: loop {
+                self.consume_permutation()?;
+                let mut issuer_copy = issuer.clone();
+                let mut path = String::new();
+                let mut recursion_list = Vec::new();
+                for r in permutation.iter().map(|i| related[*i]) {
+                    if let Some(id) = self.canonical.get(r) {
+                        path.push_str("_:");
+                        path.push_str(id);
+                    } else {
+                        if issuer_copy.get(r).is_none() {
+                            recursion_list.push(r);
+                        }
+                        path.push_str("_:");
+                        path.push_str(&issuer_copy.issue(r));
+                    }
+                    if longer_than_chosen(&path, &chosen) {
+                        if !next_permutation(&mut permutation) {
+                            break 'permutations;
+                        }
+                        continue 'permutations;
+                    }
+                }
+                for r in recursion_list {
+                    let (hash, result_issuer) = self.hash_n_degree_quads(r, issuer_copy.clone())?;
+                    path.push_str("_:");
+                    path.push_str(&issuer_copy.issue(r));
+                    path.push('<');
+                    path.push_str(&hash);
+                    path.push('>');
+                    issuer_copy = result_issuer;
+                    if longer_than_chosen(&path, &chosen) {
+                        if !next_permutation(&mut permutation) {
+                            break 'permutations;
+                        }
+                        continue 'permutations;
+                    }
+                }
+                if chosen
+                    .as_ref()
+                    .is_none_or(|(chosen_path, _)| path < *chosen_path)
+                {
+                    chosen = Some((path, issuer_copy));
+                }
+                if !next_permutation(&mut permutation) {
+                    break;
+                }
+            }
+            let (chosen_path, chosen_issuer) = chosen.unwrap(); // at least one permutation is complete
+            data_to_hash.push_str(&chosen_path);
+            issuer = chosen_issuer;
+        }
+        Ok((sha256(&data_to_hash), issuer))
+    }
+
+    /// Account for one more permutation examined by [`Canonicalizer::hash_n_degree_quads`].
+    fn consume_permutation(&self) -> Result<(), RdfcLimitExceeded> {
+        match self.budget.get().checked_sub(1) {
+            Some(budget) => {
+                self.budget.set(budget);
+                Ok(())
+            }
+            None => Err(RdfcLimitExceeded { limit: self.limit }),
+        }
+    }
+}
+
+/// [§ 4.5](https://www.w3.org/TR/rdf-canon/#issue-identifier)
+#[derive(Clone, Debug)]
+struct IdentifierIssuer {
+    prefix: &'static str,
+    /// The blank nodes with an identifier, in the order where it was issued
+    issued: Vec<String>,
+    map: BTreeMap<String, String>,
+}
+
+impl IdentifierIssuer {
+    fn new(prefix: &'static str) -> Self {
+        IdentifierIssuer {
+            prefix,
+            issued: Vec::new(),
+            map: BTreeMap::new(),
+        }
+    }
+
+    fn get(&self, bnid: &str) -> Option<&str> {
+        self.map.get(bnid).map(String::as_str)
+    }
+
+    fn issue(&mut self, bnid: &str) -> String {
+        if let Some(id) = self.map.get(bnid) {
+            return id.clone();
+        }
+        let id = format!("{}{}", self.prefix, self.issued.len());
+        self.issued.push(bnid.to_string());
+        self.map.insert(bnid.to_string(), id.clone());
+        id
+    }
+}
+
+// utility functions
+
+fn sha256(data: &str) -> String {
+    format!("{:x}", Sha256::digest(data.as_bytes()))
+}
+
+/// The canonical N-Quads serialization of `quad`, including the final line feed.
+fn nquad(quad: &SimpleQuad) -> String {
+    match &quad.graph_name {
+        None => format!("{} {} {} .\n", quad.subject, quad.predicate, quad.object),
+        Some(g) => format!(
+            "{} {} {} {} .\n",
+            quad.subject, quad.predicate, quad.object, g
+        ),
+    }
+}
+
+fn relabel(quad: &SimpleQuad, label: &mut impl FnMut(&str) -> String) -> SimpleQuad<'static> {
+    SimpleQuad::from_quad_with(quad, &mut |bnid| Cow::Owned(label(&bnid)))
+}
+
+fn for_each_bnode(quad: &SimpleQuad, f: &mut impl FnMut(&str)) {
+    for_each_related(quad, &mut |_, _, bnid| f(bnid));
+}
+
+/// Call `f` on every blank node of `quad`, with its position, and the predicate of the triple where it occurs
+/// (except for the graph name).
+fn for_each_related<'q>(
+    quad: &'q SimpleQuad,
+    f: &mut impl FnMut(&str, Option<&'q Iri<'q>>, &'q str),
+) {
+    if let SubjectProxy::BlankNode(bnid) = &quad.subject {
+        f("s", Some(&quad.predicate), bnid);
+    }
+    let mut position = String::from("o");
+    for_each_related_in_object(&quad.object, &quad.predicate, &mut position, f);
+    if let Some(GraphNameProxy::BlankNode(bnid)) = &quad.graph_name {
+        f("g", None, bnid);
+    }
+}
+
+fn for_each_related_in_object<'q>(
+    object: &'q ObjectProxy<'q, Box<SimpleTriple<'q>>>,
+    predicate: &'q Iri<'q>,
+    position: &mut String,
+    f: &mut impl FnMut(&str, Option<&'q Iri<'q>>, &'q str),
+) {
+    match object {
+        ObjectProxy::BlankNode(bnid) => f(position, Some(predicate), bnid),
+        ObjectProxy::Triple(triple) => {
+            if let SubjectProxy::BlankNode(bnid) = &triple.subject {
+                position.push('s');
+                f(position, Some(&triple.predicate), bnid);
+                position.pop();
+            }
+            position.push('o');
+            for_each_related_in_object(&triple.object, &triple.predicate, position, f);
+            position.pop();
+        }
+        _ => {}
+    }
+}
+
+/// Advance `indices` to the next permutation in lexicographic order,
+/// returning `false` (and leaving `indices` unchanged) if it is the last one.
+fn next_permutation(indices: &mut [usize]) -> bool {
+    let Some(i) = (1..indices.len())
+        .rev()
+        .find(|i| indices[i - 1] < indices[*i])
+    else {
+        return false;
+    };
+    let j = (i..indices.len())
+        .rev()
+        .find(|j| indices[*j] > indices[i - 1])
+        .unwrap(); // since indices[i] > indices[i-1]
+    indices.swap(i - 1, j);
+    indices[i..].reverse();
+    true
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{LangTag, Literal, ns::xsd, quad};
+
+    const EX: &str = "http://example.com/#";
+
+    fn nquads(quads: &[SimpleQuad]) -> String {
+        quads.iter().map(nquad).collect()
+    }
+
+    #[test]
+    fn no_blank_nodes() {
+        let d = vec![
+            quad!(EX:p, EX:q, "chat"@EN, EX:g),
+            quad!(EX:p, EX:q, EX:r),
+            quad!(EX:p, EX:q, EX:r),
+        ];
+        assert_eq!(
+            nquads(&canonicalize(&d)),
+            r#"<http://example.com/#p> <http://example.com/#q> "chat"@en <http://example.com/#g> .
+<http://example.com/#p> <http://example.com/#q> <http://example.com/#r> .
+"#
+        );
+    }
+
+    #[test]
+    fn unique_hashes() {
+        // example from https://www.w3.org/TR/rdf-canon/#example-unique-hashes
+        let d = vec![
+            quad!(EX:p, EX:q, _:e0),
+            quad!(EX:p, EX:r, _:e1),
+            quad!(_:e0, EX:s, EX:u),
+            quad!(_:e1, EX:t, EX:u),
+        ];
+        assert_eq!(
+            nquads(&canonicalize(&d)),
+            r#"<http://example.com/#p> <http://example.com/#q> _:c14n0 .
+<http://example.com/#p> <http://example.com/#r> _:c14n1 .
+_:c14n0 <http://example.com/#s> <http://example.com/#u> .
+_:c14n1 <http://example.com/#t> <http://example.com/#u> .
+"#
+        );
+    }
+
+    #[test]
+    fn shared_hashes() {
+        // example from https://www.w3.org/TR/rdf-canon/#example-shared-hashes
+        let d = vec![
+            quad!(EX:p, EX:q, _:e0),
+            quad!(EX:p, EX:q, _:e1),
+            quad!(_:e0, EX:p, _:e2),
+            quad!(_:e1, EX:p, _:e3),
+            quad!(_:e2, EX:r, _:e3),
+        ];
+        assert_eq!(
+            nquads(&canonicalize(&d)),
+            r#"<http://example.com/#p> <http://example.com/#q> _:c14n2 .
+<http://example.com/#p> <http://example.com/#q> _:c14n3 .
+_:c14n0 <http://example.com/#r> _:c14n1 .
+_:c14n2 <http://example.com/#p> _:c14n1 .
+_:c14n3 <http://example.com/#p> _:c14n0 .
+"#
+        );
+    }
+
+    /// Parse the (RDF 1.1) N-Quads of the test files in `data/rdfc10`.
+    ///
+    /// Only the subset of the syntax used in these files is supported:
+    /// one quad per line, no relative IRIs, and no prefixed names.
+    fn parse_nquads(txt: &str) -> Vec<SimpleQuad<'static>> {
+        txt.lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(|line| {
+                let mut rest = line;
+                let mut terms = Vec::new();
+                while !rest.starts_with('.') {
+                    let (term, tail) = parse_term(rest);
+                    terms.push(term);
+                    rest = tail.trim_start();
+                }
+                assert_eq!(rest, ".", "{line}");
+                let mut terms = terms.into_iter();
+                let (Some(s), Some(ObjectProxy::Iri(predicate)), Some(object)) =
+                    (terms.next(), terms.next(), terms.next())
+                else {
+                    panic!("invalid quad {line}");
+                };
+                let graph_name = terms.next().map(|g| match g {
+                    ObjectProxy::Iri(iri) => GraphNameProxy::Iri(iri),
+                    ObjectProxy::BlankNode(bnid) => GraphNameProxy::BlankNode(bnid),
+                    _ => panic!("invalid graph name in {line}"),
+                });
+                assert!(terms.next().is_none(), "{line}");
+                let subject = match s {
+                    ObjectProxy::Iri(iri) => SubjectProxy::Iri(iri),
+                    ObjectProxy::BlankNode(bnid) => SubjectProxy::BlankNode(bnid),
+                    _ => panic!("invalid subject in {line}"),
+                };
+                SimpleQuad {
+                    subject,
+                    predicate,
+                    object,
+                    graph_name,
+                }
+            })
+            .collect()
+    }
+
+    /// Parse the term at the start of `txt`, returning it with the rest of `txt`.
+    fn parse_term(txt: &str) -> (ObjectProxy<'static, Box<SimpleTriple<'static>>>, &str) {
+        let end_of_token = |txt: &str| txt.find(char::is_whitespace).unwrap_or(txt.len());
+        if let Some(rest) = txt.strip_prefix('<') {
+            let end = rest.find('>').unwrap();
+            let iri = Iri::new_unchecked(rest[..end].to_string());
+            (ObjectProxy::Iri(iri), &rest[end + 1..])
+        } else if let Some(rest) = txt.strip_prefix("_:") {
+            let end = end_of_token(rest);
+            (
+                ObjectProxy::BlankNode(rest[..end].to_string().into()),
+                &rest[end..],
+            )
+        } else if let Some(rest) = txt.strip_prefix('"') {
+            let mut lex = String::new();
+            let mut chars = rest.char_indices();
+            let end = loop {
+                match chars.next().unwrap() {
+                    (i, '"') => break i,
+                    (_, '\\') => match chars.next().unwrap().1 {
+                        'n' => lex.push('\n'),
+                        'r' => lex.push('\r'),
+                        't' => lex.push('\t'),
+                        'b' => lex.push('\u{8}'),
+                        'f' => lex.push('\u{C}'),
+                        c @ ('u' | 'U') => {
+                            let len = if c == 'u' { 4 } else { 8 };
+                            let hex: String = (0..len).map(|_| chars.next().unwrap().1).collect();
+                            lex.push(
+                                char::from_u32(u32::from_str_radix(&hex, 16).unwrap()).unwrap(),
+                            );
+                        }
+                        c => lex.push(c),
+                    },
+                    (_, c) => lex.push(c),
+                }
+            };
+            let rest = &rest[end + 1..];
+            if let Some(rest) = rest.strip_prefix('@') {
+                let end = end_of_token(rest);
+                let tag = LangTag::new_unchecked(rest[..end].to_string());
+                (
+                    ObjectProxy::Literal(Literal::new_lang(lex, tag)),
+                    &rest[end..],
+                )
+            } else if let Some(rest) = rest.strip_prefix("^^") {
+                let (ObjectProxy::Iri(datatype), rest) = parse_term(rest) else {
+                    panic!("invalid datatype in {txt}");
+                };
+                (
+                    ObjectProxy::Literal(Literal::new_typed(lex, datatype)),
+                    rest,
+                )
+            } else {
+                (
+                    ObjectProxy::Literal(Literal::new_typed(lex, xsd::STRING)),
+                    rest,
+                )
+            }
+        } else {
+            panic!("invalid term {txt}");
+        }
+    }
+
+    /// Include the input and expected output of a test case from `data/rdfc10`.
+    macro_rules! rdfc10_case {
+        ($name:literal) => {
+            (
+                $name,
+                include_str!(concat!("../data/rdfc10/", $name, "-in.nq")),
+                include_str!(concat!("../data/rdfc10/", $name, "-rdfc10.nq")),
+            )
+        };
+    }
+
+    #[test]
+    fn rdfc10() {
+        // the test cases, with the number of permutations they require (see data/rdfc10/README.md)
+        let cases = [
+            (rdfc10_case!("unique-hashes"), 0),
+            (rdfc10_case!("shared-hashes"), 2),
+            (rdfc10_case!("double-linked-cycle"), 36),
+            (rdfc10_case!("two-cycles"), 36),
+            (rdfc10_case!("six-cycle"), 72),
+            (rdfc10_case!("clique"), 480),
+            (rdfc10_case!("blank-graph-names"), 32),
+            (rdfc10_case!("language-tags"), 0),
+            (rdfc10_case!("literals"), 0),
+        ];
+        for ((name, input, expected), permutations) in cases {
+            let d = parse_nquads(input);
+            let canonical = canonicalize(&d);
+            assert_eq!(nquads(&canonical), expected, "{name}");
+            assert_eq!(
+                canonicalize_with_limit(&d, permutations),
+                Ok(canonical),
+                "{name}"
+            );
+            if permutations > 0 {
+                let limit = permutations - 1;
+                assert_eq!(
+                    canonicalize_with_limit(&d, limit),
+                    Err(RdfcLimitExceeded { limit }),
+                    "{name}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn rdfc10_poison() {
+        let d = parse_nquads(include_str!("../data/rdfc10/poison-clique-in.nq"));
+        assert_eq!(d.len(), 90);
+        assert_eq!(
+            canonicalize_with_limit(&d, 100_000),
+            Err(RdfcLimitExceeded { limit: 100_000 })
+        );
+    }
+
+    /// All the permutations of `items`.
+    fn permutations<T: Clone>(items: &[T]) -> Vec<Vec<T>> {
+        let mut indices: Vec<_> = (0..items.len()).collect();
+        let mut ret = vec![];
+        loop {
+            ret.push(indices.iter().map(|i| items[*i].clone()).collect());
+            if !next_permutation(&mut indices) {
+                return ret;
+            }
+        }
+    }
+
+    #[test]
+    fn next_permutation_all() {
+        assert_eq!(permutations(&[0; 0]).len(), 1);
+        assert_eq!(
+            permutations(&[1, 2, 3]),
+            vec![
+                vec![1, 2, 3],
+                vec![1, 3, 2],
+                vec![2, 1, 3],
+                vec![2, 3, 1],
+                vec![3, 1, 2],
+                vec![3, 2, 1],
+            ]
+        );
+    }
+
+    /// Rename the blank nodes of `quads` with `prefix`, in reverse order.
+    fn rename(quads: &[SimpleQuad], prefix: &str) -> Vec<SimpleQuad<'static>> {
+        let mut renamed: Vec<_> = quads
+            .iter()
+            .map(|q| relabel(q, &mut |bnid| format!("{prefix}{bnid}")))
+            .collect();
+        renamed.reverse();
+        renamed
+    }
+
+    #[test]
+    fn invariance() {
+        let datasets = [
+            // a cycle, where all blank nodes are indistinguishable by their first degree hash
+            vec![
+                quad!(_:e0, EX:next, _:e1),
+                quad!(_:e1, EX:next, _:e2),
+                quad!(_:e2, EX:next, _:e0),
+                quad!(_:e0, EX:prev, _:e2),
+                quad!(_:e1, EX:prev, _:e0),
+                quad!(_:e2, EX:prev, _:e1),
+            ],
+            // blank graph names
+            vec![
+                quad!(_:a, EX:p, _:b, _:g),
+                quad!(_:b, EX:p, _:a, _:g),
+                quad!(_:g, EX:p, _:a, _:h),
+                quad!(_:h, EX:p, _:b, _:g),
+            ],
+            // nested triple terms
+            vec![
+                quad!(_:a, EX:says, <<( _:b, EX:p, <<( _:a, EX:q, _:c )>> )>>),
+                quad!(_:b, EX:says, <<( _:c, EX:p, <<( _:b, EX:q, _:a )>> )>>),
+                quad!(_:c, EX:says, <<( _:a, EX:p, <<( _:c, EX:q, _:b )>> )>>),
+            ],
+        ];
+        for d in datasets {
+            let canonical = canonicalize(&d);
+            assert_eq!(canonical.len(), d.len());
+            for prefix in ["x", "y_", "zzz"] {
+                assert_eq!(canonicalize(&rename(&d, prefix)), canonical);
+            }
+            for perm in permutations(&d) {
+                assert_eq!(canonicalize(&perm), canonical);
+            }
+            // canonical form is a fixpoint
+            assert_eq!(canonicalize(&canonical), canonical);
+        }
+    }
+
+    #[test]
+    fn distinguishes() {
+        // a 6-cycle and two 3-cycles have the same first degree hashes
+        let cycle = |labels: &[&'static str]| -> Vec<SimpleQuad<'static>> {
+            (0..labels.len())
+                .map(|i| {
+                    let mut q = quad!(_:x, EX:next, _:y);
+                    q.subject = SubjectProxy::BlankNode(labels[i].into());
+                    q.object = ObjectProxy::BlankNode(labels[(i + 1) % labels.len()].into());
+                    q
+                })
+                .collect()
+        };
+        let six = cycle(&["a", "b", "c", "d", "e", "f"]);
+        let mut three_three = cycle(&["a", "b", "c"]);
+        three_three.extend(cycle(&["d", "e", "f"]));
+        assert_ne!(canonicalize(&six), canonicalize(&three_three));
+        assert_eq!(canonicalize(&six), canonicalize(&rename(&six, "x")));
+    }
+}
@@ -0,0 +1,306 @@
+use alloc::{
+    borrow::Cow,
+    boxed::Box,
+    collections::{BTreeMap, BTreeSet},
+    string::String,
+    vec,
+    vec::Vec,
+};
+
+use crate::{Iri, ObjectProxy, SimpleTriple, SubjectProxy, Triple, ns::rdf};
+
+/// Expand a triple whose object is a [triple term] into an equivalent set of triples
+/// without triple terms, suitable for systems only supporting RDF 1.1.
+///
+/// The triple term is replaced by a fresh blank node (the *reifier*, labelled by `bnode_gen`),
+/// described with the [RDF reification vocabulary]
+/// (`rdf:subject`, `rdf:predicate` and `rdf:object`).
+/// Nested triple terms are expanded in turn, each with its own reifier.
+/// If the object of `triple` is not a triple term, the returned vector only contains a copy of `triple`.
+///
+/// `bnode_gen` is expected to return labels that do not appear elsewhere in the data.
+/// The expansion can be reverted with [`unreify_objects`].
+///
+/// ```
+/// # use r2c2_statement::*;
+/// const EX: &str = "https://example.org/ns/";
+/// let t = triple!(EX:alice, EX:says, <<( EX:bob, EX:age, "42" )>>);
+/// let mut counter = 0;
+/// let expanded = reify_object(&t, &mut || {
+///     counter += 1;
+///     format!("r{counter}")
+/// });
+/// assert_eq!(expanded.len(), 4);
+/// assert_eq!(expanded[0], triple!(EX:alice, EX:says, _:r1));
+/// assert_eq!(unreify_objects(&expanded), vec![t]);
+/// ```
+///
+/// [triple term]: https://www.w3.org/TR/rdf12-concepts/#dfn-triple-term
+/// [RDF reification vocabulary]: https://www.w3.org/TR/rdf12-schema/#ch_reificationvocab
+pub fn reify_object<T: Triple>(
+    triple: &T,
+    bnode_gen: &mut impl FnMut() -> String,
+) -> Vec<SimpleTriple<'static>> {
+    let mut ret = vec![];
+    let mut current = SimpleTriple::from_triple(triple);
+    while let ObjectProxy::Triple(inner) = current.object {
+        let reifier: Cow<'static, str> = Cow::Owned(bnode_gen());
+        ret.push(SimpleTriple {
+            subject: current.subject,
+            predicate: current.predicate,
+            object: ObjectProxy::BlankNode(reifier.clone()),
+        });
+        let SimpleTriple {
+            subject,
+            predicate,
+            object,
+        } = *inner;
+        ret.push(SimpleTriple {
+            subject: SubjectProxy::BlankNode(reifier.clone()),
+            predicate: rdf::SUBJECT,
+            object: match subject {
+                SubjectProxy::Iri(iri) => ObjectProxy::Iri(iri),
+                SubjectProxy::BlankNode(bnid) => ObjectProxy::BlankNode(bnid),
+            },
+        });
+        ret.push(SimpleTriple {
+            subject: SubjectProxy::BlankNode(reifier.clone()),
+            predicate: rdf::PREDICATE,
+            object: ObjectProxy::Iri(predicate),
+        });
+        // the rdf:object triple may itself contain a triple term, which is expanded in the next iteration
+        current = SimpleTriple {
+            subject: SubjectProxy::BlankNode(reifier),
+            predicate: rdf::OBJECT,
+            object,
+        };
+    }
+    ret.push(current);
+    ret
+}
+
+/// Revert the expansion performed by [`reify_object`],
+/// replacing reifiers by the triple terms they describe.
+///
+/// A blank node is considered as a reifier if it is the subject of
+/// exactly one `rdf:subject` triple (whose object is an IRI or a blank node),
+/// exactly one `rdf:predicate` triple (whose object is an IRI)
+/// and exactly one `rdf:object` triple,
+/// is not the subject of any other triple,
+/// is the object of at least one triple,
+/// and is not involved in a cycle of reifiers.
+/// The reification triples of reifiers are removed from the output,
+/// and every occurrence of a reifier in the object position is replaced by the corresponding triple term.
+/// All other triples are copied unchanged, in their original order.
+pub fn unreify_objects<T: Triple>(triples: &[T]) -> Vec<SimpleTriple<'static>> {
+    let triples: Vec<_> = triples.iter().map(SimpleTriple::from_triple).collect();
+
+    // collect the description of candidate reifiers
+    let mut candidates: BTreeMap<&str, Description> = BTreeMap::new();
+    for t in &triples {
+        let SubjectProxy::BlankNode(bnid) = &t.subject else {
+            continue;
+        };
+        let desc = candidates.entry(bnid.as_ref()).or_default();
+        let p = &t.predicate;
+        match &t.object {
+            ObjectProxy::Iri(iri) if *p == rdf::SUBJECT => {
+                desc.subject.push(SubjectProxy::Iri(iri.clone()))
+            }
+            ObjectProxy::BlankNode(bnid) if *p == rdf::SUBJECT => {
+                desc.subject.push(SubjectProxy::BlankNode(bnid.clone()))
+            }
+            ObjectProxy::Iri(iri) if *p == rdf::PREDICATE => desc.predicate.push(iri.clone()),
+            object if *p == rdf::OBJECT => desc.object.push(object.clone()),
+            _ => desc.other = true,
+        }
+    }
+    let referenced: BTreeSet<&str> = triples
+        .iter()
+        .filter_map(|t| match &t.object {
+            ObjectProxy::BlankNode(bnid) => Some(bnid.as_ref()),
+            _ => None,
+        })
+        .collect();
+    let mut reifiers: BTreeMap<&str, Description> = candidates
+        .into_iter()
+        .filter(|(bnid, desc)| desc.is_reification() && referenced.contains(bnid))
+        .collect();
+
+    // discard reifiers involved in a cycle
+    // (as triple terms only appear in the object position, reifiers can only form chains)
+    let mut in_cycle = BTreeSet::new();
+    for start in reifiers.keys() {
+        let mut visited = vec![*start];
+        let mut current = *start;
+        while let Some(next) = reifiers[current].object_reifier(&reifiers) {
+            if let Some(pos) = visited.iter().position(|v| *v == next) {
+                in_cycle.extend(visited.drain(pos..));
+                break;
+            }
+            visited.push(next);
+            current = next;
+        }
+    }
+    reifiers.retain(|bnid, _| !in_cycle.contains(bnid));
+
+    // rebuild the triple terms, innermost first
+    let mut triple_terms: BTreeMap<&str, SimpleTriple<'static>> = BTreeMap::new();
+    for start in reifiers.keys() {
+        let mut chain = vec![];
+        let mut current = Some(*start);
+        while let Some(bnid) = current.filter(|bnid| !triple_terms.contains_key(bnid)) {
+            chain.push(bnid);
+            current = reifiers[bnid].object_reifier(&reifiers);
+        }
+        for bnid in chain.into_iter().rev() {
+            let desc = &reifiers[bnid];
+            let object = match desc.object_reifier(&reifiers) {
+                Some(inner) => ObjectProxy::Triple(Box::new(triple_terms[inner].clone())),
+                None => desc.object[0].clone(),
+            };
+            triple_terms.insert(bnid, desc.to_triple(object));
+        }
+    }
+
+    // replace reifiers in all other triples
+    triples
+        .iter()
+        .filter(|t| match &t.subject {
+            SubjectProxy::BlankNode(bnid) => !reifiers.contains_key(bnid.as_ref()),
+            SubjectProxy::Iri(_) => true,
+        })
+        .map(|t| match &t.object {
+            ObjectProxy::BlankNode(bnid) if triple_terms.contains_key(bnid.as_ref()) => {
+                SimpleTriple {
+                    subject: t.subject.clone(),
+                    predicate: t.predicate.clone(),
+                    object: ObjectProxy::Triple(Box::new(triple_terms[bnid.as_ref()].clone())),
+                }
+            }
+            _ => t.clone(),
+        })
+        .collect()
+}
+
+/// The reification triples describing a candidate reifier.
+#[derive(Default)]
+struct Description {
+    subject: Vec<SubjectProxy<'static>>,
+    predicate: Vec<Iri<'static>>,
+    object: Vec<ObjectProxy<'static, Box<SimpleTriple<'static>>>>,
+    other: bool,
+}
+
+impl Description {
+    fn is_reification(&self) -> bool {
+        self.subject.len() == 1
+            && self.predicate.len() == 1
+            && self.object.len() == 1
+            && !self.other
+    }
+
+    /// The label of the rdf:object of this description, if it is itself a reifier.
+    fn object_reifier<'k>(&self, reifiers: &BTreeMap<&'k str, Description>) -> Option<&'k str> {
+        match &self.object[0] {
+            ObjectProxy::BlankNode(bnid) => reifiers.get_key_value(bnid.as_ref()).map(|(k, _)| *k),
+            _ => None,
+        }
+    }
+
+    fn to_triple(
+        &self,
+        object: ObjectProxy<'static, Box<SimpleTriple<'static>>>,
+    ) -> SimpleTriple<'static> {
+        SimpleTriple {
+            subject: self.subject[0].clone(),
+            predicate: self.predicate[0].clone(),
+            object,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::triple;
+
+    const EX: &str = "https://example.org/ns/";
+
+    fn bnode_gen() -> impl FnMut() -> String {
+        let mut counter = 0;
+        move || {
+            counter += 1;
+            format!("r{counter}")
+        }
+    }
+
+    #[test]
+    fn flat() {
+        let t = triple!(EX:s, EX:p, _:o);
+        let expanded = reify_object(&t, &mut bnode_gen());
+        assert_eq!(expanded, vec![t.clone()]);
+        assert_eq!(unreify_objects(&expanded), vec![t]);
+    }
+
+    #[test]
+    fn nested() {
+        let t = triple!(EX:a, EX:says, <<( _:b, EX:says, <<( EX:c, EX:p, "o"@en )>> )>>);
+        let expanded = reify_object(&t, &mut bnode_gen());
+        assert_eq!(
+            expanded,
+            vec![
+                triple!(EX:a, EX:says, _:r1),
+                triple!(_:r1, <"http://www.w3.org/1999/02/22-rdf-syntax-ns#subject">, _:b),
+                triple!(_:r1, <"http://www.w3.org/1999/02/22-rdf-syntax-ns#predicate">, EX:says),
+                triple!(_:r1, <"http://www.w3.org/1999/02/22-rdf-syntax-ns#object">, _:r2),
+                triple!(_:r2, <"http://www.w3.org/1999/02/22-rdf-syntax-ns#subject">, EX:c),
+                triple!(_:r2, <"http://www.w3.org/1999/02/22-rdf-syntax-ns#predicate">, EX:p),
+                triple!(_:r2, <"http://www.w3.org/1999/02/22-rdf-syntax-ns#object">, "o"@en),
+            ]
+        );
+        assert!(expanded.iter().all(|t| !t.contains_triple_term()));
+        assert_eq!(unreify_objects(&expanded), vec![t]);
+    }
+
+    #[test]
+    fn several_statements() {
+        let triples = [
+            triple!(EX:a, EX:says, <<( EX:b, EX:p, <<( EX:c, EX:p, EX:d )>> )>>),
+            triple!(_:x, EX:p, EX:o),
+            triple!(EX:e, EX:says, <<( _:x, EX:p, EX:o )>>),
+        ];
+        let mut bnode_gen = bnode_gen();
+        let expanded: Vec<_> = triples
+            .iter()
+            .flat_map(|t| reify_object(t, &mut bnode_gen))
+            .collect();
+        assert_eq!(expanded.len(), 12);
+        assert_eq!(unreify_objects(&expanded), triples);
+    }
+
+    #[test]
+    fn not_reifiers() {
+        let triples = [
+            // missing rdf:predicate
+            triple!(EX:a, EX:says, _:r1),
+            triple!(_:r1, <"http://www.w3.org/1999/02/22-rdf-syntax-ns#subject">, EX:b),
+            triple!(_:r1, <"http://www.w3.org/1999/02/22-rdf-syntax-ns#object">, EX:c),
+            // additional property
+            triple!(EX:a, EX:says, _:r2),
+            triple!(_:r2, <"http://www.w3.org/1999/02/22-rdf-syntax-ns#subject">, EX:b),
+            triple!(_:r2, <"http://www.w3.org/1999/02/22-rdf-syntax-ns#predicate">, EX:p),
+            triple!(_:r2, <"http://www.w3.org/1999/02/22-rdf-syntax-ns#object">, EX:c),
+            triple!(_:r2, EX:source, EX:d),
+            // not referenced
+            triple!(_:r4, <"http://www.w3.org/1999/02/22-rdf-syntax-ns#subject">, EX:b),
+            triple!(_:r4, <"http://www.w3.org/1999/02/22-rdf-syntax-ns#predicate">, EX:p),
+            triple!(_:r4, <"http://www.w3.org/1999/02/22-rdf-syntax-ns#object">, EX:c),
+            // cycle
+            triple!(_:r3, <"http://www.w3.org/1999/02/22-rdf-syntax-ns#subject">, EX:b),
+            triple!(_:r3, <"http://www.w3.org/1999/02/22-rdf-syntax-ns#predicate">, EX:p),
+            triple!(_:r3, <"http://www.w3.org/1999/02/22-rdf-syntax-ns#object">, _:r3),
+        ];
+        assert_eq!(unreify_objects(&triples), triples);
+    }
+}
@@ -0,0 +1,147 @@
+use alloc::{borrow::Cow, boxed::Box, format};
+
+use crate::{
+    _simple_triple::{owned_iri, owned_literal},
+    GraphNameProxy, Iri, Literal, Object, ObjectProxy, Predicate, SimpleTriple, Subject,
+    SubjectProxy, Triple,
+};
+
+/// Replace the prefix `from` of `iri` by `to`, or return (a copy of) `iri` unchanged if it does not start with `from`.
+///
+/// The result is *not* checked, so it is the caller's responsibility to ensure that
+/// replacing `from` by `to` always yields valid IRIs.
+///
+/// ```
+/// # use r2c2_statement::*;
+/// let iri = Iri::new_unchecked("http://staging.example.org/ns/p");
+/// let remapped = remap_iri_prefix(&iri, "http://staging.example.org/", "https://example.org/");
+/// assert_eq!(remapped, "https://example.org/ns/p");
+/// let other = Iri::new_unchecked("http://other.example.org/ns/p");
+/// assert_eq!(remap_iri_prefix(&other, "http://staging.example.org/", "https://example.org/"), other);
+/// ```
+pub fn remap_iri_prefix(iri: &Iri<'_>, from: &str, to: &str) -> Iri<'static> {
+    match iri.as_str().strip_prefix(from) {
+        Some(suffix) => Iri::new_unchecked(format!("{to}{suffix}")),
+        None => owned_iri(iri.borrowed()),
+    }
+}
+
+/// Apply [`remap_iri_prefix`] to `subject`, if it is an IRI.
+pub fn remap_subject_prefix(
+    subject: &SubjectProxy<'_>,
+    from: &str,
+    to: &str,
+) -> SubjectProxy<'static> {
+    match subject {
+        SubjectProxy::Iri(iri) => SubjectProxy::Iri(remap_iri_prefix(iri, from, to)),
+        SubjectProxy::BlankNode(bnid) => SubjectProxy::BlankNode(Cow::Owned(bnid.as_ref().into())),
+    }
+}
+
+/// Apply [`remap_iri_prefix`] to `graph_name`, if it is an IRI.
+pub fn remap_graph_name_prefix(
+    graph_name: &GraphNameProxy<'_>,
+    from: &str,
+    to: &str,
+) -> GraphNameProxy<'static> {
+    match graph_name {
+        GraphNameProxy::Iri(iri) => GraphNameProxy::Iri(remap_iri_prefix(iri, from, to)),
+        GraphNameProxy::BlankNode(bnid) => {
+            GraphNameProxy::BlankNode(Cow::Owned(bnid.as_ref().into()))
+        }
+    }
+}
+
+/// Apply [`remap_iri_prefix`] to every IRI in `object`:
+/// the object itself, the datatype of a literal,
+/// and all the IRIs of a [triple term](https://www.w3.org/TR/rdf12-concepts/#dfn-triple-term) (recursively).
+///
+/// ```
+/// # use r2c2_statement::*;
+/// let t = triple!(
+///     <"http://staging/s">,
+///     <"http://staging/p">,
+///     <<( _:b, <"http://staging/q">, "42"^^<"http://staging/dt"> )>>
+/// );
+/// let remapped = remap_object_prefix(&t.object, "http://staging/", "https://example.org/");
+/// assert_eq!(
+///     remapped.to_string(),
+///     r#"<<( _:b <https://example.org/q> "42"^^<https://example.org/dt> )>>"#
+/// );
+/// ```
+pub fn remap_object_prefix<T: Triple>(
+    object: &ObjectProxy<'_, T>,
+    from: &str,
+    to: &str,
+) -> ObjectProxy<'static, Box<SimpleTriple<'static>>> {
+    match object {
+        ObjectProxy::Iri(iri) => ObjectProxy::Iri(remap_iri_prefix(iri, from, to)),
+        ObjectProxy::BlankNode(bnid) => ObjectProxy::BlankNode(Cow::Owned(bnid.as_ref().into())),
+        ObjectProxy::Literal(Literal::Typed(lex, datatype)) => {
+            ObjectProxy::Literal(Literal::Typed(
+                Cow::Owned(lex.as_ref().into()),
+                remap_iri_prefix(datatype, from, to),
+            ))
+        }
+        ObjectProxy::Literal(literal) => ObjectProxy::Literal(owned_literal(literal.borrowed())),
+        ObjectProxy::Triple(triple) => ObjectProxy::Triple(Box::new(SimpleTriple {
+            subject: remap_subject_prefix(&triple.subject().as_subject_proxy(), from, to),
+            predicate: remap_iri_prefix(&triple.predicate().as_iri(), from, to),
+            object: remap_object_prefix(&triple.object().as_object_proxy(), from, to),
+        })),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{quad, triple};
+
+    const FROM: &str = "http://staging.example.org/";
+    const TO: &str = "https://example.org/";
+
+    #[test]
+    fn iri() {
+        let iri = Iri::new_unchecked("http://staging.example.org/ns/p");
+        assert_eq!(remap_iri_prefix(&iri, FROM, TO), "https://example.org/ns/p");
+        assert_eq!(
+            remap_iri_prefix(&iri, "", TO),
+            "https://example.org/http://staging.example.org/ns/p"
+        );
+        assert_eq!(
+            remap_iri_prefix(&iri, "http://staging", "http://prod"),
+            "http://prod.example.org/ns/p"
+        );
+        // the prefix must match at the start
+        let iri = Iri::new_unchecked("urn:x:http://staging.example.org/");
+        assert_eq!(remap_iri_prefix(&iri, FROM, TO), iri);
+    }
+
+    #[test]
+    fn proxies() {
+        let q = quad!(
+            <"http://staging.example.org/s">,
+            <"http://staging.example.org/p">,
+            <<( <"http://other.example.org/s">, <"http://staging.example.org/q">, <<( _:b, <"http://staging.example.org/r">, "chat"@en )>> )>>,
+            <"http://staging.example.org/g">
+        );
+        assert_eq!(
+            remap_subject_prefix(&q.subject, FROM, TO),
+            SubjectProxy::Iri(Iri::new_unchecked("https://example.org/s"))
+        );
+        assert_eq!(
+            remap_graph_name_prefix(q.graph_name.as_ref().unwrap(), FROM, TO),
+            GraphNameProxy::Iri(Iri::new_unchecked("https://example.org/g"))
+        );
+        assert_eq!(
+            remap_object_prefix(&q.object, FROM, TO).to_string(),
+            r#"<<( <http://other.example.org/s> <https://example.org/q> <<( _:b <https://example.org/r> "chat"@en )>> )>>"#
+        );
+
+        let t = triple!(_:a, <"http://staging.example.org/p">, _:b);
+        assert_eq!(remap_subject_prefix(&t.subject, FROM, TO), t.subject);
+        assert_eq!(remap_object_prefix(&t.object, FROM, TO), t.object);
+        let g = GraphNameProxy::BlankNode("g".into());
+        assert_eq!(remap_graph_name_prefix(&g, FROM, TO), g);
+    }
+}
@@ -0,0 +1,515 @@
+use alloc::{borrow::Cow, boxed::Box, string::String};
+use core::{fmt, marker::PhantomData};
+
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{self, DeserializeSeed, MapAccess, Unexpected, Visitor},
+    ser::SerializeMap,
+};
+
+use crate::{
+    BaseDir, GraphNameProxy, Iri, LangTag, Literal, Object, ObjectProxy, Predicate, SimpleTriple,
+    Subject, SubjectProxy, Triple, ns::rdf,
+};
+
+/// The maximum nesting depth of [triple terms] accepted when deserializing an [`ObjectProxy`]
+/// (a triple term whose subject and object are not triple terms has depth 1).
+///
+/// Deeper input is rejected with an error, rather than exhausting the stack.
+///
+/// [triple terms]: https://www.w3.org/TR/rdf12-concepts/#dfn-triple-term
+pub const SERDE_MAX_DEPTH: usize = 64;
+
+const KINDS: &[&str] = &["iri", "bnode", "literal", "triple"];
+const FIELDS: &[&str] = &[
+    "kind",
+    "value",
+    "datatype",
+    "language",
+    "direction",
+    "subject",
+    "predicate",
+    "object",
+];
+
+impl Serialize for SubjectProxy<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            SubjectProxy::Iri(iri) => serialize_node(serializer, "iri", iri.as_str()),
+            SubjectProxy::BlankNode(bnid) => serialize_node(serializer, "bnode", bnid),
+        }
+    }
+}
+
+impl Serialize for GraphNameProxy<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            GraphNameProxy::Iri(iri) => serialize_node(serializer, "iri", iri.as_str()),
+            GraphNameProxy::BlankNode(bnid) => serialize_node(serializer, "bnode", bnid),
+        }
+    }
+}
+
+impl Serialize for Literal<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Literal::Typed(lex, dt) => {
+                let mut map = serializer.serialize_map(Some(3))?;
+                map.serialize_entry("kind", "literal")?;
+                map.serialize_entry("value", lex)?;
+                map.serialize_entry("datatype", dt.as_str())?;
+                map.end()
+            }
+            Literal::LanguageString(lex, tag, dir) => {
+                let mut map = serializer.serialize_map(Some(3 + usize::from(dir.is_some())))?;
+                map.serialize_entry("kind", "literal")?;
+                map.serialize_entry("value", lex)?;
+                map.serialize_entry("language", tag.as_str())?;
+                if let Some(dir) = dir {
+                    let dir = match dir {
+                        BaseDir::Ltr => "ltr",
+                        BaseDir::Rtl => "rtl",
+                    };
+                    map.serialize_entry("direction", dir)?;
+                }
+                map.end()
+            }
+        }
+    }
+}
+
+impl<T: Triple> Serialize for ObjectProxy<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            ObjectProxy::Iri(iri) => serialize_node(serializer, "iri", iri.as_str()),
+            ObjectProxy::BlankNode(bnid) => serialize_node(serializer, "bnode", bnid),
+            ObjectProxy::Literal(literal) => literal.serialize(serializer),
+            ObjectProxy::Triple(triple) => {
+                let (s, p, o) = triple.spo();
+                let mut map = serializer.serialize_map(Some(4))?;
+                map.serialize_entry("kind", "triple")?;
+                map.serialize_entry("subject", &s.as_subject_proxy())?;
+                map.serialize_entry("predicate", p.as_iri().as_str())?;
+                map.serialize_entry("object", &o.as_object_proxy())?;
+                map.end()
+            }
+        }
+    }
+}
+
+fn serialize_node<S: Serializer>(
+    serializer: S,
+    kind: &str,
+    value: &str,
+) -> Result<S::Ok, S::Error> {
+    let mut map = serializer.serialize_map(Some(2))?;
+    map.serialize_entry("kind", kind)?;
+    map.serialize_entry("value", value)?;
+    map.end()
+}
+
+/// IRIs, blank node identifiers and language tags are *not* validated during deserialization
+/// (see [`Iri::new_unchecked`] and [`LangTag::new_unchecked`]);
+/// deserialize a `r2c2_statement_validation::Validated<SubjectProxy>` instead
+/// when the input can not be trusted.
+impl<'de, 'a> Deserialize<'de> for SubjectProxy<'a> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match TermSeed::new(SERDE_MAX_DEPTH).deserialize(deserializer)? {
+            ObjectProxy::Iri(iri) => Ok(SubjectProxy::Iri(iri)),
+            ObjectProxy::BlankNode(bnid) => Ok(SubjectProxy::BlankNode(bnid)),
+            other => Err(de::Error::invalid_value(
+                unexpected(&other),
+                &"an IRI or a blank node",
+            )),
+        }
+    }
+}
+
+/// IRIs, blank node identifiers and language tags are *not* validated during deserialization
+/// (see [`Iri::new_unchecked`] and [`LangTag::new_unchecked`]);
+/// deserialize a `r2c2_statement_validation::Validated<GraphNameProxy>` instead
+/// when the input can not be trusted.
+impl<'de, 'a> Deserialize<'de> for GraphNameProxy<'a> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match TermSeed::new(SERDE_MAX_DEPTH).deserialize(deserializer)? {
+            ObjectProxy::Iri(iri) => Ok(GraphNameProxy::Iri(iri)),
+            ObjectProxy::BlankNode(bnid) => Ok(GraphNameProxy::BlankNode(bnid)),
+            other => Err(de::Error::invalid_value(
+                unexpected(&other),
+                &"an IRI or a blank node",
+            )),
+        }
+    }
+}
+
+/// The datatype IRI and language tag are *not* validated during deserialization
+/// (see [`Iri::new_unchecked`] and [`LangTag::new_unchecked`]);
+/// deserialize a `r2c2_statement_validation::Validated<Literal>` instead
+/// when the input can not be trusted.
+/// A datatype `rdf:langString` or `rdf:dirLangString` without a language tag is rejected, however.
+impl<'de, 'a> Deserialize<'de> for Literal<'a> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match TermSeed::new(SERDE_MAX_DEPTH).deserialize(deserializer)? {
+            ObjectProxy::Literal(literal) => Ok(literal),
+            other => Err(de::Error::invalid_value(unexpected(&other), &"a literal")),
+        }
+    }
+}
+
+/// Triple terms can be nested up to [`SERDE_MAX_DEPTH`].
+///
+/// IRIs, blank node identifiers and language tags are *not* validated during deserialization;
+/// deserialize a `r2c2_statement_validation::Validated<ObjectProxy>` instead
+/// when the input can not be trusted.
+impl<'de, 'a> Deserialize<'de> for ObjectProxy<'a, Box<SimpleTriple<'a>>> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        TermSeed::new(SERDE_MAX_DEPTH).deserialize(deserializer)
+    }
+}
+
+/// Triple terms can be nested up to [`SERDE_MAX_DEPTH`].
+///
+/// IRIs, blank node identifiers and language tags are *not* validated during deserialization;
+/// deserialize a `r2c2_statement_validation::Validated<ObjectProxy>` instead
+/// when the input can not be trusted.
+impl<'de, 'a> Deserialize<'de> for ObjectProxy<'a, SimpleTriple<'a>> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(
+            match TermSeed::new(SERDE_MAX_DEPTH).deserialize(deserializer)? {
+                ObjectProxy::Iri(iri) => ObjectProxy::Iri(iri),
+                ObjectProxy::BlankNode(bnid) => ObjectProxy::BlankNode(bnid),
+                ObjectProxy::Literal(literal) => ObjectProxy::Literal(literal),
+                ObjectProxy::Triple(triple) => ObjectProxy::Triple(*triple),
+            },
+        )
+    }
+}
+
+fn unexpected<T: Triple>(term: &ObjectProxy<'_, T>) -> Unexpected<'static> {
+    Unexpected::Other(match term {
+        ObjectProxy::Iri(_) => "an IRI",
+        ObjectProxy::BlankNode(_) => "a blank node",
+        ObjectProxy::Literal(_) => "a literal",
+        ObjectProxy::Triple(_) => "a triple term",
+    })
+}
+
+/// Deserialize any term, allowing at most `depth` nested triple terms.
+struct TermSeed<'a> {
+    depth: usize,
+    _lifetime: PhantomData<&'a ()>,
+}
+
+impl TermSeed<'_> {
+    fn new(depth: usize) -> Self {
+        TermSeed {
+            depth,
+            _lifetime: PhantomData,
+        }
+    }
+}
+
+impl<'de, 'a> DeserializeSeed<'de> for TermSeed<'a> {
+    type Value = ObjectProxy<'a, Box<SimpleTriple<'a>>>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de, 'a> Visitor<'de> for TermSeed<'a> {
+    type Value = ObjectProxy<'a, Box<SimpleTriple<'a>>>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an RDF term")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut kind: Option<String> = None;
+        let mut value: Option<String> = None;
+        let mut datatype: Option<String> = None;
+        let mut language: Option<String> = None;
+        let mut direction: Option<String> = None;
+        let mut subject = None;
+        let mut predicate: Option<String> = None;
+        let mut object = None;
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "kind" => set(&mut kind, "kind", map.next_value()?)?,
+                "value" => set(&mut value, "value", map.next_value()?)?,
+                "datatype" => set(&mut datatype, "datatype", map.next_value()?)?,
+                "language" => set(&mut language, "language", map.next_value()?)?,
+                "direction" => set(&mut direction, "direction", map.next_value()?)?,
+                "predicate" => set(&mut predicate, "predicate", map.next_value()?)?,
+                "subject" | "object" => {
+                    let Some(depth) = self.depth.checked_sub(1) else {
+                        return Err(de::Error::custom(format_args!(
+                            "triple terms nested deeper than {SERDE_MAX_DEPTH}"
+                        )));
+                    };
+                    let term = map.next_value_seed(TermSeed::new(depth))?;
+                    if key == "subject" {
+                        set(&mut subject, "subject", term)?;
+                    } else {
+                        set(&mut object, "object", term)?;
+                    }
+                }
+                _ => return Err(de::Error::unknown_field(&key, FIELDS)),
+            }
+        }
+
+        let kind = kind.ok_or_else(|| de::Error::missing_field("kind"))?;
+        let node_fields = [
+            ("subject", subject.is_some()),
+            ("predicate", predicate.is_some()),
+            ("object", object.is_some()),
+        ];
+        match kind.as_str() {
+            "iri" | "bnode" => {
+                reject(&kind, &[("datatype", datatype.is_some())])?;
+                reject(&kind, &[("language", language.is_some())])?;
+                reject(&kind, &[("direction", direction.is_some())])?;
+                reject(&kind, &node_fields)?;
+                let value = value.ok_or_else(|| de::Error::missing_field("value"))?;
+                Ok(if kind == "iri" {
+                    ObjectProxy::Iri(Iri::new_unchecked(value))
+                } else {
+                    ObjectProxy::BlankNode(Cow::Owned(value))
+                })
+            }
+            "literal" => {
+                reject(&kind, &node_fields)?;
+                let lex = value.ok_or_else(|| de::Error::missing_field("value"))?;
+                let literal = match language {
+                    Some(tag) => {
+                        reject(&kind, &[("datatype", datatype.is_some())])?;
+                        let dir = match direction.as_deref() {
+                            None => None,
+                            Some("ltr") => Some(BaseDir::Ltr),
+                            Some("rtl") => Some(BaseDir::Rtl),
+                            Some(other) => {
+                                return Err(de::Error::unknown_variant(other, &["ltr", "rtl"]));
+                            }
+                        };
+                        Literal::LanguageString(Cow::Owned(lex), LangTag::new_unchecked(tag), dir)
+                    }
+                    None => {
+                        reject(&kind, &[("direction", direction.is_some())])?;
+                        let dt = datatype.ok_or_else(|| de::Error::missing_field("datatype"))?;
+                        let dt = Iri::new_unchecked(dt);
+                        if dt == rdf::LANG_STRING || dt == rdf::DIR_LANG_STRING {
+                            return Err(de::Error::missing_field("language"));
+                        }
+                        Literal::Typed(Cow::Owned(lex), dt)
+                    }
+                };
+                Ok(ObjectProxy::Literal(literal))
+            }
+            "triple" => {
+                reject(
+                    &kind,
+                    &[
+                        ("value", value.is_some()),
+                        ("datatype", datatype.is_some()),
+                        ("language", language.is_some()),
+                        ("direction", direction.is_some()),
+                    ],
+                )?;
+                let subject = match subject.ok_or_else(|| de::Error::missing_field("subject"))? {
+                    ObjectProxy::Iri(iri) => SubjectProxy::Iri(iri),
+                    ObjectProxy::BlankNode(bnid) => SubjectProxy::BlankNode(bnid),
+                    other => {
+                        return Err(de::Error::invalid_value(
+                            unexpected(&other),
+                            &"an IRI or a blank node as subject",
+                        ));
+                    }
+                };
+                let predicate = predicate.ok_or_else(|| de::Error::missing_field("predicate"))?;
+                let object = object.ok_or_else(|| de::Error::missing_field("object"))?;
+                Ok(ObjectProxy::Triple(Box::new(SimpleTriple {
+                    subject,
+                    predicate: Iri::new_unchecked(predicate),
+                    object,
+                })))
+            }
+            _ => Err(de::Error::unknown_variant(&kind, KINDS)),
+        }
+    }
+}
+
+fn set<T, E: de::Error>(slot: &mut Option<T>, field: &'static str, value: T) -> Result<(), E> {
+    if slot.is_some() {
+        return Err(E::duplicate_field(field));
+    }
+    *slot = Some(value);
+    Ok(())
+}
+
+fn reject<E: de::Error>(kind: &str, fields: &[(&str, bool)]) -> Result<(), E> {
+    match fields.iter().find(|(_, present)| *present) {
+        Some((field, _)) => Err(E::custom(format_args!(
+            "unexpected field `{field}` for kind `{kind}`"
+        ))),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::{format, string::ToString, vec::Vec};
+
+    use super::*;
+    use crate::ns::xsd;
+
+    type Obj<'a> = ObjectProxy<'a, SimpleTriple<'a>>;
+
+    fn iri(txt: &str) -> Iri<'_> {
+        Iri::new_unchecked(txt)
+    }
+
+    fn round_trip<T>(term: &T, expected: &str)
+    where
+        T: Serialize + de::DeserializeOwned + PartialEq + fmt::Debug,
+    {
+        let json = serde_json::to_string(term).unwrap();
+        assert_eq!(json, expected);
+        let back: T = serde_json::from_str(&json).unwrap();
+        assert_eq!(&back, term);
+    }
+
+    #[test]
+    fn subject_and_graph_name() {
+        let s = SubjectProxy::Iri(iri("https://example.org/s"));
+        round_trip(&s, r#"{"kind":"iri","value":"https://example.org/s"}"#);
+        let s = SubjectProxy::BlankNode("b1".into());
+        round_trip(&s, r#"{"kind":"bnode","value":"b1"}"#);
+        let g = GraphNameProxy::Iri(iri("https://example.org/g"));
+        round_trip(&g, r#"{"kind":"iri","value":"https://example.org/g"}"#);
+        let g = GraphNameProxy::BlankNode("g".into());
+        round_trip(&g, r#"{"kind":"bnode","value":"g"}"#);
+    }
+
+    #[test]
+    fn literal() {
+        let lit = Literal::Typed("42".into(), xsd::INTEGER);
+        round_trip(
+            &lit,
+            r#"{"kind":"literal","value":"42","datatype":"http://www.w3.org/2001/XMLSchema#integer"}"#,
+        );
+        let lit = Literal::LanguageString("chat".into(), LangTag::new_unchecked("en"), None);
+        round_trip(&lit, r#"{"kind":"literal","value":"chat","language":"en"}"#);
+        let lit = Literal::LanguageString(
+            "chat".into(),
+            LangTag::new_unchecked("fr"),
+            Some(BaseDir::Ltr),
+        );
+        round_trip(
+            &lit,
+            r#"{"kind":"literal","value":"chat","language":"fr","direction":"ltr"}"#,
+        );
+        let lit = Literal::LanguageString(
+            "قطة".into(),
+            LangTag::new_unchecked("ar"),
+            Some(BaseDir::Rtl),
+        );
+        round_trip(
+            &lit,
+            r#"{"kind":"literal","value":"قطة","language":"ar","direction":"rtl"}"#,
+        );
+        round_trip(
+            &Obj::Literal(lit.clone()),
+            &serde_json::to_string(&lit).unwrap(),
+        );
+    }
+
+    #[test]
+    fn object() {
+        round_trip(
+            &Obj::Iri(iri("https://example.org/o")),
+            r#"{"kind":"iri","value":"https://example.org/o"}"#,
+        );
+        round_trip(
+            &Obj::BlankNode("o".into()),
+            r#"{"kind":"bnode","value":"o"}"#,
+        );
+        let inner = SimpleTriple {
+            subject: SubjectProxy::BlankNode("b".into()),
+            predicate: iri("https://example.org/p"),
+            object: ObjectProxy::Literal(Literal::Typed("a".into(), xsd::STRING)),
+        };
+        let triple = SimpleTriple {
+            subject: SubjectProxy::Iri(iri("https://example.org/s")),
+            predicate: iri("https://example.org/q"),
+            object: ObjectProxy::Triple(Box::new(inner)),
+        };
+        let expected = concat!(
+            r#"{"kind":"triple","subject":{"kind":"iri","value":"https://example.org/s"},"#,
+            r#""predicate":"https://example.org/q","object":{"kind":"triple","#,
+            r#""subject":{"kind":"bnode","value":"b"},"predicate":"https://example.org/p","#,
+            r#""object":{"kind":"literal","value":"a","#,
+            r#""datatype":"http://www.w3.org/2001/XMLSchema#string"}}}"#,
+        );
+        round_trip(&Obj::Triple(triple.clone()), expected);
+        round_trip(&ObjectProxy::Triple(Box::new(triple)), expected);
+    }
+
+    fn nested(depth: usize) -> String {
+        let mut json = r#"{"kind":"iri","value":"x"}"#.to_string();
+        for _ in 0..depth {
+            json = format!(
+                r#"{{"kind":"triple","subject":{{"kind":"bnode","value":"b"}},"predicate":"p","object":{json}}}"#
+            );
+        }
+        json
+    }
+
+    #[test]
+    fn depth_limit() {
+        let ok: Obj = serde_json::from_str(&nested(SERDE_MAX_DEPTH)).unwrap();
+        let Obj::Triple(mut triple) = ok else {
+            panic!("expected a triple term");
+        };
+        let mut depth = 1;
+        while let ObjectProxy::Triple(inner) = triple.object {
+            triple = *inner;
+            depth += 1;
+        }
+        assert_eq!(depth, SERDE_MAX_DEPTH);
+
+        let err = serde_json::from_str::<Obj>(&nested(SERDE_MAX_DEPTH + 1)).unwrap_err();
+        assert!(err.to_string().contains("nested deeper"), "{err}");
+    }
+
+    #[test]
+    fn invalid() {
+        let errors: Vec<_> = [
+            r#""https://example.org/""#,
+            r#"{"value":"x"}"#,
+            r#"{"kind":"foo","value":"x"}"#,
+            r#"{"kind":"iri"}"#,
+            r#"{"kind":"iri","value":"x","value":"y"}"#,
+            r#"{"kind":"iri","value":"x","foo":"y"}"#,
+            r#"{"kind":"bnode","value":"x","language":"en"}"#,
+            r#"{"kind":"literal","value":"x"}"#,
+            r#"{"kind":"literal","value":"x","datatype":"d","language":"en"}"#,
+            r#"{"kind":"literal","value":"x","datatype":"d","direction":"ltr"}"#,
+            r#"{"kind":"literal","value":"x","language":"en","direction":"up"}"#,
+            r#"{"kind":"literal","value":"x","datatype":"http://www.w3.org/1999/02/22-rdf-syntax-ns#langString"}"#,
+            r#"{"kind":"triple","subject":{"kind":"iri","value":"s"},"predicate":"p"}"#,
+            r#"{"kind":"triple","subject":{"kind":"literal","value":"s","language":"en"},"predicate":"p","object":{"kind":"iri","value":"o"}}"#,
+            r#"{"kind":"triple","value":"x","subject":{"kind":"iri","value":"s"},"predicate":"p","object":{"kind":"iri","value":"o"}}"#,
+        ]
+        .into_iter()
+        .filter(|json| serde_json::from_str::<Obj>(json).is_ok())
+        .collect();
+        assert!(errors.is_empty(), "{errors:?}");
+
+        let lit = r#"{"kind":"literal","value":"x","language":"en"}"#;
+        assert!(serde_json::from_str::<SubjectProxy>(lit).is_err());
+        assert!(serde_json::from_str::<GraphNameProxy>(lit).is_err());
+        let iri = r#"{"kind":"iri","value":"x"}"#;
+        assert!(serde_json::from_str::<Literal>(iri).is_err());
+        assert!(serde_json::from_str::<SubjectProxy>(&nested(1)).is_err());
+    }
+}
@@ -0,0 +1,271 @@
+use alloc::sync::Arc;
+
+use crate::{
+    BaseDir, GraphName, GraphNameProxy, Iri, LangTag, Literal, NeverTriple, Object, ObjectProxy,
+    Predicate, Subject, SubjectProxy,
+};
+
+/// A counterpart of [`Iri`] backed by an [`Arc<str>`],
+/// so that cloning it (e.g. for sharing it across threads) only increments a reference count.
+///
+/// ## Contract
+/// The same contract as [`Iri`] applies:
+/// the underlying text is assumed to be a valid IRI,
+/// and this is not re-checked on conversion from or to [`Iri`].
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct SharedIri(Arc<str>);
+
+impl SharedIri {
+    /// Return a new [`SharedIri`], assuming the argument is a valid IRI.
+    ///
+    /// ## Precondition
+    /// It is the responsibility of the caller to ensure that `txt` is a valid IRI
+    pub fn new_unchecked(txt: impl Into<Arc<str>>) -> Self {
+        SharedIri(txt.into())
+    }
+
+    /// Return the inner [`Arc<str>`].
+    pub fn unwrap(self) -> Arc<str> {
+        self.0
+    }
+}
+
+impl core::ops::Deref for SharedIri {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<Iri<'_>> for SharedIri {
+    fn from(value: Iri<'_>) -> Self {
+        SharedIri(value.as_str().into())
+    }
+}
+
+impl<'a> From<&'a SharedIri> for Iri<'a> {
+    fn from(value: &'a SharedIri) -> Self {
+        Iri::new_unchecked(&*value.0)
+    }
+}
+
+impl core::fmt::Display for SharedIri {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "<{}>", &self.0)
+    }
+}
+
+impl Predicate for SharedIri {
+    fn as_iri(&self) -> Iri<'_> {
+        self.into()
+    }
+}
+
+impl Subject for SharedIri {
+    fn as_subject_proxy(&self) -> SubjectProxy<'_> {
+        SubjectProxy::Iri(self.into())
+    }
+}
+
+impl Object for SharedIri {
+    type Triple<'x> = NeverTriple;
+
+    fn as_object_proxy(&self) -> ObjectProxy<'_, Self::Triple<'_>> {
+        ObjectProxy::Iri(self.into())
+    }
+}
+
+impl GraphName for SharedIri {
+    fn as_graph_name_proxy(&self) -> GraphNameProxy<'_> {
+        GraphNameProxy::Iri(self.into())
+    }
+}
+
+/// A counterpart of [`LangTag`] backed by an [`Arc<str>`],
+/// so that cloning it (e.g. for sharing it across threads) only increments a reference count.
+///
+/// Like [`LangTag`], it is compared and hashed case-insensitively.
+///
+/// ## Contract
+/// The same contract as [`LangTag`] applies:
+/// the underlying text is assumed to be a valid language tag,
+/// and this is not re-checked on conversion from or to [`LangTag`].
+#[derive(Clone, Debug)]
+pub struct SharedLangTag(Arc<str>);
+
+impl SharedLangTag {
+    /// Return a new [`SharedLangTag`], assuming the argument is a valid language tag.
+    ///
+    /// ## Precondition
+    /// It is the responsibility of the caller to ensure that `txt` is a valid language tag.
+    pub fn new_unchecked(txt: impl Into<Arc<str>>) -> Self {
+        SharedLangTag(txt.into())
+    }
+
+    /// Return the inner [`Arc<str>`].
+    pub fn unwrap(self) -> Arc<str> {
+        self.0
+    }
+
+    /// Borrow this [`SharedLangTag`] as a [`LangTag`].
+    pub fn as_lang_tag(&self) -> LangTag<'_> {
+        LangTag::new_unchecked(&*self.0)
+    }
+}
+
+impl core::ops::Deref for SharedLangTag {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<LangTag<'_>> for SharedLangTag {
+    fn from(value: LangTag<'_>) -> Self {
+        SharedLangTag(value.as_str().into())
+    }
+}
+
+impl<'a> From<&'a SharedLangTag> for LangTag<'a> {
+    fn from(value: &'a SharedLangTag) -> Self {
+        value.as_lang_tag()
+    }
+}
+
+impl core::hash::Hash for SharedLangTag {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_lang_tag().hash(state)
+    }
+}
+
+impl PartialEq for SharedLangTag {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_lang_tag() == other.as_lang_tag()
+    }
+}
+
+impl Eq for SharedLangTag {}
+
+impl Ord for SharedLangTag {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.as_lang_tag().cmp(&other.as_lang_tag())
+    }
+}
+
+impl PartialOrd for SharedLangTag {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl core::fmt::Display for SharedLangTag {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// A counterpart of [`Literal`] backed by [`Arc<str>`]s,
+/// so that cloning it (e.g. for sharing it across threads) only increments reference counts.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum SharedLiteral {
+    /// A literal with a specified datatype.
+    Typed(Arc<str>, SharedIri),
+    /// A [language tagged string](https://www.w3.org/TR/rdf12-concepts/#dfn-language-tagged-string),
+    /// or a [directional language tagged string](https://www.w3.org/TR/rdf12-concepts/#dfn-language-tagged-string),
+    /// depending on the presence of a [`BaseDir`] in the third component.
+    LanguageString(Arc<str>, SharedLangTag, Option<BaseDir>),
+}
+
+impl SharedLiteral {
+    /// Borrow this [`SharedLiteral`] as a [`Literal`].
+    pub fn as_literal(&self) -> Literal<'_> {
+        match self {
+            SharedLiteral::Typed(lex, iri) => Literal::Typed((&**lex).into(), iri.into()),
+            SharedLiteral::LanguageString(lex, tag, dir) => {
+                Literal::LanguageString((&**lex).into(), tag.into(), *dir)
+            }
+        }
+    }
+}
+
+impl From<Literal<'_>> for SharedLiteral {
+    fn from(value: Literal<'_>) -> Self {
+        match value {
+            Literal::Typed(lex, iri) => SharedLiteral::Typed(lex.as_ref().into(), iri.into()),
+            Literal::LanguageString(lex, tag, dir) => {
+                SharedLiteral::LanguageString(lex.as_ref().into(), tag.into(), dir)
+            }
+        }
+    }
+}
+
+impl<'a> From<&'a SharedLiteral> for Literal<'a> {
+    fn from(value: &'a SharedLiteral) -> Self {
+        value.as_literal()
+    }
+}
+
+impl Object for SharedLiteral {
+    type Triple<'x> = NeverTriple;
+
+    fn as_object_proxy(&self) -> ObjectProxy<'_, Self::Triple<'_>> {
+        ObjectProxy::Literal(self.as_literal())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn shared_iri() {
+        let iri = Iri::new_unchecked("https://example.org/ns/p");
+        let shared = SharedIri::from(iri.clone());
+        let clone = shared.clone();
+        assert!(core::ptr::eq(&*shared, &*clone));
+        assert_eq!(Predicate::as_iri(&shared), iri);
+        assert!(matches!(shared.as_object_proxy(), ObjectProxy::Iri(i) if i == iri));
+        assert_eq!(shared.as_subject_proxy(), SubjectProxy::Iri(iri.clone()));
+        assert_eq!(shared.as_graph_name_proxy(), GraphNameProxy::Iri(iri));
+        assert_eq!(shared.to_string(), "<https://example.org/ns/p>");
+    }
+
+    #[test]
+    fn shared_lang_tag() {
+        let shared = SharedLangTag::from(LangTag::new_unchecked("en-GB"));
+        assert_eq!(shared, SharedLangTag::new_unchecked("en-gb"));
+        assert_eq!(LangTag::from(&shared), LangTag::new_unchecked("EN-GB"));
+        assert_eq!(&*shared, "en-GB");
+    }
+
+    #[test]
+    fn shared_literal() {
+        let literals = [
+            Literal::Typed("42".into(), crate::ns::xsd::INTEGER),
+            Literal::LanguageString("chat".into(), LangTag::new_unchecked("en"), None),
+            Literal::LanguageString(
+                "chat".into(),
+                LangTag::new_unchecked("en"),
+                Some(BaseDir::Rtl),
+            ),
+        ];
+        for literal in literals {
+            let shared = SharedLiteral::from(literal.clone());
+            assert_eq!(shared.as_literal(), literal);
+            assert_eq!(shared.clone(), shared);
+            assert!(matches!(shared.as_object_proxy(), ObjectProxy::Literal(l) if l == literal));
+        }
+    }
+
+    #[test]
+    fn thread_safe() {
+        let shared = SharedLiteral::from(Literal::Typed("⛄".into(), crate::ns::xsd::STRING));
+        let handle = {
+            let shared = shared.clone();
+            std::thread::spawn(move || shared.as_literal().lexical_form().into_owned())
+        };
+        assert_eq!(handle.join().unwrap(), "⛄");
+    }
+}
@@ -0,0 +1,112 @@
+use alloc::{borrow::Cow, boxed::Box};
+
+use crate::{
+    _simple_triple::{owned_iri, owned_object_proxy_with},
+    FromQuad, GraphName, GraphNameProxy, Iri, Literal, Object, ObjectProxy, Predicate, Quad,
+    SimpleTriple, Subject, SubjectProxy,
+};
+
+/// A utility type implementing [`Quad`], made of the proxies of its four terms.
+///
+/// It can be used to store a copy of any other [`Quad`] (see [`SimpleQuad::from_quad`]),
+/// or as a straightforward implementation of [`Quad`] (e.g. for testing or prototyping).
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct SimpleQuad<'a> {
+    /// The [subject](https://www.w3.org/TR/rdf12-concepts/#dfn-subject) of this quad
+    pub subject: SubjectProxy<'a>,
+    /// The [predicate](https://www.w3.org/TR/rdf12-concepts/#dfn-predicate) of this quad
+    pub predicate: Iri<'a>,
+    /// The [object](https://www.w3.org/TR/rdf12-concepts/#dfn-object) of this quad
+    pub object: ObjectProxy<'a, Box<SimpleTriple<'a>>>,
+    /// The [graph name](https://www.w3.org/TR/rdf12-concepts/#dfn-graph-name) of this quad,
+    /// or `None` if it belongs to the [default graph](https://www.w3.org/TR/rdf12-concepts/#dfn-default-graph)
+    pub graph_name: Option<GraphNameProxy<'a>>,
+}
+
+impl SimpleQuad<'static> {
+    /// Build a [`SimpleQuad`] by copying the terms of any [`Quad`].
+    pub fn from_quad<Q: Quad>(quad: Q) -> Self {
+        Self::from_quad_with(quad, &mut |bnid| Cow::Owned(bnid.into_owned()))
+    }
+
+    /// Build a [`SimpleQuad`] by copying the terms of any [`Quad`],
+    /// mapping every blank node identifier (including in nested triple terms) through `bnode`.
+    pub(crate) fn from_quad_with<Q: Quad>(
+        quad: Q,
+        bnode: &mut impl FnMut(Cow<str>) -> Cow<'static, str>,
+    ) -> Self {
+        let subject = match quad.subject().as_subject_proxy() {
+            SubjectProxy::Iri(iri) => SubjectProxy::Iri(owned_iri(iri)),
+            SubjectProxy::BlankNode(bnid) => SubjectProxy::BlankNode(bnode(bnid)),
+        };
+        let predicate = owned_iri(quad.predicate().as_iri());
+        let object = owned_object_proxy_with(quad.object().as_object_proxy(), bnode);
+        let graph_name = quad.graph_name().map(|gn| match gn.as_graph_name_proxy() {
+            GraphNameProxy::Iri(iri) => GraphNameProxy::Iri(owned_iri(iri)),
+            GraphNameProxy::BlankNode(bnid) => GraphNameProxy::BlankNode(bnode(bnid)),
+        });
+        SimpleQuad {
+            subject,
+            predicate,
+            object,
+            graph_name,
+        }
+    }
+}
+
+impl FromQuad for SimpleQuad<'static> {
+    type Error = core::convert::Infallible;
+
+    fn from_quad<Q: Quad>(quad: &Q) -> Result<Self, Self::Error> {
+        Ok(SimpleQuad::from_quad(quad))
+    }
+}
+
+impl<'a> Quad for SimpleQuad<'a> {
+    type Subject<'x>
+        = &'x SubjectProxy<'a>
+    where
+        Self: 'x;
+
+    type Predicate<'x>
+        = &'x Iri<'a>
+    where
+        Self: 'x;
+
+    type Object<'x>
+        = &'x ObjectProxy<'a, Box<SimpleTriple<'a>>>
+    where
+        Self: 'x;
+
+    type GraphName<'x>
+        = &'x GraphNameProxy<'a>
+    where
+        Self: 'x;
+
+    fn subject(&self) -> Self::Subject<'_> {
+        &self.subject
+    }
+
+    fn predicate(&self) -> Self::Predicate<'_> {
+        &self.predicate
+    }
+
+    fn object(&self) -> Self::Object<'_> {
+        &self.object
+    }
+
+    fn object_literal(&self) -> Option<Literal<'_>> {
+        match &self.object {
+            ObjectProxy::Literal(literal) => Some(literal.borrowed()),
+            _ => None,
+        }
+    }
+
+    fn graph_name(&self) -> Option<Self::GraphName<'_>> {
+        self.graph_name.as_ref()
+    }
+
+    fn graph_name_proxy(&self) -> Option<GraphNameProxy<'_>> {
+        self.graph_name.as_ref().map(|gn| gn.as_graph_name_proxy())
+    }
+}
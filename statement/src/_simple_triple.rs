@@ -0,0 +1,329 @@
+use alloc::{borrow::Cow, boxed::Box};
+
+use crate::{
+    FromTriple, Iri, Literal, Object, ObjectProxy, Predicate, Subject, SubjectProxy, Triple,
+};
+
+/// A utility type implementing [`Triple`], made of the proxies of its three terms.
+///
+/// It can be used to store a copy of any other [`Triple`] (see [`SimpleTriple::from_triple`]),
+/// or as a straightforward implementation of [`Triple`] (e.g. for testing or prototyping).
+///
+/// Its implementations of [`Triple::ground`] and [`Triple::triple_term_depth`] are iterative,
+/// so they support arbitrarily deeply nested triple terms.
+/// Note however that the derived implementations of [`Clone`], [`Debug`], [`Eq`] and [`Hash`],
+/// as well as dropping, recurse through nested triple terms, so they may overflow the stack on (maliciously) deeply nested ones
+/// (i.e. thousands of levels). When building such values from untrusted input is a concern,
+/// limit the nesting depth when parsing (see for example `SERDE_MAX_DEPTH`),
+/// or dismantle the triple iteratively before dropping it:
+///
+/// ```
+/// # use r2c2_statement::*;
+/// # let mut t = triple!(_:a, <"https://example.org/p">, <<( _:b, <"https://example.org/p">, _:c )>>);
+/// while let ObjectProxy::Triple(inner) = t.object {
+///     t = *inner;
+/// }
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct SimpleTriple<'a> {
+    /// The [subject](https://www.w3.org/TR/rdf12-concepts/#dfn-subject) of this triple
+    pub subject: SubjectProxy<'a>,
+    /// The [predicate](https://www.w3.org/TR/rdf12-concepts/#dfn-predicate) of this triple
+    pub predicate: Iri<'a>,
+    /// The [object](https://www.w3.org/TR/rdf12-concepts/#dfn-object) of this triple
+    pub object: ObjectProxy<'a, Box<SimpleTriple<'a>>>,
+}
+
+impl SimpleTriple<'static> {
+    /// Build a [`SimpleTriple`] by copying the terms of any [`Triple`].
+    pub fn from_triple<T: Triple>(triple: T) -> Self {
+        Self::from_triple_with(triple, &mut |bnid| Cow::Owned(bnid.into_owned()))
+    }
+
+    /// Build a [`SimpleTriple`] by copying the terms of any [`Triple`],
+    /// mapping every blank node identifier (including in nested triple terms) through `bnode`.
+    pub(crate) fn from_triple_with<T: Triple>(
+        triple: T,
+        bnode: &mut impl FnMut(Cow<str>) -> Cow<'static, str>,
+    ) -> Self {
+        let subject = match triple.subject().as_subject_proxy() {
+            SubjectProxy::Iri(iri) => SubjectProxy::Iri(owned_iri(iri)),
+            SubjectProxy::BlankNode(bnid) => SubjectProxy::BlankNode(bnode(bnid)),
+        };
+        let predicate = owned_iri(triple.predicate().as_iri());
+        let object = owned_object_proxy_with(triple.object().as_object_proxy(), bnode);
+        SimpleTriple {
+            subject,
+            predicate,
+            object,
+        }
+    }
+}
+
+impl FromTriple for SimpleTriple<'static> {
+    type Error = core::convert::Infallible;
+
+    fn from_triple<T: Triple>(triple: &T) -> Result<Self, Self::Error> {
+        Ok(SimpleTriple::from_triple(triple))
+    }
+}
+
+impl<'a> SimpleTriple<'a> {
+    /// Iterate over the [triple terms] nested in this triple,
+    /// from the outermost (the object of this triple) to the innermost.
+    ///
+    /// The iteration is not recursive,
+    /// so it can safely be used on arbitrarily deeply nested triple terms.
+    /// See [`Triple::for_each_nested_triple`] for a counterpart for any [`Triple`].
+    ///
+    /// ```
+    /// # use r2c2_statement::*;
+    /// const EX: &str = "https://example.org/ns/";
+    /// let t = triple!(EX:a, EX:says, <<( EX:b, EX:says, <<( EX:c, EX:p, EX:o )>> )>>);
+    /// let subjects: Vec<_> = t.nested_triples().map(|t| t.subject.clone()).collect();
+    /// assert_eq!(subjects, vec![
+    ///     SubjectProxy::Iri(Iri::new_unchecked("https://example.org/ns/b")),
+    ///     SubjectProxy::Iri(Iri::new_unchecked("https://example.org/ns/c")),
+    /// ]);
+    /// ```
+    ///
+    /// [triple terms]: https://www.w3.org/TR/rdf12-concepts/#dfn-triple-term
+    pub fn nested_triples(&self) -> NestedTriples<'_, 'a> {
+        NestedTriples(Some(self))
+    }
+}
+
+impl<'a> Triple for SimpleTriple<'a> {
+    type Subject<'x>
+        = &'x SubjectProxy<'a>
+    where
+        Self: 'x;
+
+    type Predicate<'x>
+        = &'x Iri<'a>
+    where
+        Self: 'x;
+
+    type Object<'x>
+        = &'x ObjectProxy<'a, Box<SimpleTriple<'a>>>
+    where
+        Self: 'x;
+
+    fn subject(&self) -> Self::Subject<'_> {
+        &self.subject
+    }
+
+    fn predicate(&self) -> Self::Predicate<'_> {
+        &self.predicate
+    }
+
+    fn object(&self) -> Self::Object<'_> {
+        &self.object
+    }
+
+    fn object_literal(&self) -> Option<Literal<'_>> {
+        match &self.object {
+            ObjectProxy::Literal(literal) => Some(literal.borrowed()),
+            _ => None,
+        }
+    }
+
+    fn ground(&self) -> bool {
+        let mut triple = self;
+        loop {
+            if matches!(triple.subject, SubjectProxy::BlankNode(_)) {
+                return false;
+            }
+            match &triple.object {
+                ObjectProxy::Triple(inner) => triple = inner,
+                ObjectProxy::BlankNode(_) => return false,
+                _ => return true,
+            }
+        }
+    }
+
+    fn triple_term_depth(&self) -> usize {
+        self.nested_triples().count()
+    }
+
+    fn for_each_nested_triple(&self, mut f: impl FnMut(&dyn crate::DynTriple)) {
+        self.nested_triples().for_each(|t| f(t))
+    }
+}
+
+/// The iterator returned by [`SimpleTriple::nested_triples`].
+///
+/// Triple terms can only appear in the object position,
+/// so the nested triple terms of a triple form a chain rather than a tree,
+/// and no stack is needed to walk them.
+#[derive(Clone, Debug)]
+pub struct NestedTriples<'b, 'a>(Option<&'b SimpleTriple<'a>>);
+
+impl<'b, 'a> Iterator for NestedTriples<'b, 'a> {
+    type Item = &'b SimpleTriple<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &self.0?.object {
+            ObjectProxy::Triple(triple) => {
+                self.0 = Some(triple);
+                self.0
+            }
+            _ => {
+                self.0 = None;
+                None
+            }
+        }
+    }
+}
+
+impl core::iter::FusedIterator for NestedTriples<'_, '_> {}
+
+// utility functions
+
+pub(crate) fn owned_iri(iri: Iri) -> Iri<'static> {
+    iri.into_owned()
+}
+
+pub(crate) fn owned_literal(literal: Literal) -> Literal<'static> {
+    match literal {
+        Literal::Typed(lex, iri) => Literal::Typed(Cow::Owned(lex.into_owned()), owned_iri(iri)),
+        Literal::LanguageString(lex, tag, dir) => {
+            Literal::LanguageString(Cow::Owned(lex.into_owned()), tag.into_owned(), dir)
+        }
+    }
+}
+
+pub(crate) fn owned_object_proxy_with<T: Triple>(
+    object: ObjectProxy<T>,
+    bnode: &mut impl FnMut(Cow<str>) -> Cow<'static, str>,
+) -> ObjectProxy<'static, Box<SimpleTriple<'static>>> {
+    match object {
+        ObjectProxy::Iri(iri) => ObjectProxy::Iri(owned_iri(iri)),
+        ObjectProxy::BlankNode(bnid) => ObjectProxy::BlankNode(bnode(bnid)),
+        ObjectProxy::Literal(literal) => ObjectProxy::Literal(owned_literal(literal)),
+        ObjectProxy::Triple(triple) => {
+            ObjectProxy::Triple(Box::new(SimpleTriple::from_triple_with(triple, bnode)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn from_triple() {
+        let t1 = crate::triple!(_:b1, <"https://example.org/ns/p">, <<(
+            <"https://example.org/ns/s">,
+            <"https://example.org/ns/p">,
+            _:b2
+        )>>);
+        let t2 = SimpleTriple::from_triple(&t1);
+        assert_eq!(t1, t2);
+        assert!(t2.subject().as_subject_proxy() == t1.subject);
+        assert!(Predicate::as_iri(&t2.predicate()) == t1.predicate);
+        assert!(matches!(
+            t2.object().as_object_proxy(),
+            ObjectProxy::Triple(_)
+        ));
+    }
+
+    #[test]
+    fn nested_triples() {
+        const EX: &str = "https://example.org/ns/";
+        let flat = crate::triple!(EX:s, EX:p, "o");
+        assert!(!flat.contains_triple_term());
+        assert_eq!(flat.triple_term_depth(), 0);
+        assert_eq!(flat.nested_triples().count(), 0);
+
+        let t =
+            crate::triple!(_:a, EX:p, <<( _:b, EX:p, <<( _:c, EX:p, <<( _:d, EX:p, "o" )>> )>> )>>);
+        assert!(t.contains_triple_term());
+        assert_eq!(t.triple_term_depth(), 3);
+        // the default (recursive) implementation agrees with the iterative one
+        assert_eq!((&&t).triple_term_depth(), 3);
+        assert_eq!(t.object.triple_term_depth(), 3);
+        let subjects: Vec<_> = t.nested_triples().map(|nt| nt.subject.clone()).collect();
+        assert_eq!(
+            subjects,
+            ["b", "c", "d"].map(|b| SubjectProxy::BlankNode(b.into()))
+        );
+        assert!(
+            t.nested_triples()
+                .last()
+                .is_some_and(|nt| !nt.contains_triple_term())
+        );
+        let mut walked = vec![];
+        t.for_each_nested_triple(|nt| walked.push(nt.subject().as_subject_proxy().into_owned()));
+        assert_eq!(walked, subjects);
+        let mut walked = 0;
+        flat.for_each_nested_triple(|_| walked += 1);
+        assert_eq!(walked, 0);
+    }
+
+    #[test]
+    fn ground() {
+        const EX: &str = "https://example.org/ns/";
+        for (t, expected) in [
+            (crate::triple!(EX:s, EX:p, "o"), true),
+            (crate::triple!(_:s, EX:p, "o"), false),
+            (crate::triple!(EX:s, EX:p, _:o), false),
+            (
+                crate::triple!(EX:s, EX:p, <<( EX:s, EX:p, <<( EX:s, EX:p, EX:o )>> )>>),
+                true,
+            ),
+            (
+                crate::triple!(EX:s, EX:p, <<( _:s, EX:p, <<( EX:s, EX:p, EX:o )>> )>>),
+                false,
+            ),
+            (
+                crate::triple!(EX:s, EX:p, <<( EX:s, EX:p, <<( EX:s, EX:p, _:o )>> )>>),
+                false,
+            ),
+        ] {
+            assert_eq!(t.ground(), expected, "{t:?}");
+            assert_eq!(
+                Object::ground(&ObjectProxy::<SimpleTriple>::Triple(t.clone())),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn deeply_nested() {
+        const DEPTH: usize = 100_000;
+        let s = || SubjectProxy::Iri(Iri::new_unchecked("https://example.org/ns/s"));
+        let p = || Iri::new_unchecked("https://example.org/ns/p");
+        for (innermost, expected) in [
+            (
+                ObjectProxy::Literal(Literal::Typed("o".into(), crate::ns::xsd::STRING)),
+                true,
+            ),
+            (ObjectProxy::BlankNode("o".into()), false),
+        ] {
+            let mut t = SimpleTriple {
+                subject: s(),
+                predicate: p(),
+                object: innermost,
+            };
+            for _ in 0..DEPTH {
+                t = SimpleTriple {
+                    subject: s(),
+                    predicate: p(),
+                    object: ObjectProxy::Triple(Box::new(t)),
+                };
+            }
+            assert_eq!(t.ground(), expected);
+            assert_eq!(Triple::ground(&&t), expected);
+            assert_eq!(Triple::ground(&Box::new(&t)), expected);
+            assert_eq!(t.object.ground(), expected);
+            assert_eq!(crate::Term::ground(&t.object), expected);
+            assert_eq!(t.triple_term_depth(), DEPTH);
+            // dropping is recursive (see the documentation of SimpleTriple),
+            // so the triple is dismantled iteratively
+            while let ObjectProxy::Triple(inner) = t.object {
+                t = *inner;
+            }
+        }
+    }
+}
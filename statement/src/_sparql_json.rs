@@ -0,0 +1,376 @@
+use alloc::{borrow::Cow, boxed::Box, string::String};
+
+use serde_json::{Map, Value, json};
+
+use crate::{
+    BaseDir, Iri, LangTag, Literal, Object, ObjectProxy, Predicate, SimpleTriple, Subject,
+    SubjectProxy, Triple,
+    ns::{rdf, xsd},
+};
+
+/// Represent `o` as in the [SPARQL 1.2 Query Results JSON Format].
+///
+/// * IRIs are represented as `{"type": "uri", "value": ".."}`,
+/// * blank nodes as `{"type": "bnode", "value": ".."}` (where the value is the blank node identifier),
+/// * literals as `{"type": "literal", "value": ".."}`,
+///   with an additional `datatype` key (unless the datatype is `xsd:string`),
+///   or an additional `xml:lang` key (and `its:dir` key, if it has a base direction),
+/// * triple terms as `{"type": "triple", "value": {"subject": .., "predicate": .., "object": ..}}`.
+///
+/// ```
+/// # use r2c2_statement::*;
+/// # use serde_json::json;
+/// const EX: &str = "https://example.org/ns/";
+/// let t = triple!(EX:s, EX:p, "chat"@fr);
+/// assert_eq!(
+///     term_to_sparql_json(&t.object),
+///     json!({"type": "literal", "value": "chat", "xml:lang": "fr"}),
+/// );
+/// assert_eq!(term_from_sparql_json(&term_to_sparql_json(&t.object)).unwrap(), t.object);
+/// ```
+///
+/// [SPARQL 1.2 Query Results JSON Format]: https://www.w3.org/TR/sparql12-results-json/
+pub fn term_to_sparql_json<O: Object + ?Sized>(o: &O) -> Value {
+    match o.as_object_proxy() {
+        ObjectProxy::Iri(iri) => node("uri", iri.as_str()),
+        ObjectProxy::BlankNode(bnid) => node("bnode", &bnid),
+        ObjectProxy::Literal(Literal::Typed(lex, dt)) if dt == xsd::STRING => {
+            json!({"type": "literal", "value": lex})
+        }
+        ObjectProxy::Literal(Literal::Typed(lex, dt)) => {
+            json!({"type": "literal", "value": lex, "datatype": dt.as_str()})
+        }
+        ObjectProxy::Literal(Literal::LanguageString(lex, tag, None)) => {
+            json!({"type": "literal", "value": lex, "xml:lang": tag.as_str()})
+        }
+        ObjectProxy::Literal(Literal::LanguageString(lex, tag, Some(dir))) => {
+            let dir = match dir {
+                BaseDir::Ltr => "ltr",
+                BaseDir::Rtl => "rtl",
+            };
+            json!({"type": "literal", "value": lex, "xml:lang": tag.as_str(), "its:dir": dir})
+        }
+        ObjectProxy::Triple(triple) => {
+            let (s, p, o) = triple.spo();
+            let subject = match s.as_subject_proxy() {
+                SubjectProxy::Iri(iri) => node("uri", iri.as_str()),
+                SubjectProxy::BlankNode(bnid) => node("bnode", &bnid),
+            };
+            json!({
+                "type": "triple",
+                "value": {
+                    "subject": subject,
+                    "predicate": node("uri", p.as_iri().as_str()),
+                    "object": term_to_sparql_json(&o),
+                },
+            })
+        }
+    }
+}
+
+fn node(kind: &str, value: &str) -> Value {
+    json!({"type": kind, "value": value})
+}
+
+/// Parse a term represented as in the [SPARQL 1.2 Query Results JSON Format]
+/// (see [`term_to_sparql_json`]).
+///
+/// All texts are borrowed from `value`.
+/// Besides the `literal` type, the deprecated `typed-literal` type is also accepted.
+/// Literals may also specify their datatype explicitly when it is `xsd:string`,
+/// `rdf:langString` (together with `xml:lang`) or `rdf:dirLangString` (together with `xml:lang` and `its:dir`).
+/// Any other key is rejected.
+///
+/// IRIs, blank node identifiers and language tags are *not* validated
+/// (see [`Iri::new_unchecked`] and [`LangTag::new_unchecked`]);
+/// use the validating constructors of `r2c2_statement_validation` (e.g. `ObjectProxyValidation`)
+/// when the input can not be trusted.
+///
+/// [SPARQL 1.2 Query Results JSON Format]: https://www.w3.org/TR/sparql12-results-json/
+pub fn term_from_sparql_json(
+    value: &Value,
+) -> Result<ObjectProxy<'_, Box<SimpleTriple<'_>>>, SparqlJsonError> {
+    let map = value.as_object().ok_or(SparqlJsonError::NotAnObject)?;
+    let kind = get_str(map, "type")?.ok_or(SparqlJsonError::MissingKey("type"))?;
+    match kind {
+        "uri" | "bnode" => {
+            check_keys(map, &["type", "value"])?;
+            let value = get_str(map, "value")?.ok_or(SparqlJsonError::MissingKey("value"))?;
+            Ok(if kind == "uri" {
+                ObjectProxy::Iri(Iri::new_unchecked(value))
+            } else {
+                ObjectProxy::BlankNode(Cow::Borrowed(value))
+            })
+        }
+        "literal" | "typed-literal" => {
+            check_keys(map, &["type", "value", "datatype", "xml:lang", "its:dir"])?;
+            let lex = get_str(map, "value")?.ok_or(SparqlJsonError::MissingKey("value"))?;
+            let lex = Cow::Borrowed(lex);
+            let datatype = get_str(map, "datatype")?.map(Iri::new_unchecked);
+            let dir = match get_str(map, "its:dir")? {
+                None => None,
+                Some("ltr") => Some(BaseDir::Ltr),
+                Some("rtl") => Some(BaseDir::Rtl),
+                Some(_) => return Err(SparqlJsonError::InvalidValue("its:dir")),
+            };
+            let literal = match get_str(map, "xml:lang")? {
+                Some(tag) => {
+                    let implied = if dir.is_some() {
+                        rdf::DIR_LANG_STRING
+                    } else {
+                        rdf::LANG_STRING
+                    };
+                    if datatype.is_some_and(|dt| dt != implied) {
+                        return Err(SparqlJsonError::InvalidValue("datatype"));
+                    }
+                    Literal::LanguageString(lex, LangTag::new_unchecked(tag), dir)
+                }
+                None => {
+                    if dir.is_some() {
+                        return Err(SparqlJsonError::MissingKey("xml:lang"));
+                    }
+                    let dt = datatype.unwrap_or(xsd::STRING);
+                    if dt == rdf::LANG_STRING || dt == rdf::DIR_LANG_STRING {
+                        return Err(SparqlJsonError::MissingKey("xml:lang"));
+                    }
+                    Literal::Typed(lex, dt)
+                }
+            };
+            Ok(ObjectProxy::Literal(literal))
+        }
+        "triple" => {
+            check_keys(map, &["type", "value"])?;
+            let value = map
+                .get("value")
+                .ok_or(SparqlJsonError::MissingKey("value"))?;
+            let value = value.as_object().ok_or(SparqlJsonError::NotAnObject)?;
+            check_keys(value, &["subject", "predicate", "object"])?;
+            let get_term = |key: &'static str| {
+                let term = value.get(key).ok_or(SparqlJsonError::MissingKey(key))?;
+                term_from_sparql_json(term)
+            };
+            let subject = match get_term("subject")? {
+                ObjectProxy::Iri(iri) => SubjectProxy::Iri(iri),
+                ObjectProxy::BlankNode(bnid) => SubjectProxy::BlankNode(bnid),
+                _ => return Err(SparqlJsonError::InvalidValue("subject")),
+            };
+            let ObjectProxy::Iri(predicate) = get_term("predicate")? else {
+                return Err(SparqlJsonError::InvalidValue("predicate"));
+            };
+            let object = get_term("object")?;
+            Ok(ObjectProxy::Triple(Box::new(SimpleTriple {
+                subject,
+                predicate,
+                object,
+            })))
+        }
+        _ => Err(SparqlJsonError::UnknownType(kind.into())),
+    }
+}
+
+fn get_str<'a>(
+    map: &'a Map<String, Value>,
+    key: &'static str,
+) -> Result<Option<&'a str>, SparqlJsonError> {
+    map.get(key)
+        .map(|value| value.as_str().ok_or(SparqlJsonError::InvalidValue(key)))
+        .transpose()
+}
+
+fn check_keys(map: &Map<String, Value>, allowed: &[&str]) -> Result<(), SparqlJsonError> {
+    match map.keys().find(|key| !allowed.contains(&key.as_str())) {
+        Some(key) => Err(SparqlJsonError::UnexpectedKey(key.clone())),
+        None => Ok(()),
+    }
+}
+
+/// The error returned by [`term_from_sparql_json`].
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum SparqlJsonError {
+    /// A term, or the value of a triple term, is not a JSON object
+    NotAnObject,
+    /// A required key is missing
+    MissingKey(&'static str),
+    /// A key has an invalid value (including a value of the wrong JSON type)
+    InvalidValue(&'static str),
+    /// A key is not allowed for this type of term
+    UnexpectedKey(String),
+    /// The `type` of a term is unknown
+    UnknownType(String),
+}
+
+impl core::fmt::Display for SparqlJsonError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SparqlJsonError::NotAnObject => write!(f, "expected a JSON object"),
+            SparqlJsonError::MissingKey(key) => write!(f, "missing key `{key}`"),
+            SparqlJsonError::InvalidValue(key) => write!(f, "invalid value for key `{key}`"),
+            SparqlJsonError::UnexpectedKey(key) => write!(f, "unexpected key `{key}`"),
+            SparqlJsonError::UnknownType(kind) => write!(f, "unknown term type `{kind}`"),
+        }
+    }
+}
+
+impl core::error::Error for SparqlJsonError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::triple;
+
+    const EX: &str = "https://example.org/ns/";
+
+    type Obj<'a> = ObjectProxy<'a, Box<SimpleTriple<'a>>>;
+
+    fn round_trip(term: &Obj, expected: Value) {
+        let got = term_to_sparql_json(term);
+        assert_eq!(got, expected);
+        assert_eq!(&term_from_sparql_json(&got).unwrap(), term);
+    }
+
+    #[test]
+    fn round_trips() {
+        let t = triple!(_:b, EX:p, <<( EX:s, EX:p, "chat"@fr )>>);
+        round_trip(
+            &ObjectProxy::Iri(Iri::new_unchecked("https://example.org/")),
+            json!({"type": "uri", "value": "https://example.org/"}),
+        );
+        round_trip(
+            &ObjectProxy::BlankNode("b1".into()),
+            json!({"type": "bnode", "value": "b1"}),
+        );
+        round_trip(
+            &ObjectProxy::Literal(Literal::Typed("a".into(), xsd::STRING)),
+            json!({"type": "literal", "value": "a"}),
+        );
+        round_trip(
+            &ObjectProxy::Literal(Literal::Typed("42".into(), xsd::INTEGER)),
+            json!({"type": "literal", "value": "42", "datatype": xsd::INTEGER.as_str()}),
+        );
+        round_trip(
+            &ObjectProxy::Literal(Literal::LanguageString(
+                "chat".into(),
+                LangTag::new_unchecked("fr"),
+                None,
+            )),
+            json!({"type": "literal", "value": "chat", "xml:lang": "fr"}),
+        );
+        round_trip(
+            &ObjectProxy::Literal(Literal::LanguageString(
+                "chat".into(),
+                LangTag::new_unchecked("ar"),
+                Some(BaseDir::Rtl),
+            )),
+            json!({"type": "literal", "value": "chat", "xml:lang": "ar", "its:dir": "rtl"}),
+        );
+        let expected = json!({
+            "type": "triple",
+            "value": {
+                "subject": {"type": "bnode", "value": "b"},
+                "predicate": {"type": "uri", "value": "https://example.org/ns/p"},
+                "object": {
+                    "type": "triple",
+                    "value": {
+                        "subject": {"type": "uri", "value": "https://example.org/ns/s"},
+                        "predicate": {"type": "uri", "value": "https://example.org/ns/p"},
+                        "object": {"type": "literal", "value": "chat", "xml:lang": "fr"},
+                    },
+                },
+            },
+        });
+        round_trip(&ObjectProxy::Triple(Box::new(t)), expected);
+    }
+
+    #[test]
+    fn lenient() {
+        for (input, expected) in [
+            (
+                json!({"type": "typed-literal", "value": "42", "datatype": xsd::INTEGER.as_str()}),
+                Literal::Typed("42".into(), xsd::INTEGER),
+            ),
+            (
+                json!({"type": "literal", "value": "a", "datatype": xsd::STRING.as_str()}),
+                Literal::Typed("a".into(), xsd::STRING),
+            ),
+            (
+                json!({"type": "literal", "value": "a", "xml:lang": "en", "datatype": rdf::LANG_STRING.as_str()}),
+                Literal::LanguageString("a".into(), LangTag::new_unchecked("en"), None),
+            ),
+            (
+                json!({"type": "literal", "value": "a", "xml:lang": "en", "its:dir": "ltr", "datatype": rdf::DIR_LANG_STRING.as_str()}),
+                Literal::LanguageString(
+                    "a".into(),
+                    LangTag::new_unchecked("en"),
+                    Some(BaseDir::Ltr),
+                ),
+            ),
+        ] {
+            let got = term_from_sparql_json(&input).unwrap();
+            assert_eq!(got, Obj::Literal(expected), "{input}");
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        use SparqlJsonError::*;
+        let literal = json!({"type": "literal", "value": "a"});
+        for (input, expected) in [
+            (json!("https://example.org/"), NotAnObject),
+            (json!({"value": "a"}), MissingKey("type")),
+            (json!({"type": 42, "value": "a"}), InvalidValue("type")),
+            (
+                json!({"type": "iri", "value": "a"}),
+                UnknownType("iri".into()),
+            ),
+            (json!({"type": "uri"}), MissingKey("value")),
+            (json!({"type": "uri", "value": 42}), InvalidValue("value")),
+            (
+                json!({"type": "bnode", "value": "b", "xml:lang": "en"}),
+                UnexpectedKey("xml:lang".into()),
+            ),
+            (
+                json!({"type": "literal", "value": "a", "foo": "bar"}),
+                UnexpectedKey("foo".into()),
+            ),
+            (
+                json!({"type": "literal", "value": "a", "xml:lang": "en", "its:dir": "up"}),
+                InvalidValue("its:dir"),
+            ),
+            (
+                json!({"type": "literal", "value": "a", "its:dir": "ltr"}),
+                MissingKey("xml:lang"),
+            ),
+            (
+                json!({"type": "literal", "value": "a", "datatype": rdf::LANG_STRING.as_str()}),
+                MissingKey("xml:lang"),
+            ),
+            (
+                json!({"type": "literal", "value": "a", "xml:lang": "en", "datatype": xsd::STRING.as_str()}),
+                InvalidValue("datatype"),
+            ),
+            (
+                json!({"type": "literal", "value": "a", "xml:lang": "en", "datatype": rdf::DIR_LANG_STRING.as_str()}),
+                InvalidValue("datatype"),
+            ),
+            (json!({"type": "triple", "value": "a"}), NotAnObject),
+            (
+                json!({"type": "triple", "value": {"predicate": literal, "object": literal}}),
+                MissingKey("subject"),
+            ),
+            (
+                json!({"type": "triple", "value": {"subject": literal, "predicate": literal, "object": literal}}),
+                InvalidValue("subject"),
+            ),
+            (
+                json!({"type": "triple", "value": {"subject": {"type": "bnode", "value": "b"}, "predicate": literal, "object": literal}}),
+                InvalidValue("predicate"),
+            ),
+            (
+                json!({"type": "triple", "value": {}, "subject": literal}),
+                UnexpectedKey("subject".into()),
+            ),
+        ] {
+            assert_eq!(term_from_sparql_json(&input), Err(expected), "{input}");
+        }
+    }
+}
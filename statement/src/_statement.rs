@@ -0,0 +1,172 @@
+use alloc::{borrow::Cow, boxed::Box};
+
+use crate::{
+    _simple_triple::owned_object_proxy_with, GraphName, NeverTriple, Object, ObjectProxy,
+    Predicate, Quad, SimpleTriple, Subject, TermProxy, Triple,
+};
+
+/// A sealed trait abstracting over [`Triple`]s and [`Quad`]s,
+/// for code (e.g. a serializer) that handles both the same way, position by position.
+///
+/// It is implemented for every [`Triple`] (with `K` = [`TripleStatement`])
+/// and every [`Quad`] (with `K` = [`QuadStatement`]).
+/// The type parameter `K` is only there to keep the two implementations apart,
+/// and is inferred in generic code, as long as a type does not implement both [`Triple`] and [`Quad`].
+///
+/// Terms are indexed in the order subject (0), predicate (1), object (2)
+/// and, for quads, graph name (3).
+/// Consistently with [`Quad::graph_name`], the latter is `None` for quads in the [default graph],
+/// so a quad in the default graph can be handled like a triple.
+///
+/// ```
+/// # use r2c2_statement::*;
+/// fn write<S: Statement<K>, K>(statement: &S) -> String {
+///     let mut out = String::new();
+///     for i in 0..S::STATEMENT_ARITY {
+///         if let Some(term) = statement.nth_term(i) {
+///             out.push_str(&term.to_string());
+///             out.push(' ');
+///         }
+///     }
+///     out.push('.');
+///     out
+/// }
+///
+/// assert_eq!(
+///     write(&triple!(_:s, <"https://example.org/p">, "o")),
+///     r#"_:s <https://example.org/p> "o" ."#
+/// );
+/// assert_eq!(
+///     write(&quad!(_:s, <"https://example.org/p">, "o", _:g)),
+///     r#"_:s <https://example.org/p> "o" _:g ."#
+/// );
+/// assert_eq!(
+///     write(&quad!(_:s, <"https://example.org/p">, "o")),
+///     r#"_:s <https://example.org/p> "o" ."#
+/// );
+/// ```
+///
+/// [default graph]: https://www.w3.org/TR/rdf12-concepts/#dfn-default-graph
+pub trait Statement<K>: sealed::Sealed<K> {
+    /// The number of positions of this kind of statement: 3 for triples, 4 for quads.
+    const STATEMENT_ARITY: usize;
+
+    /// Return the term at position `i` of this statement,
+    /// or `None` if `i` is not lower than [`Statement::STATEMENT_ARITY`],
+    /// or if `i` is 3 and this is a quad in the default graph.
+    ///
+    /// As the proxies of a statement borrow the values returned by its accessors
+    /// (see [`SpoProxies`](crate::SpoProxies)), the term is returned as an owned copy.
+    fn nth_term(&self, i: usize) -> Option<TermProxy<'static, Box<SimpleTriple<'static>>>>;
+}
+
+/// The marker type for the implementation of [`Statement`] for [`Triple`]s.
+#[derive(Clone, Copy, Debug)]
+pub enum TripleStatement {}
+
+/// The marker type for the implementation of [`Statement`] for [`Quad`]s.
+#[derive(Clone, Copy, Debug)]
+pub enum QuadStatement {}
+
+impl<T: Triple + ?Sized> sealed::Sealed<TripleStatement> for T {}
+
+impl<T: Triple + ?Sized> Statement<TripleStatement> for T {
+    const STATEMENT_ARITY: usize = 3;
+
+    fn nth_term(&self, i: usize) -> Option<TermProxy<'static, Box<SimpleTriple<'static>>>> {
+        match i {
+            0 => Some(owned_term(TermProxy::<NeverTriple>::from(
+                self.subject().as_subject_proxy(),
+            ))),
+            1 => Some(owned_term(TermProxy::<NeverTriple>::from(
+                self.predicate().as_iri(),
+            ))),
+            2 => Some(owned_term(self.object().as_object_proxy())),
+            _ => None,
+        }
+    }
+}
+
+impl<Q: Quad + ?Sized> sealed::Sealed<QuadStatement> for Q {}
+
+impl<Q: Quad + ?Sized> Statement<QuadStatement> for Q {
+    const STATEMENT_ARITY: usize = 4;
+
+    fn nth_term(&self, i: usize) -> Option<TermProxy<'static, Box<SimpleTriple<'static>>>> {
+        match i {
+            0 => Some(owned_term(TermProxy::<NeverTriple>::from(
+                self.subject().as_subject_proxy(),
+            ))),
+            1 => Some(owned_term(TermProxy::<NeverTriple>::from(
+                self.predicate().as_iri(),
+            ))),
+            2 => Some(owned_term(self.object().as_object_proxy())),
+            3 => self
+                .graph_name()
+                .map(|g| owned_term(TermProxy::<NeverTriple>::from(g.as_graph_name_proxy()))),
+            _ => None,
+        }
+    }
+}
+
+fn owned_term<T: Triple>(term: ObjectProxy<T>) -> TermProxy<'static, Box<SimpleTriple<'static>>> {
+    owned_object_proxy_with(term, &mut |bnid| Cow::Owned(bnid.into_owned()))
+}
+
+mod sealed {
+    pub trait Sealed<K> {}
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{GraphNameProxy, Iri, SubjectProxy, quad, triple};
+
+    const EX: &str = "https://example.org/ns/";
+
+    fn arity<S: Statement<K> + ?Sized, K>(_: &S) -> usize {
+        S::STATEMENT_ARITY
+    }
+
+    #[test]
+    fn triple() {
+        let t = triple!(_:a, EX:p, <<( EX:s, EX:q, "o" )>>);
+        assert_eq!(arity(&t), 3);
+        assert_eq!(
+            t.nth_term(0),
+            Some(SubjectProxy::BlankNode("a".into()).into())
+        );
+        assert_eq!(
+            t.nth_term(1),
+            Some(Iri::new_unchecked("https://example.org/ns/p").into())
+        );
+        assert_eq!(t.nth_term(2), Some(t.object.clone()));
+        assert_eq!(t.nth_term(3), None);
+        assert_eq!(t.nth_term(usize::MAX), None);
+    }
+
+    #[test]
+    fn quad() {
+        let q = quad!(EX:s, EX:p, "o", _:g);
+        assert_eq!(arity(&q), 4);
+        assert_eq!(
+            q.nth_term(0),
+            Some(Iri::new_unchecked("https://example.org/ns/s").into())
+        );
+        assert_eq!(
+            q.nth_term(1),
+            Some(Iri::new_unchecked("https://example.org/ns/p").into())
+        );
+        assert_eq!(q.nth_term(2), Some(q.object.clone()));
+        assert_eq!(
+            q.nth_term(3),
+            Some(GraphNameProxy::BlankNode("g".into()).into())
+        );
+        assert_eq!(q.nth_term(4), None);
+
+        let q = quad!(EX:s, EX:p, "o");
+        assert_eq!(arity(&q), 4);
+        assert!(q.nth_term(2).is_some());
+        assert_eq!(q.nth_term(3), None);
+    }
+}
@@ -1,6 +1,6 @@
-use std::borrow::Cow;
+use alloc::{borrow::Cow, boxed::Box, rc::Rc, sync::Arc};
 
-use crate::Iri;
+use crate::{_simple_triple::owned_iri, BnodeId, Iri, TermKind};
 
 /// A trait for [RDF terms] allowed in the [subject] position of an [RDF triple].
 ///
@@ -16,7 +16,7 @@ pub trait Subject {
     /// # Implementers
     /// A default implementation is provided for this method, based on [`Subject::as_subject_proxy`].
     /// It may be useful to override it, especially for types where the inner values of [`SubjectProxy`]
-    /// are allocated as owned [`Cow<str>`](std::borrow::Cow) rather than borrowed.
+    /// are allocated as owned [`Cow<str>`](alloc::borrow::Cow) rather than borrowed.
     fn subject_kind(&self) -> SubjectKind {
         match self.as_subject_proxy() {
             SubjectProxy::Iri(_) => SubjectKind::Iri,
@@ -24,6 +24,14 @@ pub trait Subject {
         }
     }
 
+    /// Return the [kind](TermKind) of this subject, independently of its position.
+    ///
+    /// # Implementers
+    /// A default implementation is provided for this method, based on [`Subject::subject_kind`].
+    fn term_kind(&self) -> TermKind {
+        self.subject_kind().into()
+    }
+
     /// Whether this subject is [ground](https://https://www.w3.org/TR/rdf12-concepts/#dfn-ground).
     fn ground(&self) -> bool {
         match self.subject_kind() {
@@ -31,6 +39,33 @@ pub trait Subject {
             SubjectKind::BlankNode => false,
         }
     }
+
+    /// Return the [IRI](https://www.w3.org/TR/rdf12-concepts/#section-IRIs) of this subject, if it is one.
+    fn as_iri(&self) -> Option<Iri<'_>> {
+        match self.as_subject_proxy() {
+            SubjectProxy::Iri(iri) => Some(iri),
+            SubjectProxy::BlankNode(_) => None,
+        }
+    }
+
+    /// Return the [blank node identifier](https://www.w3.org/TR/rdf12-concepts/#dfn-blank-node-identifier)
+    /// of this subject, if it is a blank node.
+    fn as_blank_node(&self) -> Option<Cow<'_, str>> {
+        match self.as_subject_proxy() {
+            SubjectProxy::Iri(_) => None,
+            SubjectProxy::BlankNode(bnid) => Some(bnid),
+        }
+    }
+
+    /// Whether this subject is an [IRI](https://www.w3.org/TR/rdf12-concepts/#section-IRIs).
+    fn is_iri(&self) -> bool {
+        self.subject_kind() == SubjectKind::Iri
+    }
+
+    /// Whether this subject is a [blank node](https://www.w3.org/TR/rdf12-concepts/#dfn-blank-node).
+    fn is_blank_node(&self) -> bool {
+        self.subject_kind() == SubjectKind::BlankNode
+    }
 }
 
 /// An enum conveying the inner information of a value implementing [`Subject`].
@@ -49,6 +84,45 @@ pub enum SubjectProxy<'a> {
     BlankNode(Cow<'a, str>),
 }
 
+impl SubjectProxy<'_> {
+    /// Convert this [`SubjectProxy`] into one that owns its data,
+    /// so that it can be stored independently of the value it was obtained from.
+    pub fn into_owned(self) -> SubjectProxy<'static> {
+        match self {
+            SubjectProxy::Iri(iri) => SubjectProxy::Iri(owned_iri(iri)),
+            SubjectProxy::BlankNode(bnid) => SubjectProxy::BlankNode(Cow::Owned(bnid.into_owned())),
+        }
+    }
+
+    /// If this is a blank node, return its identifier as a valid [`BnodeId`]
+    /// (see [`BnodeId::sanitized`]), otherwise None.
+    pub fn as_bnode_id(&self) -> Option<BnodeId<'_>> {
+        match self {
+            SubjectProxy::BlankNode(bnid) => Some(BnodeId::sanitized(bnid)),
+            SubjectProxy::Iri(_) => None,
+        }
+    }
+}
+
+/// Display this subject in [N-Triples] syntax, i.e. `<iri>` or `_:label`,
+/// as written by [`write_ntriple`](crate::write_ntriple).
+///
+/// Blank node labels are written as is, without any escaping,
+/// as they are considered opaque.
+/// The output is therefore only valid N-Triples if the label matches the [`BLANK_NODE_LABEL`] production;
+/// for other labels, this is a best-effort representation.
+///
+/// [N-Triples]: https://www.w3.org/TR/rdf12-n-triples/
+/// [`BLANK_NODE_LABEL`]: https://www.w3.org/TR/rdf12-n-triples/#grammar-production-BLANK_NODE_LABEL
+impl core::fmt::Display for SubjectProxy<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SubjectProxy::Iri(iri) => write!(f, "{iri}"),
+            SubjectProxy::BlankNode(bnid) => write!(f, "_:{bnid}"),
+        }
+    }
+}
+
 /// An enum representing the different kinds of [RDF terms] that can be [subject].
 /// The return type of [`Subject::subject_kind`].
 ///
@@ -73,9 +147,83 @@ impl<T: Subject> Subject for &'_ T {
         (*self).subject_kind()
     }
 
+    fn term_kind(&self) -> TermKind {
+        (*self).term_kind()
+    }
+
     fn ground(&self) -> bool {
         (*self).ground()
     }
+
+    fn as_iri(&self) -> Option<Iri<'_>> {
+        (*self).as_iri()
+    }
+
+    fn as_blank_node(&self) -> Option<Cow<'_, str>> {
+        (*self).as_blank_node()
+    }
+
+    fn is_iri(&self) -> bool {
+        (*self).is_iri()
+    }
+
+    fn is_blank_node(&self) -> bool {
+        (*self).is_blank_node()
+    }
+}
+
+/// Any [`Box`], [`Rc`] or [`Arc`] of a [`Subject`] also trivially implements [`Subject`].
+macro_rules! impl_subject_for_pointer {
+    ($($ptr:ident),*) => {$(
+        impl<T: Subject + ?Sized> Subject for $ptr<T> {
+            fn as_subject_proxy(&self) -> SubjectProxy<'_> {
+                self.as_ref().as_subject_proxy()
+            }
+
+            fn subject_kind(&self) -> SubjectKind {
+                self.as_ref().subject_kind()
+            }
+
+            fn term_kind(&self) -> TermKind {
+                self.as_ref().term_kind()
+            }
+
+            fn ground(&self) -> bool {
+                self.as_ref().ground()
+            }
+
+            fn as_iri(&self) -> Option<Iri<'_>> {
+                self.as_ref().as_iri()
+            }
+
+            fn as_blank_node(&self) -> Option<Cow<'_, str>> {
+                self.as_ref().as_blank_node()
+            }
+
+            fn is_iri(&self) -> bool {
+                self.as_ref().is_iri()
+            }
+
+            fn is_blank_node(&self) -> bool {
+                self.as_ref().is_blank_node()
+            }
+        }
+    )*};
+}
+impl_subject_for_pointer!(Box, Rc, Arc);
+
+/// [`Iri`] implements the trait [`Subject`], always returning the [`SubjectProxy::Iri`] variant
+/// (borrowing the IRI rather than cloning it).
+///
+/// This is mostly useful for testing or prototyping, as [`Iri`] also implements [`Predicate`](crate::Predicate).
+impl Subject for Iri<'_> {
+    fn as_subject_proxy(&self) -> SubjectProxy<'_> {
+        SubjectProxy::Iri(self.borrowed())
+    }
+
+    fn subject_kind(&self) -> SubjectKind {
+        SubjectKind::Iri
+    }
 }
 
 /// [`SubjectProxy`] implements the trait [`Subject`].
@@ -92,3 +240,29 @@ impl Subject for SubjectProxy<'_> {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn display() {
+        let iri = SubjectProxy::Iri(Iri::new_unchecked("https://example.org/ns/s"));
+        assert_eq!(iri.to_string(), "<https://example.org/ns/s>");
+        let bnode = SubjectProxy::BlankNode("b1".into());
+        assert_eq!(bnode.to_string(), "_:b1");
+        // labels that are not valid in N-Triples are written as is
+        let bnode = SubjectProxy::BlankNode("a b>.".into());
+        assert_eq!(bnode.to_string(), "_:a b>.");
+    }
+
+    #[test]
+    fn as_bnode_id() {
+        let iri = SubjectProxy::Iri(Iri::new_unchecked("https://example.org/ns/s"));
+        assert_eq!(iri.as_bnode_id(), None);
+        let bnode = SubjectProxy::BlankNode("b1".into());
+        assert_eq!(bnode.as_bnode_id(), Some(BnodeId::new_unchecked("b1")));
+        let bnode = SubjectProxy::BlankNode("a b>.".into());
+        assert_eq!(bnode.as_bnode_id().unwrap().to_string(), "_:a_20_b_3e__2e_");
+    }
+}
@@ -0,0 +1,750 @@
+use alloc::borrow::Cow;
+
+use crate::{
+    GraphName, GraphNameKind, GraphNameProxy, Iri, Literal, Object, ObjectKind, ObjectProxy,
+    Predicate, SimpleTriple, Subject, SubjectKind, SubjectProxy, Triple,
+};
+
+/// A trait for any [RDF term], whatever the position where it occurs.
+///
+/// This trait is implemented by all types implementing [`Object`]
+/// (since RDF 1.2 allows any kind of term in the object position),
+/// as well as by [`SubjectProxy`], [`GraphNameProxy`], [`Iri`] and [`Literal`],
+/// so that generic code can handle the terms of every position uniformly.
+///
+/// ```
+/// # use r2c2_statement::*;
+/// fn describe<T: Term>(term: &T) -> &'static str {
+///     match term.term_kind() {
+///         TermKind::Iri => "IRI",
+///         TermKind::BlankNode => "blank node",
+///         TermKind::Literal => "literal",
+///         TermKind::Triple => "triple term",
+///     }
+/// }
+/// let t = triple!(_:a, <"https://example.org/ns/p">, "chat");
+/// assert_eq!(describe(&t.subject), "blank node");
+/// assert_eq!(describe(&t.predicate), "IRI");
+/// assert_eq!(describe(&t.object), "literal");
+/// ```
+///
+/// [RDF term]: https://www.w3.org/TR/rdf12-concepts/#dfn-rdf-term
+pub trait Term {
+    /// The type representing [triple terms] for this implementation of [`Term`]
+    ///
+    /// [triple terms]: https://www.w3.org/TR/rdf12-concepts/#dfn-triple-term
+    type Triple<'x>: Triple
+    where
+        Self: 'x;
+
+    /// Return a [`TermProxy`] representing this term.
+    fn as_term_proxy(&self) -> TermProxy<'_, Self::Triple<'_>>;
+
+    /// Return the [kind](TermKind) of this term.
+    ///
+    /// # Implementers
+    /// A default implementation is provided for this method, based on [`Term::as_term_proxy`].
+    fn term_kind(&self) -> TermKind {
+        match self.as_term_proxy() {
+            TermProxy::Iri(_) => TermKind::Iri,
+            TermProxy::BlankNode(_) => TermKind::BlankNode,
+            TermProxy::Literal(_) => TermKind::Literal,
+            TermProxy::Triple(_) => TermKind::Triple,
+        }
+    }
+
+    /// Whether this term is [ground](https://https://www.w3.org/TR/rdf12-concepts/#dfn-ground).
+    fn ground(&self) -> bool {
+        match self.as_term_proxy() {
+            TermProxy::Iri(_) | TermProxy::Literal(_) => true,
+            TermProxy::BlankNode(_) => false,
+            TermProxy::Triple(triple) => triple.ground(),
+        }
+    }
+}
+
+impl<O: Object> Term for O {
+    type Triple<'x>
+        = O::Triple<'x>
+    where
+        Self: 'x;
+
+    fn as_term_proxy(&self) -> TermProxy<'_, Self::Triple<'_>> {
+        self.as_object_proxy()
+    }
+
+    fn term_kind(&self) -> TermKind {
+        Object::term_kind(self)
+    }
+
+    fn ground(&self) -> bool {
+        Object::ground(self)
+    }
+}
+
+impl Term for GraphNameProxy<'_> {
+    type Triple<'x>
+        = SimpleTriple<'x>
+    where
+        Self: 'x;
+
+    fn as_term_proxy(&self) -> TermProxy<'_, Self::Triple<'_>> {
+        match self {
+            GraphNameProxy::Iri(iri) => TermProxy::Iri(iri.borrowed()),
+            GraphNameProxy::BlankNode(bnid) => TermProxy::BlankNode(Cow::from(bnid.as_ref())),
+        }
+    }
+}
+
+/// An enum conveying the inner information of any [RDF term].
+///
+/// Since RDF 1.2 allows any kind of term in the [object] position,
+/// this is simply an alias for [`ObjectProxy`].
+/// [`SubjectProxy`], [`GraphNameProxy`] and [`Iri`] can be converted into a [`TermProxy`] with [`From`].
+///
+/// [RDF term]: https://www.w3.org/TR/rdf12-concepts/#dfn-rdf-term
+/// [object]: https://www.w3.org/TR/rdf12-concepts/#dfn-object
+pub type TermProxy<'a, T> = ObjectProxy<'a, T>;
+
+/// An enum representing the different kinds of [RDF terms].
+/// The return type of [`Term::term_kind`].
+///
+/// Unlike [`SubjectKind`], [`ObjectKind`] and [`GraphNameKind`], this enum does not depend on the position of the term,
+/// so that code handling the terms of every positions can use it uniformly.
+/// Any positional kind can be converted into a [`TermKind`] with [`From`],
+/// and a [`TermKind`] can be converted back with [`TryFrom`]
+/// (or [`From`] for [`ObjectKind`], since any term can be an object).
+///
+/// [RDF terms]: https://www.w3.org/TR/rdf12-concepts/#dfn-rdf-term
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum TermKind {
+    /// An [IRI](https://www.w3.org/TR/rdf12-concepts/#section-IRIs)
+    Iri,
+    /// A [blank node](https://www.w3.org/TR/rdf12-concepts/#dfn-blank-node)
+    BlankNode,
+    /// A [literal](https://www.w3.org/TR/rdf12-concepts/#dfn-literal)
+    Literal,
+    /// A [triple term](https://www.w3.org/TR/rdf12-concepts/#dfn-triple-term)
+    Triple,
+}
+
+impl From<SubjectKind> for TermKind {
+    fn from(value: SubjectKind) -> Self {
+        match value {
+            SubjectKind::Iri => TermKind::Iri,
+            SubjectKind::BlankNode => TermKind::BlankNode,
+        }
+    }
+}
+
+impl From<ObjectKind> for TermKind {
+    fn from(value: ObjectKind) -> Self {
+        match value {
+            ObjectKind::Iri => TermKind::Iri,
+            ObjectKind::BlankNode => TermKind::BlankNode,
+            ObjectKind::Literal => TermKind::Literal,
+            ObjectKind::Triple => TermKind::Triple,
+        }
+    }
+}
+
+impl From<GraphNameKind> for TermKind {
+    fn from(value: GraphNameKind) -> Self {
+        match value {
+            GraphNameKind::Iri => TermKind::Iri,
+            GraphNameKind::BlankNode => TermKind::BlankNode,
+        }
+    }
+}
+
+impl From<TermKind> for ObjectKind {
+    fn from(value: TermKind) -> Self {
+        match value {
+            TermKind::Iri => ObjectKind::Iri,
+            TermKind::BlankNode => ObjectKind::BlankNode,
+            TermKind::Literal => ObjectKind::Literal,
+            TermKind::Triple => ObjectKind::Triple,
+        }
+    }
+}
+
+impl TryFrom<TermKind> for SubjectKind {
+    type Error = &'static str;
+
+    fn try_from(value: TermKind) -> Result<Self, Self::Error> {
+        match value {
+            TermKind::Iri => Ok(SubjectKind::Iri),
+            TermKind::BlankNode => Ok(SubjectKind::BlankNode),
+            _ => Err("Only IRIs and blank nodes can be subjects"),
+        }
+    }
+}
+
+impl TryFrom<TermKind> for GraphNameKind {
+    type Error = &'static str;
+
+    fn try_from(value: TermKind) -> Result<Self, Self::Error> {
+        match value {
+            TermKind::Iri => Ok(GraphNameKind::Iri),
+            TermKind::BlankNode => Ok(GraphNameKind::BlankNode),
+            _ => Err("Only IRIs and blank nodes can be graph names"),
+        }
+    }
+}
+
+impl<'a, T: Triple> From<SubjectProxy<'a>> for ObjectProxy<'a, T> {
+    fn from(value: SubjectProxy<'a>) -> Self {
+        match value {
+            SubjectProxy::Iri(iri) => ObjectProxy::Iri(iri),
+            SubjectProxy::BlankNode(bnid) => ObjectProxy::BlankNode(bnid),
+        }
+    }
+}
+
+impl<'a, T: Triple> From<GraphNameProxy<'a>> for ObjectProxy<'a, T> {
+    fn from(value: GraphNameProxy<'a>) -> Self {
+        match value {
+            GraphNameProxy::Iri(iri) => ObjectProxy::Iri(iri),
+            GraphNameProxy::BlankNode(bnid) => ObjectProxy::BlankNode(bnid),
+        }
+    }
+}
+
+/// Any graph name can be used as a subject, e.g. for describing a named graph.
+///
+/// ```
+/// # use r2c2_statement::*;
+/// const EX: &str = "https://example.org/ns/";
+/// const DCT: &str = "http://purl.org/dc/terms/";
+/// let quads = [quad!(_:a, EX:p, EX:o, EX:g1), quad!(_:b, EX:p, EX:o, _:g2)];
+/// let provenance: Vec<_> = quads
+///     .iter()
+///     .filter_map(|q| q.graph_name.clone())
+///     .map(|g| SimpleTriple {
+///         subject: g.into(),
+///         ..triple!(_:x, DCT:modified, "2024-01-01"^^<"http://www.w3.org/2001/XMLSchema#date">)
+///     })
+///     .collect();
+/// assert_eq!(
+///     write_ntriples(&provenance),
+///     r#"<https://example.org/ns/g1> <http://purl.org/dc/terms/modified> "2024-01-01"^^<http://www.w3.org/2001/XMLSchema#date> .
+/// _:g2 <http://purl.org/dc/terms/modified> "2024-01-01"^^<http://www.w3.org/2001/XMLSchema#date> .
+/// "#
+/// );
+/// ```
+impl<'a> From<GraphNameProxy<'a>> for SubjectProxy<'a> {
+    fn from(value: GraphNameProxy<'a>) -> Self {
+        match value {
+            GraphNameProxy::Iri(iri) => SubjectProxy::Iri(iri),
+            GraphNameProxy::BlankNode(bnid) => SubjectProxy::BlankNode(bnid),
+        }
+    }
+}
+
+/// Any subject can be used as a graph name.
+impl<'a> From<SubjectProxy<'a>> for GraphNameProxy<'a> {
+    fn from(value: SubjectProxy<'a>) -> Self {
+        match value {
+            SubjectProxy::Iri(iri) => GraphNameProxy::Iri(iri),
+            SubjectProxy::BlankNode(bnid) => GraphNameProxy::BlankNode(bnid),
+        }
+    }
+}
+
+impl<'a, T: Triple> From<Iri<'a>> for ObjectProxy<'a, T> {
+    fn from(value: Iri<'a>) -> Self {
+        ObjectProxy::Iri(value)
+    }
+}
+
+impl<'a, T: Triple> TryFrom<ObjectProxy<'a, T>> for SubjectProxy<'a> {
+    type Error = NotASubject<'a, T>;
+
+    fn try_from(value: ObjectProxy<'a, T>) -> Result<Self, Self::Error> {
+        match value {
+            ObjectProxy::Iri(iri) => Ok(SubjectProxy::Iri(iri)),
+            ObjectProxy::BlankNode(bnid) => Ok(SubjectProxy::BlankNode(bnid)),
+            ObjectProxy::Literal(literal) => Err(NotASubject::WasLiteral(literal)),
+            ObjectProxy::Triple(triple) => Err(NotASubject::WasTripleTerm(triple)),
+        }
+    }
+}
+
+/// The error returned when converting an [`ObjectProxy`] into a [`SubjectProxy`] fails,
+/// as only IRIs and blank nodes can be [subjects].
+///
+/// The rejected term is carried by the error, so that the caller can recover it.
+///
+/// ```
+/// # use r2c2_statement::*;
+/// let t = triple!(_:a, <"https://example.org/ns/p">, "chat");
+/// match SubjectProxy::try_from(t.object) {
+///     Err(NotASubject::WasLiteral(lit)) => assert_eq!(lit.lexical_form(), "chat"),
+///     _ => unreachable!(),
+/// }
+/// ```
+///
+/// [subjects]: https://www.w3.org/TR/rdf12-concepts/#dfn-subject
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum NotASubject<'a, T> {
+    /// The object was a [literal](https://www.w3.org/TR/rdf12-concepts/#dfn-literal)
+    WasLiteral(Literal<'a>),
+    /// The object was a [triple term](https://www.w3.org/TR/rdf12-concepts/#dfn-triple-term)
+    WasTripleTerm(T),
+}
+
+impl<T> core::fmt::Display for NotASubject<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            NotASubject::WasLiteral(_) => write!(f, "a literal can not be a subject"),
+            NotASubject::WasTripleTerm(_) => write!(f, "a triple term can not be a subject"),
+        }
+    }
+}
+
+impl<T: core::fmt::Debug> core::error::Error for NotASubject<'_, T> {}
+
+impl<'a, T: Triple> TryFrom<ObjectProxy<'a, T>> for GraphNameProxy<'a> {
+    type Error = &'static str;
+
+    fn try_from(value: ObjectProxy<'a, T>) -> Result<Self, Self::Error> {
+        match value {
+            ObjectProxy::Iri(iri) => Ok(GraphNameProxy::Iri(iri)),
+            ObjectProxy::BlankNode(bnid) => Ok(GraphNameProxy::BlankNode(bnid)),
+            _ => Err("Only IRIs and blank nodes can be graph names"),
+        }
+    }
+}
+
+impl<'a, T: Triple> TryFrom<ObjectProxy<'a, T>> for Iri<'a> {
+    type Error = &'static str;
+
+    fn try_from(value: ObjectProxy<'a, T>) -> Result<Self, Self::Error> {
+        match value {
+            ObjectProxy::Iri(iri) => Ok(iri),
+            _ => Err("Only IRIs can be predicates"),
+        }
+    }
+}
+
+/// The iterator returned by [`Triple::terms`] and [`Quad::terms`](crate::Quad::terms).
+///
+/// It yields the subject, the predicate, the object and (for quads) the graph name of the statement,
+/// in that order, as [`StatementTerm`]s.
+/// These are the values returned by the accessors of the statement,
+/// whose [`TermProxy`] can be obtained with [`Term::as_term_proxy`]
+/// (as the proxies of a statement borrow those values, see [`SpoProxies`](crate::SpoProxies)).
+/// Nothing is copied nor allocated.
+///
+/// The terms of the [triple terms] nested in the statement are not yielded,
+/// but they can be iterated on demand, from the proxy of the object:
+///
+/// ```
+/// # use r2c2_statement::*;
+/// const EX: &str = "https://example.org/ns/";
+/// let t = triple!(_:a, EX:says, <<( _:b, EX:name, "Bob" )>>);
+/// let mut kinds = vec![];
+/// for term in t.terms() {
+///     kinds.push(term.term_kind());
+///     if let TermProxy::Triple(nested) = term.as_term_proxy() {
+///         kinds.extend(nested.terms().map(|t| t.term_kind()));
+///     }
+/// }
+/// assert_eq!(kinds, [
+///     TermKind::BlankNode, TermKind::Iri, TermKind::Triple,
+///     TermKind::BlankNode, TermKind::Iri, TermKind::Literal,
+/// ]);
+/// ```
+///
+/// See also [`Triple::for_each_nested_triple`] for walking all the levels of nesting.
+///
+/// [triple terms]: https://www.w3.org/TR/rdf12-concepts/#dfn-triple-term
+#[derive(Clone, Debug)]
+pub struct Terms<S, P, O, G = GraphNameProxy<'static>> {
+    subject: Option<S>,
+    predicate: Option<P>,
+    object: Option<O>,
+    graph_name: Option<G>,
+}
+
+impl<S, P, O, G> Terms<S, P, O, G> {
+    pub(crate) fn new((subject, predicate, object): (S, P, O), graph_name: Option<G>) -> Self {
+        Terms {
+            subject: Some(subject),
+            predicate: Some(predicate),
+            object: Some(object),
+            graph_name,
+        }
+    }
+}
+
+impl<S, P, O, G> Iterator for Terms<S, P, O, G> {
+    type Item = StatementTerm<S, P, O, G>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(subject) = self.subject.take() {
+            return Some(StatementTerm::S(subject));
+        }
+        if let Some(predicate) = self.predicate.take() {
+            return Some(StatementTerm::P(predicate));
+        }
+        if let Some(object) = self.object.take() {
+            return Some(StatementTerm::O(object));
+        }
+        self.graph_name.take().map(StatementTerm::G)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<S, P, O, G> ExactSizeIterator for Terms<S, P, O, G> {
+    fn len(&self) -> usize {
+        usize::from(self.subject.is_some())
+            + usize::from(self.predicate.is_some())
+            + usize::from(self.object.is_some())
+            + usize::from(self.graph_name.is_some())
+    }
+}
+
+impl<S, P, O, G> core::iter::FusedIterator for Terms<S, P, O, G> {}
+
+/// A term of a statement, tagged with its position, as yielded by [`Terms`].
+///
+/// It implements [`Term`], whatever its position.
+/// Its variants are named after the positions, as in [`Quad::spog`](crate::Quad::spog).
+#[derive(Clone, Copy, Debug)]
+pub enum StatementTerm<S, P, O, G = GraphNameProxy<'static>> {
+    /// The [subject](https://www.w3.org/TR/rdf12-concepts/#dfn-subject) of the statement
+    S(S),
+    /// The [predicate](https://www.w3.org/TR/rdf12-concepts/#dfn-predicate) of the statement
+    P(P),
+    /// The [object](https://www.w3.org/TR/rdf12-concepts/#dfn-object) of the statement
+    O(O),
+    /// The [graph name](https://www.w3.org/TR/rdf12-concepts/#dfn-graph-name) of the statement
+    G(G),
+}
+
+impl<S: Subject, P: Predicate, O: Object, G: GraphName> Term for StatementTerm<S, P, O, G> {
+    type Triple<'x>
+        = O::Triple<'x>
+    where
+        Self: 'x;
+
+    fn as_term_proxy(&self) -> TermProxy<'_, Self::Triple<'_>> {
+        match self {
+            StatementTerm::S(subject) => subject.as_subject_proxy().into(),
+            StatementTerm::P(predicate) => TermProxy::Iri(predicate.as_iri()),
+            StatementTerm::O(object) => object.as_object_proxy(),
+            StatementTerm::G(graph_name) => graph_name.as_graph_name_proxy().into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::{boxed::Box, vec, vec::Vec};
+
+    use super::*;
+    use crate::{ErasedTerm, Quad, quad, triple};
+
+    const EX: &str = "https://example.org/ns/";
+
+    fn iri(txt: &'static str) -> TermProxy<'static, Box<SimpleTriple<'static>>> {
+        ObjectProxy::Iri(Iri::new_unchecked(txt))
+    }
+
+    fn bnode(txt: &'static str) -> TermProxy<'static, Box<SimpleTriple<'static>>> {
+        ObjectProxy::BlankNode(txt.into())
+    }
+
+    /// Count the IRIs, blank nodes, literals and triple terms of a quad, in all positions.
+    fn count_kinds<Q: Quad>(q: &Q, counts: &mut [usize; 4]) {
+        fn count<T: Term>(term: T, counts: &mut [usize; 4]) {
+            counts[term.term_kind() as usize] += 1;
+        }
+        count(q.subject().as_subject_proxy(), counts);
+        count(q.predicate().as_iri(), counts);
+        count(q.object().as_object_proxy(), counts);
+        if let Some(g) = q.graph_name() {
+            count(g.as_graph_name_proxy(), counts);
+        }
+    }
+
+    #[test]
+    fn term_kinds() {
+        let quads = [
+            quad!(_:a, EX:p, EX:o, EX:g),
+            quad!(EX:s, EX:p, "chat"@en, _:g),
+            quad!(_:a, EX:p, <<( _:b, EX:q, "1" )>>),
+        ];
+        let mut counts = [0; 4];
+        for q in &quads {
+            count_kinds(q, &mut counts);
+        }
+        let [iris, bnodes, literals, triples] = counts;
+        assert_eq!((iris, bnodes, literals, triples), (6, 3, 1, 1));
+    }
+
+    #[test]
+    fn kind_mappings() {
+        const ALL: [TermKind; 4] = [
+            TermKind::Iri,
+            TermKind::BlankNode,
+            TermKind::Literal,
+            TermKind::Triple,
+        ];
+        for kind in ALL {
+            assert_eq!(TermKind::from(ObjectKind::from(kind)), kind);
+            let subject = SubjectKind::try_from(kind);
+            let graph_name = GraphNameKind::try_from(kind);
+            let positional = matches!(kind, TermKind::Iri | TermKind::BlankNode);
+            assert_eq!(subject.is_ok(), positional);
+            assert_eq!(graph_name.is_ok(), positional);
+            if let (Ok(subject), Ok(graph_name)) = (subject, graph_name) {
+                assert_eq!(TermKind::from(subject), kind);
+                assert_eq!(TermKind::from(graph_name), kind);
+            }
+        }
+        for kind in [SubjectKind::Iri, SubjectKind::BlankNode] {
+            assert_eq!(SubjectKind::try_from(TermKind::from(kind)), Ok(kind));
+        }
+        for kind in [GraphNameKind::Iri, GraphNameKind::BlankNode] {
+            assert_eq!(GraphNameKind::try_from(TermKind::from(kind)), Ok(kind));
+        }
+        for kind in [
+            ObjectKind::Iri,
+            ObjectKind::BlankNode,
+            ObjectKind::Literal,
+            ObjectKind::Triple,
+        ] {
+            assert_eq!(ObjectKind::from(TermKind::from(kind)), kind);
+        }
+    }
+
+    #[test]
+    fn positional_term_kind() {
+        let q = quad!(_:a, EX:p, <<( EX:b, EX:q, "1" )>>, EX:g);
+        assert_eq!(Subject::term_kind(&q.subject), TermKind::BlankNode);
+        assert_eq!(Subject::term_kind(&q.subject), Term::term_kind(&q.subject));
+        assert_eq!(Object::term_kind(&q.object), TermKind::Triple);
+        assert_eq!(Object::term_kind(&&q.object), Term::term_kind(&q.object));
+        let g = q.graph_name.as_ref().unwrap();
+        assert_eq!(GraphName::term_kind(g), TermKind::Iri);
+        assert_eq!(GraphName::term_kind(g), Term::term_kind(g));
+    }
+
+    #[test]
+    fn term_ground() {
+        let t = triple!(_:a, EX:p, <<( EX:b, EX:q, "1" )>>);
+        assert!(!Term::ground(&t.subject));
+        assert!(Term::ground(&t.predicate));
+        assert!(Term::ground(&t.object));
+        assert!(Term::ground(&GraphNameProxy::Iri(t.predicate.clone())));
+        let ObjectProxy::Triple(inner) = &t.object else {
+            unreachable!()
+        };
+        let ObjectProxy::Literal(lit) = &inner.object else {
+            unreachable!()
+        };
+        assert!(Term::ground(lit));
+        assert_eq!(Term::term_kind(lit), TermKind::Literal);
+    }
+
+    #[test]
+    fn from_positional_proxies_borrow() {
+        let txt = "https://example.org/ns/s";
+        let bnid = "b";
+        fn check(o: ObjectProxy<SimpleTriple>, txt: &str) {
+            let cow = match o {
+                ObjectProxy::Iri(iri) => iri.unwrap(),
+                ObjectProxy::BlankNode(bnid) => bnid,
+                _ => unreachable!(),
+            };
+            assert!(matches!(&cow, Cow::Borrowed(s) if core::ptr::eq(*s, txt)));
+        }
+        check(SubjectProxy::Iri(Iri::new_unchecked(txt)).into(), txt);
+        check(SubjectProxy::BlankNode(bnid.into()).into(), bnid);
+        check(GraphNameProxy::Iri(Iri::new_unchecked(txt)).into(), txt);
+        check(GraphNameProxy::BlankNode(bnid.into()).into(), bnid);
+    }
+
+    #[test]
+    fn subject_graph_name() {
+        let q = quad!(_:a, EX:p, EX:o, EX:g);
+        let s = SubjectProxy::from(q.graph_name.clone().unwrap());
+        assert_eq!(
+            s,
+            SubjectProxy::Iri(Iri::new_unchecked("https://example.org/ns/g"))
+        );
+        assert_eq!(GraphNameProxy::from(s), q.graph_name.unwrap());
+        let g = GraphNameProxy::from(q.subject.clone());
+        assert_eq!(g, GraphNameProxy::BlankNode("a".into()));
+        assert_eq!(SubjectProxy::from(g), q.subject);
+    }
+
+    #[test]
+    fn try_from_term_proxy() {
+        let t = triple!(_:a, EX:p, <<( EX:b, EX:q, "1" )>>);
+        let s = TermProxy::<SimpleTriple>::from(t.subject.clone());
+        assert_eq!(SubjectProxy::try_from(s.clone()), Ok(t.subject.clone()));
+        assert_eq!(
+            GraphNameProxy::try_from(s.clone()),
+            Ok(GraphNameProxy::BlankNode("a".into()))
+        );
+        assert!(Iri::try_from(s).is_err());
+        let p = TermProxy::<SimpleTriple>::from(t.predicate.clone());
+        assert_eq!(Iri::try_from(p), Ok(t.predicate.clone()));
+        assert!(matches!(
+            SubjectProxy::try_from(t.object.clone()),
+            Err(NotASubject::WasTripleTerm(_))
+        ));
+        assert!(GraphNameProxy::try_from(t.object).is_err());
+    }
+
+    #[test]
+    fn not_a_subject() {
+        let t = triple!(_:a, EX:p, <<( EX:b, EX:q, "1" )>>);
+        let to_subject =
+            |o: ObjectProxy<'static, Box<SimpleTriple<'static>>>| SubjectProxy::try_from(o);
+        assert_eq!(
+            to_subject(iri("https://example.org/ns/s")),
+            Ok(SubjectProxy::Iri(Iri::new_unchecked(
+                "https://example.org/ns/s"
+            )))
+        );
+        assert_eq!(
+            to_subject(bnode("b")),
+            Ok(SubjectProxy::BlankNode("b".into()))
+        );
+        let ObjectProxy::Triple(inner) = &t.object else {
+            unreachable!()
+        };
+        let ObjectProxy::Literal(lit) = inner.object.clone() else {
+            unreachable!()
+        };
+        let err = to_subject(ObjectProxy::Literal(lit.clone())).unwrap_err();
+        assert_eq!(err.to_string(), "a literal can not be a subject");
+        assert_eq!(err, NotASubject::WasLiteral(lit));
+        let err = to_subject(t.object.clone()).unwrap_err();
+        assert_eq!(err.to_string(), "a triple term can not be a subject");
+        let NotASubject::WasTripleTerm(recovered) = err else {
+            unreachable!()
+        };
+        assert_eq!(ObjectProxy::Triple(recovered), t.object);
+        let _: &dyn std::error::Error = &NotASubject::<SimpleTriple>::WasLiteral(Literal::Typed(
+            "1".into(),
+            crate::ns::xsd::STRING,
+        ));
+    }
+
+    #[test]
+    fn triple_terms() {
+        let t = triple!(_:a, EX:p, EX:o);
+        let terms: Vec<_> = t.terms().collect();
+        assert!(matches!(
+            terms[..],
+            [
+                StatementTerm::S(_),
+                StatementTerm::P(_),
+                StatementTerm::O(_),
+            ]
+        ));
+        assert_eq!(
+            erased(terms.clone()),
+            [
+                bnode("a"),
+                iri("https://example.org/ns/p"),
+                iri("https://example.org/ns/o"),
+            ]
+            .map(ErasedTerm::from)
+        );
+        // the terms are those returned by the accessors, not copies
+        let StatementTerm::S(subject) = terms[0] else {
+            unreachable!()
+        };
+        assert!(core::ptr::eq(subject, &t.subject));
+        assert_eq!(t.terms().len(), 3);
+        // pointer types yield the same terms
+        assert_eq!(
+            erased(<&SimpleTriple as Triple>::terms(&&t)),
+            erased(terms.clone())
+        );
+        assert_eq!(erased(Box::new(t.clone()).terms()), erased(terms.clone()));
+    }
+
+    #[test]
+    fn quad_terms() {
+        let q = quad!(_:a, EX:p, EX:o, _:g);
+        let terms: Vec<_> = q.terms().collect();
+        assert!(matches!(terms[3], StatementTerm::G(_)));
+        assert_eq!(
+            erased(terms.clone()),
+            [
+                bnode("a"),
+                iri("https://example.org/ns/p"),
+                iri("https://example.org/ns/o"),
+                bnode("g"),
+            ]
+            .map(ErasedTerm::from)
+        );
+        let q = quad!(_:a, EX:p, EX:o);
+        assert_eq!(q.terms().len(), 3);
+        assert_eq!(q.terms().count(), 3);
+    }
+
+    fn erased<T: Term>(terms: impl IntoIterator<Item = T>) -> Vec<ErasedTerm> {
+        terms
+            .into_iter()
+            .map(|t| t.as_term_proxy().into())
+            .collect()
+    }
+
+    /// All the terms of `t`, descending into its triple terms (after yielding them) on demand.
+    fn all_terms<T: Triple + ?Sized>(t: &T, acc: &mut Vec<ErasedTerm>) {
+        for term in t.terms() {
+            acc.push(term.as_term_proxy().into());
+            if let TermProxy::Triple(nested) = term.as_term_proxy() {
+                all_terms(&nested, acc);
+            }
+        }
+    }
+
+    #[test]
+    fn nested_terms() {
+        let inner = triple!(_:c, EX:q, EX:d);
+        let middle = triple!(_:b, EX:says, <<( _:c, EX:q, EX:d )>>);
+        let q = quad!(_:a, EX:says, <<( _:b, EX:says, <<( _:c, EX:q, EX:d )>> )>>, EX:g);
+        let nested = |t: SimpleTriple<'static>| ObjectProxy::Triple(Box::new(t));
+        assert_eq!(
+            erased(q.terms()),
+            vec![
+                bnode("a").into(),
+                iri("https://example.org/ns/says").into(),
+                nested(middle.clone()).into(),
+                iri("https://example.org/ns/g").into(),
+            ]
+        );
+        let StatementTerm::O(object) = q.terms().nth(2).unwrap() else {
+            unreachable!()
+        };
+        let TermProxy::Triple(triple) = object.as_term_proxy() else {
+            unreachable!()
+        };
+        let mut acc = vec![];
+        all_terms(&triple, &mut acc);
+        assert_eq!(
+            acc,
+            vec![
+                bnode("b").into(),
+                iri("https://example.org/ns/says").into(),
+                nested(inner).into(),
+                bnode("c").into(),
+                iri("https://example.org/ns/q").into(),
+                iri("https://example.org/ns/d").into(),
+            ]
+        );
+    }
+}
@@ -0,0 +1,88 @@
+use crate::{Iri, Literal, Object, ObjectProxy, Predicate, Subject, SubjectProxy, Triple};
+
+/// A factory building the terms of a given implementation.
+///
+/// While [`FromTriple`](crate::FromTriple) and [`FromQuad`](crate::FromQuad) convert whole statements,
+/// this trait allows generic code (e.g. a parser) to mint individual terms in a target implementation.
+///
+/// The factory is responsible for mapping blank node labels to the target implementation
+/// (which may put constraints on them, or may need to keep them distinct from other labels),
+/// and may hold some state for that purpose, hence the `&mut self` receivers.
+///
+/// ```
+/// # use r2c2_statement::*;
+/// fn make_triple<F: TermFactory>(factory: &mut F) -> Result<F::Triple, F::Error> {
+///     let s = factory.bnode("alice");
+///     let p = factory.iri(Iri::new_unchecked("https://example.org/ns/name"));
+///     let o = factory.literal(Literal::Typed(
+///         "Alice".into(),
+///         ns::xsd::STRING,
+///     ))?;
+///     factory.triple(s, p, o)
+/// }
+/// ```
+pub trait TermFactory {
+    /// The type of terms built by this factory.
+    type Term;
+    /// The type of triples built by this factory (from which [triple terms] are built).
+    ///
+    /// [triple terms]: https://www.w3.org/TR/rdf12-concepts/#dfn-triple-term
+    type Triple;
+    /// The error raised when a term or a triple can not be built.
+    type Error;
+
+    /// Build an [IRI](https://www.w3.org/TR/rdf12-concepts/#dfn-iri).
+    fn iri(&mut self, iri: Iri) -> Self::Term;
+
+    /// Build a [blank node](https://www.w3.org/TR/rdf12-concepts/#dfn-blank-node) from its label.
+    ///
+    /// The label is not constrained (see [`SubjectProxy::BlankNode`]);
+    /// how it is mapped to the target implementation is up to the factory.
+    fn bnode(&mut self, label: &str) -> Self::Term;
+
+    /// Build a [literal](https://www.w3.org/TR/rdf12-concepts/#dfn-literal).
+    ///
+    /// This fails if the target implementation does not support this literal
+    /// (e.g. a directional language string).
+    fn literal(&mut self, literal: Literal) -> Result<Self::Term, Self::Error>;
+
+    /// Build a triple from three terms.
+    ///
+    /// This fails if `subject` is not an IRI or a blank node,
+    /// or if `predicate` is not an IRI.
+    fn triple(
+        &mut self,
+        subject: Self::Term,
+        predicate: Self::Term,
+        object: Self::Term,
+    ) -> Result<Self::Triple, Self::Error>;
+
+    /// Build a [triple term](https://www.w3.org/TR/rdf12-concepts/#dfn-triple-term).
+    ///
+    /// This fails if the target implementation does not support triple terms.
+    fn triple_term(&mut self, triple: Self::Triple) -> Result<Self::Term, Self::Error>;
+
+    /// Build a term by copying the given [`ObjectProxy`] (or [`TermProxy`](crate::TermProxy)).
+    fn term<T: Triple>(&mut self, proxy: ObjectProxy<'_, T>) -> Result<Self::Term, Self::Error> {
+        match proxy {
+            ObjectProxy::Iri(iri) => Ok(self.iri(iri)),
+            ObjectProxy::BlankNode(label) => Ok(self.bnode(&label)),
+            ObjectProxy::Literal(literal) => self.literal(literal),
+            ObjectProxy::Triple(triple) => {
+                let triple = self.copy_triple(&triple)?;
+                self.triple_term(triple)
+            }
+        }
+    }
+
+    /// Build a triple by copying the terms of any [`Triple`].
+    fn copy_triple<T: Triple>(&mut self, triple: &T) -> Result<Self::Triple, Self::Error> {
+        let subject = match triple.subject().as_subject_proxy() {
+            SubjectProxy::Iri(iri) => self.iri(iri),
+            SubjectProxy::BlankNode(label) => self.bnode(&label),
+        };
+        let predicate = self.iri(triple.predicate().as_iri());
+        let object = self.term(triple.object().as_object_proxy())?;
+        self.triple(subject, predicate, object)
+    }
+}
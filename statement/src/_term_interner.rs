@@ -0,0 +1,249 @@
+use alloc::{borrow::Cow, boxed::Box, collections::BTreeSet, sync::Arc};
+
+use crate::{
+    GraphName, GraphNameProxy, Iri, LangTag, Literal, Object, ObjectProxy, Predicate, Quad,
+    SimpleQuad, SimpleTriple, Subject, SubjectProxy, Triple,
+};
+
+/// A utility type for deduplicating the IRIs and language tags of many statements.
+///
+/// Each distinct text passed to the interner is stored once, in an [`Arc<str>`],
+/// and every [`Iri`] or [`LangTag`] returned by the interner is [shared](Iri::shared) with that text.
+/// This saves memory when storing many statements with few distinct IRIs (e.g. predicates),
+/// and makes cloning the returned terms cheap.
+///
+/// Blank node labels and lexical forms of literals, on the other hand,
+/// are exposed as [`Cow<str>`] by the proxies, so they are only copied, not deduplicated.
+///
+/// ```
+/// # use r2c2_statement::*;
+/// const EX: &str = "https://example.org/ns/";
+/// let source = [
+///     Ok::<_, &str>(triple!(EX:s1, EX:p, "a")),
+///     Ok(triple!(EX:s2, EX:p, <<( EX:s1, EX:p, "b"@en )>>)),
+/// ];
+/// let mut interner = TermInterner::new();
+/// let triples: Vec<_> = source
+///     .into_iter()
+///     .map(|res| res.map(|t| interner.intern_triple(t)))
+///     .collect::<Result<_, _>>()
+///     .unwrap();
+/// assert_eq!(triples[0], triple!(EX:s1, EX:p, "a"));
+/// assert_eq!(
+///     triples[0].predicate.as_str().as_ptr(),
+///     triples[1].predicate.as_str().as_ptr(),
+/// );
+/// assert_eq!(interner.len(), 5); // s1, s2, p, xsd:string, en
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct TermInterner {
+    texts: BTreeSet<Arc<str>>,
+    bytes_saved: usize,
+}
+
+impl TermInterner {
+    /// Create a new, empty interner.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of distinct texts (IRIs and language tags) interned so far.
+    pub fn len(&self) -> usize {
+        self.texts.len()
+    }
+
+    /// Whether no text has been interned so far.
+    pub fn is_empty(&self) -> bool {
+        self.texts.is_empty()
+    }
+
+    /// An estimate of the memory saved so far, in bytes,
+    /// i.e. the total length of the texts that were found already interned
+    /// (ignoring allocation overhead).
+    pub fn bytes_saved(&self) -> usize {
+        self.bytes_saved
+    }
+
+    /// Forget all the texts interned so far, and reset [`bytes_saved`](TermInterner::bytes_saved).
+    ///
+    /// Terms returned before remain valid, as they own a reference to their text.
+    pub fn clear(&mut self) {
+        self.texts.clear();
+        self.bytes_saved = 0;
+    }
+
+    fn intern_text(&mut self, txt: &str) -> Arc<str> {
+        if let Some(interned) = self.texts.get(txt) {
+            self.bytes_saved += txt.len();
+            return interned.clone();
+        }
+        let interned: Arc<str> = Arc::from(txt);
+        self.texts.insert(interned.clone());
+        interned
+    }
+
+    /// Return an [`Iri`] equal to `iri`, sharing its text with all equal IRIs returned by this interner.
+    pub fn intern_iri(&mut self, iri: Iri<'_>) -> Iri<'static> {
+        Iri::new_shared_unchecked(self.intern_text(iri.as_str()))
+    }
+
+    /// Return a [`LangTag`] with the same text as `tag`,
+    /// sharing it with all identical language tags returned by this interner.
+    pub fn intern_lang_tag(&mut self, tag: LangTag<'_>) -> LangTag<'static> {
+        LangTag::new_shared_unchecked(self.intern_text(tag.as_str()))
+    }
+
+    /// Return a [`Literal`] equal to `literal`, with an interned datatype or language tag.
+    ///
+    /// The lexical form is copied.
+    pub fn intern_literal(&mut self, literal: Literal<'_>) -> Literal<'static> {
+        match literal {
+            Literal::Typed(lex, dt) => {
+                Literal::Typed(Cow::Owned(lex.into_owned()), self.intern_iri(dt))
+            }
+            Literal::LanguageString(lex, tag, dir) => Literal::LanguageString(
+                Cow::Owned(lex.into_owned()),
+                self.intern_lang_tag(tag),
+                dir,
+            ),
+        }
+    }
+
+    /// Return a [`SubjectProxy`] equal to `subject`, with an interned IRI.
+    pub fn intern_subject(&mut self, subject: SubjectProxy<'_>) -> SubjectProxy<'static> {
+        match subject {
+            SubjectProxy::Iri(iri) => SubjectProxy::Iri(self.intern_iri(iri)),
+            SubjectProxy::BlankNode(bnid) => SubjectProxy::BlankNode(Cow::Owned(bnid.into_owned())),
+        }
+    }
+
+    /// Return a [`GraphNameProxy`] equal to `graph_name`, with an interned IRI.
+    pub fn intern_graph_name(&mut self, graph_name: GraphNameProxy<'_>) -> GraphNameProxy<'static> {
+        match graph_name {
+            GraphNameProxy::Iri(iri) => GraphNameProxy::Iri(self.intern_iri(iri)),
+            GraphNameProxy::BlankNode(bnid) => {
+                GraphNameProxy::BlankNode(Cow::Owned(bnid.into_owned()))
+            }
+        }
+    }
+
+    /// Return an [`ObjectProxy`] equal to `object`, with interned IRIs and language tags,
+    /// including in nested triple terms.
+    ///
+    /// Triple terms are copied into [`SimpleTriple`]s.
+    pub fn intern_object<T: Triple>(
+        &mut self,
+        object: ObjectProxy<'_, T>,
+    ) -> ObjectProxy<'static, Box<SimpleTriple<'static>>> {
+        match object {
+            ObjectProxy::Iri(iri) => ObjectProxy::Iri(self.intern_iri(iri)),
+            ObjectProxy::BlankNode(bnid) => ObjectProxy::BlankNode(Cow::Owned(bnid.into_owned())),
+            ObjectProxy::Literal(literal) => ObjectProxy::Literal(self.intern_literal(literal)),
+            ObjectProxy::Triple(triple) => {
+                ObjectProxy::Triple(Box::new(self.intern_triple(triple)))
+            }
+        }
+    }
+
+    /// Copy `triple` into a [`SimpleTriple`], interning all its IRIs and language tags.
+    pub fn intern_triple<T: Triple>(&mut self, triple: T) -> SimpleTriple<'static> {
+        SimpleTriple {
+            subject: self.intern_subject(triple.subject().as_subject_proxy()),
+            predicate: self.intern_iri(triple.predicate().as_iri()),
+            object: self.intern_object(triple.object().as_object_proxy()),
+        }
+    }
+
+    /// Copy `quad` into a [`SimpleQuad`], interning all its IRIs and language tags.
+    pub fn intern_quad<Q: Quad>(&mut self, quad: Q) -> SimpleQuad<'static> {
+        SimpleQuad {
+            subject: self.intern_subject(quad.subject().as_subject_proxy()),
+            predicate: self.intern_iri(quad.predicate().as_iri()),
+            object: self.intern_object(quad.object().as_object_proxy()),
+            graph_name: quad
+                .graph_name()
+                .map(|gn| self.intern_graph_name(gn.as_graph_name_proxy())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{BaseDir, quad, triple};
+
+    use super::*;
+
+    const EX: &str = "https://example.org/ns/";
+
+    #[test]
+    fn intern_iri() {
+        let mut interner = TermInterner::new();
+        assert!(interner.is_empty());
+        let p1 = interner.intern_iri(Iri::new_unchecked(format!("{EX}p")));
+        let p2 = interner.intern_iri(Iri::new_unchecked(format!("{EX}p")));
+        let q = interner.intern_iri(Iri::new_unchecked(format!("{EX}q")));
+        assert_eq!(p1, p2);
+        assert_eq!(p1.as_str().as_ptr(), p2.as_str().as_ptr());
+        assert_eq!(p1.clone().as_str().as_ptr(), p1.as_str().as_ptr());
+        assert_ne!(p1, q);
+        assert_eq!(interner.len(), 2);
+        assert_eq!(interner.bytes_saved(), EX.len() + 1);
+        interner.clear();
+        assert!(interner.is_empty());
+        assert_eq!(interner.bytes_saved(), 0);
+        assert_eq!(p1, format!("{EX}p"));
+    }
+
+    #[test]
+    fn intern_literal() {
+        let mut interner = TermInterner::new();
+        let tag = LangTag::new_unchecked("en-GB");
+        let l1 = Literal::LanguageString("a".into(), tag.clone(), Some(BaseDir::Rtl));
+        let l2 = Literal::LanguageString("b".into(), tag.clone(), None);
+        let (Literal::LanguageString(_, t1, _), Literal::LanguageString(_, t2, _)) = (
+            interner.intern_literal(l1.clone()),
+            interner.intern_literal(l2.clone()),
+        ) else {
+            panic!("expected language strings");
+        };
+        assert_eq!(t1.as_str().as_ptr(), t2.as_str().as_ptr());
+        assert_eq!(interner.intern_literal(l1.clone()), l1);
+        assert_eq!(interner.intern_literal(l2.clone()), l2);
+    }
+
+    #[test]
+    fn intern_statements() {
+        let mut interner = TermInterner::new();
+        let t1 = triple!(_:b, EX:p, <<( EX:s, EX:p, "42"^^<"http://www.w3.org/2001/XMLSchema#integer"> )>>);
+        let t2 = triple!(EX:s, EX:p, "chat"@fr);
+        let i1 = interner.intern_triple(&t1);
+        let i2 = interner.intern_triple(&t2);
+        assert_eq!(i1, t1);
+        assert_eq!(i2, t2);
+        let ObjectProxy::Triple(inner) = &i1.object else {
+            panic!("expected a triple term");
+        };
+        assert_eq!(
+            inner.predicate.as_str().as_ptr(),
+            i1.predicate.as_str().as_ptr()
+        );
+        assert_eq!(
+            i2.predicate.as_str().as_ptr(),
+            i1.predicate.as_str().as_ptr()
+        );
+        assert_eq!(
+            i2.subject.as_iri().unwrap().as_str().as_ptr(),
+            inner.subject.as_iri().unwrap().as_str().as_ptr()
+        );
+
+        let q = quad!(EX:s, EX:p, _:o, EX:g);
+        let iq = interner.intern_quad(&q);
+        assert_eq!(iq, SimpleQuad::from_quad(&q));
+        assert_eq!(
+            iq.predicate.as_str().as_ptr(),
+            i1.predicate.as_str().as_ptr()
+        );
+        // s, p, xsd:integer, fr, g
+        assert_eq!(interner.len(), 5);
+    }
+}
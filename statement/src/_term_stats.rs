@@ -0,0 +1,114 @@
+use alloc::collections::BTreeSet;
+
+use crate::{Dataset, ErasedTerm, Quad, Term, TermProxy, Triple};
+
+/// The number of distinct terms of each kind in a dataset, as returned by [`term_stats`].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct TermStats {
+    /// The number of distinct [IRIs](https://www.w3.org/TR/rdf12-concepts/#section-IRIs)
+    pub iris: usize,
+    /// The number of distinct [blank nodes](https://www.w3.org/TR/rdf12-concepts/#dfn-blank-node)
+    /// (i.e. of distinct blank node identifiers)
+    pub blank_nodes: usize,
+    /// The number of distinct [literals](https://www.w3.org/TR/rdf12-concepts/#dfn-literal)
+    pub literals: usize,
+    /// The number of distinct [triple terms](https://www.w3.org/TR/rdf12-concepts/#dfn-triple-term)
+    pub triple_terms: usize,
+}
+
+impl TermStats {
+    /// The total number of distinct terms.
+    pub fn total(&self) -> usize {
+        self.iris + self.blank_nodes + self.literals + self.triple_terms
+    }
+}
+
+/// Count the distinct terms of each kind in `dataset`.
+///
+/// All the terms of every quad are counted, including predicates, graph names,
+/// and the terms nested in triple terms (see [`Quad::terms`] and [`Triple::for_each_nested_triple`]),
+/// but not the datatypes of literals.
+/// Terms are compared as per [`ErasedTerm`]'s equality,
+/// so that equal terms from different implementations are only counted once.
+///
+/// ```
+/// # use r2c2_statement::*;
+/// const EX: &str = "https://example.org/ns/";
+/// let d = vec![
+///     quad!(_:a, EX:name, "Alice", EX:g),
+///     quad!(_:a, EX:says, <<( _:b, EX:name, "Bob" )>>),
+/// ];
+/// let stats = term_stats(&d);
+/// assert_eq!(stats.iris, 3);
+/// assert_eq!(stats.blank_nodes, 2);
+/// assert_eq!(stats.literals, 2);
+/// assert_eq!(stats.triple_terms, 1);
+/// assert_eq!(stats.total(), 8);
+/// ```
+pub fn term_stats<D: Dataset + ?Sized>(dataset: &D) -> TermStats {
+    let mut seen = BTreeSet::new();
+    let mut stats = TermStats::default();
+    let mut count = |term: ErasedTerm| {
+        let counter = match term {
+            ErasedTerm::Iri(_) => &mut stats.iris,
+            ErasedTerm::BlankNode(_) => &mut stats.blank_nodes,
+            ErasedTerm::Literal(_) => &mut stats.literals,
+            ErasedTerm::Triple(_) => &mut stats.triple_terms,
+        };
+        if seen.insert(term) {
+            *counter += 1;
+        }
+    };
+    for quad in dataset.quads() {
+        for term in quad.terms() {
+            count(term.as_term_proxy().into());
+            if let TermProxy::Triple(triple) = term.as_term_proxy() {
+                triple.terms().for_each(|t| count(t.as_term_proxy().into()));
+                triple.for_each_nested_triple(|nested| {
+                    nested.terms().for_each(|t| count(t.as_term_proxy().into()));
+                });
+            }
+        }
+    }
+    stats
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{SimpleQuad, quad};
+
+    const EX: &str = "https://example.org/ns/";
+
+    #[test]
+    fn empty() {
+        let d: Vec<SimpleQuad> = vec![];
+        assert_eq!(term_stats(&d), TermStats::default());
+        assert_eq!(term_stats(&d).total(), 0);
+    }
+
+    #[test]
+    fn distinct() {
+        let d = vec![
+            quad!(EX:s, EX:p, "chat"@en),
+            // language tags are case-insensitive
+            quad!(EX:s, EX:p, "chat"@EN, EX:s),
+            quad!(_:a, EX:p, "chat"),
+            quad!(_:a, EX:p, <<( _:a, EX:p, <<( EX:s, EX:p, "chat" )>> )>>, _:g),
+            quad!(_:b, EX:q, <<( _:a, EX:p, <<( EX:s, EX:p, "chat" )>> )>>),
+        ];
+        assert_eq!(
+            term_stats(&d),
+            TermStats {
+                iris: 3,
+                blank_nodes: 3,
+                literals: 2,
+                triple_terms: 2,
+            }
+        );
+        // duplicates do not count
+        let mut d2 = d.clone();
+        d2.extend(d.iter().cloned());
+        assert_eq!(term_stats(&d2), term_stats(&d));
+    }
+}
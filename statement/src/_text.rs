@@ -0,0 +1,111 @@
+use alloc::{
+    borrow::Cow,
+    string::{String, ToString},
+    sync::Arc,
+};
+
+/// The underlying storage of [`Iri`](crate::Iri) and [`LangTag`](crate::LangTag).
+///
+/// This is similar to a [`Cow<str>`], but also admits the [`Text::Shared`] variant,
+/// which can be cloned without allocating.
+///
+/// All comparisons and hashes are those of the underlying [`str`],
+/// regardless of the variant.
+#[derive(Clone)]
+pub(crate) enum Text<'a> {
+    Borrowed(&'a str),
+    Owned(String),
+    Shared(Arc<str>),
+}
+
+impl<'a> Text<'a> {
+    pub(crate) fn as_str(&self) -> &str {
+        match self {
+            Text::Borrowed(txt) => txt,
+            Text::Owned(txt) => txt,
+            Text::Shared(txt) => txt,
+        }
+    }
+
+    /// Convert into a [`Cow<str>`], allocating a new [`String`] if this text is shared.
+    pub(crate) fn into_cow(self) -> Cow<'a, str> {
+        match self {
+            Text::Borrowed(txt) => Cow::Borrowed(txt),
+            Text::Owned(txt) => Cow::Owned(txt),
+            Text::Shared(txt) => Cow::Owned(txt.to_string()),
+        }
+    }
+
+    /// Convert into a text that does not borrow anything, keeping it shared if it already is.
+    pub(crate) fn into_owned(self) -> Text<'static> {
+        match self {
+            Text::Borrowed(txt) => Text::Owned(txt.to_string()),
+            Text::Owned(txt) => Text::Owned(txt),
+            Text::Shared(txt) => Text::Shared(txt),
+        }
+    }
+
+    /// Convert into a [`Text::Shared`] (allocating, unless it is already shared).
+    pub(crate) fn into_shared(self) -> Text<'static> {
+        match self {
+            Text::Borrowed(txt) => Text::Shared(Arc::from(txt)),
+            Text::Owned(txt) => Text::Shared(Arc::from(txt)),
+            Text::Shared(txt) => Text::Shared(txt),
+        }
+    }
+}
+
+impl<'a> From<Cow<'a, str>> for Text<'a> {
+    fn from(value: Cow<'a, str>) -> Self {
+        match value {
+            Cow::Borrowed(txt) => Text::Borrowed(txt),
+            Cow::Owned(txt) => Text::Owned(txt),
+        }
+    }
+}
+
+impl AsRef<str> for Text<'_> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl core::ops::Deref for Text<'_> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl core::fmt::Debug for Text<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl core::hash::Hash for Text<'_> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl PartialEq for Text<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for Text<'_> {}
+
+impl Ord for Text<'_> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl PartialOrd for Text<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
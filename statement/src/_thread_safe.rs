@@ -0,0 +1,50 @@
+use crate::{Quad, Triple};
+
+/// A [`Triple`] that can be shared and sent across threads.
+///
+/// [`Triple`] does not require [`Send`] and [`Sync`] by itself,
+/// so that implementations are free to use non-thread-safe types (e.g. [`Rc`](alloc::rc::Rc)).
+/// This trait can be used as a bound by code that does require thread safety.
+/// It is automatically implemented by any [`Triple`] that is [`Send`] and [`Sync`].
+pub trait ThreadSafeTriple: Triple + Send + Sync {}
+
+impl<T: Triple + Send + Sync + ?Sized> ThreadSafeTriple for T {}
+
+/// A [`Quad`] that can be shared and sent across threads.
+///
+/// See [`ThreadSafeTriple`].
+pub trait ThreadSafeQuad: Quad + Send + Sync {}
+
+impl<Q: Quad + Send + Sync + ?Sized> ThreadSafeQuad for Q {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        GraphNameProxy, Iri, LangTag, Literal, ObjectProxy, SimpleQuad, SimpleTriple, SubjectProxy,
+    };
+
+    fn assert_send_sync<T: Send + Sync + ?Sized>() {}
+
+    fn assert_thread_safe_triple<T: ThreadSafeTriple + ?Sized>() {}
+
+    fn assert_thread_safe_quad<Q: ThreadSafeQuad + ?Sized>() {}
+
+    #[test]
+    fn utility_types() {
+        assert_send_sync::<Iri<'static>>();
+        assert_send_sync::<LangTag<'static>>();
+        assert_send_sync::<Literal<'static>>();
+        assert_send_sync::<SubjectProxy<'static>>();
+        assert_send_sync::<ObjectProxy<'static, SimpleTriple<'static>>>();
+        assert_send_sync::<GraphNameProxy<'static>>();
+    }
+
+    #[test]
+    fn statements() {
+        assert_thread_safe_triple::<SimpleTriple<'static>>();
+        assert_thread_safe_triple::<&SimpleTriple<'static>>();
+        assert_thread_safe_triple::<Box<SimpleTriple<'static>>>();
+        assert_thread_safe_quad::<SimpleQuad<'static>>();
+    }
+}
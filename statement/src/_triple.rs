@@ -1,4 +1,9 @@
-use crate::{Object, Predicate, Subject};
+use alloc::{boxed::Box, vec::Vec};
+use core::{ops::ControlFlow, ptr::NonNull};
+
+use crate::{
+    DynTriple, Iri, Literal, Object, ObjectKind, ObjectProxy, Predicate, Subject, SubjectProxy,
+};
 
 /// A trait for [RDF triples].
 ///
@@ -42,15 +47,289 @@ pub trait Triple {
     /// [object]: https://www.w3.org/TR/rdf12-concepts/#dfn-object
     fn object(&self) -> Self::Object<'_>;
 
+    /// The [subject], [predicate] and [object] of this triple, in a single call.
+    ///
+    /// This is convenient for destructuring a triple with a `let` or a `match`.
+    /// See also [`SpoProxies::spo_proxies`] for getting the proxies of all three terms at once.
+    ///
+    /// [subject]: https://www.w3.org/TR/rdf12-concepts/#dfn-subject
+    /// [predicate]: https://www.w3.org/TR/rdf12-concepts/#dfn-predicate
+    /// [object]: https://www.w3.org/TR/rdf12-concepts/#dfn-object
+    fn spo(&self) -> (Self::Subject<'_>, Self::Predicate<'_>, Self::Object<'_>) {
+        (self.subject(), self.predicate(), self.object())
+    }
+
+    /// Whether the [predicate] of this triple is `iri`.
+    ///
+    /// [predicate]: https://www.w3.org/TR/rdf12-concepts/#dfn-predicate
+    fn has_predicate(&self, iri: &Iri) -> bool {
+        self.predicate().as_iri() == *iri
+    }
+
+    /// Whether the [subject] of this triple is `iri`.
+    ///
+    /// [subject]: https://www.w3.org/TR/rdf12-concepts/#dfn-subject
+    fn has_subject_iri(&self, iri: &Iri) -> bool {
+        self.subject().as_iri().is_some_and(|s| s == *iri)
+    }
+
+    /// The [object] of this triple, if it is a [literal].
+    ///
+    /// # Implementers
+    /// The default implementation returns an owned copy of the literal,
+    /// because the proxy returned by [`Object::as_object_proxy`] borrows the value returned by
+    /// [`Triple::object`], which is not necessarily borrowed from the triple itself.
+    /// Implementations where this is the case should override this method to avoid the copy.
+    ///
+    /// [object]: https://www.w3.org/TR/rdf12-concepts/#dfn-object
+    /// [literal]: https://www.w3.org/TR/rdf12-concepts/#dfn-literal
+    fn object_literal(&self) -> Option<Literal<'_>> {
+        match self.object().as_object_proxy() {
+            ObjectProxy::Literal(literal) => Some(crate::_simple_triple::owned_literal(literal)),
+            _ => None,
+        }
+    }
+
     /// Whether this triple is [ground](https://https://www.w3.org/TR/rdf12-concepts/#dfn-ground).
+    ///
+    /// # Implementers
+    /// The default implementation walks the nested triple terms through their proxies
+    /// (rather than through their own implementation of this method),
+    /// keeping them on the heap rather than on the call stack,
+    /// so that it handles any depth of nesting.
     fn ground(&self) -> bool {
-        self.subject().ground() && self.object().ground()
+        if !self.subject().ground() {
+            return false;
+        }
+        match walk_nested_triples(self, |nested| {
+            if Subject::ground(&nested.dyn_subject()) {
+                ControlFlow::Continue(())
+            } else {
+                ControlFlow::Break(())
+            }
+        }) {
+            ControlFlow::Continue(innermost) => innermost != ObjectKind::BlankNode,
+            ControlFlow::Break(()) => false,
+        }
+    }
+
+    /// Whether the object of this triple is a [triple term].
+    ///
+    /// Since triple terms can only appear in the object position,
+    /// this is the case if and only if [`Triple::triple_term_depth`] is not 0.
+    ///
+    /// [triple term]: https://www.w3.org/TR/rdf12-concepts/#dfn-triple-term
+    fn contains_triple_term(&self) -> bool {
+        self.object().object_kind() == crate::ObjectKind::Triple
+    }
+
+    /// The number of [triple terms] nested in this triple (see [`Object::triple_term_depth`]).
+    ///
+    /// # Implementers
+    /// As for [`Triple::ground`], the default implementation walks the nested triple terms on the heap,
+    /// so that it handles any depth of nesting.
+    ///
+    /// [triple terms]: https://www.w3.org/TR/rdf12-concepts/#dfn-triple-term
+    fn triple_term_depth(&self) -> usize {
+        let mut depth = 0;
+        let _ = walk_nested_triples(self, |_| {
+            depth += 1;
+            ControlFlow::Continue(())
+        });
+        depth
+    }
+
+    /// Call `f` on each of the [triple terms] nested in this triple,
+    /// from the outermost (the object of this triple) to the innermost.
+    ///
+    /// Unlike [`SimpleTriple::nested_triples`](crate::SimpleTriple::nested_triples),
+    /// this can not be an iterator, because each nested triple term may have a different type,
+    /// borrowed from a temporary value; they are therefore passed to `f` as a [`DynTriple`](crate::DynTriple).
+    ///
+    /// ```
+    /// # use r2c2_statement::*;
+    /// const EX: &str = "https://example.org/ns/";
+    /// let t = triple!(EX:a, EX:says, <<( EX:b, EX:says, <<( EX:c, EX:p, EX:o )>> )>>);
+    /// let mut subjects = vec![];
+    /// (&t).for_each_nested_triple(|nt| subjects.push(nt.subject().as_subject_proxy().into_owned()));
+    /// assert_eq!(subjects, vec![
+    ///     SubjectProxy::Iri(Iri::new_unchecked("https://example.org/ns/b")),
+    ///     SubjectProxy::Iri(Iri::new_unchecked("https://example.org/ns/c")),
+    /// ]);
+    /// ```
+    ///
+    /// # Implementers
+    /// As for [`Triple::ground`], the default implementation walks the nested triple terms on the heap,
+    /// so that it handles any depth of nesting.
+    ///
+    /// [triple terms]: https://www.w3.org/TR/rdf12-concepts/#dfn-triple-term
+    fn for_each_nested_triple(&self, mut f: impl FnMut(&dyn DynTriple)) {
+        let _ = walk_nested_triples(self, |nested| {
+            f(nested);
+            ControlFlow::Continue(())
+        });
+    }
+
+    /// Iterate over the [subject], [predicate] and [object] of this triple
+    /// (see [`Terms`](crate::Terms)).
+    ///
+    /// [subject]: https://www.w3.org/TR/rdf12-concepts/#dfn-subject
+    /// [predicate]: https://www.w3.org/TR/rdf12-concepts/#dfn-predicate
+    /// [object]: https://www.w3.org/TR/rdf12-concepts/#dfn-object
+    fn terms(&self) -> crate::Terms<Self::Subject<'_>, Self::Predicate<'_>, Self::Object<'_>> {
+        crate::Terms::new(self.spo(), None)
+    }
+}
+
+/// Call `f` on each of the triple terms nested in `triple`, from the outermost to the innermost,
+/// until it breaks, and return the kind of the innermost object (which is not a triple term).
+///
+/// Each nested triple term may have a different type, borrowed from a temporary value
+/// (the object, or the proxy of the object, of the enclosing triple),
+/// so the objects and triple terms are boxed as [`Nested`] trait objects,
+/// and kept alive in a [`NestedStack`] while the next ones are walked,
+/// which (unlike recursion) works for any depth of nesting.
+///
+/// Each object is turned into a proxy only once.
+fn walk_nested_triples<T: Triple + ?Sized>(
+    triple: &T,
+    mut f: impl FnMut(&dyn DynTriple) -> ControlFlow<()>,
+) -> ControlFlow<(), ObjectKind> {
+    let mut stack = NestedStack(Vec::new());
+    let mut current = stack.push(Box::new(NestedObject(triple.object())));
+    loop {
+        // SAFETY: current is alive until stack is dropped (see NestedStack)
+        let node = unsafe { current.as_ref() };
+        if let Some(nested) = node.triple() {
+            f(nested)?;
+        }
+        match node.next() {
+            Ok(next) => current = stack.push(next),
+            Err(innermost) => return ControlFlow::Continue(innermost),
+        }
+    }
+}
+
+/// A level of the walk of [`walk_nested_triples`]:
+/// either the object of a triple ([`NestedObject`]), or a triple term ([`NestedTriple`]).
+trait Nested {
+    /// The next level: the triple term of a [`NestedObject`]
+    /// (or its kind, if it is not a triple term), or the object of a [`NestedTriple`].
+    fn next(&self) -> Result<Box<dyn Nested + '_>, ObjectKind>;
+
+    /// The triple term of a [`NestedTriple`].
+    fn triple(&self) -> Option<&dyn DynTriple>;
+}
+
+struct NestedObject<O>(O);
+
+impl<O: Object> Nested for NestedObject<O> {
+    fn next(&self) -> Result<Box<dyn Nested + '_>, ObjectKind> {
+        match self.0.as_object_proxy() {
+            ObjectProxy::Triple(triple) => Ok(Box::new(NestedTriple(triple))),
+            proxy => Err(proxy.object_kind()),
+        }
+    }
+
+    fn triple(&self) -> Option<&dyn DynTriple> {
+        None
+    }
+}
+
+struct NestedTriple<T>(T);
+
+impl<T: Triple> Nested for NestedTriple<T> {
+    fn next(&self) -> Result<Box<dyn Nested + '_>, ObjectKind> {
+        Ok(Box::new(NestedObject(self.0.object())))
+    }
+
+    fn triple(&self) -> Option<&dyn DynTriple> {
+        Some(&self.0)
+    }
+}
+
+/// The levels of [`walk_nested_triples`], each one borrowing from the previous one
+/// (and the first one from the walked triple).
+///
+/// The levels are stored as raw pointers to boxes (which, unlike the boxes themselves, can be moved
+/// without invalidating the borrows of their content), with their lifetime erased,
+/// and they are dropped in the reverse order of their creation (including when unwinding),
+/// so that no level outlives what it borrows.
+struct NestedStack(Vec<NonNull<dyn Nested>>);
+
+impl NestedStack {
+    /// Push `level`, and return a pointer to it, valid until this stack is dropped.
+    ///
+    /// `level` must only borrow from the levels already in this stack,
+    /// or from values outliving this stack.
+    fn push(&mut self, level: Box<dyn Nested + '_>) -> NonNull<dyn Nested> {
+        let ptr: *mut (dyn Nested + '_) = Box::into_raw(level);
+        // SAFETY: only the lifetime is changed (see the documentation of NestedStack)
+        let ptr: *mut (dyn Nested + 'static) = unsafe { core::mem::transmute(ptr) };
+        // SAFETY: Box::into_raw never returns a null pointer
+        let ptr = unsafe { NonNull::new_unchecked(ptr) };
+        self.0.push(ptr);
+        ptr
+    }
+}
+
+impl Drop for NestedStack {
+    fn drop(&mut self) {
+        while let Some(ptr) = self.0.pop() {
+            // SAFETY: ptr was obtained from Box::into_raw, and no level borrowing from it remains
+            drop(unsafe { Box::from_raw(ptr.as_ptr()) });
+        }
+    }
+}
+
+/// Extension trait for the tuples returned by [`Triple::spo`],
+/// providing the proxies of the three terms at once.
+///
+/// The proxies can not be returned directly by a method of [`Triple`],
+/// because they borrow the terms returned by [`Triple::subject`], [`Triple::predicate`] and [`Triple::object`],
+/// which are not necessarily borrowed from the triple itself.
+/// The tuple returned by [`Triple::spo`] must therefore be stored in a variable:
+///
+/// ```
+/// # use r2c2_statement::*;
+/// fn describe<T: Triple>(triple: &T) -> String {
+///     let spo = triple.spo();
+///     match spo.spo_proxies() {
+///         (SubjectProxy::Iri(s), p, ObjectProxy::Literal(lit)) => {
+///             format!("{s} has {p} = {}", lit.lexical_form())
+///         }
+///         _ => "something else".into(),
+///     }
+/// }
+/// ```
+pub trait SpoProxies {
+    /// The type of triple terms in the object proxy.
+    type Triple<'x>: Triple
+    where
+        Self: 'x;
+
+    /// Return the [`SubjectProxy`], [`Iri`] and [`ObjectProxy`] of the three terms.
+    fn spo_proxies(&self) -> (SubjectProxy<'_>, Iri<'_>, ObjectProxy<'_, Self::Triple<'_>>);
+}
+
+impl<S: Subject, P: Predicate, O: Object> SpoProxies for (S, P, O) {
+    type Triple<'x>
+        = O::Triple<'x>
+    where
+        Self: 'x;
+
+    fn spo_proxies(&self) -> (SubjectProxy<'_>, Iri<'_>, ObjectProxy<'_, Self::Triple<'_>>) {
+        (
+            self.0.as_subject_proxy(),
+            self.1.as_iri(),
+            self.2.as_object_proxy(),
+        )
     }
 }
 
 /// Any reference to a [`Triple`] also trivially implements [`Triple`]
 /// (as all methods of [`Triple`] apply to `&self` anyway).
-impl<T: Triple> Triple for &'_ T {
+impl<T: Triple + ?Sized> Triple for &'_ T {
     type Subject<'x>
         = T::Subject<'x>
     where
@@ -77,11 +356,47 @@ impl<T: Triple> Triple for &'_ T {
     fn object(&self) -> Self::Object<'_> {
         (*self).object()
     }
+
+    fn spo(&self) -> (Self::Subject<'_>, Self::Predicate<'_>, Self::Object<'_>) {
+        (*self).spo()
+    }
+
+    fn has_predicate(&self, iri: &Iri) -> bool {
+        (*self).has_predicate(iri)
+    }
+
+    fn has_subject_iri(&self, iri: &Iri) -> bool {
+        (*self).has_subject_iri(iri)
+    }
+
+    fn object_literal(&self) -> Option<Literal<'_>> {
+        (*self).object_literal()
+    }
+
+    fn ground(&self) -> bool {
+        (*self).ground()
+    }
+
+    fn contains_triple_term(&self) -> bool {
+        (*self).contains_triple_term()
+    }
+
+    fn triple_term_depth(&self) -> usize {
+        (*self).triple_term_depth()
+    }
+
+    fn for_each_nested_triple(&self, f: impl FnMut(&dyn DynTriple)) {
+        (*self).for_each_nested_triple(f)
+    }
+
+    fn terms(&self) -> crate::Terms<Self::Subject<'_>, Self::Predicate<'_>, Self::Object<'_>> {
+        (*self).terms()
+    }
 }
 
 /// Any boxed [`Triple`] also trivially implements [`Triple`]
 /// (as all methods of [`Triple`] apply to `&self` anyway).
-impl<T: Triple> Triple for Box<T> {
+impl<T: Triple + ?Sized> Triple for Box<T> {
     type Subject<'x>
         = T::Subject<'x>
     where
@@ -108,9 +423,49 @@ impl<T: Triple> Triple for Box<T> {
     fn object(&self) -> Self::Object<'_> {
         self.as_ref().object()
     }
+
+    fn spo(&self) -> (Self::Subject<'_>, Self::Predicate<'_>, Self::Object<'_>) {
+        self.as_ref().spo()
+    }
+
+    fn has_predicate(&self, iri: &Iri) -> bool {
+        self.as_ref().has_predicate(iri)
+    }
+
+    fn has_subject_iri(&self, iri: &Iri) -> bool {
+        self.as_ref().has_subject_iri(iri)
+    }
+
+    fn object_literal(&self) -> Option<Literal<'_>> {
+        self.as_ref().object_literal()
+    }
+
+    fn ground(&self) -> bool {
+        self.as_ref().ground()
+    }
+
+    fn contains_triple_term(&self) -> bool {
+        self.as_ref().contains_triple_term()
+    }
+
+    fn triple_term_depth(&self) -> usize {
+        self.as_ref().triple_term_depth()
+    }
+
+    fn for_each_nested_triple(&self, f: impl FnMut(&dyn DynTriple)) {
+        self.as_ref().for_each_nested_triple(f)
+    }
+
+    fn terms(&self) -> crate::Terms<Self::Subject<'_>, Self::Predicate<'_>, Self::Object<'_>> {
+        self.as_ref().terms()
+    }
 }
 
 /// A utility empty type for indicating that a given implementation does not support triple terms.
+///
+/// As it has no value, it trivially implements all the usual traits,
+/// so that proxies using it (e.g. [`ObjectProxy<NeverTriple>`](crate::ObjectProxy)) do as well.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum NeverTriple {}
 
 impl Triple for NeverTriple {
@@ -0,0 +1,183 @@
+use alloc::borrow::Cow;
+
+use crate::{
+    GraphName, GraphNameProxy, Iri, Literal, Object, ObjectProxy, Predicate, Quad, Subject,
+    SubjectProxy, Triple,
+};
+
+/// A visitor of the [RDF terms] of a statement, driven by [`walk_triple`] or [`walk_quad`].
+///
+/// All methods do nothing by default,
+/// so that implementers only need to override the ones relevant to them.
+///
+/// ```
+/// # use r2c2_statement::*;
+/// const EX: &str = "https://example.org/ns/";
+/// struct IriCollector(Vec<String>);
+/// impl TermVisitor for IriCollector {
+///     fn visit_iri(&mut self, iri: Iri<'_>) {
+///         self.0.push(iri.as_str().to_string());
+///     }
+/// }
+///
+/// let t = triple!(_:a, EX:says, <<( EX:s, EX:p, "o" )>>);
+/// let mut collector = IriCollector(vec![]);
+/// walk_triple(&t, &mut collector);
+/// assert_eq!(
+///     collector.0,
+///     [
+///         "https://example.org/ns/says",
+///         "https://example.org/ns/s",
+///         "https://example.org/ns/p",
+///     ]
+/// );
+/// ```
+///
+/// [RDF terms]: https://www.w3.org/TR/rdf12-concepts/#dfn-rdf-term
+pub trait TermVisitor {
+    /// Visit an [IRI](https://www.w3.org/TR/rdf12-concepts/#section-IRIs),
+    /// in any position (including the predicate).
+    fn visit_iri(&mut self, iri: Iri<'_>) {
+        let _ = iri;
+    }
+
+    /// Visit a [blank node](https://www.w3.org/TR/rdf12-concepts/#dfn-blank-node),
+    /// via its internal identifier.
+    fn visit_blank_node(&mut self, bnid: Cow<'_, str>) {
+        let _ = bnid;
+    }
+
+    /// Visit a [literal](https://www.w3.org/TR/rdf12-concepts/#dfn-literal).
+    ///
+    /// Note that the datatype of the literal is *not* passed to [`TermVisitor::visit_iri`].
+    fn visit_literal(&mut self, literal: Literal<'_>) {
+        let _ = literal;
+    }
+}
+
+/// Visit every term of `triple` with `visitor`,
+/// in the order subject, predicate, object,
+/// descending into [triple terms](https://www.w3.org/TR/rdf12-concepts/#dfn-triple-term)
+/// (which are not passed to `visitor` themselves).
+pub fn walk_triple<T: Triple + ?Sized, V: TermVisitor + ?Sized>(triple: &T, visitor: &mut V) {
+    walk_subject(triple.subject().as_subject_proxy(), visitor);
+    visitor.visit_iri(triple.predicate().as_iri());
+    walk_object(triple.object().as_object_proxy(), visitor);
+}
+
+/// Visit every term of `quad` with `visitor`,
+/// as [`walk_triple`] does, followed by its graph name (if any).
+pub fn walk_quad<Q: Quad + ?Sized, V: TermVisitor + ?Sized>(quad: &Q, visitor: &mut V) {
+    walk_subject(quad.subject().as_subject_proxy(), visitor);
+    visitor.visit_iri(quad.predicate().as_iri());
+    walk_object(quad.object().as_object_proxy(), visitor);
+    match quad
+        .graph_name()
+        .as_ref()
+        .map(GraphName::as_graph_name_proxy)
+    {
+        Some(GraphNameProxy::Iri(iri)) => visitor.visit_iri(iri),
+        Some(GraphNameProxy::BlankNode(bnid)) => visitor.visit_blank_node(bnid),
+        None => {}
+    }
+}
+
+fn walk_subject<V: TermVisitor + ?Sized>(subject: SubjectProxy, visitor: &mut V) {
+    match subject {
+        SubjectProxy::Iri(iri) => visitor.visit_iri(iri),
+        SubjectProxy::BlankNode(bnid) => visitor.visit_blank_node(bnid),
+    }
+}
+
+fn walk_object<T: Triple, V: TermVisitor + ?Sized>(object: ObjectProxy<T>, visitor: &mut V) {
+    match object {
+        ObjectProxy::Iri(iri) => visitor.visit_iri(iri),
+        ObjectProxy::BlankNode(bnid) => visitor.visit_blank_node(bnid),
+        ObjectProxy::Literal(literal) => visitor.visit_literal(literal),
+        ObjectProxy::Triple(triple) => walk_triple(&triple, visitor),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{quad, triple};
+
+    const EX: &str = "https://example.org/ns/";
+
+    /// Record every visited term in N-Triples syntax
+    #[derive(Default)]
+    struct Recorder(Vec<String>);
+
+    impl TermVisitor for Recorder {
+        fn visit_iri(&mut self, iri: Iri<'_>) {
+            self.0.push(iri.to_string());
+        }
+
+        fn visit_blank_node(&mut self, bnid: Cow<'_, str>) {
+            self.0.push(format!("_:{bnid}"));
+        }
+
+        fn visit_literal(&mut self, literal: Literal<'_>) {
+            self.0
+                .push(ObjectProxy::<crate::NeverTriple>::Literal(literal).to_string());
+        }
+    }
+
+    #[test]
+    fn nested() {
+        let t = triple!(_:a, EX:p, <<( _:b, EX:q, <<( EX:s, EX:r, "chat"@en )>> )>>);
+        let mut rec = Recorder::default();
+        walk_triple(&t, &mut rec);
+        assert_eq!(
+            rec.0,
+            [
+                "_:a",
+                "<https://example.org/ns/p>",
+                "_:b",
+                "<https://example.org/ns/q>",
+                "<https://example.org/ns/s>",
+                "<https://example.org/ns/r>",
+                r#""chat"@en"#,
+            ]
+        );
+    }
+
+    #[test]
+    fn quads() {
+        let mut rec = Recorder::default();
+        walk_quad(&quad!(EX:s, EX:p, _:o, _:g), &mut rec);
+        walk_quad(&quad!(EX:s, EX:p, "42", EX:g), &mut rec);
+        walk_quad(&quad!(_:s, EX:p, EX:o), &mut rec);
+        assert_eq!(
+            rec.0,
+            [
+                "<https://example.org/ns/s>",
+                "<https://example.org/ns/p>",
+                "_:o",
+                "_:g",
+                "<https://example.org/ns/s>",
+                "<https://example.org/ns/p>",
+                r#""42""#,
+                "<https://example.org/ns/g>",
+                "_:s",
+                "<https://example.org/ns/p>",
+                "<https://example.org/ns/o>",
+            ]
+        );
+    }
+
+    #[test]
+    fn default_methods() {
+        struct BnodeCounter(usize);
+        impl TermVisitor for BnodeCounter {
+            fn visit_blank_node(&mut self, _: Cow<'_, str>) {
+                self.0 += 1;
+            }
+        }
+        let mut counter = BnodeCounter(0);
+        walk_triple(&triple!(_:a, EX:p, <<( _:b, EX:q, "o" )>>), &mut counter);
+        walk_quad(&quad!(EX:s, EX:p, EX:o, _:g), &mut counter);
+        assert_eq!(counter.0, 3);
+    }
+}
@@ -0,0 +1,376 @@
+//! Direct conversions between the RDF implementations supported by the `poc_impl` feature.
+//!
+//! Only present with the `poc_impl` feature.
+//!
+//! These functions are implemented purely in terms of this crate's traits,
+//! illustrating how R2C2 allows to bridge independent RDF implementations.
+use crate::{convert, convert_quad, impl_oxrdf};
+use oxrdf as ox;
+use rdf_types as rt;
+
+/// Convert an [`oxrdf`] triple into an [`rdf_types`] triple.
+///
+/// This fails if the triple contains a triple term, which [`rdf_types`] does not support.
+pub fn oxrdf_to_rdf_types_triple(t: &ox::Triple) -> Result<rt::LexicalTriple, &'static str> {
+    convert(t)
+}
+
+/// Convert an [`rdf_types`] triple into an [`oxrdf`] triple.
+pub fn rdf_types_to_oxrdf_triple(t: &rt::LexicalTriple) -> ox::Triple {
+    impl_oxrdf::from_r2c2_triple(t)
+}
+
+/// Convert an [`oxrdf`] quad into an [`rdf_types`] quad.
+///
+/// This fails if the quad contains a triple term, which [`rdf_types`] does not support.
+pub fn oxrdf_to_rdf_types_quad(q: &ox::Quad) -> Result<rt::LexicalQuad, &'static str> {
+    convert_quad(q)
+}
+
+/// Convert an [`rdf_types`] quad into an [`oxrdf`] quad.
+pub fn rdf_types_to_oxrdf_quad(q: &rt::LexicalQuad) -> ox::Quad {
+    impl_oxrdf::from_r2c2_quad(q)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn triple() -> TestResult {
+        let t1 = ox::Triple::new(
+            ox::BlankNode::new("b1")?,
+            ox::NamedNode::new("https://example.org/ns/p")?,
+            ox::Literal::new_language_tagged_literal("chat", "en")?,
+        );
+        let t2 = oxrdf_to_rdf_types_triple(&t1)?;
+        assert_eq!(rdf_types_to_oxrdf_triple(&t2), t1);
+        Ok(())
+    }
+
+    #[test]
+    fn triple_term() -> TestResult {
+        let inner = ox::Triple::new(
+            ox::NamedNode::new("https://example.org/ns/s")?,
+            ox::NamedNode::new("https://example.org/ns/p")?,
+            ox::NamedNode::new("https://example.org/ns/o")?,
+        );
+        let t1 = ox::Triple::new(
+            ox::BlankNode::new("b1")?,
+            ox::NamedNode::new("https://example.org/ns/p")?,
+            inner,
+        );
+        assert!(oxrdf_to_rdf_types_triple(&t1).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn quad() -> TestResult {
+        for graph_name in [
+            ox::GraphName::DefaultGraph,
+            ox::NamedNode::new("https://example.org/")?.into(),
+            ox::BlankNode::new("g")?.into(),
+        ] {
+            let q1 = ox::Quad::new(
+                ox::BlankNode::new("b1")?,
+                ox::NamedNode::new("https://example.org/ns/p")?,
+                ox::Literal::new_simple_literal("⛄"),
+                graph_name,
+            );
+            let q2 = oxrdf_to_rdf_types_quad(&q1)?;
+            assert_eq!(rdf_types_to_oxrdf_quad(&q2), q1);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn generic_conversion() -> TestResult {
+        use crate::{FromQuad, FromTriple, Quad, Triple, quad_eq, triple_eq};
+
+        fn round_trip<S: Triple, D: FromTriple + Triple>(s: &S) -> Result<D, D::Error> {
+            let d = D::from_triple(s)?;
+            assert!(triple_eq(s, &d));
+            Ok(d)
+        }
+
+        fn round_trip_quad<S: Quad, D: FromQuad + Quad>(s: &S) -> Result<D, D::Error> {
+            let d = D::from_quad(s)?;
+            assert!(quad_eq(s, &d));
+            Ok(d)
+        }
+
+        let t1 = ox::Triple::new(
+            ox::NamedNode::new("https://example.org/ns/s")?,
+            ox::NamedNode::new("https://example.org/ns/p")?,
+            ox::Literal::new_typed_literal("42", ox::NamedNode::new("https://example.org/ns/dt")?),
+        );
+        let t2: rt::LexicalTriple = round_trip(&t1)?;
+        let t3: ox::Triple = round_trip(&t2)?;
+        assert_eq!(t3, t1);
+
+        let q1 = ox::Quad::new(
+            ox::BlankNode::new("b1")?,
+            ox::NamedNode::new("https://example.org/ns/p")?,
+            ox::Literal::new_language_tagged_literal("chat", "fr")?,
+            ox::NamedNode::new("https://example.org/g")?,
+        );
+        let q2: rt::LexicalQuad = round_trip_quad(&q1)?;
+        let q3: ox::Quad = round_trip_quad(&q2)?;
+        assert_eq!(q3, q1);
+        Ok(())
+    }
+
+    #[test]
+    fn quad_key() -> TestResult {
+        use crate::{FromQuad, QuadKey, dedup_quads, quad};
+
+        const EX: &str = "https://example.org/ns/";
+        let ox_quads = [
+            ox::Quad::new(
+                ox::BlankNode::new("b1")?,
+                ox::NamedNode::new("https://example.org/ns/p")?,
+                ox::Literal::new_simple_literal("chat"),
+                ox::NamedNode::new("https://example.org/ns/g")?,
+            ),
+            ox::Quad::new(
+                ox::BlankNode::new("b1")?,
+                ox::NamedNode::new("https://example.org/ns/p")?,
+                ox::Literal::new_language_tagged_literal("chat", "en-gb")?,
+                ox::GraphName::DefaultGraph,
+            ),
+        ];
+        let rt_quads = [
+            rt::LexicalQuad::from_quad(&quad!(
+                _:b1,
+                EX:p,
+                "chat"^^<"http://www.w3.org/2001/XMLSchema#string">,
+                EX:g
+            ))?,
+            rt::LexicalQuad::from_quad(&quad!(_:b1, EX:p, "chat"@EN-GB))?,
+        ];
+        for (q1, q2) in ox_quads.iter().zip(&rt_quads) {
+            let k1 = QuadKey::from_quad(q1);
+            let k2 = QuadKey::from_quad(q2);
+            assert_eq!(k1, k2);
+            assert_eq!(k1.as_quad(), k2.as_quad());
+        }
+        assert_eq!(
+            dedup_quads(ox_quads.iter().chain(&ox_quads)).count(),
+            ox_quads.len()
+        );
+        let mixed = dedup_quads(
+            ox_quads
+                .iter()
+                .map(QuadKey::from_quad)
+                .chain(rt_quads.iter().map(QuadKey::from_quad))
+                .map(QuadKey::into_quad),
+        );
+        assert_eq!(mixed.count(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn term_factories() -> TestResult {
+        use crate::{
+            BaseDir, Iri, LangTag, Literal, TermFactory, impl_oxrdf::OxrdfTermFactory,
+            impl_rdf_types::RdfTypesTermFactory, triple_eq,
+        };
+
+        fn build_graph<F: TermFactory>(f: &mut F) -> Result<Vec<F::Triple>, F::Error> {
+            let ex = |local: &str| Iri::new_unchecked(format!("https://example.org/ns/{local}"));
+            let (alice1, alice2, bob) = (f.bnode("alice"), f.bnode("alice"), f.bnode("b o b"));
+            let (knows1, knows2) = (f.iri(ex("knows")), f.iri(ex("knows")));
+            let (name, label) = (f.iri(ex("name")), f.iri(ex("label")));
+            let alice_name = f.literal(Literal::LanguageString(
+                "Alice".into(),
+                LangTag::new_unchecked("en"),
+                None,
+            ))?;
+            let knows_label = f.literal(Literal::Typed("knows".into(), crate::ns::xsd::STRING))?;
+            Ok(vec![
+                f.triple(alice1, knows1, bob)?,
+                f.triple(alice2, name, alice_name)?,
+                f.triple(knows2, label, knows_label)?,
+            ])
+        }
+
+        let g1 = build_graph(&mut OxrdfTermFactory::default())?;
+        let g2 = build_graph(&mut RdfTypesTermFactory::default())?;
+        assert_eq!(g1.len(), g2.len());
+        for (t1, t2) in g1.iter().zip(&g2) {
+            assert!(triple_eq(t1, t2));
+        }
+
+        let mut f = OxrdfTermFactory::with_bnode_label(|label| format!("x{}", label.len()));
+        assert_eq!(f.bnode("alice"), ox::BlankNode::new("x5")?.into());
+        // labels with '.' are valid in N-Triples, but not in rdf_types
+        let bnode = |label: &str| -> rt::Object {
+            rt::Object::Id(rt::Id::Blank(rt::BlankIdBuf::new(label.into()).unwrap()))
+        };
+        assert_eq!(
+            RdfTypesTermFactory::default().bnode("a.b"),
+            bnode("_:a_2e_b")
+        );
+        let mut f = RdfTypesTermFactory::with_bnode_label(|label| format!("{label}.x"));
+        assert_eq!(f.bnode("a"), bnode("_:a_2e_x"));
+        let mut f = RdfTypesTermFactory::with_bnode_label(|label| format!("x{}", label.len()));
+        assert_eq!(f.bnode("alice"), bnode("_:x5"));
+
+        let dir_lit = Literal::LanguageString(
+            "Alice".into(),
+            LangTag::new_unchecked("en"),
+            Some(BaseDir::Ltr),
+        );
+        assert!(
+            OxrdfTermFactory::default()
+                .literal(dir_lit.clone())
+                .is_err()
+        );
+        assert!(RdfTypesTermFactory::default().literal(dir_lit).is_err());
+
+        let t = g1[0].clone();
+        assert!(OxrdfTermFactory::default().triple_term(t).is_ok());
+        let t = g2[0].clone();
+        assert!(RdfTypesTermFactory::default().triple_term(t).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn term_eq() -> TestResult {
+        use crate::{graph_name_eq, object_eq, predicate_eq, subject_eq, triple};
+
+        let ox_iri = ox::NamedNode::new("https://example.org/ns/a")?;
+        let rt_iri = rt::IriBuf::new("https://example.org/ns/a".into())?;
+        let rt_other = rt::IriBuf::new("https://example.org/ns/b".into())?;
+        let ox_bnode = ox::BlankNode::new("b1")?;
+        let rt_bnode = rt::BlankIdBuf::new("_:b1".into()).unwrap();
+
+        // subjects
+        let ox_s: ox::Subject = ox_iri.clone().into();
+        assert!(subject_eq(&ox_s, &rt::Subject::Iri(rt_iri.clone())));
+        assert!(!subject_eq(&ox_s, &rt::Subject::Iri(rt_other.clone())));
+        let ox_s: ox::Subject = ox_bnode.clone().into();
+        assert!(subject_eq(&ox_s, &rt::Subject::Blank(rt_bnode.clone())));
+        assert!(!subject_eq(&ox_s, &rt::Subject::Iri(rt_iri.clone())));
+
+        // predicates
+        assert!(predicate_eq(&ox_iri, &rt_iri));
+        assert!(!predicate_eq(&ox_iri, &rt_other));
+
+        // objects
+        let ox_o: ox::Term = ox_iri.clone().into();
+        assert!(object_eq(
+            &ox_o,
+            &rt::Object::Id(rt::Id::Iri(rt_iri.clone()))
+        ));
+        let ox_o: ox::Term = ox_bnode.clone().into();
+        assert!(object_eq(
+            &ox_o,
+            &rt::Object::Id(rt::Id::Blank(rt_bnode.clone()))
+        ));
+        assert!(!object_eq(
+            &ox_o,
+            &rt::Object::Id(rt::Id::Iri(rt_iri.clone()))
+        ));
+        // simple literals are literals with datatype xsd:string
+        let ox_o: ox::Term = ox::Literal::new_simple_literal("chat").into();
+        let rt_o = rt::Object::Literal(rt::Literal::new(
+            "chat".into(),
+            rt::LiteralType::Any(rt::IriBuf::new(
+                "http://www.w3.org/2001/XMLSchema#string".into(),
+            )?),
+        ));
+        assert!(object_eq(&ox_o, &rt_o));
+        let hash = |o: &dyn Fn(&mut std::hash::DefaultHasher)| {
+            let mut state = std::hash::DefaultHasher::new();
+            o(&mut state);
+            std::hash::Hasher::finish(&state)
+        };
+        assert_eq!(
+            hash(&|h| crate::object_hash(&ox_o, h)),
+            hash(&|h| crate::object_hash(&rt_o, h))
+        );
+        // language tags are compared case-insensitively
+        let ox_o: ox::Term = ox::Literal::new_language_tagged_literal("chat", "en-GB")?.into();
+        let rt_o = rt::Object::Literal(rt::Literal::new(
+            "chat".into(),
+            rt::LiteralType::LangString(langtag::LangTagBuf::new("EN-gb".into()).unwrap()),
+        ));
+        assert!(object_eq(&ox_o, &rt_o));
+        assert_eq!(
+            hash(&|h| crate::object_hash(&ox_o, h)),
+            hash(&|h| crate::object_hash(&rt_o, h))
+        );
+        let rt_o = rt::Object::Literal(rt::Literal::new(
+            "chat".into(),
+            rt::LiteralType::LangString(langtag::LangTagBuf::new("en".into()).unwrap()),
+        ));
+        assert!(!object_eq(&ox_o, &rt_o));
+        // triple terms are compared recursively
+        let ox_o: ox::Term = ox::Triple::new(
+            ox_bnode.clone(),
+            ox_iri.clone(),
+            ox::Literal::new_language_tagged_literal("chat", "en")?,
+        )
+        .into();
+        let t = triple!(_:x, <"https://example.org/ns/p">, <<( _:b1, <"https://example.org/ns/a">, "chat"@EN )>>);
+        assert!(object_eq(&ox_o, &t.object));
+        let t = triple!(_:x, <"https://example.org/ns/p">, <<( _:b2, <"https://example.org/ns/a">, "chat"@EN )>>);
+        assert!(!object_eq(&ox_o, &t.object));
+
+        // graph names
+        let ox_g: ox::Subject = ox_iri.into();
+        assert!(graph_name_eq(&ox_g, &rt::GraphLabel::Iri(rt_iri.clone())));
+        assert!(!graph_name_eq(
+            &ox_g,
+            &rt::GraphLabel::Blank(rt_bnode.clone())
+        ));
+        let ox_g: ox::Subject = ox_bnode.into();
+        assert!(graph_name_eq(&ox_g, &rt::GraphLabel::Blank(rt_bnode)));
+        assert!(!graph_name_eq(&ox_g, &rt::GraphLabel::Iri(rt_iri)));
+        Ok(())
+    }
+
+    #[test]
+    fn object_proxy_eq() -> TestResult {
+        use crate::{Object, triple};
+        use std::hash::{BuildHasher, RandomState};
+
+        // nested triple terms, with different cases for the language tag
+        let ox_o: ox::Term = ox::Triple::new(
+            ox::BlankNode::new("b1")?,
+            ox::NamedNode::new("https://example.org/ns/p")?,
+            ox::Triple::new(
+                ox::NamedNode::new("https://example.org/ns/s")?,
+                ox::NamedNode::new("https://example.org/ns/q")?,
+                ox::Literal::new_language_tagged_literal("chat", "en-GB")?,
+            ),
+        )
+        .into();
+        let t = triple!(_:x, <"https://example.org/ns/p">, <<( _:b1, <"https://example.org/ns/p">, <<( <"https://example.org/ns/s">, <"https://example.org/ns/q">, "chat"@EN-gb )>> )>>);
+        let ox_proxy = ox_o.as_object_proxy();
+        assert!(ox_proxy == t.object);
+        assert!(t.object == ox_proxy);
+        let hasher = RandomState::new();
+        assert_eq!(hasher.hash_one(&ox_proxy), hasher.hash_one(&t.object));
+
+        let t = triple!(_:x, <"https://example.org/ns/p">, <<( _:b1, <"https://example.org/ns/p">, <<( <"https://example.org/ns/s">, <"https://example.org/ns/q">, "chat"@en )>> )>>);
+        assert!(ox_proxy != t.object);
+        assert!(t.object != ox_proxy);
+
+        // rdf_types objects have no triple terms
+        let ox_o: ox::Term = ox::NamedNode::new("https://example.org/ns/a")?.into();
+        let rt_o = rt::Object::Id(rt::Id::Iri(rt::IriBuf::new(
+            "https://example.org/ns/a".into(),
+        )?));
+        assert!(ox_o.as_object_proxy() == rt_o.as_object_proxy());
+        assert_eq!(
+            hasher.hash_one(ox_o.as_object_proxy()),
+            hasher.hash_one(rt_o.as_object_proxy())
+        );
+        assert!(ox_proxy != rt_o.as_object_proxy());
+        Ok(())
+    }
+
+    type TestResult = Result<(), Box<dyn std::error::Error>>;
+}
@@ -7,13 +7,13 @@
 //! - [`oxrdf`] does not support base direction in literals, so it is not complete;
 //! - [`oxrdf`] with the [`rdf-star`] feature allows triple terms in the subject position, so it is not strict.
 //!
-//! This is handled by panic'ing when those situations are encountered.
-//!
-//! A more future proof way of dealing with this would be:
-//! - for incomplete implementations, the conversions *from* R2C2 should use
-//!   [`TryFrom`] rather than [`From`] (see for example [`crate::impl_rdf_types`])
-//! - for generalized implementations, R2C2 would need to be augmented,
-//!   possibly with a GeneralizedTerm trait that would allow fallible conversions to strict term categories.
+//! This is handled as follows:
+//! - conversions *from* R2C2 objects use [`TryFrom`] rather than [`From`],
+//!   and fail on literals with a base direction
+//!   ([`from_r2c2_triple`] and [`from_r2c2_quad`] are panic'ing convenience functions);
+//! - triple terms in the subject (or graph name) position cause a panic,
+//!   but can be detected gracefully with [`GeneralizedSubject`] (or [`GeneralizedGraphName`]),
+//!   on which the strict implementations are based.
 use crate::*;
 use oxrdf as ox;
 
@@ -46,15 +46,39 @@ impl Triple for ox::Triple {
     fn object(&self) -> Self::Object<'_> {
         &self.object
     }
+
+    fn object_literal(&self) -> Option<Literal<'_>> {
+        match self.object.as_object_proxy() {
+            ObjectProxy::Literal(literal) => Some(literal),
+            _ => None,
+        }
+    }
 }
 
 /// This function would typically be implemented as a method of oxrdf::Triple in the crate itself.
+///
+/// # Panics
+/// If the triple contains a literal with a base direction, which [`oxrdf`] does not support
+/// (see [`try_from_r2c2_triple`] for a fallible alternative).
 pub fn from_r2c2_triple<T: Triple>(triple: T) -> ox::Triple {
-    ox::Triple::new(
-        triple.subject().as_subject_proxy(),
-        triple.predicate().as_iri(),
-        triple.object().as_object_proxy(),
-    )
+    try_from_r2c2_triple(triple).unwrap()
+}
+
+/// This function would typically be implemented as a method of oxrdf::Triple in the crate itself.
+pub fn try_from_r2c2_triple<T: Triple>(triple: T) -> Result<ox::Triple, &'static str> {
+    ox::Triple::from_triple(&triple)
+}
+
+impl FromTriple for ox::Triple {
+    type Error = &'static str;
+
+    fn from_triple<T: Triple>(triple: &T) -> Result<Self, Self::Error> {
+        Ok(ox::Triple::new(
+            triple.subject().as_subject_proxy(),
+            triple.predicate().as_iri(),
+            ox::Term::try_from(triple.object().as_object_proxy())?,
+        ))
+    }
 }
 
 // oxrdf::TripleRef as Triple
@@ -123,6 +147,13 @@ impl Quad for ox::Quad {
         &self.object
     }
 
+    fn object_literal(&self) -> Option<Literal<'_>> {
+        match self.object.as_object_proxy() {
+            ObjectProxy::Literal(literal) => Some(literal),
+            _ => None,
+        }
+    }
+
     fn graph_name(&self) -> Option<Self::GraphName<'_>> {
         match self.graph_name.as_ref() {
             ox::GraphNameRef::NamedNode(named_node) => {
@@ -134,19 +165,48 @@ impl Quad for ox::Quad {
             ox::GraphNameRef::DefaultGraph => None,
         }
     }
+
+    fn is_default_graph(&self) -> bool {
+        self.graph_name.is_default_graph()
+    }
+
+    fn graph_name_iri(&self) -> Option<Iri<'_>> {
+        match &self.graph_name {
+            ox::GraphName::NamedNode(named_node) => Some(Iri::new_unchecked(named_node.as_str())),
+            _ => None,
+        }
+    }
 }
 
 /// This function would typically be implemented as a method of oxrdf::Quad in the crate itself.
+///
+/// # Panics
+/// If the quad contains a literal with a base direction, which [`oxrdf`] does not support
+/// (see [`try_from_r2c2_quad`] for a fallible alternative).
 pub fn from_r2c2_quad<T: Quad>(quad: T) -> ox::Quad {
-    ox::Quad::new(
-        quad.subject().as_subject_proxy(),
-        quad.predicate().as_iri(),
-        quad.object().as_object_proxy(),
-        match quad.graph_name() {
-            None => ox::GraphName::DefaultGraph,
-            Some(gn) => gn.as_graph_name_proxy().into(),
-        },
-    )
+    try_from_r2c2_quad(quad).unwrap()
+}
+
+/// This function would typically be implemented as a method of oxrdf::Quad in the crate itself.
+pub fn try_from_r2c2_quad<T: Quad>(quad: T) -> Result<ox::Quad, &'static str> {
+    ox::Quad::from_quad(&quad)
+}
+
+impl FromQuad for ox::Quad {
+    type Error = &'static str;
+
+    fn from_quad<Q: Quad>(quad: &Q) -> Result<Self, Self::Error> {
+        Ok(ox::Quad::new(
+            quad.subject().as_subject_proxy(),
+            quad.predicate().as_iri(),
+            ox::Term::try_from(quad.object().as_object_proxy())?,
+            GraphNameOrDefault::from(
+                quad.graph_name()
+                    .as_ref()
+                    .map(|gn| gn.as_graph_name_proxy()),
+            ),
+        ))
+    }
 }
 
 // oxrdf::QuadRef as Quad
@@ -195,25 +255,72 @@ impl Quad for ox::QuadRef<'_> {
             ox::GraphNameRef::DefaultGraph => None,
         }
     }
+
+    fn is_default_graph(&self) -> bool {
+        self.graph_name.is_default_graph()
+    }
+
+    fn graph_name_iri(&self) -> Option<Iri<'_>> {
+        match self.graph_name {
+            ox::GraphNameRef::NamedNode(named_node) => {
+                Some(Iri::new_unchecked(named_node.as_str()))
+            }
+            _ => None,
+        }
+    }
+}
+
+// oxrdf::Subject and oxrdf::SubjectRef as GeneralizedSubject
+//
+// ox::Subject has a variant 'Triple', which only exists because we enabled the `rdf-star` feature,
+// in order to emulate RDF 1.2's triple terms.
+// It is assumed that OxRdf will eventually implement (strict) RDF 1.2, and that this variant will disappear.
+// In the meantime, the strict implementations below are based on the generalized ones,
+// and panic on that variant.
+
+impl GeneralizedSubject for ox::Subject {
+    fn try_as_subject_proxy(&self) -> Result<SubjectProxy<'_>, GeneralizedError> {
+        match self {
+            ox::Subject::NamedNode(named_node) => Ok(SubjectProxy::Iri(named_node.as_iri())),
+            ox::Subject::BlankNode(blank_node) => {
+                Ok(SubjectProxy::BlankNode(blank_node.as_str().into()))
+            }
+            ox::Subject::Triple(_) => Err(GeneralizedError::TripleTermInSubject),
+        }
+    }
+}
+
+impl GeneralizedSubject for ox::SubjectRef<'_> {
+    fn try_as_subject_proxy(&self) -> Result<SubjectProxy<'_>, GeneralizedError> {
+        match self {
+            ox::SubjectRef::NamedNode(named_node) => Ok(SubjectProxy::Iri(named_node.as_iri())),
+            ox::SubjectRef::BlankNode(blank_node) => {
+                Ok(SubjectProxy::BlankNode(blank_node.as_str().into()))
+            }
+            ox::SubjectRef::Triple(_) => Err(GeneralizedError::TripleTermInSubject),
+        }
+    }
+}
+
+/// Panic on a term that can not be handled by the strict implementations
+/// (see [`GeneralizedSubject`] and [`GeneralizedGraphName`] for handling it gracefully).
+fn not_strict(err: GeneralizedError) -> ! {
+    panic!("{err} (use GeneralizedSubject or GeneralizedGraphName to detect it)")
 }
 
 // oxrdf::Subject as Subject
 
 impl Subject for ox::Subject {
     fn as_subject_proxy(&self) -> SubjectProxy<'_> {
+        self.try_as_subject_proxy()
+            .unwrap_or_else(|err| not_strict(err))
+    }
+
+    fn subject_kind(&self) -> SubjectKind {
         match self {
-            ox::Subject::NamedNode(named_node) => SubjectProxy::Iri(named_node.as_iri()),
-            ox::Subject::BlankNode(blank_node) => {
-                SubjectProxy::BlankNode(blank_node.as_str().into())
-            }
-            ox::Subject::Triple(_) => {
-                panic!()
-                // This only exists because we enabled the `rdf-star` feature, in order to emulate RDF 1.2's triple terms.
-                // It is assumed that OxRdf will eventually implement (strict) RDF 1.2, and that this panic!() will disappear.
-                //
-                // In the future we may have traits for types that *extend* RDF,
-                // with methods of the form `try_as_subject_proxy`, etc...
-            }
+            ox::Subject::NamedNode(_) => SubjectKind::Iri,
+            ox::Subject::BlankNode(_) => SubjectKind::BlankNode,
+            ox::Subject::Triple(_) => not_strict(GeneralizedError::TripleTermInSubject),
         }
     }
 }
@@ -231,19 +338,15 @@ impl<'a> From<SubjectProxy<'a>> for ox::Subject {
 
 impl Subject for ox::SubjectRef<'_> {
     fn as_subject_proxy(&self) -> SubjectProxy<'_> {
+        self.try_as_subject_proxy()
+            .unwrap_or_else(|err| not_strict(err))
+    }
+
+    fn subject_kind(&self) -> SubjectKind {
         match self {
-            ox::SubjectRef::NamedNode(named_node) => SubjectProxy::Iri(named_node.as_iri()),
-            ox::SubjectRef::BlankNode(blank_node) => {
-                SubjectProxy::BlankNode(blank_node.as_str().into())
-            }
-            ox::SubjectRef::Triple(_) => {
-                panic!()
-                // This only exists because we enabled the `rdf-star` feature, in order to emulate RDF 1.2's triple terms.
-                // It is assumed that OxRdf will eventually implement (strict) RDF 1.2, and that this panic!() will disappear.
-                //
-                // In the future we may have traits for types that *extend* RDF,
-                // with methods of the form `try_as_subject_proxy`, etc...
-            }
+            ox::SubjectRef::NamedNode(_) => SubjectKind::Iri,
+            ox::SubjectRef::BlankNode(_) => SubjectKind::BlankNode,
+            ox::SubjectRef::Triple(_) => not_strict(GeneralizedError::TripleTermInSubject),
         }
     }
 }
@@ -283,7 +386,7 @@ impl Object for ox::Term {
             ox::Term::NamedNode(named_node) => ObjectProxy::Iri(named_node.as_iri()),
             ox::Term::BlankNode(blank_node) => ObjectProxy::BlankNode(blank_node.as_str().into()),
             ox::Term::Literal(literal) => ObjectProxy::Literal(match literal.as_ref().destruct() {
-                (lex, None, None) => Literal::Typed(lex.into(), Iri::new_unchecked(XSD_STRING)),
+                (lex, None, None) => Literal::Typed(lex.into(), ns::xsd::STRING),
                 (lex, _, Some(tag)) => {
                     Literal::LanguageString(lex.into(), LangTag::new_unchecked(tag), None)
                 }
@@ -292,36 +395,57 @@ impl Object for ox::Term {
             ox::Term::Triple(triple) => ObjectProxy::Triple(triple),
         }
     }
-}
 
-impl<'a, T: Triple> From<ObjectProxy<'a, T>> for ox::Term {
-    fn from(value: ObjectProxy<'a, T>) -> Self {
-        match value {
-            ObjectProxy::Iri(iri) => ox::NamedNode::from(iri).into(),
-            ObjectProxy::BlankNode(bnid) => safe_bnode(bnid).into(),
-            ObjectProxy::Literal(literal) => match literal {
-                Literal::Typed(lex, iri) => {
-                    ox::Literal::new_typed_literal(lex.into_owned(), iri).into()
-                }
-                Literal::LanguageString(lex, lang_tag, base_dir) => {
-                    if base_dir.is_some() {
-                        panic!()
-                        // Assuming here that oxrdf will eventually support base direction,
-                        // this panic!() will go away.
-                        //
-                        // For a type that is *not* expected to implement all of RDF 1.2,
-                        // they should implement TryFrom instead.
-                    }
-                    ox::Literal::new_language_tagged_literal_unchecked(
-                        lex.into_owned(),
-                        lang_tag.unwrap().into_owned(),
-                    )
-                    .into()
-                }
-            },
-            ObjectProxy::Triple(triple) => ox::Term::Triple(Box::new(from_r2c2_triple(triple))),
+    fn object_kind(&self) -> ObjectKind {
+        match self {
+            ox::Term::NamedNode(_) => ObjectKind::Iri,
+            ox::Term::BlankNode(_) => ObjectKind::BlankNode,
+            ox::Term::Literal(_) => ObjectKind::Literal,
+            ox::Term::Triple(_) => ObjectKind::Triple,
+        }
+    }
+
+    fn ground(&self) -> bool {
+        match self {
+            ox::Term::NamedNode(_) | ox::Term::Literal(_) => true,
+            ox::Term::BlankNode(_) => false,
+            ox::Term::Triple(triple) => Triple::ground(triple.as_ref()),
         }
     }
+
+    fn literal_lexical_form(&self) -> Option<std::borrow::Cow<'_, str>> {
+        match self {
+            ox::Term::Literal(literal) => Some(literal.value().into()),
+            _ => None,
+        }
+    }
+
+    fn literal_datatype(&self) -> Option<Iri<'_>> {
+        match self {
+            ox::Term::Literal(literal) => Some(Iri::new_unchecked(literal.datatype().as_str())),
+            _ => None,
+        }
+    }
+
+    fn literal_language_tag(&self) -> Option<LangTag<'_>> {
+        match self {
+            ox::Term::Literal(literal) => literal.language().map(LangTag::new_unchecked),
+            _ => None,
+        }
+    }
+
+    fn literal_base_direction(&self) -> Option<BaseDir> {
+        // oxrdf does not support base direction
+        None
+    }
+}
+
+impl<'a, T: Triple> TryFrom<ObjectProxy<'a, T>> for ox::Term {
+    type Error = &'static str;
+
+    fn try_from(value: ObjectProxy<'a, T>) -> Result<Self, Self::Error> {
+        OxrdfTermFactory::default().term(value)
+    }
 }
 
 // oxrdf::TermRef as Object
@@ -339,7 +463,7 @@ impl Object for ox::TermRef<'_> {
                 ObjectProxy::BlankNode(blank_node.as_str().into())
             }
             ox::TermRef::Literal(literal) => ObjectProxy::Literal(match literal.destruct() {
-                (lex, None, None) => Literal::Typed(lex.into(), Iri::new_unchecked(XSD_STRING)),
+                (lex, None, None) => Literal::Typed(lex.into(), ns::xsd::STRING),
                 (lex, _, Some(tag)) => {
                     Literal::LanguageString(lex.into(), LangTag::new_unchecked(tag), None)
                 }
@@ -348,6 +472,49 @@ impl Object for ox::TermRef<'_> {
             ox::TermRef::Triple(triple) => ObjectProxy::Triple(triple),
         }
     }
+
+    fn object_kind(&self) -> ObjectKind {
+        match self {
+            ox::TermRef::NamedNode(_) => ObjectKind::Iri,
+            ox::TermRef::BlankNode(_) => ObjectKind::BlankNode,
+            ox::TermRef::Literal(_) => ObjectKind::Literal,
+            ox::TermRef::Triple(_) => ObjectKind::Triple,
+        }
+    }
+
+    fn ground(&self) -> bool {
+        match self {
+            ox::TermRef::NamedNode(_) | ox::TermRef::Literal(_) => true,
+            ox::TermRef::BlankNode(_) => false,
+            ox::TermRef::Triple(triple) => Triple::ground(*triple),
+        }
+    }
+
+    fn literal_lexical_form(&self) -> Option<std::borrow::Cow<'_, str>> {
+        match self {
+            ox::TermRef::Literal(literal) => Some(literal.value().into()),
+            _ => None,
+        }
+    }
+
+    fn literal_datatype(&self) -> Option<Iri<'_>> {
+        match self {
+            ox::TermRef::Literal(literal) => Some(Iri::new_unchecked(literal.datatype().as_str())),
+            _ => None,
+        }
+    }
+
+    fn literal_language_tag(&self) -> Option<LangTag<'_>> {
+        match self {
+            ox::TermRef::Literal(literal) => literal.language().map(LangTag::new_unchecked),
+            _ => None,
+        }
+    }
+
+    fn literal_base_direction(&self) -> Option<BaseDir> {
+        // oxrdf does not support base direction
+        None
+    }
 }
 
 // oxrdf::Subject as GraphName
@@ -357,21 +524,29 @@ impl Object for ox::TermRef<'_> {
 //
 // Note however that, conversely, GraphNameProxy can be converted to an ox::GraphName
 
-impl GraphName for ox::Subject {
-    fn as_graph_name_proxy(&self) -> GraphNameProxy<'_> {
+impl GeneralizedGraphName for ox::Subject {
+    fn try_as_graph_name_proxy(&self) -> Result<GraphNameProxy<'_>, GeneralizedError> {
         match self {
-            ox::Subject::NamedNode(named_node) => GraphNameProxy::Iri(named_node.as_iri()),
+            ox::Subject::NamedNode(named_node) => Ok(GraphNameProxy::Iri(named_node.as_iri())),
             ox::Subject::BlankNode(blank_node) => {
-                GraphNameProxy::BlankNode(blank_node.as_str().into())
-            }
-            ox::Subject::Triple(_) => {
-                panic!()
-                // This only exists because we enabled the `rdf-star` feature, in order to emulate RDF 1.2's triple terms.
-                // It is assumed that OxRdf will eventually implement (strict) RDF 1.2, and that this panic!() will disappear.
-                //
-                // In the future we may have traits for types that *extend* RDF,
-                // with methods of the form `try_as_subject_proxy`, etc...
+                Ok(GraphNameProxy::BlankNode(blank_node.as_str().into()))
             }
+            ox::Subject::Triple(_) => Err(GeneralizedError::TripleTermInGraphName),
+        }
+    }
+}
+
+impl GraphName for ox::Subject {
+    fn as_graph_name_proxy(&self) -> GraphNameProxy<'_> {
+        self.try_as_graph_name_proxy()
+            .unwrap_or_else(|err| not_strict(err))
+    }
+
+    fn graph_name_kind(&self) -> GraphNameKind {
+        match self {
+            ox::Subject::NamedNode(_) => GraphNameKind::Iri,
+            ox::Subject::BlankNode(_) => GraphNameKind::BlankNode,
+            ox::Subject::Triple(_) => not_strict(GeneralizedError::TripleTermInGraphName),
         }
     }
 }
@@ -385,6 +560,37 @@ impl<'a> From<GraphNameProxy<'a>> for ox::GraphName {
     }
 }
 
+// oxrdf::GraphName and oxrdf::GraphNameRef as GraphNameOrDefault
+
+impl<'a> From<GraphNameOrDefault<'a>> for ox::GraphName {
+    fn from(value: GraphNameOrDefault<'a>) -> Self {
+        match value {
+            GraphNameOrDefault::Default => ox::GraphName::DefaultGraph,
+            GraphNameOrDefault::Named(gn) => gn.into(),
+        }
+    }
+}
+
+impl<'a> From<ox::GraphNameRef<'a>> for GraphNameOrDefault<'a> {
+    fn from(value: ox::GraphNameRef<'a>) -> Self {
+        match value {
+            ox::GraphNameRef::NamedNode(named_node) => {
+                GraphNameProxy::Iri(Iri::new_unchecked(named_node.as_str())).into()
+            }
+            ox::GraphNameRef::BlankNode(blank_node) => {
+                GraphNameProxy::BlankNode(blank_node.as_str().into()).into()
+            }
+            ox::GraphNameRef::DefaultGraph => GraphNameOrDefault::Default,
+        }
+    }
+}
+
+impl<'a> From<&'a ox::GraphName> for GraphNameOrDefault<'a> {
+    fn from(value: &'a ox::GraphName) -> Self {
+        value.as_ref().into()
+    }
+}
+
 // oxrdf::NamedOrBlankNodeRef as GraphName
 //
 // NB: ox::GraphNameRef can not implement GraphName,
@@ -401,6 +607,99 @@ impl GraphName for ox::NamedOrBlankNodeRef<'_> {
             }
         }
     }
+
+    fn graph_name_kind(&self) -> GraphNameKind {
+        match self {
+            ox::NamedOrBlankNodeRef::NamedNode(_) => GraphNameKind::Iri,
+            ox::NamedOrBlankNodeRef::BlankNode(_) => GraphNameKind::BlankNode,
+        }
+    }
+}
+
+// OxrdfTermFactory
+
+/// A [`TermFactory`] building [`oxrdf`] terms.
+///
+/// Blank node labels are mapped through a function,
+/// which by default is [`BnodeId::sanitized`]
+/// (as for [`RdfTypesTermFactory`](crate::impl_rdf_types::RdfTypesTermFactory),
+/// so that both factories label blank nodes identically).
+#[derive(Clone, Debug)]
+pub struct OxrdfTermFactory<F = fn(&str) -> String> {
+    bnode_label: F,
+}
+
+impl<F: FnMut(&str) -> String> OxrdfTermFactory<F> {
+    /// Build a factory mapping blank node labels through `bnode_label`.
+    ///
+    /// ## Precondition
+    /// `bnode_label` must return [valid blank node labels](is_valid_bnode_label).
+    pub fn with_bnode_label(bnode_label: F) -> Self {
+        OxrdfTermFactory { bnode_label }
+    }
+}
+
+impl Default for OxrdfTermFactory {
+    fn default() -> Self {
+        OxrdfTermFactory::with_bnode_label(|label| BnodeId::sanitized(label).unwrap().into_owned())
+    }
+}
+
+impl<F: FnMut(&str) -> String> TermFactory for OxrdfTermFactory<F> {
+    type Term = ox::Term;
+    type Triple = ox::Triple;
+    type Error = &'static str;
+
+    fn iri(&mut self, iri: Iri) -> Self::Term {
+        ox::NamedNode::from(iri).into()
+    }
+
+    fn bnode(&mut self, label: &str) -> Self::Term {
+        let label = (self.bnode_label)(label);
+        debug_assert!(is_valid_bnode_label(&label));
+        ox::BlankNode::new_unchecked(label).into()
+    }
+
+    fn literal(&mut self, literal: Literal) -> Result<Self::Term, Self::Error> {
+        Ok(match literal {
+            Literal::Typed(lex, iri) => {
+                ox::Literal::new_typed_literal(lex.into_owned(), iri).into()
+            }
+            Literal::LanguageString(lex, lang_tag, None) => {
+                ox::Literal::new_language_tagged_literal_unchecked(
+                    lex.into_owned(),
+                    lang_tag.unwrap().into_owned(),
+                )
+                .into()
+            }
+            Literal::LanguageString(_, _, Some(_)) => {
+                Err("directional language strings are not supported by the crate oxrdf")?
+                // Assuming here that oxrdf will eventually support base direction,
+                // this error will go away.
+            }
+        })
+    }
+
+    fn triple(
+        &mut self,
+        subject: Self::Term,
+        predicate: Self::Term,
+        object: Self::Term,
+    ) -> Result<Self::Triple, Self::Error> {
+        let subject = match subject {
+            ox::Term::NamedNode(n) => ox::Subject::NamedNode(n),
+            ox::Term::BlankNode(b) => ox::Subject::BlankNode(b),
+            _ => Err("the subject of a triple must be an IRI or a blank node")?,
+        };
+        let ox::Term::NamedNode(predicate) = predicate else {
+            Err("the predicate of a triple must be an IRI")?
+        };
+        Ok(ox::Triple::new(subject, predicate, object))
+    }
+
+    fn triple_term(&mut self, triple: Self::Triple) -> Result<Self::Term, Self::Error> {
+        Ok(ox::Term::Triple(Box::new(triple)))
+    }
 }
 
 // utility functions and constants
@@ -408,17 +707,9 @@ impl GraphName for ox::NamedOrBlankNodeRef<'_> {
 /// This function converts an R2C2 bnode label into an OxRDF Blank Node,
 /// ensuring that bnode labels that are not valid SPARQL bnodeIds are correctly handled
 fn safe_bnode(bnid: std::borrow::Cow<str>) -> ox::BlankNode {
-    use std::hash::{DefaultHasher, Hash, Hasher};
-    let mut s = DefaultHasher::new();
-    bnid.hash(&mut s);
-    let h = s.finish();
-
-    ox::BlankNode::new(bnid.into_owned())
-        .unwrap_or_else(|_| ox::BlankNode::new_from_unique_id(h as u128))
+    ox::BlankNode::new_unchecked(sanitize_bnode_label(&bnid))
 }
 
-static XSD_STRING: &str = "http://www.w3.org/2001/XMLSchema#string";
-
 #[cfg(test)]
 mod test_round_trip {
     use super::*;
@@ -430,6 +721,9 @@ mod test_round_trip {
         assert_eq!(s1, s2);
         let s2: ox::Subject = s1.as_ref().as_subject_proxy().into();
         assert_eq!(s1, s2);
+        assert!(s1.is_iri() && !s1.is_blank_node());
+        assert_eq!(s1.as_iri().unwrap(), "https://example.org/ns/alice");
+        assert_eq!(s1.as_ref().as_blank_node(), None);
         Ok(())
     }
 
@@ -440,6 +734,9 @@ mod test_round_trip {
         assert_eq!(s1, s2);
         let s2: ox::Subject = s1.as_ref().as_subject_proxy().into();
         assert_eq!(s1, s2);
+        assert!(s1.is_blank_node() && !s1.is_iri());
+        assert!(s1.as_blank_node().is_some());
+        assert_eq!(s1.as_ref().as_iri(), None);
         Ok(())
     }
 
@@ -456,9 +753,9 @@ mod test_round_trip {
     #[test]
     fn object_iri() -> TestResult {
         let o1: ox::Term = ox::NamedNode::new("https://example.org/ns/alice")?.into();
-        let o2: ox::Term = o1.as_object_proxy().into();
+        let o2: ox::Term = o1.as_object_proxy().try_into()?;
         assert_eq!(o1, o2);
-        let o2: ox::Term = o1.as_ref().as_object_proxy().into();
+        let o2: ox::Term = o1.as_ref().as_object_proxy().try_into()?;
         assert_eq!(o1, o2);
         Ok(())
     }
@@ -466,9 +763,9 @@ mod test_round_trip {
     #[test]
     fn object_bnode() -> TestResult {
         let o1: ox::Term = ox::BlankNode::default().into();
-        let o2: ox::Term = o1.as_object_proxy().into();
+        let o2: ox::Term = o1.as_object_proxy().try_into()?;
         assert_eq!(o1, o2);
-        let o2: ox::Term = o1.as_ref().as_object_proxy().into();
+        let o2: ox::Term = o1.as_ref().as_object_proxy().try_into()?;
         assert_eq!(o1, o2);
         Ok(())
     }
@@ -476,20 +773,65 @@ mod test_round_trip {
     #[test]
     fn object_simple_literal() -> TestResult {
         let o1: ox::Term = ox::Literal::new_simple_literal("⛄").into();
-        let o2: ox::Term = o1.as_object_proxy().into();
+        let ObjectProxy::Literal(lit) = o1.as_object_proxy() else {
+            panic!("expected a literal");
+        };
+        assert!(lit.is_simple());
+        let o2: ox::Term = o1.as_object_proxy().try_into()?;
         assert_eq!(o1, o2);
-        let o2: ox::Term = o1.as_ref().as_object_proxy().into();
+        let o2: ox::Term = o1.as_ref().as_object_proxy().try_into()?;
         assert_eq!(o1, o2);
         Ok(())
     }
 
+    #[test]
+    fn object_literal_accessors() -> TestResult {
+        let objects: [ox::Term; 3] = [
+            ox::Literal::new_simple_literal("⛄").into(),
+            ox::Literal::new_typed_literal("42", ox::NamedNode::new(XSD_INTEGER)?).into(),
+            ox::Literal::new_language_tagged_literal("chat", "en-GB")?.into(),
+        ];
+        for o in &objects {
+            let ObjectProxy::Literal(lit) = o.as_object_proxy() else {
+                panic!("expected a literal");
+            };
+            assert_eq!(o.literal_lexical_form(), Some(lit.lexical_form()));
+            assert_eq!(o.as_ref().literal_lexical_form(), Some(lit.lexical_form()));
+            assert_eq!(o.literal_datatype(), Some(lit.datatype_iri()));
+            assert_eq!(o.as_ref().literal_datatype(), Some(lit.datatype_iri()));
+            assert_eq!(o.literal_language_tag(), lit.language_tag());
+            assert_eq!(o.as_ref().literal_language_tag(), lit.language_tag());
+            assert_eq!(o.literal_base_direction(), None);
+        }
+        assert_eq!(objects[1].literal_datatype().unwrap(), XSD_INTEGER);
+        assert_eq!(objects[2].literal_language_tag().unwrap(), "en-gb");
+
+        let non_literals: [ox::Term; 3] = [
+            ox::NamedNode::new("https://example.org/ns/a")?.into(),
+            ox::BlankNode::new("b1")?.into(),
+            ox::Triple::new(
+                ox::BlankNode::new("b1")?,
+                ox::NamedNode::new("https://example.org/ns/p")?,
+                objects[0].clone(),
+            )
+            .into(),
+        ];
+        for o in &non_literals {
+            assert_eq!(o.literal_lexical_form(), None);
+            assert_eq!(o.as_ref().literal_datatype(), None);
+            assert_eq!(o.literal_language_tag(), None);
+            assert_eq!(o.as_ref().literal_base_direction(), None);
+        }
+        Ok(())
+    }
+
     #[test]
     fn object_typed_literal() -> TestResult {
         let o1: ox::Term =
             ox::Literal::new_typed_literal("42", ox::NamedNode::new(XSD_INTEGER)?).into();
-        let o2: ox::Term = o1.as_object_proxy().into();
+        let o2: ox::Term = o1.as_object_proxy().try_into()?;
         assert_eq!(o1, o2);
-        let o2: ox::Term = o1.as_ref().as_object_proxy().into();
+        let o2: ox::Term = o1.as_ref().as_object_proxy().try_into()?;
         assert_eq!(o1, o2);
         Ok(())
     }
@@ -497,13 +839,24 @@ mod test_round_trip {
     #[test]
     fn object_language_tagged_literal() -> TestResult {
         let o1: ox::Term = ox::Literal::new_language_tagged_literal("chat", "en-Latn-UK")?.into();
-        let o2: ox::Term = o1.as_object_proxy().into();
+        let o2: ox::Term = o1.as_object_proxy().try_into()?;
         assert_eq!(o1, o2);
-        let o2: ox::Term = o1.as_ref().as_object_proxy().into();
+        let o2: ox::Term = o1.as_ref().as_object_proxy().try_into()?;
         assert_eq!(o1, o2);
         Ok(())
     }
 
+    #[test]
+    fn object_directional_language_tagged_literal() -> TestResult {
+        let o1 = ObjectProxy::<NeverTriple>::Literal(Literal::LanguageString(
+            "chat".into(),
+            LangTag::new_unchecked("en-Latn-UK"),
+            Some(BaseDir::Ltr),
+        ));
+        assert!(ox::Term::try_from(o1).is_err());
+        Ok(())
+    }
+
     #[test]
     fn object_triple_term() -> TestResult {
         let subject = ox::BlankNode::default().into();
@@ -515,9 +868,9 @@ mod test_round_trip {
             object,
         }
         .into();
-        let o2: ox::Term = o1.as_object_proxy().into();
+        let o2: ox::Term = o1.as_object_proxy().try_into()?;
         assert_eq!(o1, o2);
-        let o2: ox::Term = o1.as_ref().as_object_proxy().into();
+        let o2: ox::Term = o1.as_ref().as_object_proxy().try_into()?;
         assert_eq!(o1, o2);
         Ok(())
     }
@@ -539,6 +892,26 @@ mod test_round_trip {
         Ok(())
     }
 
+    #[test]
+    fn triple_directional_language_tagged_literal() -> TestResult {
+        let t1 = SimpleTriple {
+            subject: SubjectProxy::BlankNode("b1".into()),
+            predicate: Iri::new_unchecked("https://example.org/ns/p"),
+            object: ObjectProxy::Literal(Literal::LanguageString(
+                "chat".into(),
+                LangTag::new_unchecked("en"),
+                Some(BaseDir::Rtl),
+            )),
+        };
+        assert!(try_from_r2c2_triple(&t1).is_err());
+        let t2 = SimpleTriple {
+            object: ObjectProxy::Triple(Box::new(t1.clone())),
+            ..t1.clone()
+        };
+        assert!(try_from_r2c2_triple(&t2).is_err());
+        Ok(())
+    }
+
     #[test]
     fn quad_default_graph() -> TestResult {
         let subject = ox::BlankNode::default().into();
@@ -577,7 +950,391 @@ mod test_round_trip {
         Ok(())
     }
 
+    #[test]
+    fn graph_name_or_default() -> TestResult {
+        let bnode = ox::BlankNode::default();
+        for (g1, expected) in [
+            (
+                ox::GraphName::from(ox::NamedNode::new("https://example.org/")?),
+                "<https://example.org/>".to_string(),
+            ),
+            (
+                ox::GraphName::from(bnode.clone()),
+                format!("_:{}", bnode.as_str()),
+            ),
+            (ox::GraphName::DefaultGraph, "DEFAULT".to_string()),
+        ] {
+            let g = GraphNameOrDefault::from(&g1);
+            assert_eq!(g.to_string(), expected);
+            assert_eq!(g.is_default(), g1.is_default_graph());
+            assert_eq!(g, GraphNameOrDefault::from(g1.as_ref()));
+            let g2: ox::GraphName = g.into();
+            assert_eq!(g1, g2);
+
+            let q1 = ox::Quad {
+                subject: ox::BlankNode::default().into(),
+                predicate: ox::NamedNode::new("https://example.org/ns/p")?,
+                object: ox::Literal::new_simple_literal("⛄").into(),
+                graph_name: g1,
+            };
+            assert_eq!(from_r2c2_quad(&q1), q1);
+            let g = GraphNameOrDefault::from(q1.graph_name_proxy());
+            assert_eq!(g, GraphNameOrDefault::from(&q1.graph_name));
+        }
+        Ok(())
+    }
+
     type TestResult = Result<(), Box<dyn std::error::Error>>;
 
     static XSD_INTEGER: &str = "http://www.w3.org/2001/XMLSchema#integer";
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn literal_object() {
+        /// A triple made of the crate's own types
+        struct LiteralTriple(Iri<'static>, Iri<'static>, Literal<'static>);
+
+        impl Triple for LiteralTriple {
+            type Subject<'x> = &'x Iri<'static>;
+            type Predicate<'x> = &'x Iri<'static>;
+            type Object<'x> = &'x Literal<'static>;
+
+            fn subject(&self) -> Self::Subject<'_> {
+                &self.0
+            }
+
+            fn predicate(&self) -> Self::Predicate<'_> {
+                &self.1
+            }
+
+            fn object(&self) -> Self::Object<'_> {
+                &self.2
+            }
+        }
+
+        let t = LiteralTriple(
+            Iri::new_unchecked("https://example.org/ns/s"),
+            Iri::new_unchecked("https://example.org/ns/p"),
+            Literal::LanguageString("chat".into(), LangTag::new_unchecked("en"), None),
+        );
+        assert_eq!(t.object().object_kind(), ObjectKind::Literal);
+        assert!(t.ground());
+        let ox_t = from_r2c2_triple(&t);
+        assert_eq!(
+            ox_t.to_string(),
+            r#"<https://example.org/ns/s> <https://example.org/ns/p> "chat"@en"#
+        );
+        assert!(crate::triple_eq(&t, &ox_t));
+    }
+
+    #[test]
+    fn smart_pointers() -> TestResult {
+        fn object_kind(o: impl Object) -> ObjectKind {
+            o.object_kind()
+        }
+        fn predicate_iri(p: impl Predicate) -> String {
+            p.as_iri().as_str().to_string()
+        }
+        let term: ox::Term = ox::Literal::new_simple_literal("⛄").into();
+        let arc = std::sync::Arc::new(term);
+        assert_eq!(object_kind(arc.clone()), ObjectKind::Literal);
+        assert_eq!(
+            object_kind(std::rc::Rc::clone(&std::rc::Rc::new(arc))),
+            ObjectKind::Literal
+        );
+        let boxed = Box::new(Iri::new_unchecked("https://example.org/ns/p"));
+        assert_eq!(predicate_iri(boxed), "https://example.org/ns/p");
+        let subject: Box<ox::Subject> =
+            Box::new(ox::NamedNode::new("https://example.org/ns/s")?.into());
+        assert!(subject.is_iri());
+        Ok(())
+    }
+
+    #[test]
+    fn spo() -> TestResult {
+        let t = ox::Triple {
+            subject: ox::BlankNode::default().into(),
+            predicate: ox::NamedNode::new("https://example.org/ns/p")?,
+            object: ox::Literal::new_simple_literal("⛄").into(),
+        };
+        let (s, p, o) = t.spo();
+        assert_eq!(s, &t.subject);
+        assert_eq!(p, &t.predicate);
+        assert_eq!(o, &t.object);
+        let t_ref = t.as_ref();
+        let (s, p, o) = t_ref.spo();
+        assert_eq!(
+            (s, p, o),
+            (t.subject.as_ref(), t.predicate.as_ref(), t.object.as_ref())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn spo_proxies() -> TestResult {
+        let t = ox::Triple {
+            subject: ox::NamedNode::new("https://example.org/ns/s")?.into(),
+            predicate: ox::NamedNode::new("https://example.org/ns/p")?,
+            object: ox::Literal::new_simple_literal("⛄").into(),
+        };
+        let spo = t.spo();
+        let (SubjectProxy::Iri(s), p, ObjectProxy::Literal(o)) = spo.spo_proxies() else {
+            panic!("unexpected proxies");
+        };
+        assert_eq!(s, "https://example.org/ns/s");
+        assert_eq!(p, "https://example.org/ns/p");
+        assert_eq!(o.lexical_form(), "⛄");
+        Ok(())
+    }
+
+    #[test]
+    fn spog() -> TestResult {
+        let q = ox::Quad {
+            subject: ox::BlankNode::default().into(),
+            predicate: ox::NamedNode::new("https://example.org/ns/p")?,
+            object: ox::Literal::new_simple_literal("⛄").into(),
+            graph_name: ox::NamedNode::new("https://example.org/")?.into(),
+        };
+        let (s, p, o, g) = q.spog();
+        assert_eq!(s, &q.subject);
+        assert_eq!(p, &q.predicate);
+        assert_eq!(o, &q.object);
+        assert!(g.is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn spog_proxies() -> TestResult {
+        let q = ox::Quad {
+            subject: ox::BlankNode::new("b1")?.into(),
+            predicate: ox::NamedNode::new("https://example.org/ns/p")?,
+            object: ox::NamedNode::new("https://example.org/ns/o")?.into(),
+            graph_name: ox::GraphName::DefaultGraph,
+        };
+        let q_ref = q.as_ref();
+        let spog = q_ref.spog();
+        let (SubjectProxy::BlankNode(s), p, ObjectProxy::Iri(o), None) = spog.spog_proxies() else {
+            panic!("unexpected proxies");
+        };
+        assert_eq!(s, "b1");
+        assert_eq!(p, "https://example.org/ns/p");
+        assert_eq!(o, "https://example.org/ns/o");
+        Ok(())
+    }
+
+    #[test]
+    fn graph_name_methods() -> TestResult {
+        let mut q = ox::Quad {
+            subject: ox::BlankNode::default().into(),
+            predicate: ox::NamedNode::new("https://example.org/ns/p")?,
+            object: ox::Literal::new_simple_literal("⛄").into(),
+            graph_name: ox::GraphName::DefaultGraph,
+        };
+        assert!(q.is_default_graph());
+        assert!(q.as_ref().is_default_graph());
+        assert_eq!(q.graph_name_kind(), None);
+        assert_eq!(q.graph_name_iri(), None);
+
+        q.graph_name = ox::NamedNode::new("https://example.org/")?.into();
+        assert!(!q.is_default_graph());
+        assert!(!q.as_ref().is_default_graph());
+        assert_eq!(q.graph_name_kind(), Some(GraphNameKind::Iri));
+        assert_eq!(
+            q.graph_name_iri(),
+            Some(Iri::new_unchecked("https://example.org/"))
+        );
+        assert_eq!(q.as_ref().graph_name_iri(), q.graph_name_iri());
+        // the overridden method agrees with the default implementation
+        assert_eq!(
+            <&ox::Quad as Quad>::graph_name_proxy(&&q),
+            q.graph_name_proxy()
+        );
+
+        q.graph_name = ox::BlankNode::new("g")?.into();
+        assert!(!q.is_default_graph());
+        assert_eq!(q.graph_name_kind(), Some(GraphNameKind::BlankNode));
+        assert_eq!(q.as_ref().graph_name_kind(), Some(GraphNameKind::BlankNode));
+        assert_eq!(q.graph_name_iri(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn pattern_methods() -> TestResult {
+        let ex = |local: &str| ox::NamedNode::new(format!("https://example.org/ns/{local}"));
+        let quads = vec![
+            ox::Quad::new(
+                ex("alice")?,
+                ex("name")?,
+                ox::Literal::new_simple_literal("Alice"),
+                ox::GraphName::DefaultGraph,
+            ),
+            ox::Quad::new(ex("alice")?, ex("knows")?, ex("bob")?, ex("g")?),
+            ox::Quad::new(
+                ox::BlankNode::new("b")?,
+                ex("name")?,
+                ox::Literal::new_simple_literal("Bob"),
+                ex("g")?,
+            ),
+            ox::Quad::new(
+                ex("bob")?,
+                ex("name")?,
+                ex("alice")?,
+                ox::BlankNode::new("g")?,
+            ),
+        ];
+        let name = Iri::new_unchecked("https://example.org/ns/name");
+        let names: Vec<_> = quads
+            .iter()
+            .filter(|q| q.has_predicate(&name))
+            .filter_map(|q| q.object_literal())
+            .map(|lit| lit.lexical_form().into_owned())
+            .collect();
+        assert_eq!(names, ["Alice", "Bob"]);
+
+        let alice = Iri::new_unchecked("https://example.org/ns/alice");
+        assert_eq!(
+            quads.iter().filter(|q| q.has_subject_iri(&alice)).count(),
+            2
+        );
+        let g = Iri::new_unchecked("https://example.org/ns/g");
+        assert_eq!(quads.iter().filter(|q| q.has_graph_name_iri(&g)).count(), 2);
+
+        // the overridden method agrees with the default implementation
+        for q in &quads {
+            assert_eq!(<&ox::Quad as Quad>::object_literal(&q), q.object_literal());
+            let t = ox::Triple::from(q.clone());
+            assert_eq!(t.has_predicate(&name), q.has_predicate(&name));
+            assert_eq!(
+                <&ox::Triple as Triple>::object_literal(&&t),
+                t.object_literal()
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn generalized_subject() -> TestResult {
+        let s: ox::Subject = ox::NamedNode::new("https://example.org/ns/s")?.into();
+        assert_eq!(s.try_as_subject_proxy()?, s.as_subject_proxy());
+        let s: ox::Subject = ox::Triple {
+            subject: ox::BlankNode::default().into(),
+            predicate: ox::NamedNode::new("https://example.org/ns/p")?,
+            object: ox::Literal::new_simple_literal("⛄").into(),
+        }
+        .into();
+        assert_eq!(
+            s.try_as_subject_proxy(),
+            Err(GeneralizedError::TripleTermInSubject)
+        );
+        assert_eq!(
+            s.as_ref().try_as_subject_proxy(),
+            Err(GeneralizedError::TripleTermInSubject)
+        );
+        assert_eq!(
+            s.try_as_graph_name_proxy(),
+            Err(GeneralizedError::TripleTermInGraphName)
+        );
+        let err = std::panic::catch_unwind(|| s.as_subject_proxy().into_owned()).unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<String>().map(String::as_str),
+            Some(
+                "triple term in subject position (use GeneralizedSubject or GeneralizedGraphName to detect it)"
+            )
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn generalized_graph_name() -> TestResult {
+        let g: ox::Subject = ox::NamedNode::new("https://example.org/ns/g")?.into();
+        assert_eq!(g.try_as_graph_name_proxy()?, g.as_graph_name_proxy());
+        let g: ox::Subject = ox::BlankNode::default().into();
+        assert_eq!(g.try_as_graph_name_proxy()?, g.as_graph_name_proxy());
+        Ok(())
+    }
+
+    #[test]
+    fn kinds() -> TestResult {
+        let p = ox::NamedNode::new("https://example.org/ns/p")?;
+        let iri: ox::Term = p.clone().into();
+        let bnode: ox::Term = ox::BlankNode::default().into();
+        let literal: ox::Term = ox::Literal::new_language_tagged_literal("chat", "en")?.into();
+        let ground_triple: ox::Term = ox::Triple {
+            subject: p.clone().into(),
+            predicate: p.clone(),
+            object: literal.clone(),
+        }
+        .into();
+        let nested_bnode: ox::Term = ox::Triple {
+            subject: p.clone().into(),
+            predicate: p.clone(),
+            object: ox::Triple {
+                subject: p.clone().into(),
+                predicate: p.clone(),
+                object: bnode.clone(),
+            }
+            .into(),
+        }
+        .into();
+        for (term, kind, ground) in [
+            (iri, ObjectKind::Iri, true),
+            (bnode, ObjectKind::BlankNode, false),
+            (literal, ObjectKind::Literal, true),
+            (ground_triple, ObjectKind::Triple, true),
+            (nested_bnode, ObjectKind::Triple, false),
+        ] {
+            // the overridden methods agree with the default ones, based on the proxy
+            assert_eq!(term.as_object_proxy().object_kind(), kind);
+            assert_eq!(Object::ground(&term.as_object_proxy()), ground);
+            assert_eq!(term.object_kind(), kind);
+            assert_eq!(term.as_ref().object_kind(), kind);
+            assert_eq!(Object::ground(&term), ground);
+            assert_eq!(Object::ground(&term.as_ref()), ground);
+        }
+
+        let s: ox::Subject = p.clone().into();
+        assert_eq!(s.subject_kind(), SubjectKind::Iri);
+        assert_eq!(s.as_ref().subject_kind(), SubjectKind::Iri);
+        assert_eq!(s.graph_name_kind(), GraphNameKind::Iri);
+        let g = ox::NamedOrBlankNodeRef::from(p.as_ref());
+        assert_eq!(g.graph_name_kind(), GraphNameKind::Iri);
+        let s: ox::Subject = ox::BlankNode::default().into();
+        assert_eq!(s.subject_kind(), SubjectKind::BlankNode);
+        assert_eq!(s.as_ref().subject_kind(), SubjectKind::BlankNode);
+        assert_eq!(s.graph_name_kind(), GraphNameKind::BlankNode);
+        assert!(!Subject::ground(&s) && !GraphName::ground(&s));
+        let b = ox::BlankNode::default();
+        let g = ox::NamedOrBlankNodeRef::from(b.as_ref());
+        assert_eq!(g.graph_name_kind(), GraphNameKind::BlankNode);
+        Ok(())
+    }
+
+    #[test]
+    fn deeply_nested() -> TestResult {
+        let p = ox::NamedNode::new("https://example.org/ns/p")?;
+        for (innermost, ground) in [
+            (ox::Term::from(p.clone()), true),
+            (ox::BlankNode::default().into(), false),
+        ] {
+            let mut t = ox::Triple::new(p.clone(), p.clone(), innermost);
+            for _ in 0..1000 {
+                t = ox::Triple::new(p.clone(), p.clone(), t);
+            }
+            assert_eq!(Triple::ground(&t), ground);
+            assert_eq!(Triple::triple_term_depth(&t), 1000);
+            let mut nested = 0;
+            t.for_each_nested_triple(|_| nested += 1);
+            assert_eq!(nested, 1000);
+            let o = ox::Term::from(t);
+            assert_eq!(Object::ground(&o), ground);
+            assert_eq!(o.triple_term_depth(), 1001);
+        }
+        Ok(())
+    }
+
+    type TestResult = Result<(), Box<dyn std::error::Error>>;
+}
@@ -42,11 +42,19 @@ impl Triple for rt::LexicalTriple {
 
 /// This function would typically be implemented as a method of rdf_types::Triple in the crate itself.
 pub fn try_from_r2c2_triple<T: Triple>(triple: T) -> Result<rt::LexicalTriple, &'static str> {
-    Ok(rt::Triple(
-        triple.subject().as_subject_proxy().into(),
-        triple.predicate().as_iri().into(),
-        triple.object().as_object_proxy().try_into()?,
-    ))
+    rt::LexicalTriple::from_triple(&triple)
+}
+
+impl FromTriple for rt::LexicalTriple {
+    type Error = &'static str;
+
+    fn from_triple<T: Triple>(triple: &T) -> Result<Self, Self::Error> {
+        Ok(rt::Triple(
+            triple.subject().as_subject_proxy().into(),
+            triple.predicate().as_iri().into(),
+            triple.object().as_object_proxy().try_into()?,
+        ))
+    }
 }
 
 // rdf_types::LexicalTripleRef as Triple
@@ -118,16 +126,28 @@ impl Quad for rt::LexicalQuad {
     fn graph_name(&self) -> Option<Self::GraphName<'_>> {
         self.graph().map(|gn| gn.as_graph_label_ref())
     }
+
+    fn graph_name_proxy(&self) -> Option<GraphNameProxy<'_>> {
+        self.graph().map(|gn| gn.as_graph_name_proxy())
+    }
 }
 
 /// This function would typically be implemented as a method of rdf_types::Quad in the crate itself.
 pub fn try_from_r2c2_quad<T: Quad>(quad: T) -> Result<rt::LexicalQuad, &'static str> {
-    Ok(rt::Quad(
-        quad.subject().as_subject_proxy().into(),
-        quad.predicate().as_iri().into(),
-        quad.object().as_object_proxy().try_into()?,
-        quad.graph_name().map(|gn| gn.as_graph_name_proxy().into()),
-    ))
+    rt::LexicalQuad::from_quad(&quad)
+}
+
+impl FromQuad for rt::LexicalQuad {
+    type Error = &'static str;
+
+    fn from_quad<Q: Quad>(quad: &Q) -> Result<Self, Self::Error> {
+        Ok(rt::Quad(
+            quad.subject().as_subject_proxy().into(),
+            quad.predicate().as_iri().into(),
+            quad.object().as_object_proxy().try_into()?,
+            quad.graph_name_proxy().map(Into::into),
+        ))
+    }
 }
 
 // rdf_types::LexicalQuadRef as Quad
@@ -168,6 +188,10 @@ impl Quad for rt::LexicalQuadRef<'_> {
     fn graph_name(&self) -> Option<Self::GraphName<'_>> {
         self.graph().copied()
     }
+
+    fn graph_name_proxy(&self) -> Option<GraphNameProxy<'_>> {
+        self.graph().map(|gn| gn.as_graph_name_proxy())
+    }
 }
 
 // rdf_types::Subject as Subject
@@ -179,6 +203,13 @@ impl Subject for rt::Subject {
             rt::Id::Iri(iri) => SubjectProxy::Iri(Iri::new_unchecked(iri.as_str())),
         }
     }
+
+    fn subject_kind(&self) -> SubjectKind {
+        match self {
+            rt::Id::Blank(_) => SubjectKind::BlankNode,
+            rt::Id::Iri(_) => SubjectKind::Iri,
+        }
+    }
 }
 
 impl<'a> From<SubjectProxy<'a>> for rt::Subject {
@@ -199,6 +230,13 @@ impl Subject for rt::LexicalSubjectRef<'_> {
             rt::Id::Iri(iri) => SubjectProxy::Iri(Iri::new_unchecked(iri.as_str())),
         }
     }
+
+    fn subject_kind(&self) -> SubjectKind {
+        match self {
+            rt::Id::Blank(_) => SubjectKind::BlankNode,
+            rt::Id::Iri(_) => SubjectKind::Iri,
+        }
+    }
 }
 
 // rdf_types::IriBuf as Predicate
@@ -250,36 +288,25 @@ impl Object for rt::Object {
             }),
         }
     }
+
+    fn object_kind(&self) -> ObjectKind {
+        match self {
+            rt::Term::Id(rt::Id::Blank(_)) => ObjectKind::BlankNode,
+            rt::Term::Id(rt::Id::Iri(_)) => ObjectKind::Iri,
+            rt::Term::Literal(_) => ObjectKind::Literal,
+        }
+    }
+
+    fn ground(&self) -> bool {
+        !matches!(self, rt::Term::Id(rt::Id::Blank(_)))
+    }
 }
 
 impl<'a, T: Triple> TryFrom<ObjectProxy<'a, T>> for rt::Object {
     type Error = &'static str;
 
     fn try_from(value: ObjectProxy<'a, T>) -> Result<Self, Self::Error> {
-        Ok(match value {
-            ObjectProxy::Iri(iri) => rt::Object::Id(rt::Id::Iri(rt::IriBuf::from(iri))),
-            ObjectProxy::BlankNode(bnid) => rt::Object::Id(rt::Id::Blank(safe_bnode(bnid))),
-            ObjectProxy::Literal(literal) => rt::Object::Literal(match literal {
-                Literal::Typed(lex, iri) => rt::Literal::new(
-                    lex.into_owned(),
-                    rt::LiteralType::Any(unsafe {
-                        // SAFETY: iri is known to be a valid IRI
-                        rt::IriBuf::new_unchecked(iri.unwrap().into_owned())
-                    }),
-                ),
-                Literal::LanguageString(lex, lang_tag, None) => rt::Literal::new(
-                    lex.into_owned(),
-                    rt::LiteralType::LangString(unsafe {
-                        // SAFETY: lang_tag is known to be a valid language tag
-                        langtag::LangTagBuf::new_unchecked(lang_tag.unwrap().into_owned())
-                    }),
-                ),
-                Literal::LanguageString(_, _, Some(_)) => {
-                    Err("directional language strings are not supported by the crate rdf_types")?
-                }
-            }),
-            ObjectProxy::Triple(_) => Err("triple-terms are not supported by the crate rdf_types")?,
-        })
+        RdfTypesTermFactory::default().term(value)
     }
 }
 
@@ -307,6 +334,18 @@ impl Object for rt::LexicalObjectRef<'_> {
             }),
         }
     }
+
+    fn object_kind(&self) -> ObjectKind {
+        match self {
+            rt::Term::Id(rt::Id::Blank(_)) => ObjectKind::BlankNode,
+            rt::Term::Id(rt::Id::Iri(_)) => ObjectKind::Iri,
+            rt::Term::Literal(_) => ObjectKind::Literal,
+        }
+    }
+
+    fn ground(&self) -> bool {
+        !matches!(self, rt::Term::Id(rt::Id::Blank(_)))
+    }
 }
 
 // rdf_types::GraphLabel as GraphName
@@ -318,6 +357,13 @@ impl GraphName for rt::GraphLabel {
             rt::Id::Iri(iri) => GraphNameProxy::Iri(Iri::new_unchecked(iri.as_str())),
         }
     }
+
+    fn graph_name_kind(&self) -> GraphNameKind {
+        match self {
+            rt::Id::Blank(_) => GraphNameKind::BlankNode,
+            rt::Id::Iri(_) => GraphNameKind::Iri,
+        }
+    }
 }
 
 impl<'a> From<GraphNameProxy<'a>> for rt::GraphLabel {
@@ -338,18 +384,116 @@ impl GraphName for rt::LexicalGraphLabelRef<'_> {
             rt::Id::Iri(iri) => GraphNameProxy::Iri(Iri::new_unchecked(iri.as_str())),
         }
     }
+
+    fn graph_name_kind(&self) -> GraphNameKind {
+        match self {
+            rt::Id::Blank(_) => GraphNameKind::BlankNode,
+            rt::Id::Iri(_) => GraphNameKind::Iri,
+        }
+    }
+}
+
+// RdfTypesTermFactory
+
+/// A [`TermFactory`] building [`rdf_types`] terms.
+///
+/// As [`rdf_types`] implements RDF 1.1,
+/// building directional language strings or triple terms fails.
+///
+/// Blank node labels are mapped through a function,
+/// which by default is [`BnodeId::sanitized`], whose output [`rdf_types`] always accepts
+/// (unlike the output of [`sanitize_bnode_label`], which may contain `.`).
+#[derive(Clone, Debug)]
+pub struct RdfTypesTermFactory<F = fn(&str) -> String> {
+    bnode_label: F,
+}
+
+impl<F: FnMut(&str) -> String> RdfTypesTermFactory<F> {
+    /// Build a factory mapping blank node labels through `bnode_label`.
+    ///
+    /// `bnode_label` should return labels (without the `_:` prefix) that [`rdf_types`] accepts,
+    /// i.e. [valid blank node labels](is_valid_bnode_label) without `.`;
+    /// any other label it returns is replaced by [`BnodeId::sanitized`].
+    pub fn with_bnode_label(bnode_label: F) -> Self {
+        RdfTypesTermFactory { bnode_label }
+    }
+}
+
+impl Default for RdfTypesTermFactory {
+    fn default() -> Self {
+        RdfTypesTermFactory::with_bnode_label(|label| {
+            BnodeId::sanitized(label).unwrap().into_owned()
+        })
+    }
+}
+
+impl<F: FnMut(&str) -> String> TermFactory for RdfTypesTermFactory<F> {
+    type Term = rt::Object;
+    type Triple = rt::LexicalTriple;
+    type Error = &'static str;
+
+    fn iri(&mut self, iri: Iri) -> Self::Term {
+        rt::Object::Id(rt::Id::Iri(rt::IriBuf::from(iri)))
+    }
+
+    fn bnode(&mut self, label: &str) -> Self::Term {
+        let label = (self.bnode_label)(label);
+        rt::Object::Id(rt::Id::Blank(safe_bnode(label.into())))
+    }
+
+    fn literal(&mut self, literal: Literal) -> Result<Self::Term, Self::Error> {
+        Ok(rt::Object::Literal(match literal {
+            Literal::Typed(lex, iri) => rt::Literal::new(
+                lex.into_owned(),
+                rt::LiteralType::Any(unsafe {
+                    // SAFETY: iri is known to be a valid IRI
+                    rt::IriBuf::new_unchecked(iri.unwrap().into_owned())
+                }),
+            ),
+            Literal::LanguageString(lex, lang_tag, None) => rt::Literal::new(
+                lex.into_owned(),
+                rt::LiteralType::LangString(unsafe {
+                    // SAFETY: lang_tag is known to be a valid language tag
+                    langtag::LangTagBuf::new_unchecked(lang_tag.unwrap().into_owned())
+                }),
+            ),
+            Literal::LanguageString(_, _, Some(_)) => {
+                Err("directional language strings are not supported by the crate rdf_types")?
+            }
+        }))
+    }
+
+    fn triple(
+        &mut self,
+        subject: Self::Term,
+        predicate: Self::Term,
+        object: Self::Term,
+    ) -> Result<Self::Triple, Self::Error> {
+        let rt::Object::Id(subject) = subject else {
+            Err("the subject of a triple must be an IRI or a blank node")?
+        };
+        let rt::Object::Id(rt::Id::Iri(predicate)) = predicate else {
+            Err("the predicate of a triple must be an IRI")?
+        };
+        Ok(rt::Triple(subject, predicate, object))
+    }
+
+    fn triple_term(&mut self, _: Self::Triple) -> Result<Self::Term, Self::Error> {
+        Err("triple-terms are not supported by the crate rdf_types")
+    }
 }
 
 // utility functions
 
 /// This function converts an R2C2 bnode label into an rdf_types Blank Node,
 /// ensuring that bnode labels that are not valid SPARQL bnodeIds are correctly handled
+/// (they are replaced by [`BnodeId::sanitized`]).
 fn safe_bnode(bnid: std::borrow::Cow<str>) -> rt::BlankIdBuf {
-    rt::BlankIdBuf::new(format!("_:{bnid}")).unwrap_or_else(|err| {
-        use std::hash::{DefaultHasher, Hash, Hasher};
-        let mut s = DefaultHasher::new();
-        err.0.hash(&mut s);
-        rt::BlankIdBuf::from_u64(s.finish())
+    rt::BlankIdBuf::new(format!("_:{bnid}")).unwrap_or_else(|_| unsafe {
+        // SAFETY: BnodeId::sanitized only keeps PN_CHARS, excluding '_' and ':',
+        // starting with a letter or a digit, and escapes every other character with '_' and hex digits,
+        // which rdf_types accepts ('.', which rdf_types rejects, is never kept)
+        rt::BlankIdBuf::new_unchecked(format!("_:{}", BnodeId::sanitized(&bnid).as_str()))
     })
 }
 
@@ -369,6 +513,27 @@ mod test_round_trip {
         Ok(())
     }
 
+    #[test]
+    fn bnode_with_dot() {
+        // valid in N-Triples, but not in rdf_types
+        for label in ["a.b", "a..b", "é.1"] {
+            let s = SubjectProxy::BlankNode(label.into());
+            let rt::Subject::Blank(bnid) = rt::Subject::from(s) else {
+                panic!("subject {label:?} is not a blank node");
+            };
+            assert!(rt::BlankIdBuf::new(bnid.to_string()).is_ok(), "{bnid}");
+            assert_eq!(
+                bnid.as_str(),
+                format!("_:{}", BnodeId::sanitized(label).as_str())
+            );
+        }
+        let g = GraphNameProxy::BlankNode("g.".into());
+        let rt::GraphLabel::Blank(bnid) = rt::GraphLabel::from(g) else {
+            panic!("graph name is not a blank node");
+        };
+        assert_eq!(bnid.as_str(), "_:g_2e_");
+    }
+
     #[test]
     fn subject_bnode() -> TestResult {
         let s1 = rt::Subject::Blank(rt::BlankIdBuf::new("_:b1".into()).unwrap());
@@ -435,6 +600,40 @@ mod test_round_trip {
         Ok(())
     }
 
+    #[test]
+    fn object_literal_accessors() -> TestResult {
+        let o = rt::Object::Literal(rt::Literal {
+            value: "⛄".into(),
+            type_: rt::LiteralType::Any(rt::IriBuf::new(XSD_STRING.into())?),
+        });
+        assert_eq!(o.literal_lexical_form().unwrap(), "⛄");
+        assert_eq!(o.literal_datatype(), Some(ns::xsd::STRING));
+        assert_eq!(o.literal_language_tag(), None);
+        assert_eq!(o.literal_base_direction(), None);
+
+        let o = rt::Object::Literal(rt::Literal {
+            value: "chat".into(),
+            type_: rt::LiteralType::LangString(langtag::LangTagBuf::new("en-GB".into())?),
+        });
+        let o = o.as_lexical_object_ref();
+        assert_eq!(o.literal_lexical_form().unwrap(), "chat");
+        assert_eq!(o.literal_datatype(), Some(ns::rdf::LANG_STRING));
+        assert_eq!(o.literal_language_tag().unwrap(), "en-GB");
+        assert_eq!(o.literal_base_direction(), None);
+
+        let non_literals: [rt::Object; 2] = [
+            rt::Object::from_iri(rt::IriBuf::new("https://example.org/ns/alice".into())?),
+            rt::Object::from_blank(rt::BlankIdBuf::new("_:b1".into()).unwrap()),
+        ];
+        for o in non_literals {
+            assert_eq!(o.literal_lexical_form(), None);
+            assert_eq!(o.literal_datatype(), None);
+            assert_eq!(o.literal_language_tag(), None);
+            assert_eq!(o.literal_base_direction(), None);
+        }
+        Ok(())
+    }
+
     #[test]
     fn triple() -> TestResult {
         let s0 = rt::Subject::Blank(rt::BlankIdBuf::new("_:b1".into()).unwrap());
@@ -523,10 +722,10 @@ mod test_round_trip {
     fn object_iri_via_oxrdf() -> TestResult {
         let o1: rt::Object =
             rt::Object::from_iri(rt::IriBuf::new("https://example.org/ns/alice".into())?);
-        let o2: oxrdf::Term = o1.as_object_proxy().into();
+        let o2: oxrdf::Term = o1.as_object_proxy().try_into()?;
         let o3: rt::Object = o2.as_object_proxy().try_into()?;
         assert_eq!(o1, o3);
-        let o4: oxrdf::Term = o3.as_object_proxy().into();
+        let o4: oxrdf::Term = o3.as_object_proxy().try_into()?;
         assert_eq!(o2, o4);
         Ok(())
     }
@@ -534,10 +733,10 @@ mod test_round_trip {
     #[test]
     fn object_bnode_via_oxrdf() -> TestResult {
         let o1: rt::Object = rt::Object::from_blank(rt::BlankIdBuf::new("_:b1".into()).unwrap());
-        let o2: oxrdf::Term = o1.as_object_proxy().into();
+        let o2: oxrdf::Term = o1.as_object_proxy().try_into()?;
         let o3: rt::Object = o2.as_object_proxy().try_into()?;
         assert_eq!(o1, o3);
-        let o4: oxrdf::Term = o3.as_object_proxy().into();
+        let o4: oxrdf::Term = o3.as_object_proxy().try_into()?;
         assert_eq!(o2, o4);
         Ok(())
     }
@@ -548,10 +747,10 @@ mod test_round_trip {
             value: "⛄".into(),
             type_: rt::LiteralType::Any(rt::IriBuf::new(XSD_STRING.into())?),
         });
-        let o2: oxrdf::Term = o1.as_object_proxy().into();
+        let o2: oxrdf::Term = o1.as_object_proxy().try_into()?;
         let o3: rt::Object = o2.as_object_proxy().try_into()?;
         assert_eq!(o1, o3);
-        let o4: oxrdf::Term = o3.as_object_proxy().into();
+        let o4: oxrdf::Term = o3.as_object_proxy().try_into()?;
         assert_eq!(o2, o4);
         Ok(())
     }
@@ -562,10 +761,10 @@ mod test_round_trip {
             value: "⛄".into(),
             type_: rt::LiteralType::LangString(langtag::LangTagBuf::new("en-Latn-UK".into())?),
         });
-        let o2: oxrdf::Term = o1.as_object_proxy().into();
+        let o2: oxrdf::Term = o1.as_object_proxy().try_into()?;
         let o3: rt::Object = o2.as_object_proxy().try_into()?;
         assert_eq!(o1, o3);
-        let o4: oxrdf::Term = o3.as_object_proxy().into();
+        let o4: oxrdf::Term = o3.as_object_proxy().try_into()?;
         assert_eq!(o2, o4);
         Ok(())
     }
@@ -626,3 +825,241 @@ mod test_round_trip {
 
     static XSD_STRING: &str = "http://www.w3.org/2001/XMLSchema#string";
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn spo() -> TestResult {
+        let t = rt::Triple(
+            rt::Subject::Blank(rt::BlankIdBuf::new("_:b1".into()).unwrap()),
+            rt::IriBuf::new("https://example.org/ns/p".into())?,
+            rt::Object::Literal(rt::Literal::new(
+                "⛄".into(),
+                rt::LiteralType::Any(rt::IriBuf::new(XSD_STRING.into())?),
+            )),
+        );
+        let (s, p, o) = t.spo();
+        assert_eq!(s, t.0.as_lexical_subject_ref());
+        assert_eq!(p, t.1.as_iri());
+        assert_eq!(o, t.2.as_lexical_object_ref());
+        Ok(())
+    }
+
+    #[test]
+    fn spo_proxies() -> TestResult {
+        let t = rt::Triple(
+            rt::Subject::Blank(rt::BlankIdBuf::new("_:b1".into()).unwrap()),
+            rt::IriBuf::new("https://example.org/ns/p".into())?,
+            rt::Object::Literal(rt::Literal::new(
+                "⛄".into(),
+                rt::LiteralType::Any(rt::IriBuf::new(XSD_STRING.into())?),
+            )),
+        );
+        let t_ref = t.as_lexical_triple_ref();
+        let spo = t_ref.spo();
+        let (SubjectProxy::BlankNode(s), p, ObjectProxy::Literal(o)) = spo.spo_proxies() else {
+            panic!("unexpected proxies");
+        };
+        assert_eq!(s, "b1");
+        assert_eq!(p, "https://example.org/ns/p");
+        assert_eq!(o.lexical_form(), "⛄");
+        Ok(())
+    }
+
+    #[test]
+    fn spog_proxies() -> TestResult {
+        let q = rt::Quad(
+            rt::Subject::Iri(rt::IriBuf::new("https://example.org/ns/s".into())?),
+            rt::IriBuf::new("https://example.org/ns/p".into())?,
+            rt::Object::Literal(rt::Literal::new(
+                "⛄".into(),
+                rt::LiteralType::Any(rt::IriBuf::new(XSD_STRING.into())?),
+            )),
+            Some(rt::GraphLabel::Iri(rt::IriBuf::new(
+                "https://example.org/".into(),
+            )?)),
+        );
+        let spog = q.spog();
+        let (SubjectProxy::Iri(s), p, ObjectProxy::Literal(_), Some(GraphNameProxy::Iri(g))) =
+            spog.spog_proxies()
+        else {
+            panic!("unexpected proxies");
+        };
+        assert_eq!(s, "https://example.org/ns/s");
+        assert_eq!(p, "https://example.org/ns/p");
+        assert_eq!(g, "https://example.org/");
+        Ok(())
+    }
+
+    #[test]
+    fn graph_name_methods() -> TestResult {
+        let mut q = rt::Quad(
+            rt::Subject::Blank(rt::BlankIdBuf::new("_:b1".into()).unwrap()),
+            rt::IriBuf::new("https://example.org/ns/p".into())?,
+            rt::Object::Literal(rt::Literal::new(
+                "⛄".into(),
+                rt::LiteralType::Any(rt::IriBuf::new(XSD_STRING.into())?),
+            )),
+            None,
+        );
+        assert!(Quad::is_default_graph(&q));
+        assert!(q.as_lexical_quad_ref().is_default_graph());
+        assert_eq!(Quad::graph_name_kind(&q), None);
+        assert_eq!(Quad::graph_name_iri(&q), None);
+
+        q.3 = Some(rt::GraphLabel::Iri(rt::IriBuf::new(
+            "https://example.org/".into(),
+        )?));
+        assert!(!Quad::is_default_graph(&q));
+        assert_eq!(Quad::graph_name_kind(&q), Some(GraphNameKind::Iri));
+        assert_eq!(
+            Quad::graph_name_iri(&q),
+            Some(Iri::new_unchecked("https://example.org/"))
+        );
+        assert_eq!(
+            q.as_lexical_quad_ref().graph_name_iri(),
+            Quad::graph_name_iri(&q)
+        );
+        // the overridden method agrees with the default implementation
+        assert_eq!(
+            <&rt::LexicalQuad as Quad>::graph_name_proxy(&&q),
+            Quad::graph_name_proxy(&q)
+        );
+
+        q.3 = Some(rt::GraphLabel::Blank(
+            rt::BlankIdBuf::new("_:g".into()).unwrap(),
+        ));
+        assert!(!Quad::is_default_graph(&q));
+        assert_eq!(Quad::graph_name_kind(&q), Some(GraphNameKind::BlankNode));
+        assert_eq!(Quad::graph_name_iri(&q), None);
+        Ok(())
+    }
+
+    #[test]
+    fn hash_cross_impl() -> TestResult {
+        use std::{collections::HashSet, hash::DefaultHasher, hash::Hasher};
+
+        let t1 = rt::Triple(
+            rt::Subject::Blank(rt::BlankIdBuf::new("_:b1".into()).unwrap()),
+            rt::IriBuf::new("https://example.org/ns/p".into())?,
+            rt::Object::Literal(rt::Literal::new(
+                "chat".into(),
+                rt::LiteralType::LangString(langtag::LangTagBuf::new("en-GB".into())?),
+            )),
+        );
+        let t2 = oxrdf::Triple::new(
+            oxrdf::BlankNode::new("b1")?,
+            oxrdf::NamedNode::new("https://example.org/ns/p")?,
+            oxrdf::Literal::new_language_tagged_literal("chat", "en-gb")?,
+        );
+        let t1_ref = t1.as_lexical_triple_ref();
+        assert!(triple_eq(&t1_ref, &t2));
+        let mut h1 = DefaultHasher::new();
+        triple_hash(&t1_ref, &mut h1);
+        let mut h2 = DefaultHasher::new();
+        triple_hash(&t2, &mut h2);
+        assert_eq!(h1.finish(), h2.finish());
+
+        let set: HashSet<_> = [
+            HashableTriple(SimpleTriple::from_triple(t1_ref)),
+            HashableTriple(SimpleTriple::from_triple(&t2)),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(set.len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn dyn_triple() -> TestResult {
+        let t1 = rt::Triple(
+            rt::Subject::Blank(rt::BlankIdBuf::new("_:b1".into()).unwrap()),
+            rt::IriBuf::new("https://example.org/ns/p".into())?,
+            rt::Object::Literal(rt::Literal::new(
+                "⛄".into(),
+                rt::LiteralType::Any(rt::IriBuf::new(XSD_STRING.into())?),
+            )),
+        );
+        let t2 = oxrdf::Triple::new(
+            oxrdf::NamedNode::new("https://example.org/ns/s")?,
+            oxrdf::NamedNode::new("https://example.org/ns/p")?,
+            oxrdf::NamedNode::new("https://example.org/ns/o")?,
+        );
+        let triples: Vec<Box<dyn DynTriple>> = vec![Box::new(t1), Box::new(t2)];
+        let subjects: Vec<_> = triples.iter().map(|t| t.dyn_subject()).collect();
+        assert_eq!(
+            subjects,
+            vec![
+                SubjectProxy::BlankNode("b1".into()),
+                SubjectProxy::Iri(Iri::new_unchecked("https://example.org/ns/s")),
+            ]
+        );
+        for t in &triples {
+            assert_eq!(t.dyn_predicate(), "https://example.org/ns/p");
+            assert!(triple_eq(t, &SimpleTriple::from_triple(t)));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn kinds() -> TestResult {
+        let iri = rt::IriBuf::new("https://example.org/ns/alice".into())?;
+        let bnode = rt::BlankIdBuf::new("_:b1".into()).unwrap();
+        let objects: [(rt::Object, _, _); 3] = [
+            (
+                rt::Object::Id(rt::Id::Iri(iri.clone())),
+                ObjectKind::Iri,
+                true,
+            ),
+            (
+                rt::Object::Id(rt::Id::Blank(bnode.clone())),
+                ObjectKind::BlankNode,
+                false,
+            ),
+            (
+                rt::Object::Literal(rt::Literal::new(
+                    "⛄".into(),
+                    rt::LiteralType::Any(rt::IriBuf::new(XSD_STRING.into())?),
+                )),
+                ObjectKind::Literal,
+                true,
+            ),
+        ];
+        for (o, kind, ground) in objects {
+            // the overridden methods agree with the default ones, based on the proxy
+            assert_eq!(o.as_object_proxy().object_kind(), kind);
+            assert_eq!(Object::ground(&o.as_object_proxy()), ground);
+            assert_eq!(o.object_kind(), kind);
+            assert_eq!(o.as_lexical_object_ref().object_kind(), kind);
+            assert_eq!(Object::ground(&o), ground);
+            assert_eq!(Object::ground(&o.as_lexical_object_ref()), ground);
+        }
+
+        let s = rt::Subject::Iri(iri.clone());
+        assert_eq!(s.subject_kind(), SubjectKind::Iri);
+        assert_eq!(s.as_lexical_subject_ref().subject_kind(), SubjectKind::Iri);
+        let s = rt::Subject::Blank(bnode.clone());
+        assert_eq!(s.subject_kind(), SubjectKind::BlankNode);
+        assert_eq!(
+            s.as_lexical_subject_ref().subject_kind(),
+            SubjectKind::BlankNode
+        );
+        let g = rt::GraphLabel::Iri(iri);
+        assert_eq!(g.graph_name_kind(), GraphNameKind::Iri);
+        assert_eq!(g.as_graph_label_ref().graph_name_kind(), GraphNameKind::Iri);
+        let g = rt::GraphLabel::Blank(bnode);
+        assert_eq!(g.graph_name_kind(), GraphNameKind::BlankNode);
+        assert_eq!(
+            g.as_graph_label_ref().graph_name_kind(),
+            GraphNameKind::BlankNode
+        );
+        assert!(!GraphName::ground(&g));
+        Ok(())
+    }
+
+    type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+    static XSD_STRING: &str = "http://www.w3.org/2001/XMLSchema#string";
+}
@@ -0,0 +1,500 @@
+//! Proof-of-concept implementation of this crate's traits for [`sophia_term`].
+//!
+//! Only present with the `poc_impl_sophia` feature.
+//!
+//! [`sophia_term`] implements *generalized* RDF-star,
+//! which differs from RDF 1.2 in several ways:
+//! - it does not support base direction in literals, so it is not complete;
+//! - it allows any kind of term (including variables) in any position of a triple, so it is not strict.
+//!
+//! Therefore, similarly to [`crate::impl_oxrdf`],
+//! the traits are implemented by panic'ing when a non-strict situation is encountered
+//! (see however [`GeneralizedSubject`]),
+//! and similarly to [`crate::impl_rdf_types`],
+//! conversion from R2C2 is performed using [`TryFrom`] (when not infallible).
+//!
+//! The traits are implemented for both [`ArcTerm`](sophia_term::ArcTerm) and [`RcTerm`](sophia_term::RcTerm),
+//! with triples represented as arrays of 3 terms (as [`sophia_api`] does),
+//! and quads represented as a triple and an optional graph name.
+use crate::*;
+use sophia_api::term::{BnodeId, IriRef, LanguageTag};
+use sophia_term::GenericLiteral;
+
+macro_rules! impl_for_sophia_term {
+    ($term: ident, $wrapper: path, $try_from_triple: ident, $try_from_quad: ident) => {
+        use sophia_term::$term;
+        use $wrapper as W;
+
+        // [sophia_term::$term; 3] as Triple
+
+        impl Triple for [$term; 3] {
+            type Subject<'x>
+                = &'x $term
+            where
+                Self: 'x;
+
+            type Predicate<'x>
+                = &'x $term
+            where
+                Self: 'x;
+
+            type Object<'x>
+                = &'x $term
+            where
+                Self: 'x;
+
+            fn subject(&self) -> Self::Subject<'_> {
+                &self[0]
+            }
+
+            fn predicate(&self) -> Self::Predicate<'_> {
+                &self[1]
+            }
+
+            fn object(&self) -> Self::Object<'_> {
+                &self[2]
+            }
+        }
+
+        #[doc = concat!("This function would typically be implemented by [`sophia_term::", stringify!($term), "`] itself.")]
+        pub fn $try_from_triple<T: Triple>(triple: T) -> Result<[$term; 3], &'static str> {
+            Ok([
+                triple.subject().as_subject_proxy().into(),
+                triple.predicate().as_iri().into(),
+                triple.object().as_object_proxy().try_into()?,
+            ])
+        }
+
+        // ([sophia_term::$term; 3], Option<sophia_term::$term>) as Quad
+
+        impl Quad for ([$term; 3], Option<$term>) {
+            type Subject<'x>
+                = &'x $term
+            where
+                Self: 'x;
+
+            type Predicate<'x>
+                = &'x $term
+            where
+                Self: 'x;
+
+            type Object<'x>
+                = &'x $term
+            where
+                Self: 'x;
+
+            type GraphName<'x>
+                = &'x $term
+            where
+                Self: 'x;
+
+            fn subject(&self) -> Self::Subject<'_> {
+                &self.0[0]
+            }
+
+            fn predicate(&self) -> Self::Predicate<'_> {
+                &self.0[1]
+            }
+
+            fn object(&self) -> Self::Object<'_> {
+                &self.0[2]
+            }
+
+            fn graph_name(&self) -> Option<Self::GraphName<'_>> {
+                self.1.as_ref()
+            }
+        }
+
+        #[doc = concat!("This function would typically be implemented by [`sophia_term::", stringify!($term), "`] itself.")]
+        pub fn $try_from_quad<T: Quad>(
+            quad: T,
+        ) -> Result<([$term; 3], Option<$term>), &'static str> {
+            Ok((
+                [
+                    quad.subject().as_subject_proxy().into(),
+                    quad.predicate().as_iri().into(),
+                    quad.object().as_object_proxy().try_into()?,
+                ],
+                quad.graph_name().map(|gn| gn.as_graph_name_proxy().into()),
+            ))
+        }
+
+        // sophia_term::$term as Subject
+
+        impl Subject for $term {
+            fn as_subject_proxy(&self) -> SubjectProxy<'_> {
+                match self {
+                    $term::Iri(iri) => SubjectProxy::Iri(Iri::new_unchecked(iri.as_str())),
+                    $term::BlankNode(bnid) => SubjectProxy::BlankNode(bnid.as_str().into()),
+                    _ => {
+                        panic!()
+                        // Sophia supports generalized RDF, where any term can be used in any position.
+                        //
+                        // See the implementation of GeneralizedSubject below.
+                    }
+                }
+            }
+        }
+
+        impl GeneralizedSubject for $term {
+            fn try_as_subject_proxy(&self) -> Result<SubjectProxy<'_>, GeneralizedError> {
+                match self {
+                    $term::Iri(_) | $term::BlankNode(_) => Ok(self.as_subject_proxy()),
+                    $term::Literal(_) => Err(GeneralizedError::LiteralInSubject),
+                    $term::Triple(_) => Err(GeneralizedError::TripleTermInSubject),
+                    $term::Variable(_) => Err(GeneralizedError::NotAnRdfTerm),
+                }
+            }
+        }
+
+        impl<'a> From<SubjectProxy<'a>> for $term {
+            fn from(value: SubjectProxy<'a>) -> Self {
+                match value {
+                    SubjectProxy::Iri(iri) => iri.into(),
+                    SubjectProxy::BlankNode(bnid) => $term::BlankNode(safe_bnode(bnid)),
+                }
+            }
+        }
+
+        // sophia_term::$term as Predicate
+
+        impl Predicate for $term {
+            fn as_iri(&self) -> Iri<'_> {
+                match self {
+                    $term::Iri(iri) => Iri::new_unchecked(iri.as_str()),
+                    _ => {
+                        panic!()
+                        // Sophia supports generalized RDF, where any term can be used in any position.
+                    }
+                }
+            }
+        }
+
+        impl<'a> From<Iri<'a>> for $term {
+            fn from(value: Iri<'a>) -> Self {
+                $term::Iri(IriRef::new_unchecked(W::from(value.as_ref())))
+            }
+        }
+
+        // sophia_term::$term as Object
+
+        impl Object for $term {
+            type Triple<'x>
+                = &'x [$term; 3]
+            where
+                Self: 'x;
+
+            fn as_object_proxy(&self) -> ObjectProxy<'_, &'_ [$term; 3]> {
+                match self {
+                    $term::Iri(iri) => ObjectProxy::Iri(Iri::new_unchecked(iri.as_str())),
+                    $term::BlankNode(bnid) => ObjectProxy::BlankNode(bnid.as_str().into()),
+                    $term::Literal(GenericLiteral::Typed(lex, dt)) => ObjectProxy::Literal(
+                        Literal::Typed(lex.as_ref().into(), Iri::new_unchecked(dt.as_str())),
+                    ),
+                    $term::Literal(GenericLiteral::LanguageString(lex, tag)) => {
+                        ObjectProxy::Literal(Literal::LanguageString(
+                            lex.as_ref().into(),
+                            crate::LangTag::new_unchecked(tag.as_str()),
+                            None,
+                        ))
+                    }
+                    $term::Triple(triple) => ObjectProxy::Triple(triple),
+                    $term::Variable(_) => {
+                        panic!()
+                        // Sophia supports variables (for SPARQL and Notation3), which are not RDF terms.
+                    }
+                }
+            }
+        }
+
+        impl<'a, T: Triple> TryFrom<ObjectProxy<'a, T>> for $term {
+            type Error = &'static str;
+
+            fn try_from(value: ObjectProxy<'a, T>) -> Result<Self, Self::Error> {
+                Ok(match value {
+                    ObjectProxy::Iri(iri) => iri.into(),
+                    ObjectProxy::BlankNode(bnid) => $term::BlankNode(safe_bnode(bnid)),
+                    ObjectProxy::Literal(Literal::Typed(lex, iri)) => {
+                        $term::Literal(GenericLiteral::Typed(
+                            W::from(lex.as_ref()),
+                            IriRef::new_unchecked(W::from(iri.as_ref())),
+                        ))
+                    }
+                    ObjectProxy::Literal(Literal::LanguageString(lex, tag, None)) => {
+                        $term::Literal(GenericLiteral::LanguageString(
+                            W::from(lex.as_ref()),
+                            LanguageTag::new_unchecked(W::from(tag.as_ref())),
+                        ))
+                    }
+                    ObjectProxy::Literal(Literal::LanguageString(_, _, Some(_))) => {
+                        Err("directional language strings are not supported by the crate sophia_term")?
+                    }
+                    ObjectProxy::Triple(triple) => $term::Triple(W::new($try_from_triple(triple)?)),
+                })
+            }
+        }
+
+        // sophia_term::$term as GraphName
+
+        impl GraphName for $term {
+            fn as_graph_name_proxy(&self) -> GraphNameProxy<'_> {
+                match self {
+                    $term::Iri(iri) => GraphNameProxy::Iri(Iri::new_unchecked(iri.as_str())),
+                    $term::BlankNode(bnid) => GraphNameProxy::BlankNode(bnid.as_str().into()),
+                    _ => {
+                        panic!()
+                        // Sophia supports generalized RDF, where any term can be used in any position.
+                        //
+                        // See the implementation of GeneralizedGraphName below.
+                    }
+                }
+            }
+        }
+
+        impl GeneralizedGraphName for $term {
+            fn try_as_graph_name_proxy(&self) -> Result<GraphNameProxy<'_>, GeneralizedError> {
+                match self {
+                    $term::Iri(_) | $term::BlankNode(_) => Ok(self.as_graph_name_proxy()),
+                    $term::Literal(_) => Err(GeneralizedError::LiteralInGraphName),
+                    $term::Triple(_) => Err(GeneralizedError::TripleTermInGraphName),
+                    $term::Variable(_) => Err(GeneralizedError::NotAnRdfTerm),
+                }
+            }
+        }
+
+        impl<'a> From<GraphNameProxy<'a>> for $term {
+            fn from(value: GraphNameProxy<'a>) -> Self {
+                match value {
+                    GraphNameProxy::Iri(iri) => iri.into(),
+                    GraphNameProxy::BlankNode(bnid) => $term::BlankNode(safe_bnode(bnid)),
+                }
+            }
+        }
+
+        // utility functions
+
+        /// This function converts an R2C2 bnode label into a Sophia Blank Node identifier,
+        /// ensuring that bnode labels that are not valid Turtle bnode labels are correctly handled
+        /// (they are replaced by [`crate::BnodeId::sanitized`]).
+        fn safe_bnode(bnid: std::borrow::Cow<str>) -> BnodeId<W<str>> {
+            BnodeId::new(W::from(bnid.as_ref())).unwrap_or_else(|_| {
+                // the sanitized label never contains '.' or ':', so Sophia accepts it
+                BnodeId::new_unchecked(W::from(crate::BnodeId::sanitized(&bnid).as_str()))
+            })
+        }
+    };
+}
+
+mod arc_term {
+    use super::*;
+    impl_for_sophia_term!(
+        ArcTerm,
+        std::sync::Arc,
+        try_from_r2c2_triple_arc,
+        try_from_r2c2_quad_arc
+    );
+}
+pub use arc_term::*;
+
+mod rc_term {
+    use super::*;
+    impl_for_sophia_term!(
+        RcTerm,
+        std::rc::Rc,
+        try_from_r2c2_triple_rc,
+        try_from_r2c2_quad_rc
+    );
+}
+pub use rc_term::*;
+
+#[cfg(test)]
+mod test_round_trip {
+    use super::*;
+    use sophia_api::term::{FromTerm, Term};
+    use sophia_term::{ArcTerm, RcTerm};
+    use std::sync::Arc;
+
+    #[test]
+    fn subject_iri() -> TestResult {
+        let s1 = iri("https://example.org/ns/alice");
+        let s2: ArcTerm = s1.as_subject_proxy().into();
+        assert_eq!(s1, s2);
+        Ok(())
+    }
+
+    #[test]
+    fn bnode_with_dots() {
+        let s: ArcTerm = SubjectProxy::BlankNode("a.b".into()).into();
+        assert_eq!(s.bnode_id().unwrap().as_str(), "a.b");
+        // rejected by Sophia
+        let s: ArcTerm = SubjectProxy::BlankNode("a..b".into()).into();
+        assert_eq!(s.bnode_id().unwrap().as_str(), "a_2e__2e_b");
+    }
+
+    #[test]
+    fn subject_bnode() -> TestResult {
+        let s1 = ArcTerm::BlankNode(BnodeId::new_unchecked("b1".into()));
+        let s2: ArcTerm = s1.as_subject_proxy().into();
+        assert_eq!(s1, s2);
+        Ok(())
+    }
+
+    #[test]
+    fn predicate() -> TestResult {
+        let p1 = iri("https://example.org/ns/p");
+        let p2: ArcTerm = Predicate::as_iri(&p1).into();
+        assert_eq!(p1, p2);
+        Ok(())
+    }
+
+    #[test]
+    fn object_iri() -> TestResult {
+        let o1 = iri("https://example.org/ns/alice");
+        let o2: ArcTerm = o1.as_object_proxy().try_into()?;
+        assert_eq!(o1, o2);
+        Ok(())
+    }
+
+    #[test]
+    fn object_bnode() -> TestResult {
+        let o1 = ArcTerm::BlankNode(BnodeId::new_unchecked("b1".into()));
+        let o2: ArcTerm = o1.as_object_proxy().try_into()?;
+        assert_eq!(o1, o2);
+        Ok(())
+    }
+
+    #[test]
+    fn object_simple_literal() -> TestResult {
+        let o1 = ArcTerm::from_term("⛄");
+        let o2: ArcTerm = o1.as_object_proxy().try_into()?;
+        assert_eq!(o1, o2);
+        Ok(())
+    }
+
+    #[test]
+    fn object_typed_literal() -> TestResult {
+        let o1 = ArcTerm::from_term(42);
+        let o2: ArcTerm = o1.as_object_proxy().try_into()?;
+        assert_eq!(o1, o2);
+        Ok(())
+    }
+
+    #[test]
+    fn object_language_tagged_literal() -> TestResult {
+        let o1 = ArcTerm::Literal(GenericLiteral::LanguageString(
+            "chat".into(),
+            LanguageTag::new("en-Latn-UK".into())?,
+        ));
+        let o2: ArcTerm = o1.as_object_proxy().try_into()?;
+        assert_eq!(o1, o2);
+        Ok(())
+    }
+
+    #[test]
+    fn object_directional_language_tagged_literal() -> TestResult {
+        let o1 = ObjectProxy::<NeverTriple>::Literal(Literal::LanguageString(
+            "chat".into(),
+            crate::LangTag::new_unchecked("en-Latn-UK"),
+            Some(BaseDir::Ltr),
+        ));
+        assert!(ArcTerm::try_from(o1).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn object_triple_term() -> TestResult {
+        let subject = ArcTerm::BlankNode(BnodeId::new_unchecked("b1".into()));
+        let predicate = iri("https://example.org/ns/p");
+        let object = ArcTerm::from_term("⛄");
+        let o1 = ArcTerm::Triple(Arc::new([subject, predicate, object]));
+        let o2: ArcTerm = o1.as_object_proxy().try_into()?;
+        assert_eq!(o1, o2);
+        assert!(o2.is_triple());
+        Ok(())
+    }
+
+    #[test]
+    fn triple() -> TestResult {
+        let subject = ArcTerm::BlankNode(BnodeId::new_unchecked("b1".into()));
+        let predicate = iri("https://example.org/ns/p");
+        let object = ArcTerm::from_term("⛄");
+        let t1 = [subject, predicate, object];
+        let t2 = try_from_r2c2_triple_arc(&t1)?;
+        assert_eq!(t1, t2);
+        Ok(())
+    }
+
+    #[test]
+    fn triple_rc() -> TestResult {
+        let subject = RcTerm::BlankNode(BnodeId::new_unchecked("b1".into()));
+        let predicate = RcTerm::from_term(iri("https://example.org/ns/p"));
+        let object = RcTerm::from_term("⛄");
+        let t1 = [subject, predicate, object];
+        let t2 = try_from_r2c2_triple_rc(&t1)?;
+        assert_eq!(t1, t2);
+        Ok(())
+    }
+
+    #[test]
+    fn quad_default_graph() -> TestResult {
+        let subject = ArcTerm::BlankNode(BnodeId::new_unchecked("b1".into()));
+        let predicate = iri("https://example.org/ns/p");
+        let object = ArcTerm::from_term("⛄");
+        let q1 = ([subject, predicate, object], None);
+        let q2 = try_from_r2c2_quad_arc(&q1)?;
+        assert_eq!(q1, q2);
+        Ok(())
+    }
+
+    #[test]
+    fn quad_named_graph() -> TestResult {
+        let subject = ArcTerm::BlankNode(BnodeId::new_unchecked("b1".into()));
+        let predicate = iri("https://example.org/ns/p");
+        let object = ArcTerm::from_term("⛄");
+        let graph_name = iri("https://example.org/");
+        let q1 = ([subject, predicate, object], Some(graph_name));
+        let q2 = try_from_r2c2_quad_arc(&q1)?;
+        assert_eq!(q1, q2);
+        Ok(())
+    }
+
+    #[test]
+    fn generalized_subject() -> TestResult {
+        let s = iri("https://example.org/ns/s");
+        assert_eq!(s.try_as_subject_proxy()?, s.as_subject_proxy());
+        let s = ArcTerm::from_term("⛄");
+        assert_eq!(
+            s.try_as_subject_proxy(),
+            Err(GeneralizedError::LiteralInSubject)
+        );
+        let s = ArcTerm::Triple(Arc::new([s.clone(), s.clone(), s]));
+        assert_eq!(
+            s.try_as_subject_proxy(),
+            Err(GeneralizedError::TripleTermInSubject)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn generalized_graph_name() -> TestResult {
+        let g = iri("https://example.org/ns/g");
+        assert_eq!(g.try_as_graph_name_proxy()?, g.as_graph_name_proxy());
+        let g = ArcTerm::from_term("⛄");
+        assert_eq!(
+            g.try_as_graph_name_proxy(),
+            Err(GeneralizedError::LiteralInGraphName)
+        );
+        let g = ArcTerm::Triple(Arc::new([g.clone(), g.clone(), g]));
+        assert_eq!(
+            g.try_as_graph_name_proxy(),
+            Err(GeneralizedError::TripleTermInGraphName)
+        );
+        Ok(())
+    }
+
+    fn iri(txt: &str) -> ArcTerm {
+        ArcTerm::Iri(IriRef::new_unchecked(txt.into()))
+    }
+
+    type TestResult = Result<(), Box<dyn std::error::Error>>;
+}
@@ -7,17 +7,48 @@
 //! [RDF datasets]: https://www.w3.org/TR/rdf12-concepts/#dfn-rdf-dataset
 //!
 //! # Features
+//! * `std` (enabled by default): without this feature, the crate is `no_std`
+//!   (but still requires [`alloc`]).
+//!   All other features require `std`, unless stated otherwise.
 //! * `poc_impl`: include implementation of the traits defined in this crate
 //!   for existing RDF implementations.
 //!
 //!   As the name implies, this is only a proof of concept implementation.
 //!   It is expected that such RDF implementations will eventually implements the traits themselves.
+//! * `poc_impl_sophia`: include implementation of the traits defined in this crate
+//!   for [Sophia](https://docs.rs/sophia)'s terms (see `poc_impl` above).
+//! * `rayon`: include `write_ntriples_par`, serializing triples in parallel with [Rayon](https://docs.rs/rayon).
+//! * `rdfc`: include `canonicalize` and `canonicalize_with_limit`, implementing the [RDFC-1.0](https://www.w3.org/TR/rdf-canon/) canonicalization algorithm
+//!   (this feature does not require `std`).
+//! * `serde`: implement [Serde](https://serde.rs)'s `Serialize` and `Deserialize` for
+//!   [`SubjectProxy`], [`GraphNameProxy`], [`ObjectProxy`] and [`Literal`]
+//!   (this feature does not require `std`).
+//!
+//!   Terms are represented as small tagged maps, e.g.
+//!   `{"kind": "iri", "value": "https://example.org/"}`,
+//!   `{"kind": "literal", "value": "chat", "language": "fr", "direction": "ltr"}` or
+//!   `{"kind": "triple", "subject": {..}, "predicate": "https://example.org/p", "object": {..}}`.
+//!   These `Deserialize` implementations are the unchecked path: they do not validate IRIs,
+//!   blank node identifiers nor language tags. Untrusted input should rather be deserialized
+//!   through `r2c2_statement_validation::Validated` (feature `serde` of that crate).
+//! * `sparql_json`: include `term_to_sparql_json` and `term_from_sparql_json`,
+//!   converting terms to and from the representation of the
+//!   [SPARQL 1.2 Query Results JSON Format](https://www.w3.org/TR/sparql12-results-json/)
+//!   (this feature does not require `std`).
 #![deny(missing_docs)]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
 
 mod _iri;
+mod _text;
 pub use _iri::*;
 mod _literal;
 pub use _literal::*;
+mod _bnode_label;
+pub use _bnode_label::*;
+mod _shared;
+pub use _shared::*;
 
 mod _subject;
 pub use _subject::*;
@@ -27,13 +58,92 @@ mod _graph_name;
 pub use _graph_name::*;
 mod _object;
 pub use _object::*;
+mod _generalized;
+pub use _generalized::*;
+mod _term;
+pub use _term::*;
 
 mod _triple;
 pub use _triple::*;
 mod _quad;
 pub use _quad::*;
+mod _statement;
+pub use _statement::*;
+mod _graph;
+pub use _graph::*;
+mod _dataset;
+pub use _dataset::*;
+mod _term_stats;
+pub use _term_stats::*;
+mod _simple_triple;
+pub use _simple_triple::*;
+mod _simple_quad;
+pub use _simple_quad::*;
+mod _erased_term;
+pub use _erased_term::*;
+mod _dyn;
+pub use _dyn::*;
+mod _thread_safe;
+pub use _thread_safe::*;
+mod _from;
+pub use _from::*;
+mod _term_factory;
+pub use _term_factory::*;
+
+mod _bnode_renamer;
+pub use _bnode_renamer::*;
+mod _bnode_generator;
+pub use _bnode_generator::*;
+mod _term_interner;
+pub use _term_interner::*;
+mod _eq;
+pub use _eq::*;
+mod _hash;
+pub use _hash::*;
+mod _cmp;
+pub use _cmp::*;
+mod _quad_key;
+pub use _quad_key::*;
+mod _pattern;
+pub use _pattern::*;
+mod _visitor;
+pub use _visitor::*;
+mod _remap;
+pub use _remap::*;
+mod _isomorphism;
+pub use _isomorphism::*;
+#[cfg(feature = "rdfc")]
+mod _rdfc;
+#[cfg(feature = "rdfc")]
+pub use _rdfc::*;
+#[cfg(feature = "serde")]
+mod _serde;
+#[cfg(feature = "serde")]
+pub use _serde::*;
+#[cfg(feature = "sparql_json")]
+mod _sparql_json;
+#[cfg(feature = "sparql_json")]
+pub use _sparql_json::*;
+mod _macros;
+pub use _macros::*;
+mod _ntriples;
+pub use _ntriples::*;
+mod _reification;
+pub use _reification::*;
+mod _list;
+pub use _list::*;
+mod _quad_source;
+pub use _quad_source::*;
+mod _quad_sink;
+pub use _quad_sink::*;
 
+pub mod ns;
+
+#[cfg(feature = "poc_impl")]
+pub mod bridge;
 #[cfg(feature = "poc_impl")]
 pub mod impl_oxrdf;
 #[cfg(feature = "poc_impl")]
 pub mod impl_rdf_types;
+#[cfg(feature = "poc_impl_sophia")]
+pub mod impl_sophia;
@@ -0,0 +1,116 @@
+//! I provide [`Iri`](crate::Iri) constants for commonly used vocabularies.
+//!
+//! ```
+//! # use r2c2_statement::*;
+//! use r2c2_statement::ns::{rdf, xsd};
+//! let lit = Literal::Typed("42".into(), xsd::INTEGER);
+//! assert_eq!(lit.datatype_iri(), "http://www.w3.org/2001/XMLSchema#integer");
+//! assert_eq!(Literal::LanguageString("chat".into(), LangTag::new_unchecked("en"), None).datatype_iri(), rdf::LANG_STRING);
+//! ```
+
+/// Define a module of [`Iri`](crate::Iri) constants sharing a common namespace.
+macro_rules! namespace {
+    (
+        $(#[$attr:meta])*
+        $module:ident = $ns:literal, $prefix:literal {
+            $($name:ident = $local:literal,)*
+        }
+    ) => {
+        $(#[$attr])*
+        pub mod $module {
+            use crate::Iri;
+
+            #[doc = concat!("The namespace IRI of this vocabulary (`", $ns, "`).")]
+            pub const NAMESPACE: &str = $ns;
+
+            $(
+                #[doc = concat!("`", $prefix, ":", $local, "` (`", $ns, $local, "`)")]
+                pub const $name: Iri<'static> = Iri::new_static(concat!($ns, $local));
+            )*
+        }
+    };
+}
+
+namespace! {
+    /// The [RDF vocabulary](https://www.w3.org/TR/rdf12-concepts/#vocabularies).
+    rdf = "http://www.w3.org/1999/02/22-rdf-syntax-ns#", "rdf" {
+        DIR_LANG_STRING = "dirLangString",
+        FIRST = "first",
+        HTML = "HTML",
+        JSON = "JSON",
+        LANG_STRING = "langString",
+        LIST = "List",
+        NIL = "nil",
+        OBJECT = "object",
+        PREDICATE = "predicate",
+        PROPERTY = "Property",
+        REIFIES = "reifies",
+        REST = "rest",
+        STATEMENT = "Statement",
+        SUBJECT = "subject",
+        TYPE = "type",
+        VALUE = "value",
+        XML_LITERAL = "XMLLiteral",
+    }
+}
+
+namespace! {
+    /// The [XML Schema datatypes](https://www.w3.org/TR/rdf12-concepts/#xsd-datatypes) usable in RDF.
+    xsd = "http://www.w3.org/2001/XMLSchema#", "xsd" {
+        ANY_URI = "anyURI",
+        BASE64_BINARY = "base64Binary",
+        BOOLEAN = "boolean",
+        BYTE = "byte",
+        DATE = "date",
+        DATE_TIME = "dateTime",
+        DATE_TIME_STAMP = "dateTimeStamp",
+        DAY_TIME_DURATION = "dayTimeDuration",
+        DECIMAL = "decimal",
+        DOUBLE = "double",
+        DURATION = "duration",
+        FLOAT = "float",
+        G_DAY = "gDay",
+        G_MONTH = "gMonth",
+        G_MONTH_DAY = "gMonthDay",
+        G_YEAR = "gYear",
+        G_YEAR_MONTH = "gYearMonth",
+        HEX_BINARY = "hexBinary",
+        INT = "int",
+        INTEGER = "integer",
+        LANGUAGE = "language",
+        LONG = "long",
+        NAME = "Name",
+        NC_NAME = "NCName",
+        NEGATIVE_INTEGER = "negativeInteger",
+        NMTOKEN = "NMTOKEN",
+        NON_NEGATIVE_INTEGER = "nonNegativeInteger",
+        NON_POSITIVE_INTEGER = "nonPositiveInteger",
+        NORMALIZED_STRING = "normalizedString",
+        POSITIVE_INTEGER = "positiveInteger",
+        SHORT = "short",
+        STRING = "string",
+        TIME = "time",
+        TOKEN = "token",
+        UNSIGNED_BYTE = "unsignedByte",
+        UNSIGNED_INT = "unsignedInt",
+        UNSIGNED_LONG = "unsignedLong",
+        UNSIGNED_SHORT = "unsignedShort",
+        YEAR_MONTH_DURATION = "yearMonthDuration",
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn constants() {
+        assert_eq!(rdf::TYPE, "http://www.w3.org/1999/02/22-rdf-syntax-ns#type");
+        assert_eq!(xsd::DATE_TIME, "http://www.w3.org/2001/XMLSchema#dateTime");
+        assert!(rdf::LANG_STRING.as_str().starts_with(rdf::NAMESPACE));
+        assert!(matches!(
+            xsd::STRING.unwrap(),
+            alloc::borrow::Cow::Borrowed(_)
+        ));
+    }
+}
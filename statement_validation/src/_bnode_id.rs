@@ -0,0 +1,117 @@
+use std::{borrow::Cow, sync::LazyLock};
+
+use r2c2_statement::BnodeId;
+use regex::Regex;
+
+/// Extension trait for [`BnodeId`] providing validation methods.
+pub trait BnodeIdValidation<'a> {
+    /// Return a new [`BnodeId`] if the argument is a valid blank node label, otherwise None.
+    #[allow(clippy::new_ret_no_self)]
+    fn new(txt: impl Into<Cow<'a, str>>) -> Option<BnodeId<'a>>;
+
+    /// In debug mode, panic if this [`BnodeId`] is not valid.
+    /// In release mode, does nothing.
+    ///
+    /// Can be useful after a [`new_unchecked`](BnodeId::new_unchecked)
+    fn debug_assert_is_valid(&self);
+}
+
+impl<'a> BnodeIdValidation<'a> for BnodeId<'a> {
+    fn new(txt: impl Into<Cow<'a, str>>) -> Option<Self> {
+        let inner = txt.into();
+        BNODE_ID_REGEX
+            .is_match(&inner)
+            .then_some(BnodeId::new_unchecked(inner))
+    }
+
+    #[inline]
+    fn debug_assert_is_valid(&self) {
+        debug_assert!(BNODE_ID_REGEX.is_match(self.as_ref()))
+    }
+}
+
+pub(crate) static BNODE_ID_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(BNODE_ID_REGEX_SRC).unwrap());
+
+/// Match a valid blank node label (without the leading `_:`),
+/// as per the [`BLANK_NODE_LABEL`] production of Turtle and SPARQL.
+///
+/// [`BLANK_NODE_LABEL`]: https://www.w3.org/TR/rdf12-turtle/#grammar-production-BLANK_NODE_LABEL
+pub static BNODE_ID_REGEX_SRC: &str = r"(?x)^
+        # PN_CHARS_U | [0-9]
+        [_0-9A-Za-z\u{C0}-\u{D6}\u{D8}-\u{F6}\u{F8}-\u{2FF}\u{370}-\u{37D}\u{37F}-\u{1FFF}\u{200C}-\u{200D}\u{2070}-\u{218F}\u{2C00}-\u{2FEF}\u{3001}-\u{D7FF}\u{F900}-\u{FDCF}\u{FDF0}-\u{FFFD}\u{10000}-\u{EFFFF}]
+        (?:
+          # (PN_CHARS | '.')*
+          [-._0-9A-Za-z\u{B7}\u{C0}-\u{D6}\u{D8}-\u{F6}\u{F8}-\u{37D}\u{37F}-\u{1FFF}\u{200C}-\u{200D}\u{203F}-\u{2040}\u{2070}-\u{218F}\u{2C00}-\u{2FEF}\u{3001}-\u{D7FF}\u{F900}-\u{FDCF}\u{FDF0}-\u{FFFD}\u{10000}-\u{EFFFF}]*
+          # PN_CHARS
+          [-_0-9A-Za-z\u{B7}\u{C0}-\u{D6}\u{D8}-\u{F6}\u{F8}-\u{37D}\u{37F}-\u{1FFF}\u{200C}-\u{200D}\u{203F}-\u{2040}\u{2070}-\u{218F}\u{2C00}-\u{2FEF}\u{3001}-\u{D7FF}\u{F900}-\u{FDCF}\u{FDF0}-\u{FFFD}\u{10000}-\u{EFFFF}]
+        )?
+$";
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn regex() {
+        for txt in POSITIVE_LABELS {
+            assert!(BNODE_ID_REGEX.is_match(txt), "{txt}");
+        }
+        for txt in NEGATIVE_LABELS {
+            assert!(!BNODE_ID_REGEX.is_match(txt), "{txt}");
+        }
+    }
+
+    #[test]
+    fn new() {
+        for txt in POSITIVE_LABELS {
+            assert_eq!(BnodeId::new(*txt).unwrap().as_str(), *txt);
+        }
+        for txt in NEGATIVE_LABELS {
+            assert!(BnodeId::new(*txt).is_none());
+        }
+    }
+
+    #[test]
+    fn sanitized() {
+        for txt in POSITIVE_LABELS.iter().chain(NEGATIVE_LABELS) {
+            let label = BnodeId::sanitized(txt);
+            assert!(BNODE_ID_REGEX.is_match(label.as_str()), "{txt} -> {label}");
+        }
+    }
+
+    /// An array of valid blank node labels
+    pub const POSITIVE_LABELS: &[&str] = &[
+        "b",
+        "b1",
+        "1",
+        "_",
+        "_b",
+        "a_",
+        "a.b",
+        "a..b",
+        "a-b",
+        "é",
+        "a\u{B7}b",
+        "a\u{300}",
+        "a\u{203F}b",
+        "\u{10000}",
+    ];
+
+    /// An array of invalid blank node labels
+    pub const NEGATIVE_LABELS: &[&str] = &[
+        "",
+        ":",
+        "a:b",
+        "-a",
+        ".a",
+        "a.",
+        "a b",
+        "a/b",
+        "a#b",
+        "\u{B7}",
+        "\u{300}a",
+        "a\u{2041}",
+        "a\u{D7}b",
+    ];
+}
@@ -1,6 +1,6 @@
 use std::{borrow::Cow, sync::LazyLock};
 
-use r2c2_statement::Iri;
+use r2c2_statement::{Iri, Predicate};
 use regex::Regex;
 
 /// Extension trait for [`Iri`] providing validation methods.
@@ -14,6 +14,64 @@ pub trait IriValidation<'a> {
     ///
     /// Can be useful after a [`new_unchecked`](Iri::new_unchecked)
     fn debug_assert_is_valid(&self);
+
+    /// Apply `f` to the text of this [`Iri`],
+    /// and return the result if it is a valid IRI, otherwise None.
+    ///
+    /// This is the checked counterpart of [`Iri::map_unchecked`].
+    ///
+    /// ```
+    /// # use r2c2_statement::Iri;
+    /// # use r2c2_statement_validation::IriValidation;
+    /// let ns = Iri::new_unchecked("https://example.org/ns/");
+    /// let p = ns.clone().map(|txt| format!("{txt}p").into()).unwrap();
+    /// assert_eq!(p, "https://example.org/ns/p");
+    /// assert!(ns.map(|txt| format!("{txt}not valid").into()).is_none());
+    /// ```
+    fn map(self, f: impl FnMut(Cow<'a, str>) -> Cow<'a, str>) -> Option<Iri<'a>>;
+
+    /// Whether this [`Iri`] and `other` are equal,
+    /// comparing their scheme and host case-insensitively (as per [RFC 3987 §5.3.2.1]),
+    /// and the rest of them byte by byte.
+    ///
+    /// This is a purely syntax-based comparison, *not* a full IRI normalization:
+    /// in particular, percent-encodings, dot segments, default ports and Unicode normalization
+    /// are *not* taken into account, and only ASCII letters are case-folded.
+    /// If either IRI is not valid, this falls back to byte-exact comparison
+    /// (which is also what [`PartialEq`] always does).
+    ///
+    /// ```
+    /// # use r2c2_statement::Iri;
+    /// # use r2c2_statement_validation::IriValidation;
+    /// let i1 = Iri::new_unchecked("HTTP://Example.ORG/x");
+    /// let i2 = Iri::new_unchecked("http://example.org/x");
+    /// assert!(i1.eq_normalized(&i2));
+    /// assert_ne!(i1, i2);
+    /// assert!(!i1.eq_normalized(&Iri::new_unchecked("http://example.org/X")));
+    /// ```
+    ///
+    /// [RFC 3987 §5.3.2.1]: https://www.rfc-editor.org/rfc/rfc3987.html#section-5.3.2.1
+    fn eq_normalized(&self, other: &Iri<'_>) -> bool;
+
+    /// Return a new [`Iri`] with `segment` appended to the path of this one,
+    /// or None if this IRI has no hierarchical path, or if the result is not valid.
+    ///
+    /// A `/` is inserted before `segment`, unless the path already ends with one.
+    /// `segment` is [percent-encoded](crate::pct_encode_path_segment) (including any `/` it contains),
+    /// so it always becomes a single path segment;
+    /// for that reason, the empty segment, `.` and `..` are rejected.
+    /// The query and fragment of this IRI, if any, are dropped.
+    ///
+    /// ```
+    /// # use r2c2_statement::Iri;
+    /// # use r2c2_statement_validation::IriValidation;
+    /// let ns = Iri::new_unchecked("https://example.org/ns");
+    /// assert_eq!(ns.join("a b").unwrap(), "https://example.org/ns/a%20b");
+    /// let ns = Iri::new_unchecked("https://example.org/ns/");
+    /// assert_eq!(ns.join("a/b").unwrap(), "https://example.org/ns/a%2Fb");
+    /// assert!(Iri::new_unchecked("urn:isbn:0451450523").join("a").is_none());
+    /// ```
+    fn join(&self, segment: &str) -> Option<Iri<'static>>;
 }
 
 impl<'a> IriValidation<'a> for Iri<'a> {
@@ -28,6 +86,98 @@ impl<'a> IriValidation<'a> for Iri<'a> {
     fn debug_assert_is_valid(&self) {
         debug_assert!(IRI_REGEX.is_match(self.as_ref()))
     }
+
+    fn map(self, mut f: impl FnMut(Cow<'a, str>) -> Cow<'a, str>) -> Option<Self> {
+        let inner = f(self.unwrap());
+        IRI_REGEX
+            .is_match(&inner)
+            .then_some(Iri::new_unchecked(inner))
+    }
+
+    fn eq_normalized(&self, other: &Iri<'_>) -> bool {
+        match (
+            CaseInsensitiveParts::new(self),
+            CaseInsensitiveParts::new(other),
+        ) {
+            (Some(p1), Some(p2)) => p1 == p2,
+            _ => self == other,
+        }
+    }
+
+    fn join(&self, segment: &str) -> Option<Iri<'static>> {
+        crate::_resolve::join(self.as_str(), segment)
+    }
+}
+
+/// An IRI split around its case-insensitive parts (scheme and host).
+struct CaseInsensitiveParts<'a> {
+    scheme: &'a str,
+    before_host: &'a str,
+    host: &'a str,
+    after_host: &'a str,
+}
+
+impl<'a> CaseInsensitiveParts<'a> {
+    fn new(txt: &'a str) -> Option<Self> {
+        let captures = IRI_REGEX.captures(txt)?;
+        let scheme = captures.get(1)?;
+        let (host_start, host_end) = match captures.get(2) {
+            None => (scheme.end(), scheme.end()),
+            Some(authority) => {
+                let authority_txt = authority.as_str();
+                let start = authority_txt.find('@').map_or(0, |i| i + 1);
+                let host_and_port = &authority_txt[start..];
+                let len = if host_and_port.starts_with('[') {
+                    host_and_port
+                        .find(']')
+                        .map_or(host_and_port.len(), |i| i + 1)
+                } else {
+                    host_and_port.find(':').unwrap_or(host_and_port.len())
+                };
+                (authority.start() + start, authority.start() + start + len)
+            }
+        };
+        Some(CaseInsensitiveParts {
+            scheme: scheme.as_str(),
+            before_host: &txt[scheme.end()..host_start],
+            host: &txt[host_start..host_end],
+            after_host: &txt[host_end..],
+        })
+    }
+}
+
+impl PartialEq for CaseInsensitiveParts<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.scheme.eq_ignore_ascii_case(other.scheme)
+            && self.before_host == other.before_host
+            && self.host.eq_ignore_ascii_case(other.host)
+            && self.after_host == other.after_host
+    }
+}
+
+/// Return a [`Predicate`] for `txt` if it is a valid IRI, otherwise None.
+///
+/// This is meant as a convenience for prototyping,
+/// as `&str` does not implement [`Predicate`] itself (it is not guaranteed to be a valid IRI).
+///
+/// ```
+/// # use r2c2_statement::Predicate;
+/// # use r2c2_statement_validation::checked_predicate;
+/// let p = checked_predicate("https://example.org/ns/p").unwrap();
+/// assert_eq!(p.as_iri().as_str(), "https://example.org/ns/p");
+/// assert!(checked_predicate("not an IRI").is_none());
+/// ```
+pub fn checked_predicate(txt: &str) -> Option<impl Predicate + '_> {
+    IRI_REGEX.is_match(txt).then_some(CheckedPredicate(txt))
+}
+
+/// The return type of [`checked_predicate`].
+struct CheckedPredicate<'a>(&'a str);
+
+impl Predicate for CheckedPredicate<'_> {
+    fn as_iri(&self) -> Iri<'_> {
+        Iri::new_unchecked(self.0)
+    }
 }
 
 pub(crate) static IRI_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(IRI_REGEX_SRC).unwrap());
@@ -180,6 +330,95 @@ mod test {
         }
     }
 
+    #[test]
+    fn checked_predicate() {
+        for txt in POSITIVE_IRIS {
+            let p = super::checked_predicate(txt).unwrap();
+            assert_eq!(p.as_iri().as_str(), *txt);
+        }
+        for txt in NEGATIVE_IRIS {
+            assert!(super::checked_predicate(txt).is_none());
+        }
+    }
+
+    #[test]
+    fn map() {
+        for txt in POSITIVE_IRIS {
+            let iri = Iri::new_unchecked(*txt);
+            assert_eq!(iri.clone().map(|txt| txt), Some(iri));
+        }
+        for txt in NEGATIVE_IRIS {
+            let iri = Iri::new_unchecked("http://example.org/");
+            assert!(iri.map(|_| Cow::Borrowed(*txt)).is_none());
+        }
+        let iri = Iri::new_unchecked("http://example.org/a");
+        let mapped = iri.map(|txt| txt.replace("http:", "https:").into());
+        assert_eq!(mapped.unwrap(), "https://example.org/a");
+    }
+
+    #[test]
+    fn eq_normalized() {
+        for txt in POSITIVE_IRIS {
+            let iri = Iri::new_unchecked(*txt);
+            assert!(iri.eq_normalized(&iri));
+        }
+        for (i1, i2, expected) in [
+            ("HTTP://Example.ORG/x", "http://example.org/x", true),
+            ("http://example.org/x", "http://example.org/X", false),
+            ("http://EXAMPLE.org:80/x", "http://example.org:80/x", true),
+            ("http://User@EXAMPLE.org/", "http://User@example.org/", true),
+            (
+                "http://User@example.org/",
+                "http://user@example.org/",
+                false,
+            ),
+            ("http://[::A]/", "http://[::a]/", true),
+            ("http://example.org?Q", "http://EXAMPLE.org?q", false),
+            ("http://example.org#f", "HTTP://example.org#f", true),
+            ("TAG:Abc", "tag:Abc", true),
+            ("tag:Abc", "tag:abc", false),
+            ("urn:x:Example.ORG", "urn:x:example.org", false),
+            ("http://ex.org/%7e", "http://ex.org/%7E", false),
+            // invalid IRIs are compared byte by byte
+            ("HTTP://a/ ", "http://a/ ", false),
+            ("http://a/ ", "http://a/ ", true),
+        ] {
+            let (i1, i2) = (Iri::new_unchecked(i1), Iri::new_unchecked(i2));
+            assert_eq!(i1.eq_normalized(&i2), expected, "{i1} {i2}");
+            assert_eq!(i2.eq_normalized(&i1), expected, "{i2} {i1}");
+        }
+    }
+
+    #[test]
+    fn join() {
+        for (base, segment, expected) in [
+            ("http://ex.org/a", "b", Some("http://ex.org/a/b")),
+            ("http://ex.org/a/", "b", Some("http://ex.org/a/b")),
+            ("http://ex.org", "b", Some("http://ex.org/b")),
+            ("http://ex.org/", "b", Some("http://ex.org/b")),
+            ("file:///a", "b", Some("file:///a/b")),
+            ("tag:/a", "b", Some("tag:/a/b")),
+            ("http://ex.org/a?q#f", "b", Some("http://ex.org/a/b")),
+            (
+                "http://ex.org/a",
+                "b/c d%",
+                Some("http://ex.org/a/b%2Fc%20d%25"),
+            ),
+            ("http://ex.org/a", "été:@", Some("http://ex.org/a/été:@")),
+            ("http://ex.org/a", "...", Some("http://ex.org/a/...")),
+            ("http://ex.org/a", "", None),
+            ("http://ex.org/a", ".", None),
+            ("http://ex.org/a", "..", None),
+            ("urn:isbn:0451450523", "b", None),
+            ("mailto:someone@ex.org", "b", None),
+            ("http:", "b", None),
+            ("http://ex.org/a b", "c", None),
+        ] {
+            let got = Iri::new_unchecked(base).join(segment);
+            assert_eq!(got.as_deref(), expected, "{base} {segment}");
+        }
+    }
+
     /// An array of valid IRIs
     pub const POSITIVE_IRIS: &[&str] = &[
         "http:",
@@ -0,0 +1,190 @@
+use std::sync::LazyLock;
+
+use regex_automata::{
+    Anchored,
+    hybrid::{
+        LazyStateID,
+        dfa::{Cache, DFA},
+    },
+    util::start,
+};
+
+use crate::IRI_REGEX_SRC;
+
+/// A push-style validator for IRIs received in several chunks
+/// (e.g. by a parser reading IRIs across buffer boundaries).
+///
+/// It accepts exactly the same IRIs as [`IriValidation::new`](crate::IriValidation::new)
+/// (i.e. those matching [`IRI_REGEX_SRC`]),
+/// but proceeds incrementally, without buffering the IRI.
+///
+/// ```
+/// # use r2c2_statement_validation::IriValidator;
+/// let mut validator = IriValidator::new();
+/// validator.push("https://exa").unwrap();
+/// validator.push("mple.org/").unwrap();
+/// assert!(validator.finish().is_ok());
+///
+/// let mut validator = IriValidator::new();
+/// validator.push("https://example.org/").unwrap();
+/// assert_eq!(validator.push("a b").unwrap_err().offset, 21);
+/// ```
+#[derive(Clone, Debug)]
+pub struct IriValidator {
+    cache: Cache,
+    state: LazyStateID,
+    offset: usize,
+    error: Option<IriError>,
+}
+
+impl IriValidator {
+    /// Return a new [`IriValidator`], expecting the first chunk of an IRI.
+    pub fn new() -> Self {
+        let mut cache = IRI_DFA.create_cache();
+        let state = IRI_DFA
+            .start_state(&mut cache, &start::Config::new().anchored(Anchored::Yes))
+            .expect(CACHE_NEVER_GIVES_UP);
+        IriValidator {
+            cache,
+            state,
+            offset: 0,
+            error: None,
+        }
+    }
+
+    /// Feed the next chunk of the IRI to this validator.
+    ///
+    /// Fail as soon as the text received so far can not be the beginning of a valid IRI.
+    /// Once this method has failed, it keeps failing with the same error.
+    pub fn push(&mut self, chunk: &str) -> Result<(), IriError> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+        for (i, byte) in chunk.bytes().enumerate() {
+            self.state = IRI_DFA
+                .next_state(&mut self.cache, self.state, byte)
+                .expect(CACHE_NEVER_GIVES_UP);
+            if self.state.is_dead() {
+                let error = IriError {
+                    offset: self.offset + i,
+                };
+                self.error = Some(error);
+                return Err(error);
+            }
+        }
+        self.offset += chunk.len();
+        Ok(())
+    }
+
+    /// Check that the chunks received so far form a complete valid IRI.
+    pub fn finish(mut self) -> Result<(), IriError> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+        let state = IRI_DFA
+            .next_eoi_state(&mut self.cache, self.state)
+            .expect(CACHE_NEVER_GIVES_UP);
+        if state.is_match() {
+            Ok(())
+        } else {
+            Err(IriError {
+                offset: self.offset,
+            })
+        }
+    }
+}
+
+impl Default for IriValidator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The error returned by [`IriValidator`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct IriError {
+    /// The byte offset (from the start of the first chunk) of the first byte
+    /// that can not be part of a valid IRI,
+    /// or the total length of the chunks if the IRI is incomplete.
+    pub offset: usize,
+}
+
+impl std::fmt::Display for IriError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid IRI at byte {}", self.offset)
+    }
+}
+
+impl std::error::Error for IriError {}
+
+/// The lazy DFA never gives up, as no minimum cache clear count is configured.
+const CACHE_NEVER_GIVES_UP: &str = "the lazy DFA should never give up";
+
+/// A lazy DFA equivalent to [`IRI_REGEX`](crate::IRI_REGEX), which can be fed byte by byte.
+static IRI_DFA: LazyLock<DFA> = LazyLock::new(|| DFA::new(IRI_REGEX_SRC).unwrap());
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::IRI_REGEX;
+
+    const IRIS: &[&str] = &[
+        "http:",
+        "http://example.org",
+        "https://user:pw@example.org:8080/a/b?c=d&e#f",
+        "urn:isbn:0451450523",
+        "http://[::1]/",
+        "http://éxample.org/café?q=ü#frag",
+        "http://example.org/%41",
+        "tag:example.org,2024:\u{10000}",
+        "",
+        "http",
+        "1http://example.org/",
+        "http://example.org/a b",
+        "http://example.org/%4",
+        "http://example.org/%zz",
+        "http://example.org/<>",
+        "http://[::1/",
+        "#frag",
+    ];
+
+    #[test]
+    fn same_as_regex() {
+        for iri in IRIS {
+            let expected = IRI_REGEX.is_match(iri);
+            // every possible split in two chunks
+            for (mid, _) in iri.char_indices().chain([(iri.len(), ' ')]) {
+                let mut validator = IriValidator::new();
+                let got = validator
+                    .push(&iri[..mid])
+                    .and_then(|_| validator.push(&iri[mid..]))
+                    .and_then(|_| validator.finish());
+                assert_eq!(got.is_ok(), expected, "{iri} split at {mid}");
+            }
+            // one chunk per character
+            let mut validator = IriValidator::new();
+            let mut buf = [0; 4];
+            let got = iri
+                .chars()
+                .try_for_each(|c| validator.push(c.encode_utf8(&mut buf)))
+                .and_then(|_| validator.finish());
+            assert_eq!(got.is_ok(), expected, "{iri} char by char");
+        }
+    }
+
+    #[test]
+    fn error_offset() {
+        let mut validator = IriValidator::new();
+        validator.push("http://exa").unwrap();
+        let err = validator.push("mple.org/a b").unwrap_err();
+        assert_eq!(err, IriError { offset: 20 });
+        assert_eq!(validator.push("c"), Err(err));
+        assert_eq!(validator.finish(), Err(err));
+
+        let mut validator = IriValidator::new();
+        validator.push("http://example.org/%4").unwrap();
+        assert_eq!(validator.finish(), Err(IriError { offset: 21 }));
+        assert_eq!(IriValidator::new().finish(), Err(IriError { offset: 0 }));
+        assert_eq!(IriValidator::new().push("1"), Err(IriError { offset: 0 }));
+    }
+}
@@ -0,0 +1,208 @@
+use std::{collections::HashSet, sync::LazyLock};
+
+use r2c2_statement::LangTag;
+
+use crate::TAG_REGEX;
+
+/// Extension trait for [`LangTag`] checking that its subtags are registered.
+///
+/// [`LangTagValidation`](crate::LangTagValidation) only checks that a language tag complies with the grammar of [BCP47],
+/// which is cheap, and sufficient for RDF.
+/// This trait provides a stricter (and more expensive) check,
+/// for feeding data to systems that reject unregistered subtags.
+///
+/// [BCP47]: https://datatracker.ietf.org/doc/bcp47/
+pub trait LangTagRegistryValidation {
+    /// Whether this language tag is well-formed, and its subtags are registered, namely:
+    /// * the primary language (and extended language) subtags in ISO 639
+    ///   (or in the private use range `qaa`..`qtz`),
+    /// * the script subtag in ISO 15924
+    ///   (or in the private use range `Qaaa`..`Qabx`),
+    /// * the region subtag in ISO 3166-1, in the [UN M.49] codes of the [IANA registry],
+    ///   or among the few other region subtags of the [IANA registry]
+    ///   (deprecated, exceptionally reserved or private use ones).
+    ///
+    /// Variants and extensions are not checked.
+    /// Private use tags (e.g. `x-foo`) and grandfathered tags (e.g. `i-klingon`) are considered registered.
+    ///
+    /// The check relies on a snapshot of the ISO code lists embedded in this crate,
+    /// so it may lag behind the actual registry.
+    ///
+    /// ```
+    /// # use r2c2_statement::LangTag;
+    /// # use r2c2_statement_validation::LangTagRegistryValidation;
+    /// assert!(LangTag::new_unchecked("fr-Latn-CA").is_valid_registered());
+    /// assert!(LangTag::new_unchecked("es-419").is_valid_registered());
+    /// assert!(!LangTag::new_unchecked("xy-ZZZZ").is_valid_registered());
+    /// ```
+    ///
+    /// [UN M.49]: https://unstats.un.org/unsd/methodology/m49/
+    /// [IANA registry]: https://www.iana.org/assignments/language-subtag-registry/language-subtag-registry
+    fn is_valid_registered(&self) -> bool;
+}
+
+impl LangTagRegistryValidation for LangTag<'_> {
+    fn is_valid_registered(&self) -> bool {
+        if !TAG_REGEX.is_match(self.as_str()) {
+            return false;
+        }
+        let tag = self.as_str().to_ascii_lowercase();
+        if GRANDFATHERED.contains(&tag.as_str()) {
+            return true;
+        }
+        let mut subtags = tag.split('-').peekable();
+        let language = subtags.next().unwrap_or_default();
+        if language == "x" {
+            return true;
+        }
+        if !is_registered_language(language) {
+            return false;
+        }
+        // NB: before the script, all 3-letter subtags are extended languages
+        while let Some(extlang) = subtags.next_if(|s| s.len() == 3 && is_alpha(s)) {
+            if !is_registered_language(extlang) {
+                return false;
+            }
+        }
+        if let Some(script) = subtags.next_if(|s| s.len() == 4 && is_alpha(s))
+            && !is_registered_script(script)
+        {
+            return false;
+        }
+        if let Some(region) = subtags
+            .next_if(|s| s.len() == 2 || (s.len() == 3 && s.bytes().all(|b| b.is_ascii_digit())))
+            && !is_registered_region(region)
+        {
+            return false;
+        }
+        true
+    }
+}
+
+fn is_alpha(subtag: &str) -> bool {
+    subtag.bytes().all(|b| b.is_ascii_alphabetic())
+}
+
+fn is_registered_language(subtag: &str) -> bool {
+    (subtag.len() == 3 && ("qaa"..="qtz").contains(&subtag)) || LANGUAGES.contains(subtag)
+}
+
+fn is_registered_script(subtag: &str) -> bool {
+    ("qaaa"..="qabx").contains(&subtag) || SCRIPTS.contains(subtag)
+}
+
+fn is_registered_region(subtag: &str) -> bool {
+    ("qm"..="qz").contains(&subtag)
+        || ("xa"..="xz").contains(&subtag)
+        || OTHER_REGIONS.contains(&subtag)
+        || REGIONS.contains(subtag)
+}
+
+static LANGUAGES: LazyLock<HashSet<&str>> =
+    LazyLock::new(|| codes(include_str!("../data/languages.txt")));
+static SCRIPTS: LazyLock<HashSet<&str>> =
+    LazyLock::new(|| codes(include_str!("../data/scripts.txt")));
+static REGIONS: LazyLock<HashSet<&str>> =
+    LazyLock::new(|| codes(include_str!("../data/regions.txt")));
+
+fn codes(data: &'static str) -> HashSet<&'static str> {
+    data.lines().filter(|line| !line.starts_with('#')).collect()
+}
+
+/// The region subtags of the IANA registry that are not ISO 3166-1 alpha-2 codes
+/// (nor in the private use ranges `QM`..`QZ` and `XA`..`XZ`).
+#[rustfmt::skip]
+const OTHER_REGIONS: &[&str] = &[
+    // UN M.49
+    "001", "002", "003", "005", "009", "011", "013", "014", "015", "017", "018", "019", "021",
+    "029", "030", "034", "035", "039", "053", "054", "057", "061", "142", "143", "145", "150",
+    "151", "154", "155", "202", "419",
+    // deprecated
+    "bu", "cs", "dd", "fx", "nt", "tp", "yd", "yu", "zr",
+    // exceptionally reserved
+    "ac", "cp", "dg", "ea", "eu", "ez", "ic", "ta", "un",
+    // private use
+    "aa", "zz",
+];
+
+/// The grandfathered tags of [BCP47](https://datatracker.ietf.org/doc/bcp47/), in lowercase.
+const GRANDFATHERED: &[&str] = &[
+    "art-lojban",
+    "cel-gaulish",
+    "en-gb-oed",
+    "i-ami",
+    "i-bnn",
+    "i-default",
+    "i-enochian",
+    "i-hak",
+    "i-klingon",
+    "i-lux",
+    "i-mingo",
+    "i-navajo",
+    "i-pwn",
+    "i-tao",
+    "i-tay",
+    "i-tsu",
+    "no-bok",
+    "no-nyn",
+    "sgn-be-fr",
+    "sgn-be-nl",
+    "sgn-ch-de",
+    "zh-guoyu",
+    "zh-hakka",
+    "zh-min",
+    "zh-min-nan",
+    "zh-xiang",
+];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn registered() {
+        for tag in [
+            "en",
+            "EN-gb",
+            "fra",
+            "fr-Latn-CA",
+            "zh-Hant-TW",
+            "zh-yue-HK",
+            "gsw-u-sd-chzh",
+            "sl-rozaj-biske",
+            "es-419",
+            "de-DD",
+            "und-Zyyy-EU",
+            "qaa-Qaaa-QM",
+            "qtz-Qabx-XZ",
+            "en-x-foo",
+            "x-foo",
+            "i-klingon",
+            "zh-min-nan",
+            "sgn-CH-DE",
+        ] {
+            assert!(LangTag::new_unchecked(tag).is_valid_registered(), "{tag}");
+        }
+    }
+
+    #[test]
+    fn unregistered() {
+        for tag in [
+            "",
+            "en_GB",
+            "xy",
+            "fre",
+            "abcd",
+            "qzz-Latn",
+            "en-zzq",
+            "en-Abcd",
+            "en-Qabz",
+            "en-YY",
+            "en-999",
+            "en-QL",
+            "en-Latn-YY-x-foo",
+        ] {
+            assert!(!LangTag::new_unchecked(tag).is_valid_registered(), "{tag}");
+        }
+    }
+}
@@ -14,6 +14,20 @@ pub trait LangTagValidation<'a> {
     ///
     /// Can be useful after a [`new_unchecked`](LangTag::new_unchecked)
     fn debug_assert_is_valid(&self);
+
+    /// Apply `f` to the text of this [`LangTag`],
+    /// and return the result if it is a valid language tag, otherwise None.
+    ///
+    /// This is the checked counterpart of [`LangTag::map_unchecked`].
+    ///
+    /// ```
+    /// # use r2c2_statement::LangTag;
+    /// # use r2c2_statement_validation::LangTagValidation;
+    /// let en = LangTag::new_unchecked("en");
+    /// assert_eq!(en.clone().map(|txt| format!("{txt}-GB").into()).unwrap(), "en-GB");
+    /// assert!(en.map(|txt| format!("{txt}-").into()).is_none());
+    /// ```
+    fn map(self, f: impl FnMut(Cow<'a, str>) -> Cow<'a, str>) -> Option<LangTag<'a>>;
 }
 
 impl<'a> LangTagValidation<'a> for LangTag<'a> {
@@ -28,6 +42,13 @@ impl<'a> LangTagValidation<'a> for LangTag<'a> {
     fn debug_assert_is_valid(&self) {
         debug_assert!(TAG_REGEX.is_match(self.as_ref()))
     }
+
+    fn map(self, mut f: impl FnMut(Cow<'a, str>) -> Cow<'a, str>) -> Option<Self> {
+        let inner = f(self.unwrap());
+        TAG_REGEX
+            .is_match(&inner)
+            .then_some(LangTag::new_unchecked(inner))
+    }
 }
 
 pub(crate) static TAG_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(TAG_REGEX_SRC).unwrap());
@@ -135,6 +156,21 @@ mod test {
         }
     }
 
+    #[test]
+    fn map() {
+        for tag in valid_tags() {
+            let mapped = LangTag::new_unchecked("en").map(|_| tag.clone().into());
+            assert_eq!(mapped.unwrap(), tag.as_str());
+        }
+        for txt in INVALID_TAGS {
+            assert!(
+                LangTag::new_unchecked("en")
+                    .map(|_| Cow::Borrowed(*txt))
+                    .is_none()
+            );
+        }
+    }
+
     // below are utility functions used to generate valid (and invalid) tags for testing
 
     fn valid_tags() -> impl Iterator<Item = String> {
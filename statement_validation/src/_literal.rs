@@ -0,0 +1,85 @@
+use std::borrow::Cow;
+
+use r2c2_statement::{BaseDir, Iri, LangTag, Literal, ns::rdf};
+
+use crate::{IriValidation, LangTagValidation};
+
+/// Extension trait for [`Literal`] providing validating constructors.
+pub trait LiteralValidation<'a> {
+    /// Return a new [`Literal::Typed`] if `dt` is a valid IRI, otherwise None.
+    ///
+    /// This also returns None if `dt` is `rdf:langString` or `rdf:dirLangString`,
+    /// as literals with these datatypes require a language tag
+    /// (see [`lang`](LiteralValidation::lang) and [`dir_lang`](LiteralValidation::dir_lang)).
+    fn typed(lex: impl Into<Cow<'a, str>>, dt: impl Into<Cow<'a, str>>) -> Option<Literal<'a>>;
+
+    /// Return a new [language tagged string] if `tag` is a valid language tag, otherwise None.
+    ///
+    /// [language tagged string]: https://www.w3.org/TR/rdf12-concepts/#dfn-language-tagged-string
+    fn lang(lex: impl Into<Cow<'a, str>>, tag: impl Into<Cow<'a, str>>) -> Option<Literal<'a>>;
+
+    /// Return a new [directional language tagged string] if `tag` is a valid language tag, otherwise None.
+    ///
+    /// [directional language tagged string]: https://www.w3.org/TR/rdf12-concepts/#dfn-dir-lang-string
+    fn dir_lang(
+        lex: impl Into<Cow<'a, str>>,
+        tag: impl Into<Cow<'a, str>>,
+        dir: BaseDir,
+    ) -> Option<Literal<'a>>;
+}
+
+impl<'a> LiteralValidation<'a> for Literal<'a> {
+    fn typed(lex: impl Into<Cow<'a, str>>, dt: impl Into<Cow<'a, str>>) -> Option<Self> {
+        let dt = <Iri as IriValidation>::new(dt)?;
+        (dt != rdf::LANG_STRING && dt != rdf::DIR_LANG_STRING)
+            .then(|| Literal::Typed(lex.into(), dt))
+    }
+
+    fn lang(lex: impl Into<Cow<'a, str>>, tag: impl Into<Cow<'a, str>>) -> Option<Self> {
+        let tag = <LangTag as LangTagValidation>::new(tag)?;
+        Some(Literal::LanguageString(lex.into(), tag, None))
+    }
+
+    fn dir_lang(
+        lex: impl Into<Cow<'a, str>>,
+        tag: impl Into<Cow<'a, str>>,
+        dir: BaseDir,
+    ) -> Option<Self> {
+        let tag = <LangTag as LangTagValidation>::new(tag)?;
+        Some(Literal::LanguageString(lex.into(), tag, Some(dir)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn typed() {
+        let lit = Literal::typed("42", "http://www.w3.org/2001/XMLSchema#integer").unwrap();
+        assert_eq!(lit.lexical_form(), "42");
+        assert_eq!(
+            lit.datatype_iri(),
+            Iri::new_unchecked("http://www.w3.org/2001/XMLSchema#integer")
+        );
+        assert!(Literal::typed("42", "not an IRI").is_none());
+        assert!(Literal::typed("42", rdf::LANG_STRING.as_str()).is_none());
+        assert!(Literal::typed("42", rdf::DIR_LANG_STRING.as_str()).is_none());
+    }
+
+    #[test]
+    fn lang() {
+        let lit = Literal::lang("chat", String::from("en-GB")).unwrap();
+        assert_eq!(lit.language_tag(), Some(LangTag::new_unchecked("en-gb")));
+        assert_eq!(lit.base_direction(), None);
+        assert!(Literal::lang("chat", "en_GB").is_none());
+    }
+
+    #[test]
+    fn dir_lang() {
+        let lit = Literal::dir_lang("chat", "fr", BaseDir::Rtl).unwrap();
+        assert_eq!(lit.language_tag(), Some(LangTag::new_unchecked("fr")));
+        assert_eq!(lit.base_direction(), Some(BaseDir::Rtl));
+        assert!(Literal::dir_lang("chat", "", BaseDir::Ltr).is_none());
+    }
+}
@@ -0,0 +1,170 @@
+use std::{borrow::Cow, fmt::Write};
+
+/// Percent-encode `s` so that it can be used as a path segment in an IRI.
+///
+/// Every character not allowed in an [`isegment`] (i.e. not in `ipchar`) is percent-encoded,
+/// including `/` and `%` itself.
+/// Sub-delimiters (`!$&'()*+,;=`), `:` and `@` are allowed, and therefore kept as is.
+///
+/// The input is returned borrowed if no character needs to be encoded.
+///
+/// ```
+/// # use r2c2_statement_validation::*;
+/// assert_eq!(pct_encode_path_segment("été"), "été");
+/// assert_eq!(pct_encode_path_segment("a/b c%"), "a%2Fb%20c%25");
+/// ```
+///
+/// [`isegment`]: https://www.rfc-editor.org/rfc/rfc3987.html#section-2.2
+pub fn pct_encode_path_segment(s: &str) -> Cow<'_, str> {
+    pct_encode(s, is_ipchar)
+}
+
+/// Percent-encode `s` so that it can be used as the query of an IRI.
+///
+/// Every character not allowed in an [`iquery`] is percent-encoded, including `#` and `%`.
+/// Note that sub-delimiters (`!$&'()*+,;=`) are allowed in a query, and therefore kept as is;
+/// callers building `key=value` pairs must encode them beforehand if they appear in keys or values.
+///
+/// The input is returned borrowed if no character needs to be encoded.
+///
+/// ```
+/// # use r2c2_statement_validation::*;
+/// assert_eq!(pct_encode_query("q=a/b?"), "q=a/b?");
+/// assert_eq!(pct_encode_query("q=a b#c"), "q=a%20b%23c");
+/// ```
+///
+/// [`iquery`]: https://www.rfc-editor.org/rfc/rfc3987.html#section-2.2
+pub fn pct_encode_query(s: &str) -> Cow<'_, str> {
+    pct_encode(s, |c| {
+        is_ipchar(c) || is_iprivate(c) || c == '/' || c == '?'
+    })
+}
+
+/// Percent-encode `s` so that it can be used as the fragment of an IRI.
+///
+/// Every character not allowed in an [`ifragment`] is percent-encoded, including `#` and `%`.
+/// Contrarily to [`pct_encode_query`], private-use characters are also encoded.
+///
+/// The input is returned borrowed if no character needs to be encoded.
+///
+/// ```
+/// # use r2c2_statement_validation::*;
+/// assert_eq!(pct_encode_fragment("sec/1?"), "sec/1?");
+/// assert_eq!(pct_encode_fragment("a#b\u{E000}"), "a%23b%EE%80%80");
+/// ```
+///
+/// [`ifragment`]: https://www.rfc-editor.org/rfc/rfc3987.html#section-2.2
+pub fn pct_encode_fragment(s: &str) -> Cow<'_, str> {
+    pct_encode(s, |c| is_ipchar(c) || c == '/' || c == '?')
+}
+
+fn pct_encode(s: &str, allowed: impl Fn(char) -> bool) -> Cow<'_, str> {
+    let Some(first) = s.find(|c| !allowed(c)) else {
+        return Cow::Borrowed(s);
+    };
+    let mut encoded = String::with_capacity(s.len() + 8);
+    encoded.push_str(&s[..first]);
+    for c in s[first..].chars() {
+        if allowed(c) {
+            encoded.push(c);
+        } else {
+            let mut buf = [0; 4];
+            for byte in c.encode_utf8(&mut buf).bytes() {
+                write!(encoded, "%{byte:02X}").unwrap();
+            }
+        }
+    }
+    Cow::Owned(encoded)
+}
+
+/// Characters allowed in `ipchar`, except for percent-encoded octets (see [`IRI_REGEX_SRC`](crate::IRI_REGEX_SRC)).
+fn is_ipchar(c: char) -> bool {
+    is_iunreserved(c) || "!$&'()*+,;=:@".contains(c)
+}
+
+fn is_iunreserved(c: char) -> bool {
+    c.is_ascii_alphanumeric()
+        || "-._~".contains(c)
+        || matches!(c,
+            '\u{A0}'..='\u{D7FF}'
+            | '\u{F900}'..='\u{FDCF}'
+            | '\u{FDF0}'..='\u{FFEF}'
+            | '\u{10000}'..='\u{1FFFD}'
+            | '\u{20000}'..='\u{2FFFD}'
+            | '\u{30000}'..='\u{3FFFD}'
+            | '\u{40000}'..='\u{4FFFD}'
+            | '\u{50000}'..='\u{5FFFD}'
+            | '\u{60000}'..='\u{6FFFD}'
+            | '\u{70000}'..='\u{7FFFD}'
+            | '\u{80000}'..='\u{8FFFD}'
+            | '\u{90000}'..='\u{9FFFD}'
+            | '\u{A0000}'..='\u{AFFFD}'
+            | '\u{B0000}'..='\u{BFFFD}'
+            | '\u{C0000}'..='\u{CFFFD}'
+            | '\u{D0000}'..='\u{DFFFD}'
+            | '\u{E1000}'..='\u{EFFFD}'
+        )
+}
+
+fn is_iprivate(c: char) -> bool {
+    matches!(c,
+        '\u{E000}'..='\u{F8FF}'
+        | '\u{F0000}'..='\u{FFFFD}'
+        | '\u{100000}'..='\u{10FFFD}'
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use r2c2_statement::Iri;
+
+    use super::*;
+    use crate::IriValidation;
+
+    const SAMPLES: &[&str] = &[
+        "",
+        "simple",
+        "été",
+        "a/b?c#d",
+        "50% off",
+        "<tag> \"quoted\" {x} [y] |z| ^`\\",
+        "k=v&k2=v2",
+        "\u{E000}\u{FFFF}\u{10FFFD}",
+        "tab\tnew\nline",
+    ];
+
+    #[test]
+    fn borrowed_when_unchanged() {
+        for s in ["", "abc", "été", "!$&'()*+,;=:@"] {
+            assert!(matches!(pct_encode_path_segment(s), Cow::Borrowed(_)));
+            assert!(matches!(pct_encode_query(s), Cow::Borrowed(_)));
+            assert!(matches!(pct_encode_fragment(s), Cow::Borrowed(_)));
+        }
+        assert!(matches!(pct_encode_query("/?\u{E000}"), Cow::Borrowed(_)));
+        assert!(matches!(pct_encode_fragment("/?"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn encoded() {
+        assert_eq!(pct_encode_path_segment("a/b?c#d"), "a%2Fb%3Fc%23d");
+        assert_eq!(pct_encode_query("a/b?c#d"), "a/b?c%23d");
+        assert_eq!(pct_encode_fragment("a/b?c#d"), "a/b?c%23d");
+        assert_eq!(pct_encode_path_segment("\u{E000}"), "%EE%80%80");
+        assert_eq!(pct_encode_query("\u{E000}"), "\u{E000}");
+        assert_eq!(pct_encode_path_segment("\u{FFFF}"), "%EF%BF%BF");
+        assert_eq!(pct_encode_path_segment("%41"), "%2541");
+    }
+
+    #[test]
+    fn valid_iris() {
+        for s in SAMPLES {
+            let txt = format!(
+                "http://example.org/{}?{}#{}",
+                pct_encode_path_segment(s),
+                pct_encode_query(s),
+                pct_encode_fragment(s),
+            );
+            assert!(Iri::new(txt.as_str()).is_some(), "{txt}");
+        }
+    }
+}
@@ -0,0 +1,193 @@
+use std::borrow::Cow;
+
+use r2c2_statement::Iri;
+
+/// Express `iri` as the local part of a Turtle [prefixed name],
+/// given the IRI `prefix_iri` associated to the prefix.
+///
+/// Return `None` if `iri` does not start with `prefix_iri`,
+/// or if the remainder can not be written as a [`PN_LOCAL`], even with escapes
+/// (e.g. if it contains a `[`, or starts with a combining character).
+/// Otherwise, return the remainder, where reserved characters are escaped with a backslash
+/// (see [`PN_LOCAL_ESC`]) when needed.
+/// Percent-encoded octets are kept as is, as [`PN_LOCAL`] allows them.
+///
+/// The result is borrowed from `iri` when no character needs to be escaped.
+///
+/// ```
+/// # use r2c2_statement::Iri;
+/// # use r2c2_statement_validation::*;
+/// let ex = "https://example.org/ns/";
+/// let iri = Iri::new_unchecked("https://example.org/ns/a.b");
+/// assert_eq!(as_prefixed_name(&iri, ex).unwrap(), "a.b");
+/// let iri = Iri::new_unchecked("https://example.org/ns/a/b?c=d.");
+/// assert_eq!(as_prefixed_name(&iri, ex).unwrap(), r"a\/b\?c\=d\.");
+/// let iri = Iri::new_unchecked("https://example.org/ns/[a]");
+/// assert_eq!(as_prefixed_name(&iri, ex), None);
+/// let iri = Iri::new_unchecked("https://example.org/other/a");
+/// assert_eq!(as_prefixed_name(&iri, ex), None);
+/// ```
+///
+/// [prefixed name]: https://www.w3.org/TR/rdf12-turtle/#prefixed-name
+/// [`PN_LOCAL`]: https://www.w3.org/TR/rdf12-turtle/#grammar-production-PN_LOCAL
+/// [`PN_LOCAL_ESC`]: https://www.w3.org/TR/rdf12-turtle/#grammar-production-PN_LOCAL_ESC
+pub fn as_prefixed_name<'a>(iri: &'a Iri<'_>, prefix_iri: &str) -> Option<Cow<'a, str>> {
+    let local = iri.as_str().strip_prefix(prefix_iri)?;
+    let mut escaped: Option<String> = None;
+    for (i, c) in local.char_indices() {
+        let first = i == 0;
+        let last = i + c.len_utf8() == local.len();
+        let raw = match c {
+            '%' => {
+                local[i + 1..]
+                    .bytes()
+                    .take(2)
+                    .filter(u8::is_ascii_hexdigit)
+                    .count()
+                    == 2
+            }
+            '.' => !first && !last,
+            ':' => true,
+            _ if is_pn_chars_u(c) || c.is_ascii_digit() => true,
+            _ => !first && is_pn_chars(c),
+        };
+        if raw {
+            if let Some(escaped) = &mut escaped {
+                escaped.push(c);
+            }
+        } else if PN_LOCAL_ESC.contains(c) {
+            let escaped = escaped.get_or_insert_with(|| local[..i].to_string());
+            escaped.push('\\');
+            escaped.push(c);
+        } else {
+            return None;
+        }
+    }
+    Some(match escaped {
+        Some(escaped) => Cow::Owned(escaped),
+        None => Cow::Borrowed(local),
+    })
+}
+
+/// Remove the backslash escapes (see [`PN_LOCAL_ESC`]) from the local part of a Turtle [prefixed name],
+/// i.e. the inverse of [`as_prefixed_name`].
+///
+/// The input is not validated, and is returned borrowed if it contains no backslash.
+///
+/// ```
+/// # use r2c2_statement_validation::*;
+/// assert_eq!(unescape_local_name(r"a\/b\?c\=d\."), "a/b?c=d.");
+/// assert_eq!(unescape_local_name("a%20b"), "a%20b");
+/// ```
+///
+/// [prefixed name]: https://www.w3.org/TR/rdf12-turtle/#prefixed-name
+/// [`PN_LOCAL_ESC`]: https://www.w3.org/TR/rdf12-turtle/#grammar-production-PN_LOCAL_ESC
+pub fn unescape_local_name(local: &str) -> Cow<'_, str> {
+    let Some(first) = local.find('\\') else {
+        return Cow::Borrowed(local);
+    };
+    let mut unescaped = String::with_capacity(local.len());
+    unescaped.push_str(&local[..first]);
+    let mut chars = local[first..].chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => unescaped.extend(chars.next()),
+            _ => unescaped.push(c),
+        }
+    }
+    Cow::Owned(unescaped)
+}
+
+/// The characters that can be escaped with a backslash in a [`PN_LOCAL`](https://www.w3.org/TR/rdf12-turtle/#grammar-production-PN_LOCAL).
+const PN_LOCAL_ESC: &str = "_~.-!$&'()*+,;=/?#@%";
+
+fn is_pn_chars_u(c: char) -> bool {
+    c == '_'
+        || c.is_ascii_alphabetic()
+        || matches!(c,
+            '\u{C0}'..='\u{D6}'
+            | '\u{D8}'..='\u{F6}'
+            | '\u{F8}'..='\u{2FF}'
+            | '\u{370}'..='\u{37D}'
+            | '\u{37F}'..='\u{1FFF}'
+            | '\u{200C}'..='\u{200D}'
+            | '\u{2070}'..='\u{218F}'
+            | '\u{2C00}'..='\u{2FEF}'
+            | '\u{3001}'..='\u{D7FF}'
+            | '\u{F900}'..='\u{FDCF}'
+            | '\u{FDF0}'..='\u{FFFD}'
+            | '\u{10000}'..='\u{EFFFF}'
+        )
+}
+
+fn is_pn_chars(c: char) -> bool {
+    is_pn_chars_u(c)
+        || c == '-'
+        || c.is_ascii_digit()
+        || matches!(c, '\u{B7}' | '\u{300}'..='\u{36F}' | '\u{203F}'..='\u{2040}')
+}
+
+#[cfg(test)]
+mod test {
+    use regex::Regex;
+
+    use super::*;
+
+    const EX: &str = "https://example.org/ns/";
+
+    /// The [`PN_LOCAL`](https://www.w3.org/TR/rdf12-turtle/#grammar-production-PN_LOCAL) production
+    /// (restricted to ASCII and a few other characters, for the sake of readability).
+    const PN_LOCAL_REGEX_SRC: &str = r"(?x)^(?:
+        (?: [_:0-9A-Za-zé] | %[0-9A-Fa-f]{2} | \\[-_~.!$&'()*+,;=/?\#@%] )
+        (?: (?: [-_:.0-9A-Za-zé\u{B7}\u{300}] | %[0-9A-Fa-f]{2} | \\[-_~.!$&'()*+,;=/?\#@%] )*
+            (?: [-_:0-9A-Za-zé\u{B7}\u{300}] | %[0-9A-Fa-f]{2} | \\[-_~.!$&'()*+,;=/?\#@%] )
+        )?
+    )?$";
+
+    #[test]
+    fn prefixed_name() {
+        let pn_local = Regex::new(PN_LOCAL_REGEX_SRC).unwrap();
+        for (local, expected) in [
+            ("", Some("")),
+            ("a", Some("a")),
+            ("1a", Some("1a")),
+            ("_a", Some("_a")),
+            (":a:", Some(":a:")),
+            ("a.b", Some("a.b")),
+            (".a", Some(r"\.a")),
+            ("a.", Some(r"a\.")),
+            ("-a-", Some(r"\-a-")),
+            ("a~b", Some(r"a\~b")),
+            ("a/b#c", Some(r"a\/b\#c")),
+            ("%41b", Some("%41b")),
+            ("a%4", Some(r"a\%4")),
+            ("%zz", Some(r"\%zz")),
+            ("é\u{B7}\u{300}", Some("é\u{B7}\u{300}")),
+            ("\u{300}a", None),
+            ("[a]", None),
+            ("a|b", None),
+            ("a\u{D7}b", None),
+        ] {
+            let iri = Iri::new_unchecked(format!("{EX}{local}"));
+            let got = as_prefixed_name(&iri, EX);
+            assert_eq!(got.as_deref(), expected, "{local}");
+            if let Some(got) = got {
+                assert!(pn_local.is_match(&got), "{local} -> {got}");
+                assert_eq!(unescape_local_name(&got), local);
+                assert_eq!(matches!(got, Cow::Borrowed(_)), got == local);
+            }
+        }
+        assert_eq!(
+            as_prefixed_name(&Iri::new_unchecked("https://example.org/a"), EX),
+            None
+        );
+    }
+
+    #[test]
+    fn unescape() {
+        assert!(matches!(unescape_local_name("a.b"), Cow::Borrowed(_)));
+        assert_eq!(unescape_local_name(r"\.\-\_"), ".-_");
+        assert_eq!(unescape_local_name(r"a\\"), r"a\");
+        assert_eq!(unescape_local_name(r"a\"), "a");
+    }
+}
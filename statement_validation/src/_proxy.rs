@@ -0,0 +1,192 @@
+use std::borrow::Cow;
+
+use r2c2_statement::{
+    BnodeId, GraphNameProxy, Iri, Literal, ObjectProxy, SubjectProxy, Triple, ns::rdf,
+};
+
+use crate::{BnodeIdValidation, IRI_REGEX, IriValidation, TAG_REGEX};
+
+/// Extension trait for [`SubjectProxy`] providing validating constructors.
+pub trait SubjectProxyValidation<'a> {
+    /// Return a new [`SubjectProxy::Iri`] if `txt` is a valid IRI, otherwise None.
+    fn new_iri(txt: impl Into<Cow<'a, str>>) -> Option<SubjectProxy<'a>>;
+
+    /// Return a new [`SubjectProxy::BlankNode`] if `txt` is a valid blank node label
+    /// (see [`BnodeIdValidation`]), otherwise None.
+    fn new_bnode(txt: impl Into<Cow<'a, str>>) -> Option<SubjectProxy<'a>>;
+}
+
+impl<'a> SubjectProxyValidation<'a> for SubjectProxy<'a> {
+    fn new_iri(txt: impl Into<Cow<'a, str>>) -> Option<Self> {
+        <Iri as IriValidation>::new(txt).map(SubjectProxy::Iri)
+    }
+
+    fn new_bnode(txt: impl Into<Cow<'a, str>>) -> Option<Self> {
+        <BnodeId as BnodeIdValidation>::new(txt).map(|bnid| SubjectProxy::BlankNode(bnid.unwrap()))
+    }
+}
+
+/// Extension trait for [`GraphNameProxy`] providing validating constructors.
+pub trait GraphNameProxyValidation<'a> {
+    /// Return a new [`GraphNameProxy::Iri`] if `txt` is a valid IRI, otherwise None.
+    fn new_iri(txt: impl Into<Cow<'a, str>>) -> Option<GraphNameProxy<'a>>;
+
+    /// Return a new [`GraphNameProxy::BlankNode`] if `txt` is a valid blank node label
+    /// (see [`BnodeIdValidation`]), otherwise None.
+    fn new_bnode(txt: impl Into<Cow<'a, str>>) -> Option<GraphNameProxy<'a>>;
+}
+
+impl<'a> GraphNameProxyValidation<'a> for GraphNameProxy<'a> {
+    fn new_iri(txt: impl Into<Cow<'a, str>>) -> Option<Self> {
+        <Iri as IriValidation>::new(txt).map(GraphNameProxy::Iri)
+    }
+
+    fn new_bnode(txt: impl Into<Cow<'a, str>>) -> Option<Self> {
+        <BnodeId as BnodeIdValidation>::new(txt)
+            .map(|bnid| GraphNameProxy::BlankNode(bnid.unwrap()))
+    }
+}
+
+/// Extension trait for [`ObjectProxy`] providing validating constructors.
+///
+/// Triple terms are not covered, as their terms are already proxies;
+/// see [`validate_triple`](crate::validate_triple) for checking them.
+pub trait ObjectProxyValidation<'a>: Sized {
+    /// Return a new [`ObjectProxy::Iri`] if `txt` is a valid IRI, otherwise None.
+    fn new_iri(txt: impl Into<Cow<'a, str>>) -> Option<Self>;
+
+    /// Return a new [`ObjectProxy::BlankNode`] if `txt` is a valid blank node label
+    /// (see [`BnodeIdValidation`]), otherwise None.
+    fn new_bnode(txt: impl Into<Cow<'a, str>>) -> Option<Self>;
+
+    /// Return a new [`ObjectProxy::Literal`] if `literal` is well-formed, otherwise None.
+    ///
+    /// A literal is well-formed if its datatype is a valid IRI
+    /// (other than `rdf:langString` and `rdf:dirLangString`, which require a language tag),
+    /// or if its language tag is valid.
+    /// This is useful for literals built piecewise with `new_unchecked`;
+    /// see also [`LiteralValidation`](crate::LiteralValidation) for building valid literals directly.
+    fn new_literal(literal: Literal<'a>) -> Option<Self>;
+}
+
+impl<'a, T: Triple> ObjectProxyValidation<'a> for ObjectProxy<'a, T> {
+    fn new_iri(txt: impl Into<Cow<'a, str>>) -> Option<Self> {
+        <Iri as IriValidation>::new(txt).map(ObjectProxy::Iri)
+    }
+
+    fn new_bnode(txt: impl Into<Cow<'a, str>>) -> Option<Self> {
+        <BnodeId as BnodeIdValidation>::new(txt).map(|bnid| ObjectProxy::BlankNode(bnid.unwrap()))
+    }
+
+    fn new_literal(literal: Literal<'a>) -> Option<Self> {
+        let valid = match &literal {
+            Literal::Typed(_, dt) => {
+                IRI_REGEX.is_match(dt.as_str())
+                    && *dt != rdf::LANG_STRING
+                    && *dt != rdf::DIR_LANG_STRING
+            }
+            Literal::LanguageString(_, tag, _) => TAG_REGEX.is_match(tag.as_ref()),
+        };
+        valid.then_some(ObjectProxy::Literal(literal))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use r2c2_statement::{BaseDir, LangTag, SimpleTriple};
+
+    use super::*;
+
+    type Object<'a> = ObjectProxy<'a, SimpleTriple<'a>>;
+
+    const IRI: &str = "https://example.org/ns/s";
+    const BAD_IRIS: &[&str] = &["", "not an IRI", "https://example.org/a b", "#frag"];
+    const BAD_LABELS: &[&str] = &["", "a:b", "-a", "a.", "a b"];
+
+    #[test]
+    fn subject() {
+        assert_eq!(
+            SubjectProxy::new_iri(IRI),
+            Some(SubjectProxy::Iri(Iri::new_unchecked(IRI)))
+        );
+        assert_eq!(
+            SubjectProxy::new_bnode(String::from("b1")),
+            Some(SubjectProxy::BlankNode("b1".into()))
+        );
+        for txt in BAD_IRIS {
+            assert_eq!(SubjectProxy::new_iri(*txt), None, "{txt}");
+        }
+        for txt in BAD_LABELS {
+            assert_eq!(SubjectProxy::new_bnode(*txt), None, "{txt}");
+        }
+    }
+
+    #[test]
+    fn graph_name() {
+        assert_eq!(
+            GraphNameProxy::new_iri(IRI),
+            Some(GraphNameProxy::Iri(Iri::new_unchecked(IRI)))
+        );
+        assert_eq!(
+            GraphNameProxy::new_bnode("g"),
+            Some(GraphNameProxy::BlankNode("g".into()))
+        );
+        for txt in BAD_IRIS {
+            assert_eq!(GraphNameProxy::new_iri(*txt), None, "{txt}");
+        }
+        for txt in BAD_LABELS {
+            assert_eq!(GraphNameProxy::new_bnode(*txt), None, "{txt}");
+        }
+    }
+
+    #[test]
+    fn object() {
+        assert_eq!(
+            Object::new_iri(IRI),
+            Some(ObjectProxy::Iri(Iri::new_unchecked(IRI)))
+        );
+        assert_eq!(
+            Object::new_bnode("o"),
+            Some(ObjectProxy::BlankNode("o".into()))
+        );
+        for txt in BAD_IRIS {
+            assert_eq!(Object::new_iri(*txt), None, "{txt}");
+        }
+        for txt in BAD_LABELS {
+            assert_eq!(Object::new_bnode(*txt), None, "{txt}");
+        }
+
+        let valid = [
+            Literal::Typed(
+                "42".into(),
+                Iri::new_unchecked("http://www.w3.org/2001/XMLSchema#integer"),
+            ),
+            Literal::LanguageString("chat".into(), LangTag::new_unchecked("en-GB"), None),
+            Literal::LanguageString(
+                "chat".into(),
+                LangTag::new_unchecked("fr"),
+                Some(BaseDir::Rtl),
+            ),
+        ];
+        for literal in valid {
+            assert_eq!(
+                Object::new_literal(literal.clone()),
+                Some(ObjectProxy::Literal(literal))
+            );
+        }
+        let invalid = [
+            Literal::Typed("42".into(), Iri::new_unchecked("not an IRI")),
+            Literal::Typed("chat".into(), rdf::LANG_STRING),
+            Literal::Typed("chat".into(), rdf::DIR_LANG_STRING),
+            Literal::LanguageString("chat".into(), LangTag::new_unchecked("en_GB"), None),
+            Literal::LanguageString(
+                "chat".into(),
+                LangTag::new_unchecked(""),
+                Some(BaseDir::Ltr),
+            ),
+        ];
+        for literal in invalid {
+            assert_eq!(Object::new_literal(literal.clone()), None, "{literal:?}");
+        }
+    }
+}
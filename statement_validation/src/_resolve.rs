@@ -0,0 +1,456 @@
+use std::borrow::Cow;
+
+use r2c2_statement::Iri;
+
+use crate::{IRI_REGEX, pct_encode_path_segment};
+
+/// Resolve an IRI `reference` against `base`,
+/// following the algorithm of [RFC 3986 §5.2](https://www.rfc-editor.org/rfc/rfc3986.html#section-5.2)
+/// (in strict mode).
+///
+/// The reference is not validated.
+/// If `base` is a valid IRI and `reference` a valid IRI reference, the result is a valid IRI.
+///
+/// ```
+/// # use r2c2_statement::Iri;
+/// # use r2c2_statement_validation::*;
+/// let base = Iri::new_unchecked("http://example.org/a/b?c");
+/// assert_eq!(resolve(&base, "../d#e"), "http://example.org/d#e");
+/// assert_eq!(resolve(&base, "?f"), "http://example.org/a/b?f");
+/// ```
+pub fn resolve(base: &Iri<'_>, reference: &str) -> Iri<'static> {
+    let b = Parts::new(base.as_str());
+    let r = Parts::new(reference);
+    let t = if r.scheme.is_some() {
+        Parts {
+            path: &remove_dot_segments(r.path),
+            ..r
+        }
+    } else if r.authority.is_some() {
+        Parts {
+            scheme: b.scheme,
+            path: &remove_dot_segments(r.path),
+            ..r
+        }
+    } else if r.path.is_empty() {
+        Parts {
+            query: r.query.or(b.query),
+            fragment: r.fragment,
+            ..b
+        }
+    } else if r.path.starts_with('/') {
+        Parts {
+            scheme: b.scheme,
+            authority: b.authority,
+            path: &remove_dot_segments(r.path),
+            ..r
+        }
+    } else {
+        Parts {
+            scheme: b.scheme,
+            authority: b.authority,
+            path: &remove_dot_segments(&format!("{}{}", b.directory(), r.path)),
+            ..r
+        }
+    };
+    Iri::new_unchecked(t.to_string())
+}
+
+/// Express `target` as a reference relative to `base`,
+/// i.e. the inverse of [`resolve`].
+///
+/// Return the shortest reference `r` such that `resolve(base, r) == target`,
+/// or `None` if `base` and `target` differ in their scheme or authority
+/// (or, in some rare cases, if no such reference exists,
+/// e.g. when the path of `target` contains `.` or `..` segments,
+/// or when it is a rootless path that can not be reached from the path of `base`).
+///
+/// The result is borrowed from `target` when possible (e.g. when only a fragment is needed).
+///
+/// ```
+/// # use r2c2_statement::Iri;
+/// # use r2c2_statement_validation::*;
+/// let base = Iri::new_unchecked("http://example.org/dir/sub/");
+/// let target = Iri::new_unchecked("http://example.org/dir/sub/a");
+/// assert_eq!(relativize(&base, &target).unwrap(), "a");
+/// let target = Iri::new_unchecked("http://example.org/dir/other#b");
+/// assert_eq!(relativize(&base, &target).unwrap(), "../other#b");
+/// let target = Iri::new_unchecked("https://example.org/dir/sub/a");
+/// assert_eq!(relativize(&base, &target), None);
+/// ```
+pub fn relativize<'a>(base: &Iri<'_>, target: &'a Iri<'_>) -> Option<Cow<'a, str>> {
+    let b = Parts::new(base.as_str());
+    let t = Parts::new(target.as_str());
+    if b.scheme != t.scheme || b.authority != t.authority {
+        return None;
+    }
+    let suffix = |query: bool| {
+        let mut txt = String::new();
+        if let (true, Some(q)) = (query, t.query) {
+            txt.push('?');
+            txt.push_str(q);
+        }
+        if let Some(f) = t.fragment {
+            txt.push('#');
+            txt.push_str(f);
+        }
+        txt
+    };
+
+    let mut candidates = vec![];
+    if t.path == b.path {
+        if t.query == b.query {
+            candidates.push(suffix(false));
+        } else if t.query.is_some() {
+            candidates.push(suffix(true));
+        }
+    }
+    if let Some(path) = relative_path(b.directory(), t.path) {
+        candidates.push(path + &suffix(true));
+    }
+    if t.path.starts_with('/') && !t.path.starts_with("//") {
+        candidates.push(format!("{}{}", t.path, suffix(true)));
+    }
+    if let Some(authority) = t.authority {
+        candidates.push(format!("//{authority}{}{}", t.path, suffix(true)));
+    }
+
+    candidates
+        .into_iter()
+        .filter(|r| resolve(base, r) == *target)
+        .min_by_key(String::len)
+        .map(|r| match target.as_str().strip_suffix(r.as_str()) {
+            Some(prefix) => Cow::Borrowed(&target.as_str()[prefix.len()..]),
+            None => Cow::Owned(r),
+        })
+}
+
+/// Append `segment` to the path of `base`, dropping its query and fragment
+/// (see [`IriValidation::join`](crate::IriValidation::join)).
+pub(crate) fn join(base: &str, segment: &str) -> Option<Iri<'static>> {
+    if segment.is_empty() || segment == "." || segment == ".." {
+        return None;
+    }
+    let b = Parts::new(base);
+    if b.scheme.is_none() || (b.authority.is_none() && !b.path.starts_with('/')) {
+        return None;
+    }
+    let sep = if b.path.ends_with('/') { "" } else { "/" };
+    let path = format!("{}{sep}{}", b.path, pct_encode_path_segment(segment));
+    let joined = Parts {
+        path: &path,
+        query: None,
+        fragment: None,
+        ..b
+    }
+    .to_string();
+    IRI_REGEX
+        .is_match(&joined)
+        .then(|| Iri::new_unchecked(joined))
+}
+
+/// The components of an IRI reference,
+/// as split by the regular expression of [RFC 3986 Appendix B](https://www.rfc-editor.org/rfc/rfc3986.html#appendix-B).
+#[derive(Clone, Copy, Debug)]
+struct Parts<'a> {
+    scheme: Option<&'a str>,
+    authority: Option<&'a str>,
+    path: &'a str,
+    query: Option<&'a str>,
+    fragment: Option<&'a str>,
+}
+
+impl<'a> Parts<'a> {
+    fn new(txt: &'a str) -> Self {
+        let (txt, fragment) = match txt.split_once('#') {
+            Some((txt, fragment)) => (txt, Some(fragment)),
+            None => (txt, None),
+        };
+        let (txt, query) = match txt.split_once('?') {
+            Some((txt, query)) => (txt, Some(query)),
+            None => (txt, None),
+        };
+        let (scheme, txt) = match txt.find([':', '/']) {
+            Some(i) if i > 0 && txt[i..].starts_with(':') => (Some(&txt[..i]), &txt[i + 1..]),
+            _ => (None, txt),
+        };
+        let (authority, path) = match txt.strip_prefix("//") {
+            Some(txt) => {
+                let i = txt.find('/').unwrap_or(txt.len());
+                (Some(&txt[..i]), &txt[i..])
+            }
+            None => (None, txt),
+        };
+        Parts {
+            scheme,
+            authority,
+            path,
+            query,
+            fragment,
+        }
+    }
+
+    /// The path against which relative paths are merged
+    /// (see [RFC 3986 §5.2.3](https://www.rfc-editor.org/rfc/rfc3986.html#section-5.2.3)).
+    fn directory(&self) -> &'a str {
+        if self.authority.is_some() && self.path.is_empty() {
+            "/"
+        } else {
+            &self.path[..self.path.rfind('/').map_or(0, |i| i + 1)]
+        }
+    }
+}
+
+impl std::fmt::Display for Parts<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(scheme) = self.scheme {
+            write!(f, "{scheme}:")?;
+        }
+        if let Some(authority) = self.authority {
+            write!(f, "//{authority}")?;
+        }
+        write!(f, "{}", self.path)?;
+        if let Some(query) = self.query {
+            write!(f, "?{query}")?;
+        }
+        if let Some(fragment) = self.fragment {
+            write!(f, "#{fragment}")?;
+        }
+        Ok(())
+    }
+}
+
+/// See [RFC 3986 §5.2.4](https://www.rfc-editor.org/rfc/rfc3986.html#section-5.2.4).
+fn remove_dot_segments(mut input: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+    let pop = |output: &mut String| output.truncate(output.rfind('/').unwrap_or(0));
+    while !input.is_empty() {
+        if let Some(rest) = input.strip_prefix("../") {
+            input = rest;
+        } else if let Some(rest) = input.strip_prefix("./") {
+            input = rest;
+        } else if input.starts_with("/./") {
+            input = &input[2..];
+        } else if input == "/." {
+            input = "/";
+        } else if input.starts_with("/../") {
+            input = &input[3..];
+            pop(&mut output);
+        } else if input == "/.." {
+            input = "/";
+            pop(&mut output);
+        } else if input == "." || input == ".." {
+            input = "";
+        } else {
+            let start = usize::from(input.starts_with('/'));
+            let end = input[start..].find('/').map_or(input.len(), |i| i + start);
+            output.push_str(&input[..end]);
+            input = &input[end..];
+        }
+    }
+    output
+}
+
+/// A relative path leading from `directory` (ending with `/`, or empty) to `path`.
+///
+/// The result may not resolve to `path` if either contains dot segments,
+/// hence the check in [`relativize`].
+fn relative_path(directory: &str, path: &str) -> Option<String> {
+    let common = directory
+        .bytes()
+        .zip(path.bytes())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let common = directory[..common].rfind('/').map_or(0, |i| i + 1);
+    if common == 0 && directory.starts_with('/') {
+        return None;
+    }
+    let ups = directory[common..].matches('/').count();
+    let rest = &path[common..];
+    if rest.is_empty() {
+        return Some(match ups {
+            0 => ".".into(),
+            _ => "../".repeat(ups - 1) + "..",
+        });
+    }
+    let mut relative = "../".repeat(ups);
+    let first_segment = rest.split('/').next().unwrap_or_default();
+    if ups == 0 && (rest.starts_with('/') || first_segment.contains(':')) {
+        relative.push_str("./");
+    }
+    relative.push_str(rest);
+    Some(relative)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// From [RFC 3986 §5.4](https://www.rfc-editor.org/rfc/rfc3986.html#section-5.4).
+    const EXAMPLES: &[[&str; 2]] = &[
+        ["g:h", "g:h"],
+        ["g", "http://a/b/c/g"],
+        ["./g", "http://a/b/c/g"],
+        ["g/", "http://a/b/c/g/"],
+        ["/g", "http://a/g"],
+        ["//g", "http://g"],
+        ["?y", "http://a/b/c/d;p?y"],
+        ["g?y", "http://a/b/c/g?y"],
+        ["#s", "http://a/b/c/d;p?q#s"],
+        ["g#s", "http://a/b/c/g#s"],
+        ["g?y#s", "http://a/b/c/g?y#s"],
+        [";x", "http://a/b/c/;x"],
+        ["g;x", "http://a/b/c/g;x"],
+        ["g;x?y#s", "http://a/b/c/g;x?y#s"],
+        ["", "http://a/b/c/d;p?q"],
+        [".", "http://a/b/c/"],
+        ["./", "http://a/b/c/"],
+        ["..", "http://a/b/"],
+        ["../", "http://a/b/"],
+        ["../g", "http://a/b/g"],
+        ["../..", "http://a/"],
+        ["../../", "http://a/"],
+        ["../../g", "http://a/g"],
+        ["../../../g", "http://a/g"],
+        ["../../../../g", "http://a/g"],
+        ["/./g", "http://a/g"],
+        ["/../g", "http://a/g"],
+        ["g.", "http://a/b/c/g."],
+        [".g", "http://a/b/c/.g"],
+        ["g..", "http://a/b/c/g.."],
+        ["..g", "http://a/b/c/..g"],
+        ["./../g", "http://a/b/g"],
+        ["./g/.", "http://a/b/c/g/"],
+        ["g/./h", "http://a/b/c/g/h"],
+        ["g/../h", "http://a/b/c/h"],
+        ["g;x=1/./y", "http://a/b/c/g;x=1/y"],
+        ["g;x=1/../y", "http://a/b/c/y"],
+        ["g?y/./x", "http://a/b/c/g?y/./x"],
+        ["g?y/../x", "http://a/b/c/g?y/../x"],
+        ["g#s/./x", "http://a/b/c/g#s/./x"],
+        ["g#s/../x", "http://a/b/c/g#s/../x"],
+        ["http:g", "http:g"],
+    ];
+
+    #[test]
+    fn resolve_rfc_examples() {
+        let base = Iri::new_unchecked("http://a/b/c/d;p?q");
+        for [reference, expected] in EXAMPLES {
+            assert_eq!(resolve(&base, reference), *expected, "{reference}");
+        }
+        // rootless paths starting with a non-ASCII character
+        for (base, reference, expected) in [
+            ("urn:ex", "é", "urn:é"),
+            ("urn:é", "", "urn:é"),
+            ("urn:é", "#f", "urn:é#f"),
+            ("tag:é/x", "y", "tag:é/y"),
+            ("http://a/b/", "mailto:élise@ex.org", "mailto:élise@ex.org"),
+            ("http://a/b/", "mailto:é@x", "mailto:é@x"),
+            ("http://a/b/", "urn:é/./x", "urn:é/x"),
+        ] {
+            let base = Iri::new_unchecked(base);
+            assert_eq!(
+                resolve(&base, reference),
+                expected,
+                "<{base}> + <{reference}>"
+            );
+        }
+    }
+
+    #[test]
+    fn relativize_shortest() {
+        let base = Iri::new_unchecked("http://a/b/c/d;p?q");
+        for (target, expected) in [
+            ("http://a/b/c/d;p?q", ""),
+            ("http://a/b/c/d;p?q#s", "#s"),
+            ("http://a/b/c/d;p?y", "?y"),
+            ("http://a/b/c/d;p", "d;p"),
+            ("http://a/b/c/g", "g"),
+            ("http://a/b/c/", "."),
+            ("http://a/b/", ".."),
+            ("http://a/b/g?y#s", "../g?y#s"),
+            ("http://a/g", "/g"),
+            ("http://a/b/c/g:h", "./g:h"),
+            ("http://a/b/c//g", ".//g"),
+        ] {
+            let target = Iri::new_unchecked(target);
+            assert_eq!(relativize(&base, &target).as_deref(), Some(expected));
+        }
+        for target in ["https://a/b/c/g", "http://b/b/c/g", "urn:x"] {
+            assert_eq!(relativize(&base, &Iri::new_unchecked(target)), None);
+        }
+    }
+
+    #[test]
+    fn relativize_borrowed() {
+        let base = Iri::new_unchecked("http://a/b/c/d");
+        let target = Iri::new_unchecked("http://a/b/c/g#s".to_string());
+        assert!(matches!(
+            relativize(&base, &target),
+            Some(Cow::Borrowed("g#s"))
+        ));
+        let target = Iri::new_unchecked("http://a/b/g");
+        assert!(matches!(relativize(&base, &target), Some(Cow::Owned(_))));
+    }
+
+    #[test]
+    fn round_trip() {
+        let iris = [
+            "http://a",
+            "http://a/",
+            "http://a?q",
+            "http://a/#f",
+            "http://a/b",
+            "http://a/b/",
+            "http://a/b/c/d;p?q",
+            "http://a/b/c/d;p?q#f",
+            "http://a/b/c/d;p#f",
+            "http://a/b/c/d;p?",
+            "http://a/b/c/d;p#",
+            "http://a/b/c/",
+            "http://a/b/c//",
+            "http://a/b//c",
+            "http://a/b/c/g:h",
+            "http://a/x/y/z",
+            "http://a/b/../c",
+            "http://a/b/./c/",
+            "http://a/./b/c",
+            "http://a:80/b",
+            "http://b/b/c",
+            "https://a/b/c",
+            "urn:isbn:123",
+            "urn:isbn:123?q#f",
+            "urn:x/y/z",
+            "urn:x/y/",
+            "urn:",
+            "urn:/x",
+            "file:///a/b",
+            "file:///a/",
+            "urn:é",
+            "mailto:é@x",
+            "tag:é/x",
+        ];
+        let mut relativized = 0;
+        for base in iris {
+            let base = Iri::new_unchecked(base);
+            for target in iris {
+                let target = Iri::new_unchecked(target);
+                if let Some(relative) = relativize(&base, &target) {
+                    relativized += 1;
+                    assert_eq!(resolve(&base, &relative), target, "<{base}> + <{relative}>");
+                } else {
+                    let b = Parts::new(base.as_str());
+                    let t = Parts::new(target.as_str());
+                    assert!(
+                        b.scheme != t.scheme
+                            || b.authority != t.authority
+                            || remove_dot_segments(t.path) != t.path
+                            || !t.path.starts_with('/'),
+                        "<{base}> / <{target}>"
+                    );
+                }
+            }
+        }
+        assert!(relativized > iris.len() * 4);
+    }
+}
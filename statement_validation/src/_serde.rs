@@ -0,0 +1,167 @@
+use r2c2_statement::{GraphNameProxy, Literal, ObjectProxy, SimpleTriple, SubjectProxy};
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+
+use crate::{validate_graph_name_term, validate_object_term};
+
+/// A term whose [`Deserialize`] implementation checks that it is well-formed.
+///
+/// The `Deserialize` implementations provided by the `serde` feature of [`r2c2_statement`]
+/// accept any IRI, blank node identifier or language tag.
+/// Deserializing `Validated<T>` instead (for `T` one of [`SubjectProxy`], [`GraphNameProxy`],
+/// [`Literal`] or [`ObjectProxy`]) rejects ill-formed terms with an error,
+/// applying the same checks as [`validate_triple`](crate::validate_triple)
+/// (including in nested triple terms).
+///
+/// `Validated<T>` is serialized exactly as `T`.
+///
+/// ```
+/// # use r2c2_statement::SubjectProxy;
+/// # use r2c2_statement_validation::Validated;
+/// let json = r#"{"kind":"iri","value":"https://example.org/s"}"#;
+/// let Validated(s): Validated<SubjectProxy> = serde_json::from_str(json).unwrap();
+/// assert!(matches!(s, SubjectProxy::Iri(_)));
+///
+/// let json = r#"{"kind":"iri","value":"not an IRI"}"#;
+/// assert!(serde_json::from_str::<Validated<SubjectProxy>>(json).is_err());
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Validated<T>(pub T);
+
+impl<T> Validated<T> {
+    /// Unwrap the validated term.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: Serialize> Serialize for Validated<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de, 'a> Deserialize<'de> for Validated<GraphNameProxy<'a>> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let term = GraphNameProxy::deserialize(deserializer)?;
+        validate_graph_name_term(&term).map_err(|err| de::Error::custom(err.term))?;
+        Ok(Validated(term))
+    }
+}
+
+macro_rules! impl_deserialize_for_object {
+    ($($ty:ty),*) => {
+        $(
+            impl<'de, 'a> Deserialize<'de> for Validated<$ty> {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let term = <$ty>::deserialize(deserializer)?;
+                    validate_object_term(&term).map_err(|err| de::Error::custom(err.term))?;
+                    Ok(Validated(term))
+                }
+            }
+        )*
+    };
+}
+
+impl_deserialize_for_object!(
+    SubjectProxy<'a>,
+    Literal<'a>,
+    ObjectProxy<'a, Box<SimpleTriple<'a>>>,
+    ObjectProxy<'a, SimpleTriple<'a>>
+);
+
+#[cfg(test)]
+mod test {
+    use r2c2_statement::{Iri, LangTag, ns::xsd};
+
+    use super::*;
+
+    type Obj<'a> = ObjectProxy<'a, SimpleTriple<'a>>;
+
+    fn iri(txt: &str) -> Iri<'_> {
+        Iri::new_unchecked(txt)
+    }
+
+    fn error<T>(json: &str) -> String
+    where
+        Validated<T>: de::DeserializeOwned + std::fmt::Debug,
+    {
+        serde_json::from_str::<Validated<T>>(json)
+            .unwrap_err()
+            .to_string()
+    }
+
+    #[test]
+    fn valid() {
+        let s = SubjectProxy::BlankNode("b1".into());
+        let json = serde_json::to_string(&Validated(s.clone())).unwrap();
+        assert_eq!(json, serde_json::to_string(&s).unwrap());
+        let back: Validated<SubjectProxy> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.into_inner(), s);
+
+        let g = GraphNameProxy::Iri(iri("https://example.org/g"));
+        let json = serde_json::to_string(&g).unwrap();
+        let back: Validated<GraphNameProxy> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.0, g);
+
+        let lit = Literal::LanguageString("chat".into(), LangTag::new_unchecked("fr-BE"), None);
+        let json = serde_json::to_string(&lit).unwrap();
+        let back: Validated<Literal> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.0, lit);
+
+        let o: Obj = ObjectProxy::Triple(SimpleTriple {
+            subject: SubjectProxy::Iri(iri("https://example.org/s")),
+            predicate: iri("https://example.org/p"),
+            object: ObjectProxy::Literal(Literal::Typed("42".into(), xsd::INTEGER)),
+        });
+        let json = serde_json::to_string(&o).unwrap();
+        let back: Validated<Obj> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.0, o);
+    }
+
+    #[test]
+    fn invalid_iri() {
+        let json = r#"{"kind":"iri","value":"https://example.org/a b"}"#;
+        let msg = error::<SubjectProxy>(json);
+        assert_eq!(msg, r#"invalid IRI "https://example.org/a b""#);
+        assert!(error::<GraphNameProxy>(json).starts_with("invalid IRI"));
+        assert!(error::<Obj>(json).starts_with("invalid IRI"));
+        // relative IRIs are not valid terms either
+        assert!(error::<Obj>(r#"{"kind":"iri","value":"s"}"#).starts_with("invalid IRI"));
+    }
+
+    #[test]
+    fn invalid_language_tag() {
+        let json = r#"{"kind":"literal","value":"colour","language":"en_GB"}"#;
+        assert_eq!(error::<Literal>(json), r#"invalid language tag "en_GB""#);
+        assert!(error::<Obj>(json).starts_with("invalid language tag"));
+    }
+
+    #[test]
+    fn invalid_bnode_label() {
+        let json = r#"{"kind":"bnode","value":"a b"}"#;
+        assert_eq!(
+            error::<SubjectProxy>(json),
+            r#"invalid blank node label "a b""#
+        );
+        assert!(error::<GraphNameProxy>(json).starts_with("invalid blank node label"));
+        assert!(error::<Obj>(json).starts_with("invalid blank node label"));
+    }
+
+    #[test]
+    fn invalid_in_triple_term() {
+        let json = concat!(
+            r#"{"kind":"triple","subject":{"kind":"iri","value":"https://example.org/s"},"#,
+            r#""predicate":"https://example.org/p","object":{"kind":"triple","#,
+            r#""subject":{"kind":"bnode","value":"b"},"predicate":"https://example.org/q","#,
+            r#""object":{"kind":"literal","value":"x","datatype":"not a datatype"}}}"#,
+        );
+        assert!(error::<Obj>(json).starts_with("invalid datatype IRI"));
+        assert!(error::<ObjectProxy<Box<SimpleTriple>>>(json).starts_with("invalid datatype IRI"));
+
+        let json = concat!(
+            r#"{"kind":"triple","subject":{"kind":"iri","value":"https://example.org/s"},"#,
+            r#""predicate":"p","object":{"kind":"bnode","value":"b"}}"#,
+        );
+        assert!(error::<Obj>(json).starts_with("invalid IRI"));
+    }
+}
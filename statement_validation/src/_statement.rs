@@ -0,0 +1,290 @@
+use r2c2_statement::{
+    GraphName, GraphNameProxy, Iri, Literal, Object, ObjectProxy, Predicate, Quad, Subject,
+    SubjectProxy, Triple, is_valid_bnode_label,
+};
+
+use crate::{IRI_REGEX, TAG_REGEX};
+
+/// Check that every term of `triple` (including in nested triple terms) is well-formed:
+/// * IRIs (including datatype IRIs) must match [`IRI_REGEX_SRC`](crate::IRI_REGEX_SRC),
+/// * language tags must match [`TAG_REGEX_SRC`](crate::TAG_REGEX_SRC),
+/// * blank node labels must be [valid](is_valid_bnode_label).
+///
+/// This is useful for checking statements whose terms were built with `new_unchecked`.
+pub fn validate_triple<T: Triple>(triple: &T) -> Result<(), StatementError> {
+    let mut path = Vec::new();
+    validate_triple_at(triple, &mut path)
+}
+
+/// Check that every term of `quad` (including in nested triple terms) is well-formed.
+///
+/// See [`validate_triple`].
+pub fn validate_quad<Q: Quad>(quad: &Q) -> Result<(), StatementError> {
+    let mut path = Vec::new();
+    validate_subject(&quad.subject(), &mut path)?;
+    validate_predicate(&quad.predicate(), &mut path)?;
+    validate_object(&quad.object(), &mut path)?;
+    if let Some(graph_name) = quad.graph_name() {
+        path.push(Position::GraphName);
+        match graph_name.as_graph_name_proxy() {
+            GraphNameProxy::Iri(iri) => check_iri(&iri, InvalidTerm::Iri, &path)?,
+            GraphNameProxy::BlankNode(bnid) => check_bnode(&bnid, &path)?,
+        }
+        path.pop();
+    }
+    Ok(())
+}
+
+/// The error returned by [`validate_triple`] and [`validate_quad`].
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct StatementError {
+    /// The position of the invalid term in the statement.
+    ///
+    /// For terms nested in triple terms, this contains several positions, from the outermost to the innermost
+    /// (e.g. `[Object, Subject]` for the subject of the triple term in object position).
+    pub path: Vec<Position>,
+    /// The invalid term.
+    pub term: InvalidTerm,
+}
+
+/// A position in a statement, used in [`StatementError`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Position {
+    /// The [subject](https://www.w3.org/TR/rdf12-concepts/#dfn-subject)
+    Subject,
+    /// The [predicate](https://www.w3.org/TR/rdf12-concepts/#dfn-predicate)
+    Predicate,
+    /// The [object](https://www.w3.org/TR/rdf12-concepts/#dfn-object)
+    Object,
+    /// The [graph name](https://www.w3.org/TR/rdf12-concepts/#dfn-graph-name)
+    GraphName,
+}
+
+/// An ill-formed term, used in [`StatementError`].
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum InvalidTerm {
+    /// An invalid IRI
+    Iri(String),
+    /// An invalid datatype IRI in a literal
+    Datatype(String),
+    /// An invalid language tag in a literal
+    LangTag(String),
+    /// An invalid blank node label
+    BlankNode(String),
+}
+
+impl std::fmt::Display for InvalidTerm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidTerm::Iri(txt) => write!(f, "invalid IRI {txt:?}"),
+            InvalidTerm::Datatype(txt) => write!(f, "invalid datatype IRI {txt:?}"),
+            InvalidTerm::LangTag(txt) => write!(f, "invalid language tag {txt:?}"),
+            InvalidTerm::BlankNode(txt) => write!(f, "invalid blank node label {txt:?}"),
+        }
+    }
+}
+
+impl std::fmt::Display for StatementError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} in ", self.term)?;
+        for (i, position) in self.path.iter().rev().enumerate() {
+            if i > 0 {
+                write!(f, " of triple term in ")?;
+            }
+            match position {
+                Position::Subject => write!(f, "subject")?,
+                Position::Predicate => write!(f, "predicate")?,
+                Position::Object => write!(f, "object")?,
+                Position::GraphName => write!(f, "graph name")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for StatementError {}
+
+/// Check that `object` (including its nested triple terms, if any) is well-formed.
+#[cfg(feature = "serde")]
+pub(crate) fn validate_object_term<O: Object>(object: &O) -> Result<(), StatementError> {
+    validate_object(object, &mut Vec::new())
+}
+
+/// Check that `graph_name` is well-formed.
+#[cfg(feature = "serde")]
+pub(crate) fn validate_graph_name_term<G: GraphName>(graph_name: &G) -> Result<(), StatementError> {
+    let path = [Position::GraphName];
+    match graph_name.as_graph_name_proxy() {
+        GraphNameProxy::Iri(iri) => check_iri(&iri, InvalidTerm::Iri, &path),
+        GraphNameProxy::BlankNode(bnid) => check_bnode(&bnid, &path),
+    }
+}
+
+fn validate_triple_at<T: Triple>(
+    triple: &T,
+    path: &mut Vec<Position>,
+) -> Result<(), StatementError> {
+    validate_subject(&triple.subject(), path)?;
+    validate_predicate(&triple.predicate(), path)?;
+    validate_object(&triple.object(), path)
+}
+
+fn validate_subject<S: Subject>(
+    subject: &S,
+    path: &mut Vec<Position>,
+) -> Result<(), StatementError> {
+    path.push(Position::Subject);
+    match subject.as_subject_proxy() {
+        SubjectProxy::Iri(iri) => check_iri(&iri, InvalidTerm::Iri, path)?,
+        SubjectProxy::BlankNode(bnid) => check_bnode(&bnid, path)?,
+    }
+    path.pop();
+    Ok(())
+}
+
+fn validate_predicate<P: Predicate>(
+    predicate: &P,
+    path: &mut Vec<Position>,
+) -> Result<(), StatementError> {
+    path.push(Position::Predicate);
+    check_iri(&predicate.as_iri(), InvalidTerm::Iri, path)?;
+    path.pop();
+    Ok(())
+}
+
+fn validate_object<O: Object>(object: &O, path: &mut Vec<Position>) -> Result<(), StatementError> {
+    path.push(Position::Object);
+    match object.as_object_proxy() {
+        ObjectProxy::Iri(iri) => check_iri(&iri, InvalidTerm::Iri, path)?,
+        ObjectProxy::BlankNode(bnid) => check_bnode(&bnid, path)?,
+        ObjectProxy::Literal(literal) => {
+            check_iri(&literal.datatype_iri(), InvalidTerm::Datatype, path)?;
+            if let Literal::LanguageString(_, tag, _) = &literal
+                && !TAG_REGEX.is_match(tag)
+            {
+                return Err(error(path, InvalidTerm::LangTag(tag.to_string())));
+            }
+        }
+        ObjectProxy::Triple(triple) => validate_triple_at(&triple, path)?,
+    }
+    path.pop();
+    Ok(())
+}
+
+fn check_iri(
+    iri: &Iri,
+    term: fn(String) -> InvalidTerm,
+    path: &[Position],
+) -> Result<(), StatementError> {
+    if IRI_REGEX.is_match(iri) {
+        Ok(())
+    } else {
+        Err(error(path, term(iri.as_str().to_string())))
+    }
+}
+
+fn check_bnode(bnid: &str, path: &[Position]) -> Result<(), StatementError> {
+    if is_valid_bnode_label(bnid) {
+        Ok(())
+    } else {
+        Err(error(path, InvalidTerm::BlankNode(bnid.to_string())))
+    }
+}
+
+fn error(path: &[Position], term: InvalidTerm) -> StatementError {
+    StatementError {
+        path: path.to_vec(),
+        term,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use r2c2_statement::{LangTag, SimpleQuad, SimpleTriple, quad, triple};
+
+    use super::*;
+
+    const EX: &str = "https://example.org/ns/";
+
+    #[test]
+    fn valid() {
+        assert_eq!(
+            validate_triple(&triple!(_:a, EX:p, "chat"@en-GB--rtl)),
+            Ok(())
+        );
+        assert_eq!(
+            validate_triple(
+                &triple!(EX:a, EX:p, <<( _:b, EX:p, "42"^^<"http://www.w3.org/2001/XMLSchema#integer"> )>>)
+            ),
+            Ok(())
+        );
+        assert_eq!(validate_quad(&quad!(EX:a, EX:p, EX:o, _:g)), Ok(()));
+        assert_eq!(validate_quad(&quad!(EX:a, EX:p, EX:o)), Ok(()));
+    }
+
+    #[test]
+    fn macro_heuristics() {
+        // accepted by the debug checks of the macros, but not well-formed
+        let t = triple!(_:a, <"https://example.org/%zz">, "chat");
+        assert_eq!(
+            validate_triple(&t).unwrap_err().term,
+            InvalidTerm::Iri("https://example.org/%zz".into())
+        );
+        let t = triple!(_:a, EX:p, "chat"@a-fr);
+        assert_eq!(
+            validate_triple(&t).unwrap_err().term,
+            InvalidTerm::LangTag("a-fr".into())
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        let bad_iri = || Iri::new_unchecked("not an IRI");
+        let mut t = triple!(_:a, EX:p, <<( _:b, EX:p, "chat"@en )>>);
+        t.predicate = bad_iri();
+        let err = validate_triple(&t).unwrap_err();
+        assert_eq!(err.path, [Position::Predicate]);
+        assert_eq!(err.term, InvalidTerm::Iri("not an IRI".into()));
+        assert_eq!(err.to_string(), r#"invalid IRI "not an IRI" in predicate"#);
+
+        let inner = |object: ObjectProxy<'static, Box<SimpleTriple<'static>>>| {
+            let mut t = triple!(_:a, EX:p, <<( _:b, EX:p, "chat"@en )>>);
+            let ObjectProxy::Triple(inner) = &mut t.object else {
+                unreachable!()
+            };
+            inner.object = object;
+            t
+        };
+        let err = validate_triple(&inner(ObjectProxy::Literal(Literal::LanguageString(
+            "chat".into(),
+            LangTag::new_unchecked("en_GB"),
+            None,
+        ))))
+        .unwrap_err();
+        assert_eq!(err.path, [Position::Object, Position::Object]);
+        assert_eq!(err.term, InvalidTerm::LangTag("en_GB".into()));
+        assert_eq!(
+            err.to_string(),
+            r#"invalid language tag "en_GB" in object of triple term in object"#
+        );
+        let err = validate_triple(&inner(ObjectProxy::Literal(Literal::Typed(
+            "chat".into(),
+            bad_iri(),
+        ))))
+        .unwrap_err();
+        assert_eq!(err.term, InvalidTerm::Datatype("not an IRI".into()));
+        let err = validate_triple(&inner(ObjectProxy::BlankNode("b c".into()))).unwrap_err();
+        assert_eq!(err.term, InvalidTerm::BlankNode("b c".into()));
+
+        let mut q = quad!(_:a, EX:p, EX:o, EX:g);
+        q.graph_name = Some(GraphNameProxy::BlankNode(".g".into()));
+        let err = validate_quad(&q).unwrap_err();
+        assert_eq!(err.path, [Position::GraphName]);
+        assert_eq!(err.term, InvalidTerm::BlankNode(".g".into()));
+        let q = SimpleQuad {
+            subject: SubjectProxy::Iri(bad_iri()),
+            ..q
+        };
+        assert_eq!(validate_quad(&q).unwrap_err().path, [Position::Subject]);
+    }
+}
@@ -1,8 +1,39 @@
 //! I extends the utility types of [`r2c2_statement`]
 //! with validating constructors, as a convenience for implementers.
+//!
+//! # Features
+//! * `langtag_registry`: include [`LangTagRegistryValidation`],
+//!   checking that the subtags of language tags are registered
+//!   (based on a snapshot of the ISO code lists embedded in this crate).
+//! * `serde`: include [`Validated`], whose `Deserialize` implementations
+//!   check the terms deserialized by the (unchecked) `serde` feature of [`r2c2_statement`].
 #![deny(missing_docs)]
 
+mod _bnode_id;
+pub use _bnode_id::*;
 mod _iri;
 pub use _iri::*;
+mod _iri_validator;
+pub use _iri_validator::*;
 mod _language_tag;
 pub use _language_tag::*;
+#[cfg(feature = "langtag_registry")]
+mod _langtag_registry;
+#[cfg(feature = "langtag_registry")]
+pub use _langtag_registry::*;
+mod _literal;
+pub use _literal::*;
+mod _pct_encode;
+pub use _pct_encode::*;
+mod _prefixed_name;
+pub use _prefixed_name::*;
+mod _proxy;
+pub use _proxy::*;
+mod _resolve;
+pub use _resolve::*;
+#[cfg(feature = "serde")]
+mod _serde;
+#[cfg(feature = "serde")]
+pub use _serde::*;
+mod _statement;
+pub use _statement::*;